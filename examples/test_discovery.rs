@@ -4,7 +4,7 @@ fn main() {
     println!("🔍 Testing Skill Discovery System\n");
     println!("=================================\n");
 
-    match discover_skills() {
+    match discover_skills(&[]) {
         Ok(skills) => {
             println!("✅ Discovered {} skill(s):\n", skills.len());
 
@@ -331,11 +331,11 @@ organize_artifacts() {
 
     if [ "$DRY_RUN" = true ]; then
         execute mkdir -p "$dist_dir/bin"
-        execute mkdir -p "$dist_dir/share/pane/skills/claude-tips/data"
+        execute mkdir -p "$dist_dir/share/pane/builtin-skills/claude-tips/data"
         execute cp "$binary_dir/pane" "$dist_dir/bin/"
         execute cp "$binary_dir/claude-tips" "$dist_dir/bin/"
-        execute cp "skills/claude-tips/pane-skill.yaml" "$dist_dir/share/pane/skills/claude-tips/"
-        execute cp "skills/claude-tips/data/claude-tips.yaml" "$dist_dir/share/pane/skills/claude-tips/data/"
+        execute cp "skills/claude-tips/pane-skill.yaml" "$dist_dir/share/pane/builtin-skills/claude-tips/"
+        execute cp "skills/claude-tips/data/claude-tips.yaml" "$dist_dir/share/pane/builtin-skills/claude-tips/data/"
         return 0
     fi
 
@@ -344,8 +344,8 @@ organize_artifacts() {
         error "Failed to create directory: $dist_dir/bin"
     fi
 
-    if ! mkdir -p "$dist_dir/share/pane/skills/claude-tips/data"; then
-        error "Failed to create directory: $dist_dir/share/pane/skills/claude-tips/data"
+    if ! mkdir -p "$dist_dir/share/pane/builtin-skills/claude-tips/data"; then
+        error "Failed to create directory: $dist_dir/share/pane/builtin-skills/claude-tips/data"
     fi
 
     # Copy binaries
@@ -360,15 +360,15 @@ organize_artifacts() {
     info "✓ Copied claude-tips to $dist_dir/bin"
 
     # Copy skill files
-    if ! cp "skills/claude-tips/pane-skill.yaml" "$dist_dir/share/pane/skills/claude-tips/"; then
-        error "Failed to copy pane-skill.yaml to $dist_dir/share/pane/skills/claude-tips/"
+    if ! cp "skills/claude-tips/pane-skill.yaml" "$dist_dir/share/pane/builtin-skills/claude-tips/"; then
+        error "Failed to copy pane-skill.yaml to $dist_dir/share/pane/builtin-skills/claude-tips/"
     fi
-    info "✓ Copied pane-skill.yaml to $dist_dir/share/pane/skills/claude-tips"
+    info "✓ Copied pane-skill.yaml to $dist_dir/share/pane/builtin-skills/claude-tips"
 
-    if ! cp "skills/claude-tips/data/claude-tips.yaml" "$dist_dir/share/pane/skills/claude-tips/data/"; then
-        error "Failed to copy claude-tips.yaml to $dist_dir/share/pane/skills/claude-tips/data/"
+    if ! cp "skills/claude-tips/data/claude-tips.yaml" "$dist_dir/share/pane/builtin-skills/claude-tips/data/"; then
+        error "Failed to copy claude-tips.yaml to $dist_dir/share/pane/builtin-skills/claude-tips/data/"
     fi
-    info "✓ Copied claude-tips.yaml to $dist_dir/share/pane/skills/claude-tips/data"
+    info "✓ Copied claude-tips.yaml to $dist_dir/share/pane/builtin-skills/claude-tips/data"
 }
 
 # Create tar.gz archive
@@ -166,22 +166,22 @@ install_binaries() {
 
 # Install bundled skills
 install_skills() {
-    info "Installing Claude Tips Viewer skill to $PREFIX/share/pane/skills..."
+    info "Installing Claude Tips Viewer skill to $PREFIX/share/pane/builtin-skills..."
 
     # Create skill directories
-    execute mkdir -p "$PREFIX/share/pane/skills/claude-tips/data" || rollback
+    execute mkdir -p "$PREFIX/share/pane/builtin-skills/claude-tips/data" || rollback
 
     # Copy skill manifest
     if [ ! -f "skills/claude-tips/pane-skill.yaml" ]; then
         error "Skill manifest not found: skills/claude-tips/pane-skill.yaml"
     fi
-    execute cp -f skills/claude-tips/pane-skill.yaml "$PREFIX/share/pane/skills/claude-tips/pane-skill.yaml" || rollback
+    execute cp -f skills/claude-tips/pane-skill.yaml "$PREFIX/share/pane/builtin-skills/claude-tips/pane-skill.yaml" || rollback
 
     # Copy skill data
     if [ ! -f "skills/claude-tips/data/claude-tips.yaml" ]; then
         error "Skill data not found: skills/claude-tips/data/claude-tips.yaml"
     fi
-    execute cp -f skills/claude-tips/data/claude-tips.yaml "$PREFIX/share/pane/skills/claude-tips/data/claude-tips.yaml" || rollback
+    execute cp -f skills/claude-tips/data/claude-tips.yaml "$PREFIX/share/pane/builtin-skills/claude-tips/data/claude-tips.yaml" || rollback
 
     info "Skills installed successfully"
 }
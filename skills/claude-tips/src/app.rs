@@ -6,6 +6,14 @@
 use crate::model::Tip;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent};
+use nucleo_matcher::{
+    pattern::{CaseMatching, Pattern},
+    Matcher, Utf32Str,
+};
+use std::collections::HashSet;
+
+/// Detail view text width assumed until the first frame reports the real one.
+const DEFAULT_DETAIL_WIDTH: usize = 80;
 
 /// Main application state for the tips browser TUI.
 ///
@@ -57,6 +65,10 @@ pub struct AppState {
     /// Empty string means no active search (all tips visible).
     search_query: String,
 
+    /// Category the list is narrowed to, combined with the search query.
+    /// `None` means all categories are shown.
+    active_category: Option<String>,
+
     /// Whether search mode is currently active.
     /// When true, keyboard input appends to search_query.
     search_mode: bool,
@@ -65,6 +77,18 @@ pub struct AppState {
     /// When true, displays full tip content instead of just list.
     detail_mode: bool,
 
+    /// Number of rows the detail view is scrolled down.
+    /// Reset to 0 when entering detail mode or changing selection.
+    detail_scroll: u16,
+
+    /// Columns available for detail view text, which decides how tips wrap.
+    /// Updated from the terminal size before each frame.
+    detail_width: usize,
+
+    /// IDs of tips that have been opened in detail view.
+    /// Persisted between launches by the `read_state` module.
+    read: HashSet<String>,
+
     /// Application exit flag.
     /// When true, the main event loop should terminate.
     should_quit: bool,
@@ -106,16 +130,22 @@ impl AppState {
             filtered_tips,
             selected_index: 0,
             search_query: String::new(),
+            active_category: None,
             search_mode: false,
             detail_mode: false,
+            detail_scroll: 0,
+            detail_width: DEFAULT_DETAIL_WIDTH,
+            read: HashSet::new(),
             should_quit: false,
         }
     }
 
     /// Updates the search query and re-filters the tips list.
     ///
-    /// Performs case-insensitive substring matching across title, text,
-    /// category, and tags fields. Resets selected_index to 0 after filtering.
+    /// Performs case-insensitive fuzzy matching across title, text, category,
+    /// and tags fields, ranking results by match score (best first) like the
+    /// main Pane skill search. Only tips in the active category (if any) are
+    /// kept. Resets selected_index to 0 after filtering.
     ///
     /// # Arguments
     ///
@@ -150,36 +180,78 @@ impl AppState {
     /// ```
     pub fn update_search(&mut self, query: String) {
         self.search_query = query.to_lowercase();
+        self.apply_filters();
+    }
 
+    /// Recomputes `filtered_tips` from the search query and active category.
+    ///
+    /// Resets the selection and detail scroll since the visible list changed.
+    fn apply_filters(&mut self) {
         if self.search_query.is_empty() {
             // No search query - show all tips
             self.filtered_tips = (0..self.tips.len()).collect();
         } else {
-            // Filter tips by case-insensitive substring matching
-            self.filtered_tips = self
+            // Score every tip, then order matches by score (stable for ties)
+            let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
+            let pattern = Pattern::parse(&self.search_query, CaseMatching::Ignore);
+
+            let mut scored: Vec<(usize, u32)> = self
                 .tips
                 .iter()
                 .enumerate()
-                .filter(|(_, tip)| {
-                    let query = &self.search_query;
-                    tip.title.to_lowercase().contains(query)
-                        || tip.text.to_lowercase().contains(query)
-                        || tip
-                            .tags
-                            .iter()
-                            .any(|tag| tag.to_lowercase().contains(query))
-                        || tip
-                            .category
-                            .as_ref()
-                            .map(|c| c.to_lowercase().contains(query))
-                            .unwrap_or(false)
-                })
-                .map(|(i, _)| i)
+                .filter_map(|(i, tip)| score_tip(&pattern, &mut matcher, tip).map(|s| (i, s)))
                 .collect();
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+            self.filtered_tips = scored.into_iter().map(|(i, _)| i).collect();
+        }
+
+        // Narrow to the active category, keeping search ranking order
+        if let Some(category) = &self.active_category {
+            let tips = &self.tips;
+            self.filtered_tips
+                .retain(|&i| tips[i].category.as_deref() == Some(category.as_str()));
         }
 
         // Reset selection to first item
         self.selected_index = 0;
+        self.detail_scroll = 0;
+    }
+
+    /// Returns the distinct tip categories, sorted alphabetically.
+    ///
+    /// Tips without a category are not listed; they are only visible when
+    /// no category is active.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self
+            .tips
+            .iter()
+            .filter_map(|tip| tip.category.as_deref())
+            .collect();
+        categories.sort_unstable();
+        categories.dedup();
+        categories
+    }
+
+    /// Cycles the active category: all → first → … → last → all.
+    ///
+    /// Re-filters the list, keeping the current search query applied.
+    pub fn cycle_category(&mut self) {
+        let categories = self.categories();
+        let next = match &self.active_category {
+            None => categories.first(),
+            Some(current) => categories
+                .iter()
+                .position(|c| c == current)
+                .and_then(|i| categories.get(i + 1)),
+        };
+        self.active_category = next.map(|c| c.to_string());
+        self.apply_filters();
+    }
+
+    /// Returns the active category, if the list is narrowed to one.
+    pub fn active_category(&self) -> Option<&str> {
+        self.active_category.as_deref()
     }
 
     /// Moves selection to the next tip in the filtered list.
@@ -189,6 +261,7 @@ impl AppState {
     pub fn select_next(&mut self) {
         if !self.filtered_tips.is_empty() {
             self.selected_index = (self.selected_index + 1) % self.filtered_tips.len();
+            self.detail_scroll = 0;
         }
     }
 
@@ -203,6 +276,18 @@ impl AppState {
             } else {
                 self.selected_index -= 1;
             }
+            self.detail_scroll = 0;
+        }
+    }
+
+    /// Jumps the selection to a random tip within the current filter.
+    ///
+    /// Does nothing if the filtered list is empty. The detail scroll
+    /// offset is reset like any other selection change.
+    pub fn select_random(&mut self) {
+        if !self.filtered_tips.is_empty() {
+            self.selected_index = fastrand::usize(..self.filtered_tips.len());
+            self.detail_scroll = 0;
         }
     }
 
@@ -222,9 +307,76 @@ impl AppState {
     /// Toggles detail view mode on/off.
     ///
     /// Detail mode shows the full content of the selected tip
-    /// instead of just the tips list.
+    /// instead of just the tips list. The detail scroll offset is reset
+    /// so each tip opens at the top, and entering detail marks the selected
+    /// tip as read.
     pub fn toggle_detail(&mut self) {
         self.detail_mode = !self.detail_mode;
+        self.detail_scroll = 0;
+
+        if self.detail_mode {
+            if let Some(id) = self.selected_tip().map(|tip| tip.id.clone()) {
+                self.read.insert(id);
+            }
+        }
+    }
+
+    /// Replaces the set of read tip IDs (e.g. with state loaded from disk).
+    pub fn set_read_tips(&mut self, read: HashSet<String>) {
+        self.read = read;
+    }
+
+    /// Returns the IDs of tips that have been viewed in detail.
+    pub fn read_tips(&self) -> &HashSet<String> {
+        &self.read
+    }
+
+    /// Returns whether the tip with the given ID has been viewed in detail.
+    pub fn is_read(&self, id: &str) -> bool {
+        self.read.contains(id)
+    }
+
+    /// Scrolls the detail view down by one row.
+    ///
+    /// Stops once the last row of the selected tip reaches the top
+    /// of the view (see [`AppState::max_detail_scroll`]).
+    pub fn scroll_detail_down(&mut self) {
+        if self.detail_scroll < self.max_detail_scroll() {
+            self.detail_scroll += 1;
+        }
+    }
+
+    /// Scrolls the detail view up by one row, stopping at the top.
+    pub fn scroll_detail_up(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_sub(1);
+    }
+
+    /// Returns the current detail view scroll offset in rows.
+    pub fn detail_scroll(&self) -> u16 {
+        self.detail_scroll
+    }
+
+    /// Sets the columns available for detail view text.
+    ///
+    /// Call before each frame with [`ui::detail_text_width`] of the terminal
+    /// width. The scroll offset is clamped if the tip now takes fewer rows.
+    ///
+    /// [`ui::detail_text_width`]: crate::ui::detail_text_width
+    pub fn set_detail_width(&mut self, width: usize) {
+        self.detail_width = width;
+        self.detail_scroll = self.detail_scroll.min(self.max_detail_scroll());
+    }
+
+    /// Returns the largest allowed detail scroll offset.
+    ///
+    /// Equal to the number of detail rows minus one, counted after wrapping
+    /// to the detail width, so the last row always stays visible. Returns 0
+    /// when no tip is selected.
+    pub fn max_detail_scroll(&self) -> u16 {
+        self.selected_tip()
+            .map(|tip| crate::ui::detail_lines(tip, self.detail_width).len())
+            .map(|rows| u16::try_from(rows.saturating_sub(1)).unwrap_or(u16::MAX))
+            .unwrap_or(0)
     }
 
     /// Sets the quit flag to true, signaling the application should exit.
@@ -288,6 +440,41 @@ impl AppState {
     }
 }
 
+/// Calculates the fuzzy match score for a single tip.
+///
+/// Scores the title, text, category, and space-joined tags separately and
+/// keeps the best one.
+///
+/// # Arguments
+///
+/// * `pattern` - The nucleo pattern to match against
+/// * `matcher` - The nucleo matcher instance
+/// * `tip` - The tip to score
+///
+/// # Returns
+///
+/// `Some(score)` if any field matches (higher is better), `None` otherwise.
+fn score_tip(pattern: &Pattern, matcher: &mut Matcher, tip: &Tip) -> Option<u32> {
+    let tags = tip.tags.join(" ");
+    let fields = [
+        Some(tip.title.as_str()),
+        Some(tip.text.as_str()),
+        tip.category.as_deref(),
+        Some(tags.as_str()),
+    ];
+
+    let mut buf = Vec::new();
+    fields
+        .into_iter()
+        .flatten()
+        .filter(|field| !field.is_empty())
+        .filter_map(|field| {
+            buf.clear();
+            pattern.score(Utf32Str::new(field, &mut buf), matcher)
+        })
+        .max()
+}
+
 /// Handles keyboard input events and updates application state accordingly.
 ///
 /// Processes key events based on the current mode (normal, search, or detail)
@@ -311,6 +498,8 @@ impl AppState {
 /// - `Up` or `k`: Move selection up
 /// - `Enter`: Toggle detail view
 /// - `/`: Activate search mode
+/// - `c`: Cycle category filter (all → each category → all)
+/// - `r`: Open a random tip from the current filter in detail view
 /// - `Esc`: Quit application
 ///
 /// **Search Mode:**
@@ -319,6 +508,8 @@ impl AppState {
 /// - `Esc`: Exit search mode
 ///
 /// **Detail Mode:**
+/// - `Down` or `j`: Scroll detail content down
+/// - `Up` or `k`: Scroll detail content up
 /// - `Esc`: Exit detail view
 ///
 /// # Examples
@@ -344,10 +535,16 @@ impl AppState {
 /// ```
 pub fn handle_key_event(state: &mut AppState, key: KeyEvent) -> Result<()> {
     match (state.is_search_mode(), state.is_detail_mode(), key.code) {
-        // Detail mode - only Esc closes it
+        // Detail mode - scroll content, Esc closes it
         (_, true, KeyCode::Esc) => {
             state.toggle_detail();
         }
+        (_, true, KeyCode::Down) | (_, true, KeyCode::Char('j')) => {
+            state.scroll_detail_down();
+        }
+        (_, true, KeyCode::Up) | (_, true, KeyCode::Char('k')) => {
+            state.scroll_detail_up();
+        }
 
         // Search mode input handling
         (true, false, KeyCode::Char(c)) => {
@@ -377,6 +574,13 @@ pub fn handle_key_event(state: &mut AppState, key: KeyEvent) -> Result<()> {
         (false, false, KeyCode::Char('/')) => {
             state.toggle_search();
         }
+        (false, false, KeyCode::Char('c')) => {
+            state.cycle_category();
+        }
+        (false, false, KeyCode::Char('r')) if state.filtered_count() > 0 => {
+            state.select_random();
+            state.toggle_detail();
+        }
         (false, false, KeyCode::Esc) => {
             state.quit();
         }
@@ -526,4 +730,241 @@ mod tests {
         state.quit();
         assert!(state.should_quit());
     }
+
+    #[test]
+    fn test_detail_scroll_moves_down_and_up() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+        state.toggle_detail();
+
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Down)).unwrap();
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Char('j'))).unwrap();
+        assert_eq!(state.detail_scroll(), 2);
+
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Char('k'))).unwrap();
+        assert_eq!(state.detail_scroll(), 1);
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_detail_scroll_clamps_to_content_length() {
+        let mut tips = create_sample_tips();
+        tips[0].text = "line one\nline two\nline three".to_string();
+        let mut state = AppState::new(tips);
+        state.toggle_detail();
+
+        for _ in 0..50 {
+            state.scroll_detail_down();
+        }
+        assert_eq!(state.max_detail_scroll(), 9);
+        assert_eq!(state.detail_scroll(), 9);
+
+        for _ in 0..50 {
+            state.scroll_detail_up();
+        }
+        assert_eq!(state.detail_scroll(), 0);
+    }
+
+    #[test]
+    fn test_detail_scroll_counts_wrapped_rows() {
+        let mut tips = create_sample_tips();
+        tips[0].text = "word ".repeat(40);
+        let mut state = AppState::new(tips);
+        state.toggle_detail();
+
+        state.set_detail_width(200);
+        let wide = state.max_detail_scroll();
+        state.set_detail_width(20);
+        for _ in 0..50 {
+            state.scroll_detail_down();
+        }
+
+        // 200 characters of text: one row at width 200, ten at width 20
+        assert_eq!(wide, 7);
+        assert!(state.max_detail_scroll() > wide + 8);
+        assert_eq!(state.detail_scroll(), state.max_detail_scroll());
+
+        state.set_detail_width(200);
+        assert_eq!(state.detail_scroll(), wide);
+    }
+
+    #[test]
+    fn test_detail_scroll_resets_on_enter_and_selection_change() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        state.toggle_detail();
+        state.scroll_detail_down();
+        state.toggle_detail();
+        assert_eq!(state.detail_scroll(), 0);
+
+        state.toggle_detail();
+        state.scroll_detail_down();
+        state.select_next();
+        assert_eq!(state.detail_scroll(), 0);
+
+        state.scroll_detail_down();
+        state.select_prev();
+        assert_eq!(state.detail_scroll(), 0);
+    }
+
+    #[test]
+    fn test_detail_scroll_without_selection_stays_at_zero() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+        state.update_search("nonexistentterm".to_string());
+
+        state.scroll_detail_down();
+
+        assert_eq!(state.max_detail_scroll(), 0);
+        assert_eq!(state.detail_scroll(), 0);
+    }
+
+    #[test]
+    fn test_search_matches_non_contiguous_fuzzy_query() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        state.update_search("dbg".to_string());
+
+        assert_eq!(state.filtered_count(), 1);
+        assert_eq!(state.selected_tip().unwrap().title, "Debugging Workflow");
+    }
+
+    #[test]
+    fn test_search_results_are_ordered_by_score() {
+        let mut tips = create_sample_tips();
+        tips[0].title = "Save Handy Output Rules Today".to_string();
+        let mut state = AppState::new(tips);
+
+        state.update_search("short".to_string());
+
+        let ids: Vec<&str> = state
+            .filtered_tips()
+            .iter()
+            .map(|tip| tip.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["cc-003", "cc-001"]);
+    }
+
+    #[test]
+    fn test_categories_are_sorted_and_unique() {
+        let mut tips = create_sample_tips();
+        tips[2].category = Some("debugging".to_string());
+        let state = AppState::new(tips);
+
+        assert_eq!(state.categories(), vec!["debugging", "prompting"]);
+    }
+
+    #[test]
+    fn test_category_filters_tips() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        state.cycle_category();
+        assert_eq!(state.active_category(), Some("debugging"));
+
+        assert_eq!(state.filtered_count(), 1);
+        assert_eq!(state.selected_tip().unwrap().id, "cc-002");
+    }
+
+    #[test]
+    fn test_cycle_category_returns_to_all() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Char('c'))).unwrap();
+        assert_eq!(state.active_category(), Some("debugging"));
+        assert_eq!(state.filtered_count(), 1);
+
+        state.cycle_category();
+        assert_eq!(state.active_category(), Some("features"));
+        state.cycle_category();
+        assert_eq!(state.active_category(), Some("prompting"));
+
+        state.cycle_category();
+        assert_eq!(state.active_category(), None);
+        assert_eq!(state.filtered_count(), 3);
+    }
+
+    #[test]
+    fn test_search_within_category_narrows_further() {
+        let mut tips = create_sample_tips();
+        tips[2].category = Some("prompting".to_string());
+        let mut state = AppState::new(tips);
+
+        state.cycle_category();
+        state.cycle_category();
+        assert_eq!(state.active_category(), Some("prompting"));
+        assert_eq!(state.filtered_count(), 2);
+
+        state.update_search("keyboard".to_string());
+
+        assert_eq!(state.filtered_count(), 1);
+        assert_eq!(state.selected_tip().unwrap().id, "cc-003");
+    }
+
+    #[test]
+    fn test_entering_detail_marks_tip_read() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+        state.select_next();
+
+        state.toggle_detail();
+        state.toggle_detail();
+
+        assert!(state.is_read("cc-002"));
+        assert!(!state.is_read("cc-001"));
+        assert_eq!(state.read_tips().len(), 1);
+    }
+
+    #[test]
+    fn test_set_read_tips_restores_marks() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        state.set_read_tips(HashSet::from(["cc-003".to_string()]));
+
+        assert!(state.is_read("cc-003"));
+        assert!(!state.is_read("cc-001"));
+    }
+
+    #[test]
+    fn test_select_random_stays_in_bounds() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+        state.update_search("u".to_string());
+        let count = state.filtered_count();
+        assert!(count > 0);
+
+        for _ in 0..100 {
+            state.select_random();
+            assert!(state.selected_index() < count);
+            assert!(state.selected_tip().is_some());
+        }
+    }
+
+    #[test]
+    fn test_select_random_empty_list_is_noop() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+        state.update_search("nonexistentterm".to_string());
+
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Char('r'))).unwrap();
+
+        assert_eq!(state.selected_index(), 0);
+        assert!(!state.is_detail_mode());
+    }
+
+    #[test]
+    fn test_r_key_opens_random_tip_in_detail() {
+        let tips = create_sample_tips();
+        let mut state = AppState::new(tips);
+
+        handle_key_event(&mut state, KeyEvent::from(KeyCode::Char('r'))).unwrap();
+
+        assert!(state.is_detail_mode());
+        let id = state.selected_tip().unwrap().id.clone();
+        assert!(state.is_read(&id));
+    }
 }
@@ -10,11 +10,12 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{io, path::Path, time::Duration};
+use std::{io, time::Duration};
 
 mod app;
 mod model;
 mod parser;
+mod read_state;
 mod ui;
 
 use app::{handle_key_event, AppState};
@@ -50,19 +51,28 @@ impl Drop for TerminalGuard {
 }
 
 fn main() -> anyhow::Result<()> {
-    // Load tips from bundled data file
-    let tips_path = Path::new("data/claude-tips.yaml");
-    let tips = parser::load_tips(tips_path).context("Failed to load tips from YAML file")?;
+    // Load tips from bundled data file (PANE_TIPS_PATH, executable dir, install share dir, or cwd)
+    let tips_path = parser::resolve_tips_path();
+    let tips = parser::load_tips(&tips_path).with_context(|| {
+        format!(
+            "Failed to load tips from YAML file (set {} to override the location)",
+            parser::TIPS_PATH_ENV
+        )
+    })?;
 
     if tips.is_empty() {
         anyhow::bail!("No tips found in data file");
     }
 
-    // Initialize application state
+    // Initialize application state, restoring which tips were already read
     let mut state = AppState::new(tips);
+    let read_state_path = read_state::default_read_state_path();
+    if let Some(path) = &read_state_path {
+        state.set_read_tips(read_state::load_read_tips(path));
+    }
 
     // Initialize terminal with RAII guard for cleanup
-    let _terminal_guard = TerminalGuard::new()?;
+    let terminal_guard = TerminalGuard::new()?;
 
     let mut terminal =
         Terminal::new(CrosstermBackend::new(io::stdout())).context("Failed to create terminal")?;
@@ -72,7 +82,9 @@ fn main() -> anyhow::Result<()> {
 
     // Main event loop
     loop {
-        // Render UI
+        // Render UI, wrapping the detail view to the current width
+        let size = terminal.size().context("Failed to read terminal size")?;
+        state.set_detail_width(ui::detail_text_width(size.width));
         terminal
             .draw(|frame| ui::render(frame, &state))
             .context("Failed to render UI")?;
@@ -91,6 +103,15 @@ fn main() -> anyhow::Result<()> {
         }
     }
 
-    // Terminal cleanup happens automatically via TerminalGuard's Drop
+    // Restore the terminal before reporting anything on stderr
+    drop(terminal_guard);
+
+    // Persisting read state is best-effort; a failure shouldn't fail the skill
+    if let Some(path) = &read_state_path {
+        if let Err(e) = read_state::save_read_tips(path, state.read_tips()) {
+            eprintln!("Warning: {:#}", e);
+        }
+    }
+
     Ok(())
 }
@@ -6,10 +6,69 @@
 
 use anyhow::{anyhow, Context, Result};
 use std::collections::HashSet;
-use std::path::Path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 
 use crate::model::Tip;
 
+/// Environment variable that overrides the tips data file location.
+pub const TIPS_PATH_ENV: &str = "PANE_TIPS_PATH";
+
+/// Tips data file location relative to the skill directory.
+pub const DEFAULT_TIPS_PATH: &str = "data/claude-tips.yaml";
+
+/// Tips data file location relative to an installed executable in
+/// `$PREFIX/bin`, where the release layout puts the skill under
+/// `$PREFIX/share/pane/builtin-skills/claude-tips`.
+pub const INSTALLED_TIPS_PATH: &str =
+    "../share/pane/builtin-skills/claude-tips/data/claude-tips.yaml";
+
+/// Resolves the tips data file location for the running process.
+///
+/// Pane may launch the skill from an arbitrary working directory, so the
+/// path is resolved in this order:
+/// 1. `PANE_TIPS_PATH`, if set and non-empty
+/// 2. `data/claude-tips.yaml` next to the executable, if that file exists
+/// 3. The installed copy under `../share/pane/builtin-skills/claude-tips`
+///    relative to the executable, if that file exists
+/// 4. `data/claude-tips.yaml` relative to the current directory
+///
+/// # Returns
+///
+/// The path to pass to [`load_tips`]. The file is not guaranteed to exist.
+pub fn resolve_tips_path() -> PathBuf {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    resolve_tips_path_from(std::env::var_os(TIPS_PATH_ENV), exe_dir.as_deref())
+}
+
+/// Resolves the tips path from an explicit override and executable directory.
+///
+/// Split out from [`resolve_tips_path`] so the precedence rules can be
+/// tested without touching the process environment.
+///
+/// # Arguments
+///
+/// * `env_override` - Value of `PANE_TIPS_PATH`, if set
+/// * `exe_dir` - Directory containing the running executable, if known
+fn resolve_tips_path_from(env_override: Option<OsString>, exe_dir: Option<&Path>) -> PathBuf {
+    if let Some(path) = env_override.filter(|path| !path.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    if let Some(dir) = exe_dir {
+        for candidate in [DEFAULT_TIPS_PATH, INSTALLED_TIPS_PATH] {
+            let candidate = dir.join(candidate);
+            if candidate.is_file() {
+                return candidate;
+            }
+        }
+    }
+
+    PathBuf::from(DEFAULT_TIPS_PATH)
+}
+
 /// Loads and validates tips from a YAML file.
 ///
 /// This function reads the YAML file, parses it into a collection of tips,
@@ -276,4 +335,67 @@ mod tests {
             .to_string()
             .contains("missing required field 'text'"));
     }
+
+    #[test]
+    fn test_resolve_tips_path_env_override_wins() {
+        let exe_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(exe_dir.path().join("data")).unwrap();
+        std::fs::write(exe_dir.path().join(DEFAULT_TIPS_PATH), "[]").unwrap();
+
+        let path = resolve_tips_path_from(
+            Some(OsString::from("/srv/tips/custom.yaml")),
+            Some(exe_dir.path()),
+        );
+
+        assert_eq!(path, PathBuf::from("/srv/tips/custom.yaml"));
+    }
+
+    #[test]
+    fn test_resolve_tips_path_uses_executable_directory_when_file_exists() {
+        let exe_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(exe_dir.path().join("data")).unwrap();
+        std::fs::write(exe_dir.path().join(DEFAULT_TIPS_PATH), "[]").unwrap();
+
+        let path = resolve_tips_path_from(None, Some(exe_dir.path()));
+
+        assert_eq!(path, exe_dir.path().join(DEFAULT_TIPS_PATH));
+    }
+
+    #[test]
+    fn test_resolve_tips_path_finds_installed_share_directory() {
+        let prefix = tempfile::tempdir().unwrap();
+        let bin_dir = prefix.path().join("bin");
+        let data_dir = prefix
+            .path()
+            .join("share/pane/builtin-skills/claude-tips/data");
+        std::fs::create_dir_all(&bin_dir).unwrap();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        std::fs::write(data_dir.join("claude-tips.yaml"), "[]").unwrap();
+
+        let path = resolve_tips_path_from(None, Some(&bin_dir));
+
+        assert_eq!(path, bin_dir.join(INSTALLED_TIPS_PATH));
+        assert!(path.is_file());
+    }
+
+    #[test]
+    fn test_resolve_tips_path_falls_back_to_relative_default() {
+        let exe_dir = tempfile::tempdir().unwrap();
+
+        let path = resolve_tips_path_from(Some(OsString::new()), Some(exe_dir.path()));
+
+        assert_eq!(path, PathBuf::from(DEFAULT_TIPS_PATH));
+    }
+
+    #[test]
+    fn test_load_tips_missing_file_reports_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.yaml");
+
+        let result = load_tips(&missing);
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Tips file not found"));
+        assert!(message.contains(&missing.display().to_string()));
+    }
 }
@@ -0,0 +1,113 @@
+//! Persistence for which tips have been read.
+//!
+//! Tip IDs viewed in detail are stored as a YAML list so they stay marked
+//! across launches. A missing or unreadable state file is treated as
+//! "nothing read yet" rather than an error.
+
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// File name of the read-state file inside the Pane state directory.
+const READ_STATE_FILE: &str = "claude-tips-read.yaml";
+
+/// Returns the default location of the read-state file.
+///
+/// Uses `$XDG_STATE_HOME/pane/claude-tips-read.yaml`, falling back to
+/// `~/.local/state/pane/claude-tips-read.yaml`.
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set.
+pub fn default_read_state_path() -> Option<PathBuf> {
+    let state_dir = env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))?;
+    Some(state_dir.join("pane").join(READ_STATE_FILE))
+}
+
+/// Loads the set of read tip IDs from `path`.
+///
+/// # Arguments
+///
+/// * `path` - Location of the read-state file
+///
+/// # Returns
+///
+/// The stored tip IDs, or an empty set if the file is missing or corrupt.
+pub fn load_read_tips(path: &Path) -> HashSet<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<Vec<String>>(&contents).ok())
+        .map(|ids| ids.into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// Saves the set of read tip IDs to `path`, creating parent directories.
+///
+/// IDs are written sorted so the file is stable between saves.
+///
+/// # Arguments
+///
+/// * `path` - Location of the read-state file
+/// * `read` - Tip IDs to persist
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file
+/// cannot be written.
+pub fn save_read_tips(path: &Path, read: &HashSet<String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory {}", parent.display()))?;
+    }
+
+    let mut ids: Vec<&String> = read.iter().collect();
+    ids.sort();
+    let contents = serde_yaml::to_string(&ids).context("Failed to serialize read tips")?;
+
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write read state to {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_tips_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(READ_STATE_FILE);
+        let read: HashSet<String> = ["cc-002", "cc-001"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+
+        save_read_tips(&path, &read).unwrap();
+        let loaded = load_read_tips(&path);
+
+        assert_eq!(loaded, read);
+    }
+
+    #[test]
+    fn test_load_read_tips_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let loaded = load_read_tips(&dir.path().join(READ_STATE_FILE));
+
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn test_load_read_tips_corrupt_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(READ_STATE_FILE);
+        std::fs::write(&path, "not: [a, list").unwrap();
+
+        let loaded = load_read_tips(&path);
+
+        assert!(loaded.is_empty());
+    }
+}
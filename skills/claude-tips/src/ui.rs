@@ -10,9 +10,10 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Main render function for the tips browser UI.
 ///
@@ -76,7 +77,7 @@ pub fn render(frame: &mut Frame, state: &AppState) {
     // Render main content area (list or detail view)
     if state.is_detail_mode() {
         if let Some(tip) = state.selected_tip() {
-            render_detail_view(main_area, frame, tip);
+            render_detail_view(main_area, frame, tip, state.detail_scroll());
         }
     } else {
         render_tips_list(main_area, frame, state);
@@ -186,7 +187,14 @@ fn render_tips_list(area: Rect, frame: &mut Frame, state: &AppState) {
                 Style::default().fg(Color::White)
             };
 
-            ListItem::new(Line::from(Span::styled(content, style)))
+            // Dim checkmark for tips already viewed in detail
+            let marker = if state.is_read(&tip.id) {
+                Span::styled("✓ ", style.fg(Color::DarkGray))
+            } else {
+                Span::styled("  ", style)
+            };
+
+            ListItem::new(Line::from(vec![marker, Span::styled(content, style)]))
         })
         .collect();
 
@@ -202,14 +210,40 @@ fn render_tips_list(area: Rect, frame: &mut Frame, state: &AppState) {
 /// Renders the detail view for a single tip.
 ///
 /// Displays the full tip content including title, category, text (with wrapping),
-/// and tags. The text content is wrapped to fit the terminal width.
+/// and tags. The content is wrapped by [`detail_lines`] so the rows drawn
+/// match what [`AppState::max_detail_scroll`] counts.
 ///
 /// # Arguments
 ///
 /// * `area` - The rectangular area to render the detail view in
 /// * `frame` - Mutable reference to the terminal frame
 /// * `tip` - The tip to display in detail
-fn render_detail_view(area: Rect, frame: &mut Frame, tip: &Tip) {
+/// * `scroll` - Number of rows scrolled past the top of the content
+fn render_detail_view(area: Rect, frame: &mut Frame, tip: &Tip, scroll: u16) {
+    let paragraph = Paragraph::new(detail_lines(tip, detail_text_width(area.width)))
+        .block(Block::default().borders(Borders::ALL).title("Tip Detail"))
+        .scroll((scroll, 0));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Returns the text width of the detail view in a terminal `width` columns wide.
+///
+/// The detail view spans the full width, less its left and right borders.
+pub fn detail_text_width(width: u16) -> usize {
+    usize::from(width.saturating_sub(2)).max(1)
+}
+
+/// Builds the detail view rows for a tip, wrapped to `width` columns.
+///
+/// The view renders these rows without further wrapping, so their count is
+/// the number of rows the tip takes on screen.
+///
+/// # Arguments
+///
+/// * `tip` - The tip to lay out
+/// * `width` - Columns available for text (see [`detail_text_width`])
+pub fn detail_lines(tip: &Tip, width: usize) -> Vec<Line<'static>> {
     let category = tip
         .category
         .as_ref()
@@ -222,33 +256,82 @@ fn render_detail_view(area: Rect, frame: &mut Frame, tip: &Tip) {
         format!("Tags: {}", tip.tags.join(", "))
     };
 
-    let content = vec![
-        Line::from(Span::styled(
-            &tip.title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(category, Style::default().fg(Color::Yellow))),
-        Line::from(Span::styled(tags, Style::default().fg(Color::Yellow))),
-        Line::from(""),
-        Line::from("─".repeat(area.width as usize)),
-        Line::from(""),
-        Line::from(tip.text.as_str()),
-    ];
-
-    let paragraph = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title("Tip Detail"))
-        .wrap(Wrap { trim: true });
+    let title_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let meta_style = Style::default().fg(Color::Yellow);
 
-    frame.render_widget(paragraph, area);
+    let mut content = styled_rows(&tip.title, width, title_style);
+    content.push(Line::from(""));
+    content.extend(styled_rows(&category, width, meta_style));
+    content.extend(styled_rows(&tags, width, meta_style));
+    content.push(Line::from(""));
+    content.push(Line::from("─".repeat(width)));
+    content.push(Line::from(""));
+    for line in tip.text.lines() {
+        content.extend(styled_rows(line, width, Style::default()));
+    }
+    content
+}
+
+/// Wraps one line of text and styles every resulting row.
+fn styled_rows(text: &str, width: usize, style: Style) -> Vec<Line<'static>> {
+    wrap_text(text, width)
+        .into_iter()
+        .map(|row| Line::from(Span::styled(row, style)))
+        .collect()
+}
+
+/// Wraps a single line to `width` columns, breaking at spaces.
+///
+/// Spaces at a break are dropped; leading indentation is kept. Words wider
+/// than `width` are split between characters. An empty line stays one
+/// empty row.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let indent_len = text.len() - text.trim_start().len();
+    let mut rows = Vec::new();
+    let mut row = text[..indent_len].to_string();
+    let mut row_width = row.width();
+
+    for word in text[indent_len..]
+        .split(' ')
+        .filter(|word| !word.is_empty())
+    {
+        let word_width = word.width();
+        let gap = usize::from(row_width > 0 && !row.ends_with(' '));
+        if row_width + gap + word_width <= width {
+            if gap == 1 {
+                row.push(' ');
+            }
+            row.push_str(word);
+            row_width += gap + word_width;
+            continue;
+        }
+
+        if row_width > 0 && !row.trim().is_empty() {
+            rows.push(std::mem::take(&mut row));
+            row_width = 0;
+        }
+        for c in word.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if row_width + char_width > width && row_width > 0 {
+                rows.push(std::mem::take(&mut row));
+                row_width = 0;
+            }
+            row.push(c);
+            row_width += char_width;
+        }
+    }
+
+    rows.push(row);
+    rows
 }
 
 /// Renders the footer with contextual key hints.
 ///
 /// Displays different key hints based on the current application mode:
-/// - Normal mode: Navigation, view, search, exit keys
+/// - Normal mode: Navigation, view, search, category (with the active one), exit keys
 /// - Search mode: Search input instructions
 /// - Detail mode: Return to list instruction
 ///
@@ -259,14 +342,55 @@ fn render_detail_view(area: Rect, frame: &mut Frame, tip: &Tip) {
 /// * `state` - Immutable reference to application state
 fn render_footer(area: Rect, frame: &mut Frame, state: &AppState) {
     let key_hints = if state.is_detail_mode() {
-        "Esc: back to list"
+        String::from("↑/↓ or j/k: scroll | Esc: back to list")
     } else if state.is_search_mode() {
-        "Type to search | Esc: cancel"
+        String::from("Type to search | Esc: cancel")
     } else {
-        "↑/↓ or j/k: navigate | Enter: view detail | /: search | Esc: exit"
+        format!(
+            "↑/↓ or j/k: navigate | Enter: view detail | /: search | c: category ({}) | r: random | Esc: exit",
+            state.active_category().unwrap_or("all")
+        )
     };
 
     let footer = Paragraph::new(key_hints).style(Style::default().fg(Color::DarkGray));
 
     frame.render_widget(footer, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_breaks_at_spaces_and_splits_long_words() {
+        assert_eq!(
+            wrap_text("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(
+            wrap_text("see abcdefghijkl", 5),
+            vec!["see", "abcde", "fghij", "kl"]
+        );
+        assert_eq!(wrap_text("  indented text", 10), vec!["  indented", "text"]);
+        assert_eq!(wrap_text("", 10), vec![""]);
+    }
+
+    #[test]
+    fn test_detail_lines_wraps_text_to_width() {
+        let tip = Tip {
+            id: "cc-001".to_string(),
+            title: "Title".to_string(),
+            category: Some("git".to_string()),
+            text: "one two three four five six".to_string(),
+            tags: vec![],
+        };
+
+        let narrow = detail_lines(&tip, 13);
+        let wide = detail_lines(&tip, 80);
+
+        // Seven header rows, then the text on two rows instead of one
+        assert_eq!(wide.len(), 8);
+        assert_eq!(narrow.len(), 9);
+        assert_eq!(narrow.last().unwrap().spans[0].content, "four five six");
+    }
+}
@@ -1,22 +1,67 @@
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
+use ratatui::layout::Rect;
 
 use crate::{
-    context::SkillContext, discover_skills, input::poll_event, load_config,
-    skills::manifest::UiMode, skills::runner, state::AppState, terminal::TerminalGuard, ui::render,
-    InputEvent,
+    clipboard::{Clipboard, ClipboardDelivery, SystemClipboard},
+    config::{default_config_toml, get_config_path, Config},
+    context::{detect_git_root, SkillContext},
+    disabled, favorites,
+    i18n::{Language, Translations},
+    input::poll_event,
+    json, load_config, session,
+    skills::bundle::bundle_yaml,
+    skills::discover_skills_with_collisions,
+    skills::discovery_directories,
+    skills::manifest::{SkillManifest, UiMode},
+    skills::output::{OutputStream, SkillOutput},
+    skills::runner,
+    skills::Discovery,
+    skills::{Skill, SkillSource},
+    state::AppState,
+    terminal::TerminalGuard,
+    ui::{output_panel, render, renderer::list_viewport_height},
+    usage, InputEvent,
 };
 
+/// Version string with build information, as shown by `pane --version`
+///
+/// Includes the crate version, the short commit hash, and the build date
+/// embedded by `build.rs`, e.g. `0.1.0 (1a2b3c4 2024-05-01)`.
+pub const LONG_VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("PANE_GIT_HASH"),
+    " ",
+    env!("PANE_BUILD_DATE"),
+    ")"
+);
+
 /// Run the main TUI application
 ///
 /// This is the main entry point for the TUI. It:
 /// 1. Loads user configuration
-/// 2. Discovers skills from all configured locations
-/// 3. Initializes the terminal and application state
-/// 4. Runs the event loop
+/// 2. Starts discovering skills from all configured locations on a
+///    background thread
+/// 3. Initializes the terminal and application state, showing a loading
+///    screen if discovery takes longer than `DISCOVERY_LOADING_DELAY`
+/// 4. Runs the event loop, swapping in the skills once discovery finishes
 /// 5. Cleans up the terminal on exit
 ///
+/// # Arguments
+///
+/// * `skill_paths` - Extra directories from `--skill-path`, searched ahead of
+///   every configured and built-in source
+/// * `profile` - Time config loading, skill discovery, and the first render,
+///   printing the timings to stderr on exit (`--profile`)
+///
 /// # Returns
 ///
 /// Ok(()) on successful exit, or an error if initialization or the event loop fails.
@@ -28,51 +73,1088 @@ use crate::{
 /// - Skill discovery fails
 /// - Terminal initialization fails
 /// - Terminal rendering fails
-pub fn run() -> Result<()> {
+pub fn run(skill_paths: &[PathBuf], profile: bool) -> Result<()> {
     tracing::info!("Starting Pane TUI application");
+    let mut profiler = Profiler::new(profile);
 
     // Load user configuration
-    let config = load_config().context("Failed to load configuration")?;
+    let (config, config_warnings) = profiler
+        .time("config", load_config)
+        .context("Failed to load configuration")?;
     tracing::debug!("Configuration loaded: {:?}", config);
 
-    // Discover all available skills
-    let skills = discover_skills().context("Failed to discover skills")?;
-    tracing::info!("Discovered {} skills", skills.len());
+    // Discover all available skills in the background so a slow filesystem
+    // shows a loading screen instead of a blank terminal
+    let discovery_start = Instant::now();
+    let discovery_rx = spawn_discovery(config.clone(), skill_paths.to_vec())?;
+
+    // Initialize application state; it is filled in once discovery finishes
+    let mut state = AppState::new_loading(config);
+    show_config_warnings(&mut state, &config_warnings);
+    let usage_path = usage::default_usage_path();
+    let disabled_path = disabled::default_disabled_path();
+    let favorites_path = favorites::default_favorites_path();
+    let session_path = session::default_session_path();
+    let startup_paths = StartupPaths {
+        usage: usage_path.as_deref(),
+        disabled: disabled_path.as_deref(),
+        favorites: favorites_path.as_deref(),
+        session: session_path.as_deref(),
+    };
 
-    // Initialize application state
-    let mut state = AppState::new(skills, config);
+    // Fast discovery skips the loading screen entirely, so there is no flicker
+    if let Ok(result) = discovery_rx.recv_timeout(DISCOVERY_LOADING_DELAY) {
+        let discovery = result.context("Failed to discover skills")?;
+        profiler.record("discovery", discovery_start.elapsed());
+        apply_discovery(&mut state, discovery, &startup_paths);
+    }
 
     // Initialize terminal (RAII guard handles cleanup)
     let mut term_guard = TerminalGuard::new().context("Failed to initialize terminal")?;
     let terminal = term_guard.terminal();
 
     // Main event loop
+    let mut first_render = true;
     loop {
         // Render current state
+        let render_start = Instant::now();
         terminal
-            .draw(|frame| render(frame, &state))
+            .draw(|frame| {
+                // Keep scroll bounds in sync with the current terminal size
+                state.set_output_viewport_height(output_panel::content_viewport_height(
+                    frame.size(),
+                    state.config().output_panel_percent(),
+                ));
+                state.set_output_viewport_width(output_panel::content_viewport_width(
+                    frame.size(),
+                    state.config().output_panel_percent(),
+                ));
+                state.set_list_viewport_height(list_viewport_height(
+                    frame.size(),
+                    state.is_detail_pane_visible(),
+                ));
+                render(frame, &state)
+            })
             .context("Failed to render UI")?;
+        if first_render {
+            profiler.record("first render", render_start.elapsed());
+            first_render = false;
+        }
 
-        // Poll for input events (250ms timeout for responsive rendering)
-        // Pass current input mode for mode-aware key mapping
-        if let Some(event) = poll_event(Duration::from_millis(250), state.input_mode())? {
+        // Poll for input events (poll_interval_ms timeout, 250ms by default)
+        // Pass current input mode and panel state for context-aware key mapping
+        let output_panel_keys = state.is_output_panel_visible() && !state.is_output_search_active();
+        if let Some(event) = poll_event(
+            state.config().poll_interval(),
+            state.input_mode(),
+            output_panel_keys,
+        )? {
             handle_event(event, &mut state);
         }
 
+        // Show the output of an inline skill once it finishes
+        receive_inline_output(&mut state);
+
+        // Swap in the skills once background discovery finishes
+        if state.is_loading() && receive_discovery(&discovery_rx, &mut state, &startup_paths)? {
+            profiler.record("discovery", discovery_start.elapsed());
+        }
+        state.advance_loading_tick();
+
         // Check exit condition
         if state.should_quit() {
             break;
         }
     }
 
+    // Quitting before discovery finished leaves nothing worth saving, and
+    // writing the empty state would erase what earlier sessions stored
+    if !state.is_loading() {
+        if let Some(path) = startup_paths.usage {
+            if let Err(e) = usage::save_usage(path, state.usage()) {
+                tracing::warn!("Failed to save usage counts: {:?}", e);
+            }
+        }
+        if let Some(path) = startup_paths.disabled {
+            if let Err(e) = disabled::save_disabled(path, state.disabled_skills()) {
+                tracing::warn!("Failed to save disabled skills: {:?}", e);
+            }
+        }
+        if let Some(path) = startup_paths.favorites {
+            if let Err(e) = favorites::save_favorites(path, state.favorites()) {
+                tracing::warn!("Failed to save favorites: {:?}", e);
+            }
+        }
+        if let Some(path) = startup_paths
+            .session
+            .filter(|_| state.config().restore_session)
+        {
+            if let Err(e) = session::save_session(path, &state.session()) {
+                tracing::warn!("Failed to save session: {:?}", e);
+            }
+        }
+    }
+
+    // Restore the terminal before printing so the timings stay visible
+    drop(term_guard);
+    if let Some(output) = state.take_exit_output() {
+        print!("{}", output.stdout);
+        eprint!("{}", output.stderr);
+    }
+    if let Some(report) = profiler.report() {
+        eprint!("{}", report);
+    }
+
     tracing::info!("Pane TUI application exiting");
     Ok(())
 }
 
+/// How long startup waits for skill discovery before showing the loading screen
+///
+/// Discovery that finishes within this window (the usual case on a local
+/// disk) goes straight to the skill list without flashing the loading screen.
+const DISCOVERY_LOADING_DELAY: Duration = Duration::from_millis(100);
+
+/// Files that per-session state is restored from once skills are discovered
+struct StartupPaths<'a> {
+    /// Where execution counts are stored, if a data directory is available
+    usage: Option<&'a Path>,
+    /// Where disabled skill IDs are stored, if a data directory is available
+    disabled: Option<&'a Path>,
+    /// Where favorite skill IDs are stored, if a data directory is available
+    favorites: Option<&'a Path>,
+    /// Where the last view is stored for `restore_session`, if a data
+    /// directory is available
+    session: Option<&'a Path>,
+}
+
+/// Run skill discovery on a background thread
+///
+/// # Arguments
+///
+/// * `config` - User configuration to discover with
+/// * `skill_paths` - Extra directories from `--skill-path`, searched first
+///
+/// # Returns
+///
+/// A receiver that yields the discovery result exactly once
+///
+/// # Errors
+///
+/// Returns an error if the thread cannot be spawned
+fn spawn_discovery(
+    config: Config,
+    skill_paths: Vec<PathBuf>,
+) -> Result<mpsc::Receiver<Result<Discovery>>> {
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .name("skill-discovery".to_string())
+        .spawn(move || {
+            // The receiver is gone if the user quit while loading
+            let _ = tx.send(discover_skills_with_collisions(&config, &skill_paths));
+        })
+        .context("Failed to start skill discovery")?;
+    Ok(rx)
+}
+
+/// Hand the skills from background discovery to the state, if they are ready
+///
+/// # Arguments
+///
+/// * `receiver` - The receiver returned by `spawn_discovery`
+/// * `state` - Application state waiting in the loading state
+/// * `paths` - Files to restore usage counts, disabled skills, favorites, and the session from
+///
+/// # Returns
+///
+/// `true` if discovery finished and the state was updated, `false` if it is
+/// still running
+///
+/// # Errors
+///
+/// Returns an error if discovery failed or its thread exited without a result
+fn receive_discovery(
+    receiver: &mpsc::Receiver<Result<Discovery>>,
+    state: &mut AppState,
+    paths: &StartupPaths,
+) -> Result<bool> {
+    match receiver.try_recv() {
+        Ok(result) => {
+            let discovery = result.context("Failed to discover skills")?;
+            apply_discovery(state, discovery, paths);
+            Ok(true)
+        }
+        Err(mpsc::TryRecvError::Empty) => Ok(false),
+        Err(mpsc::TryRecvError::Disconnected) => {
+            anyhow::bail!("Skill discovery stopped without a result")
+        }
+    }
+}
+
+/// Load discovered skills into the state and report discovery problems
+///
+/// Restores execution counts, disabled skills, favorites, and (with
+/// `restore_session`) the last view from earlier sessions, then shows a
+/// status toast for unreadable paths or duplicate IDs.
+///
+/// # Arguments
+///
+/// * `state` - Application state waiting in the loading state
+/// * `discovery` - The finished discovery
+/// * `paths` - Files to restore usage counts, disabled skills, favorites, and the session from
+fn apply_discovery(state: &mut AppState, discovery: Discovery, paths: &StartupPaths) {
+    tracing::info!("Discovered {} skills", discovery.skills.len());
+    let warned: Vec<String> = discovery
+        .skills
+        .iter()
+        .filter(|skill| !skill.manifest.warnings().is_empty())
+        .map(|skill| skill.manifest.id.clone())
+        .collect();
+    state.finish_loading(discovery.skills);
+
+    // Restore execution counts from previous sessions
+    if let Some(path) = paths.usage {
+        state.set_usage(usage::load_usage(path));
+    }
+
+    // Keep skills the user switched off in earlier sessions disabled
+    if let Some(path) = paths.disabled {
+        state.set_disabled_skills(disabled::load_disabled(path));
+    }
+
+    // Keep skills starred in earlier sessions in the Favorites view
+    if let Some(path) = paths.favorites {
+        state.set_favorites(favorites::load_favorites(path));
+    }
+
+    // Pick up where the last session left off, once every filter input is loaded
+    if state.config().restore_session {
+        if let Some(session) = paths.session.and_then(session::load_session) {
+            state.restore_session(session);
+        }
+    }
+
+    // Suspicious manifests come first so collisions and unreadable paths win
+    if !warned.is_empty() {
+        state.set_status_message(format!(
+            "{} {}",
+            state.translations().status_manifest_warnings,
+            warned.join(", ")
+        ));
+    }
+
+    // Let skill authors know when manifests in one source share an ID
+    if !discovery.collisions.is_empty() {
+        let ids: Vec<&str> = discovery
+            .collisions
+            .iter()
+            .map(|collision| collision.id.as_str())
+            .collect();
+        state.set_status_message(format!(
+            "{} {}",
+            state.translations().status_duplicate_skill_ids,
+            ids.join(", ")
+        ));
+    }
+
+    // Unreadable paths take precedence over collisions since they may hide skills
+    if !discovery.errors.is_empty() {
+        let paths: Vec<String> = discovery
+            .errors
+            .iter()
+            .map(|error| format!("{} ({})", error.path.display(), error.message))
+            .collect();
+        state.set_status_message(format!(
+            "{} {}",
+            state.translations().status_unreadable_skill_paths,
+            paths.join(", ")
+        ));
+    }
+}
+
+/// Startup phase timings collected for `--profile`
+///
+/// When disabled, phases run untimed and nothing is recorded, so normal runs
+/// are unaffected.
+struct Profiler {
+    /// Whether `--profile` was given
+    enabled: bool,
+    /// Recorded phases in the order they finished
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl Profiler {
+    /// Create a profiler that records only when `enabled`
+    fn new(enabled: bool) -> Self {
+        Profiler {
+            enabled,
+            phases: Vec::new(),
+        }
+    }
+
+    /// Run `f` as the named phase, recording how long it took
+    ///
+    /// # Arguments
+    ///
+    /// * `phase` - Name shown in the report
+    /// * `f` - The work to time
+    ///
+    /// # Returns
+    ///
+    /// Whatever `f` returns, unchanged
+    fn time<T>(&mut self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+        if !self.enabled {
+            return f();
+        }
+        let _span = tracing::info_span!("profile", phase).entered();
+        let start = Instant::now();
+        let result = f();
+        self.record(phase, start.elapsed());
+        result
+    }
+
+    /// Record a phase timed by the caller (ignored when disabled)
+    fn record(&mut self, phase: &'static str, elapsed: Duration) {
+        if self.enabled {
+            tracing::info!("{} took {:?}", phase, elapsed);
+            self.phases.push((phase, elapsed));
+        }
+    }
+
+    /// Format the recorded timings, one phase per line
+    ///
+    /// # Returns
+    ///
+    /// `None` when profiling is disabled
+    fn report(&self) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        let mut report = String::from("pane startup profile:\n");
+        for (phase, elapsed) in &self.phases {
+            report.push_str(&format!(
+                "  {:<13} {:>8.2}ms\n",
+                phase,
+                elapsed.as_secs_f64() * 1000.0
+            ));
+        }
+        Some(report)
+    }
+}
+
+/// Run a single skill by ID without starting the TUI
+///
+/// Backs the `pane run <id>` command. With `dry_run`, the skill context and
+/// environment are resolved and printed (as JSON when `json` is set) and
+/// nothing is executed. With `json` alone, an inline skill's output is
+/// captured and printed as a JSON object once it completes. With `ndjson`,
+/// output is streamed as JSON lines instead (see `stream_ndjson`).
+///
+/// # Arguments
+///
+/// * `id` - ID of the skill to run
+/// * `dry_run` - Print the resolved command instead of executing it
+/// * `json` - Print the dry run or the completed run as a JSON object
+/// * `ndjson` - Stream the run's output as JSON lines while it executes
+/// * `skill_paths` - Extra directories from `--skill-path`, searched first
+///
+/// # Returns
+///
+/// The exit code to report: the skill's exit code, or 0 for a dry run.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Configuration loading or skill discovery fails
+/// - No skill with the given ID exists
+/// - `json` or `ndjson` is set for a skill that is not in inline mode
+/// - `ndjson` is set for a skill that sets `pty` or `retries`
+/// - Context building or skill execution fails
+pub fn run_skill(
+    id: &str,
+    dry_run: bool,
+    json: bool,
+    ndjson: bool,
+    skill_paths: &[PathBuf],
+) -> Result<i32> {
+    let (config, warnings) = load_config().context("Failed to load configuration")?;
+    for warning in &warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    let skills = discover_skills_with_collisions(&config, skill_paths)
+        .context("Failed to discover skills")?
+        .skills;
+
+    let skill = find_skill(&skills, id)?;
+
+    let context = SkillContext::build(skill, &config)
+        .with_context(|| format!("Failed to build context for skill '{}'", id))?;
+
+    if dry_run {
+        let resolved = runner::resolve_command(skill, &context);
+        if json {
+            println!("{}", resolved.to_json());
+        } else {
+            print!("{}", resolved);
+        }
+        return Ok(0);
+    }
+
+    // Only inline skills have output that can be captured and reported
+    if (json || ndjson) && skill.manifest.ui.mode != UiMode::Inline {
+        anyhow::bail!(
+            "--{} requires an inline skill, but '{}' runs in {:?} mode",
+            if ndjson { "ndjson" } else { "json" },
+            id,
+            skill.manifest.ui.mode
+        );
+    }
+    let translations = Translations::load(Language::from_code(&config.language));
+
+    if ndjson {
+        return stream_ndjson(
+            skill,
+            &context,
+            &translations,
+            &mut std::io::stdout().lock(),
+        );
+    }
+
+    if json {
+        let output = runner::execute_inline(skill, &context, &translations)?;
+        println!("{}", output.to_json(id));
+        return Ok(output.exit_code.unwrap_or(1));
+    }
+
+    let status = runner::execute_headless(skill, &context)?;
+    Ok(status.code().unwrap_or(1))
+}
+
+/// Run a skill and write its output to `out` as newline-delimited JSON
+///
+/// Each chunk of output becomes `{"stream":"stdout"|"stderr","data":"..."}`
+/// as soon as it is read, followed by a final
+/// `{"event":"exit","code":N,"ms":T}` (`code` is `null` when the process was
+/// killed by a signal). Every line is flushed immediately so log processors
+/// see it without delay.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to run
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the post_run failure message
+/// * `out` - Where the JSON lines are written
+///
+/// # Returns
+///
+/// The skill's exit code, or 1 if it was killed by a signal
+///
+/// # Errors
+///
+/// Returns an error if the skill cannot be streamed (see
+/// `runner::execute_streaming`) or `out` cannot be written
+fn stream_ndjson<W: Write>(
+    skill: &Skill,
+    context: &SkillContext,
+    translations: &Translations,
+    out: &mut W,
+) -> Result<i32> {
+    let start = Instant::now();
+    let status = runner::execute_streaming(
+        skill,
+        context,
+        translations,
+        |stream: OutputStream, data| {
+            writeln!(
+                out,
+                "{{\"stream\":{},\"data\":{}}}",
+                json::quote(stream.name()),
+                json::quote(data)
+            )?;
+            out.flush()?;
+            Ok(())
+        },
+    )?;
+
+    let code = status.code();
+    writeln!(
+        out,
+        "{{\"event\":\"exit\",\"code\":{},\"ms\":{}}}",
+        code.map_or_else(|| "null".to_string(), |code| code.to_string()),
+        start.elapsed().as_millis()
+    )?;
+    out.flush()?;
+    Ok(code.unwrap_or(1))
+}
+
+/// Build the `pane about` report for bug reports
+///
+/// Lists the version and build info, supported UI languages, the config file
+/// in use, and the skill discovery directories. Reads only the environment
+/// and the filesystem; the terminal is left untouched.
+///
+/// # Arguments
+///
+/// * `skill_paths` - Extra directories from `--skill-path`, listed first
+///
+/// # Returns
+///
+/// A multi-line, human-readable report
+pub fn about_text(skill_paths: &[PathBuf]) -> String {
+    let config_path = get_config_path();
+    let config_status = if config_path.exists() {
+        "found"
+    } else {
+        "not found, using defaults"
+    };
+
+    let languages: Vec<&str> = Language::ALL.iter().map(|lang| lang.code()).collect();
+
+    let mut text = format!(
+        "pane {}\n\nLanguages: {}\nConfig: {} ({})\nSkill directories:\n",
+        LONG_VERSION,
+        languages.join(", "),
+        config_path.display(),
+        config_status
+    );
+    for (path, source) in discovery_directories(skill_paths) {
+        let status = if path.is_dir() { "found" } else { "missing" };
+        text.push_str(&format!(
+            "  {:<8} {} ({})\n",
+            format!("{:?}", source).to_lowercase(),
+            path.display(),
+            status
+        ));
+    }
+    text
+}
+
+/// Write a commented default config file for `pane init`
+///
+/// Creates the config file's directory and the user skills directory
+/// (`~/.config/pane/skills`) if they are missing, then writes the output of
+/// `default_config_toml` to the config path (`PANE_CONFIG_PATH` or
+/// `~/.config/pane/config.toml`).
+///
+/// # Arguments
+///
+/// * `force` - Overwrite an existing config file
+///
+/// # Returns
+///
+/// A short report of the paths that were created or already existed
+///
+/// # Errors
+///
+/// Returns an error if the config file exists and `force` is not set, or if
+/// a directory or the file cannot be written
+pub fn init_config(force: bool) -> Result<String> {
+    let config_path = get_config_path();
+    if config_path.exists() && !force {
+        anyhow::bail!(
+            "Config file already exists at {}; use --force to overwrite it",
+            config_path.display()
+        );
+    }
+
+    let mut report = String::new();
+    if let Some(config_dir) = config_path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(config_dir)
+            .with_context(|| format!("Failed to create {}", config_dir.display()))?;
+    }
+    let verb = if config_path.exists() {
+        "Overwrote"
+    } else {
+        "Created"
+    };
+    std::fs::write(&config_path, default_config_toml()?)
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+    report.push_str(&format!("{} {}\n", verb, config_path.display()));
+
+    if let Some((skills_dir, _)) = discovery_directories(&[])
+        .into_iter()
+        .find(|(_, source)| *source == SkillSource::User)
+    {
+        if skills_dir.is_dir() {
+            report.push_str(&format!("Kept {}\n", skills_dir.display()));
+        } else {
+            std::fs::create_dir_all(&skills_dir)
+                .with_context(|| format!("Failed to create {}", skills_dir.display()))?;
+            report.push_str(&format!("Created {}\n", skills_dir.display()));
+        }
+    }
+    Ok(report)
+}
+
+/// Export discovered skills as a YAML bundle for `pane export`
+///
+/// Loads the configuration and discovers skills as the launcher would, then
+/// serializes each manifest with its source using `bundle_yaml`.
+///
+/// # Arguments
+///
+/// * `out` - File to write the bundle to; the bundle is returned instead if `None`
+/// * `source` - Only export skills from this source, if set
+/// * `skill_paths` - Extra directories from `--skill-path`, searched first
+///
+/// # Returns
+///
+/// The bundle itself when `out` is `None`, otherwise a one-line report of
+/// how many skills were written and where
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded, discovery fails, or the
+/// bundle cannot be serialized or written
+pub fn export_skills(
+    out: Option<&Path>,
+    source: Option<&SkillSource>,
+    skill_paths: &[PathBuf],
+) -> Result<String> {
+    let (config, warnings) = load_config().context("Failed to load configuration")?;
+    for warning in &warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    let skills = discover_skills_with_collisions(&config, skill_paths)
+        .context("Failed to discover skills")?
+        .skills;
+    let (yaml, count) = bundle_yaml(&skills, source)?;
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, yaml)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(format!("Exported {} skills to {}\n", count, path.display()))
+        }
+        None => Ok(yaml),
+    }
+}
+
+/// Build the `pane doctor` troubleshooting report
+///
+/// Loads the configuration and discovers skills exactly as the launcher
+/// would, then reports the config file in use, each skill directory and
+/// whether it can be read, how many skills were found per source, manifests
+/// that failed to load, the git repository detected from the current
+/// directory, and the resolved language and theme. The terminal is left
+/// untouched.
+///
+/// Critical problems are an unreadable or invalid config file, skill paths
+/// that could not be read, manifests that failed to load, and finding no
+/// skills at all. Missing directories and duplicate IDs are only noted.
+///
+/// # Arguments
+///
+/// * `skill_paths` - Extra directories from `--skill-path`, searched first
+///
+/// # Returns
+///
+/// The multi-line report and the number of critical problems found
+pub fn doctor_report(skill_paths: &[PathBuf]) -> (String, usize) {
+    let mut problems = 0;
+
+    let config_path = get_config_path();
+    let (config, config_status) = if !config_path.exists() {
+        (Config::default(), "not found, using defaults".to_string())
+    } else {
+        match load_config() {
+            Ok((config, warnings)) if warnings.is_empty() => (config, "ok".to_string()),
+            Ok((config, warnings)) => (config, format!("warning: {}", warnings.join("; "))),
+            Err(e) => {
+                problems += 1;
+                (Config::default(), format!("PROBLEM: {:#}", e))
+            }
+        }
+    };
+
+    let language = if Language::is_recognized(&config.language) {
+        Language::from_code(&config.language).code().to_string()
+    } else {
+        format!("{:?} (not recognized, using en)", config.language)
+    };
+    let theme = match (&config.theme_file, &config.theme) {
+        (Some(path), _) => format!("theme file {}", path.display()),
+        (None, Some(_)) => "[theme] table in config".to_string(),
+        (None, None) => "default".to_string(),
+    };
+    let git_root = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| detect_git_root(&cwd))
+        .map_or_else(
+            || "none detected".to_string(),
+            |root| root.display().to_string(),
+        );
+
+    let mut text = format!(
+        "pane doctor\n\nConfig: {} ({})\nLanguage: {}\nTheme: {}\nGit repository: {}\nSkill directories:\n",
+        config_path.display(),
+        config_status,
+        language,
+        theme,
+        git_root
+    );
+    for (path, source) in discovery_directories(skill_paths) {
+        let status = if !config.source_enabled(&source) {
+            "disabled"
+        } else if !path.is_dir() {
+            "missing"
+        } else if std::fs::read_dir(&path).is_err() {
+            "PROBLEM: not readable"
+        } else {
+            "readable"
+        };
+        text.push_str(&format!(
+            "  {:<8} {} ({})\n",
+            source.to_string().to_lowercase(),
+            path.display(),
+            status
+        ));
+    }
+
+    let discovery = match discover_skills_with_collisions(&config, skill_paths) {
+        Ok(discovery) => discovery,
+        Err(e) => {
+            text.push_str(&format!("Skill discovery failed: {:#}\n", e));
+            return (text, problems + 1);
+        }
+    };
+
+    let mut per_source: BTreeMap<SkillSource, usize> = BTreeMap::new();
+    for skill in &discovery.skills {
+        *per_source.entry(skill.source.clone()).or_default() += 1;
+    }
+    let breakdown: Vec<String> = per_source
+        .iter()
+        .rev()
+        .map(|(source, count)| format!("{} {}", count, source.to_string().to_lowercase()))
+        .collect();
+    text.push_str(&format!("Skills discovered: {}", discovery.skills.len()));
+    if !breakdown.is_empty() {
+        text.push_str(&format!(" ({})", breakdown.join(", ")));
+    }
+    text.push('\n');
+    if discovery.skills.is_empty() {
+        problems += 1;
+        text.push_str("  PROBLEM: no skills found\n");
+    }
+
+    for (heading, errors) in [
+        ("Invalid manifests", &discovery.invalid_manifests),
+        ("Unreadable paths", &discovery.errors),
+    ] {
+        text.push_str(&format!("{}: {}\n", heading, errors.len()));
+        for error in errors {
+            text.push_str(&format!("  {}: {}\n", error.path.display(), error.message));
+        }
+        problems += errors.len();
+    }
+    for collision in &discovery.collisions {
+        text.push_str(&format!(
+            "Duplicate ID '{}': {} ignored, keeping {}\n",
+            collision.id,
+            collision.conflicting_path.display(),
+            collision.kept_path.display()
+        ));
+    }
+
+    // Warnings do not stop a skill from running, so they are not counted as problems
+    let warnings: Vec<(&Skill, String)> = discovery
+        .skills
+        .iter()
+        .flat_map(|skill| {
+            skill
+                .manifest
+                .warnings()
+                .into_iter()
+                .map(move |warning| (skill, warning))
+        })
+        .collect();
+    if !warnings.is_empty() {
+        text.push_str(&format!("Manifest warnings: {}\n", warnings.len()));
+        for (skill, warning) in warnings {
+            text.push_str(&format!(
+                "  {}: {}\n",
+                skill.manifest_path.display(),
+                warning
+            ));
+        }
+    }
+
+    text.push_str(&match problems {
+        0 => "\nNo problems found\n".to_string(),
+        n => format!("\n{} problem(s) found\n", n),
+    });
+    (text, problems)
+}
+
+/// Look up a skill by ID for headless commands
+///
+/// Hidden skills are included, since they are meant to be run directly by ID.
+fn find_skill<'a>(skills: &'a [Skill], id: &str) -> Result<&'a Skill> {
+    skills
+        .iter()
+        .find(|skill| skill.manifest.id == id)
+        .with_context(|| format!("Skill '{}' not found", id))
+}
+
+/// Re-read the configuration file and apply it to the running state
+///
+/// Reports the outcome through a status toast; on failure the current
+/// configuration stays in effect.
+fn reload_config(state: &mut AppState) {
+    match load_config() {
+        Ok((config, warnings)) => {
+            tracing::info!("Configuration reloaded");
+            state.reload_config(config);
+            state.set_status_message(state.translations().status_config_reloaded.to_string());
+            show_config_warnings(state, &warnings);
+        }
+        Err(e) => {
+            tracing::warn!("Failed to reload configuration: {:?}", e);
+            state.set_status_message(format!(
+                "{} {:#}",
+                state.translations().status_config_reload_failed,
+                e
+            ));
+        }
+    }
+}
+
+/// Report configuration warnings through a status toast
+///
+/// Does nothing when there are no warnings, so an earlier toast stays visible.
+///
+/// # Arguments
+///
+/// * `state` - Application state to show the toast on
+/// * `warnings` - Warnings returned by `load_config`
+fn show_config_warnings(state: &mut AppState, warnings: &[String]) {
+    if warnings.is_empty() {
+        return;
+    }
+    for warning in warnings {
+        tracing::warn!("Configuration warning: {}", warning);
+    }
+    state.set_status_message(format!(
+        "{} {}",
+        state.translations().status_config_warning,
+        warnings.join("; ")
+    ));
+}
+
+/// Which value of the selected skill `copy_selected` copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CopyTarget {
+    /// The manifest `id`
+    Id,
+    /// The path of the manifest file the skill was loaded from
+    ManifestPath,
+}
+
+/// Copy a value of the selected skill to the clipboard
+///
+/// Reports the copied value, or the failure, as a status toast. When the
+/// value could only be sent to the terminal (OSC 52), the toast says so
+/// rather than claiming it was copied. Does nothing when no skill is
+/// selected (e.g. the list is empty).
+///
+/// # Arguments
+///
+/// * `state` - The application state to read the selection from
+/// * `target` - Which value to copy
+/// * `clipboard` - Where the value is copied to
+fn copy_selected(state: &mut AppState, target: CopyTarget, clipboard: &mut dyn Clipboard) {
+    let Some(skill) = state.selected_skill() else {
+        return;
+    };
+    let value = match target {
+        CopyTarget::Id => skill.manifest.id.clone(),
+        CopyTarget::ManifestPath => skill.manifest_path.display().to_string(),
+    };
+
+    match clipboard.set_text(&value) {
+        Ok(delivery) => {
+            let prefix = match delivery {
+                ClipboardDelivery::Copied => state.translations().status_copied,
+                ClipboardDelivery::SentToTerminal => state.translations().status_sent_to_terminal,
+            };
+            state.set_status_message(format!("{} {}", prefix, value))
+        }
+        Err(e) => {
+            tracing::warn!("Failed to copy {:?} to the clipboard: {:?}", value, e);
+            state.set_status_error(format!(
+                "{} {:#}",
+                state.translations().status_copy_failed,
+                e
+            ));
+        }
+    }
+}
+
+/// Open the selected skill's manifest in the user's editor and reload it
+///
+/// The TUI is suspended while the editor runs. Editor and parse failures are
+/// reported as status toasts; the previous manifest is kept in that case.
+///
+/// # Arguments
+///
+/// * `state` - The application state to update
+fn edit_selected_manifest(state: &mut AppState) {
+    let Some(manifest_path) = state
+        .selected_skill()
+        .map(|skill| skill.manifest_path.clone())
+    else {
+        return;
+    };
+
+    if let Err(e) = runner::open_in_editor(&manifest_path) {
+        tracing::warn!("Failed to edit manifest {:?}: {:?}", manifest_path, e);
+        state.set_status_message(format!(
+            "{} {:#}",
+            state.translations().status_editor_failed,
+            e
+        ));
+        return;
+    }
+
+    let limits = state.config().manifest_limits;
+    match SkillManifest::from_yaml_file_with_limits(manifest_path.clone(), &limits) {
+        Ok(manifest) => {
+            tracing::info!("Reloaded manifest {:?}", manifest_path);
+            state.replace_skill_manifest(&manifest_path, manifest);
+            state.set_status_message(state.translations().status_manifest_reloaded.to_string());
+        }
+        Err(e) => {
+            tracing::warn!("Failed to reload manifest {:?}: {:?}", manifest_path, e);
+            state.set_status_message(format!(
+                "{} {:#}",
+                state.translations().status_manifest_reload_failed,
+                e
+            ));
+        }
+    }
+}
+
+/// Show how a TUI skill finished as a status toast
+///
+/// The toast reads e.g. "Deploy exited with code 1" and is drawn as an error
+/// when the exit status is not successful.
+///
+/// # Arguments
+///
+/// * `state` - The application state to update
+/// * `skill_name` - Display name of the skill that exited
+/// * `status` - Exit status returned by the skill process
+fn show_exit_status(state: &mut AppState, skill_name: &str, status: ExitStatus) {
+    let t = state.translations();
+    let message = match status.code() {
+        Some(code) => format!(
+            "{} {} {}",
+            skill_name, t.status_skill_exited_with_code, code
+        ),
+        None => format!("{} {}", skill_name, t.status_skill_terminated),
+    };
+
+    if status.success() {
+        state.set_status_message(message);
+    } else {
+        state.set_status_error(message);
+    }
+}
+
+/// Build the context for running a skill, appending any extra launch arguments
+///
+/// # Arguments
+///
+/// * `skill` - The skill about to run
+/// * `config` - User configuration
+/// * `extra_args` - Arguments entered for this run, shell-split and appended
+///   after the manifest args
+///
+/// # Errors
+///
+/// Returns an error if the context cannot be built or the extra arguments
+/// cannot be split (e.g. an unterminated quote)
+fn build_context(skill: &Skill, config: &Config, extra_args: Option<&str>) -> Result<SkillContext> {
+    let mut context = SkillContext::build(skill, config)?;
+    if let Some(extra_args) = extra_args {
+        let words = runner::split_shell_words(extra_args).context("Invalid extra arguments")?;
+        context.args.extend(words);
+    }
+    Ok(context)
+}
+
+/// Show a skill execution failure in the output panel
+///
+/// Displays the full error chain (`{:#}`) as stderr so failures are never
+/// silent.
+///
+/// # Arguments
+///
+/// * `state` - The application state to update
+/// * `summary` - Short description of what failed
+/// * `error` - The error that caused the failure
+fn show_error_panel(state: &mut AppState, summary: &str, error: &anyhow::Error) {
+    state.show_output_panel(SkillOutput::error(format!("{}: {:#}", summary, error)));
+}
+
+/// Show the result of a background inline run, if it has finished
+///
+/// Records the run like any other execution (usage, recent skills, and the
+/// last-output preview), then shows the output in the output panel, or quits
+/// with it when `quit_after_run` is set and the skill succeeded. A run that
+/// could not be started or read shows its error instead.
+///
+/// # Arguments
+///
+/// * `state` - The application state to update
+fn receive_inline_output(state: &mut AppState) {
+    let Some((run, result)) = state.take_finished_inline_run() else {
+        return;
+    };
+    let skill_id = run.skill_id().to_string();
+    let skill_name = run.skill_name().to_string();
+
+    match result {
+        Ok(output) => {
+            // Keep a preview and update recent skills and usage
+            state.record_last_output(skill_id.clone(), &output);
+            state.record_run(&skill_id);
+            state.add_to_recent(skill_id);
+
+            // Log exit status if debug logging enabled
+            if let Some(code) = output.exit_code {
+                tracing::debug!("Inline skill '{}' exited with code {}", skill_name, code);
+            }
+
+            // A failed run stays open so its output can be read
+            if state.config().quit_after_run && output.exit_code == Some(0) {
+                // Print the output once the terminal is restored
+                state.quit_with_output(output);
+            } else {
+                // Show output panel automatically
+                state.show_skill_output(skill_name, output);
+            }
+        }
+        Err(e) => {
+            // Log error and display in output panel
+            tracing::error!("Failed to execute inline skill '{}': {:?}", skill_name, e);
+            show_error_panel(state, "Failed to execute skill", &e);
+        }
+    }
+}
+
 /// Handle an input event and update application state
 ///
 /// Routes events based on application context:
+/// - Terminal resizes: recompute viewport heights and re-clamp scroll offsets
+/// - If a confirmation is pending: answer it (y) or dismiss it (n/Esc)
 /// - If output panel is visible: scroll output or close panel (Esc)
+/// - If category picker is visible: move, select (Enter), or cancel (Esc)
+/// - If tag picker is visible: move, toggle a tag (Enter), or close (Esc)
 /// - Otherwise: normal skill list navigation and search
 ///
 /// # Arguments
@@ -80,12 +1162,78 @@ pub fn run() -> Result<()> {
 /// * `event` - The input event to handle
 /// * `state` - The application state to update
 fn handle_event(event: InputEvent, state: &mut AppState) {
+    // Resizes are not key presses: update viewports without touching modals or toasts
+    if let InputEvent::Resize(width, height) = event {
+        let area = Rect::new(0, 0, width, height);
+        state.set_output_viewport_width(output_panel::content_viewport_width(
+            area,
+            state.config().output_panel_percent(),
+        ));
+        state.resize_viewports(
+            list_viewport_height(area, state.is_detail_pane_visible()),
+            output_panel::content_viewport_height(area, state.config().output_panel_percent()),
+        );
+        return;
+    }
+
+    // Nothing to act on until discovery finishes; only quitting is allowed
+    if state.is_loading() {
+        if event == InputEvent::Quit {
+            state.quit();
+        }
+        return;
+    }
+
+    // Any key press dismisses the status toast
+    state.clear_status_message();
+
+    // Confirmation prompt is pending - only y/n answers are accepted
+    if state.pending_confirmation().is_some() {
+        match event {
+            InputEvent::JumpToPrefix('y') | InputEvent::CharInput('y') => state.confirm_pending(),
+            InputEvent::JumpToPrefix('n')
+            | InputEvent::CharInput('n')
+            | InputEvent::OutputSearchNext
+            | InputEvent::Quit
+            | InputEvent::EnterNormalMode => state.cancel_pending(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Output panel search query is being typed - keys edit the query
+    if state.is_output_panel_visible() && state.is_output_search_active() {
+        match event {
+            InputEvent::CharInput(c) => state.push_output_search_char(c),
+            InputEvent::Backspace => state.pop_output_search_char(),
+            InputEvent::Enter => state.submit_output_search(),
+            InputEvent::Quit | InputEvent::EnterNormalMode => state.cancel_output_search(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Launch arguments are being typed - keys edit them, Enter runs the skill
+    if state.is_args_input_active() {
+        match event {
+            InputEvent::CharInput(c) => state.push_args_input_char(c),
+            InputEvent::Backspace => state.pop_args_input_char(),
+            InputEvent::Enter => {
+                state.submit_args_input();
+                handle_event(InputEvent::Enter, state);
+            }
+            InputEvent::Quit | InputEvent::EnterNormalMode => state.cancel_args_input(),
+            _ => {}
+        }
+        return;
+    }
+
     // Output panel is visible - handle output panel navigation
     if state.is_output_panel_visible() {
         match event {
             InputEvent::Quit => {
-                // Esc closes output panel
-                state.hide_output_panel();
+                // Esc closes output panel (the first step back)
+                state.go_back();
             }
             InputEvent::MoveUp | InputEvent::CharInput('k') => {
                 // Scroll output up
@@ -95,33 +1243,79 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
                 // Scroll output down (max_lines calculated internally from content)
                 state.scroll_output_down();
             }
+            InputEvent::JumpToTop | InputEvent::CharInput('g') => {
+                state.scroll_output_to_top();
+            }
+            InputEvent::JumpToBottom | InputEvent::CharInput('G') => {
+                state.scroll_output_to_bottom();
+            }
+            InputEvent::HalfPageDown => state.scroll_output_half_page_down(),
+            // Ctrl-u arrives as ClearSearch when the panel opened from Insert mode
+            InputEvent::HalfPageUp | InputEvent::ClearSearch => state.scroll_output_half_page_up(),
+            // `/` searches the output; n/N step through matches
+            InputEvent::EnterInsertMode | InputEvent::CharInput('/') => state.start_output_search(),
+            InputEvent::OutputSearchNext => state.next_output_match(),
+            InputEvent::OutputSearchPrev => state.prev_output_match(),
+            // Tab cycles combined / stdout-only / stderr-only
+            InputEvent::Tab => state.cycle_output_stream_view(),
             // Other keys ignored when output panel is visible
             _ => {}
         }
         return;
     }
 
+    // Category picker is visible - handle picker navigation
+    if state.is_category_picker_visible() {
+        match event {
+            InputEvent::Quit | InputEvent::EnterNormalMode => state.close_category_picker(),
+            InputEvent::MoveUp => state.move_category_picker_up(),
+            InputEvent::MoveDown => state.move_category_picker_down(),
+            InputEvent::Enter => state.select_category_from_picker(),
+            // Other keys ignored while the picker is open
+            _ => {}
+        }
+        return;
+    }
+
+    // Tag picker is visible - toggle tags until the picker is closed
+    if state.is_tag_picker_visible() {
+        match event {
+            InputEvent::Quit | InputEvent::EnterNormalMode => state.close_tag_picker(),
+            InputEvent::MoveUp => state.move_tag_picker_up(),
+            InputEvent::MoveDown => state.move_tag_picker_down(),
+            InputEvent::Enter => state.toggle_tag_from_picker(),
+            // Other keys ignored while the picker is open
+            _ => {}
+        }
+        return;
+    }
+
     // Normal skill list navigation
     match event {
         InputEvent::Quit => {
-            // Double-Esc behavior: Clear search first, then quit
-            if !state.search_query().is_empty() {
-                state.set_search_query(String::new());
-                state.apply_view_filter();
-                tracing::debug!("Cleared search query");
+            // Esc steps back through searches and view switches, then quits
+            if state.go_back() {
+                tracing::debug!("Stepped back to previous view state");
             } else {
-                state.quit();
-                tracing::debug!("Quitting application");
+                state.request_quit();
+                tracing::debug!("Quit requested");
             }
         }
+        // Up/Down recall past searches while the query is empty or recalled
+        InputEvent::MoveUp if state.can_recall_previous_search() => state.recall_previous_search(),
+        InputEvent::MoveDown if state.is_recalling_search() => state.recall_next_search(),
         InputEvent::MoveUp => state.move_selection_up(),
         InputEvent::MoveDown => state.move_selection_down(),
         InputEvent::CharInput(c) => {
-            state.append_to_search(c);
+            state.insert_into_search(c);
         }
         InputEvent::Backspace => {
             state.remove_from_search();
         }
+        InputEvent::Delete => state.delete_from_search(),
+        InputEvent::ClearSearch => state.clear_search(),
+        InputEvent::CursorLeft => state.move_search_cursor_left(),
+        InputEvent::CursorRight => state.move_search_cursor_right(),
         InputEvent::PageDown => {
             // TODO: Calculate page size dynamically based on terminal height
             // page_size = terminal_height - header(3) - footer(3) - borders(2)
@@ -132,57 +1326,79 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
             const DEFAULT_PAGE_SIZE: usize = 10;
             state.move_selection_page_up(DEFAULT_PAGE_SIZE);
         }
+        InputEvent::HalfPageDown => state.move_selection_half_page_down(),
+        InputEvent::HalfPageUp => state.move_selection_half_page_up(),
+        // Only meaningful while the detail pane is on screen
+        InputEvent::ScrollDetailDown | InputEvent::ScrollDetailUp
+            if !state.is_detail_pane_visible() => {}
+        InputEvent::ScrollDetailDown => state.scroll_detail_down(),
+        InputEvent::ScrollDetailUp => state.scroll_detail_up(),
+        // One skill runs at a time; the toast shows the one still running
+        InputEvent::Enter if state.is_executing_inline() => {}
         InputEvent::Enter => {
-            // Execute the selected skill
-            if let Some(selected_skill) = state.selected_skill() {
+            state.push_search_history();
+
+            // Extra arguments from the `A` prompt apply to this run only
+            let pending_args = state.take_pending_args();
+
+            // Execute the selected skill (or the top match, if configured)
+            if let Some(selected_skill) = state.skill_to_run() {
                 // Clone data we need before execution to avoid borrow issues
                 let skill_id = selected_skill.manifest.id.clone();
-                let skill_name = selected_skill.manifest.name.clone();
+                let skill_name = selected_skill.display_name().to_string();
                 let ui_mode = selected_skill.manifest.ui.mode.clone();
 
                 // Build context for skill execution
-                match SkillContext::build(selected_skill, state.config()) {
+                match build_context(selected_skill, state.config(), pending_args.as_deref()) {
                     Ok(context) => {
                         // Route execution based on UI mode
                         match ui_mode {
                             UiMode::Inline => {
-                                // Inline execution - capture output and display in panel
+                                // Inline execution - runs in the background while the
+                                // launcher stays responsive; see receive_inline_output
                                 tracing::info!("Executing inline skill: {}", skill_name);
 
-                                match runner::execute_inline(selected_skill, &context) {
-                                    Ok(output) => {
-                                        // Update recent skills list
-                                        state.add_to_recent(skill_id);
-
-                                        // Log exit status if debug logging enabled
-                                        if let Some(code) = output.exit_code {
-                                            tracing::debug!(
-                                                "Inline skill '{}' exited with code {}",
-                                                skill_name,
-                                                code
-                                            );
-                                        }
+                                match runner::spawn_inline(
+                                    selected_skill.clone(),
+                                    context,
+                                    state.translations().clone(),
+                                ) {
+                                    Ok(run) => state.start_inline_run(run),
+                                    Err(e) => {
+                                        tracing::error!(
+                                            "Failed to execute inline skill '{}': {:?}",
+                                            skill_name,
+                                            e
+                                        );
+                                        show_error_panel(state, "Failed to execute skill", &e);
+                                    }
+                                }
+                            }
+                            UiMode::Tui if selected_skill.manifest.ui.capture => {
+                                // TUI execution with output teed into the output panel
+                                tracing::info!("Executing captured TUI skill: {}", skill_name);
 
-                                        // Show output panel automatically
-                                        state.show_output_panel(output);
+                                match runner::execute_tui_captured(selected_skill, &context) {
+                                    Ok(output) => {
+                                        state.record_last_output(skill_id.clone(), &output);
+                                        state.record_run(&skill_id);
+                                        state.add_to_recent(skill_id);
+                                        // The output was already teed to the terminal
+                                        if state.config().quit_after_run
+                                            && output.exit_code == Some(0)
+                                        {
+                                            state.quit();
+                                        } else {
+                                            state.show_skill_output(skill_name, output);
+                                        }
                                     }
                                     Err(e) => {
-                                        // Log error and display in output panel
                                         tracing::error!(
-                                            "Failed to execute inline skill '{}': {:?}",
+                                            "Failed to execute TUI skill '{}': {:?}",
                                             skill_name,
                                             e
                                         );
-
-                                        // Create error output for display
-                                        let error_output = crate::skills::output::SkillOutput {
-                                            stdout: String::new(),
-                                            stderr: format!("Failed to execute skill: {:#}", e),
-                                            exit_code: None,
-                                            truncated: false,
-                                            execution_time: std::time::Duration::from_secs(0),
-                                        };
-                                        state.show_output_panel(error_output);
+                                        show_error_panel(state, "Failed to execute skill", &e);
                                     }
                                 }
                             }
@@ -192,9 +1408,17 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
 
                                 match runner::execute_skill(selected_skill, context) {
                                     Ok(status) => {
-                                        // Update recent skills list
+                                        // Update recent skills list and usage counts
+                                        state.record_run(&skill_id);
                                         state.add_to_recent(skill_id);
 
+                                        // Tell the user how the skill finished; a failure
+                                        // keeps the launcher open so the toast is seen
+                                        show_exit_status(state, &skill_name, status);
+                                        if state.config().quit_after_run && status.success() {
+                                            state.quit();
+                                        }
+
                                         // Log exit status if debug logging enabled
                                         if let Some(code) = status.code() {
                                             tracing::debug!(
@@ -205,12 +1429,13 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
                                         }
                                     }
                                     Err(e) => {
-                                        // Log error
+                                        // Log error and display in output panel
                                         tracing::error!(
                                             "Failed to execute TUI skill '{}': {:?}",
                                             skill_name,
                                             e
                                         );
+                                        show_error_panel(state, "Failed to execute skill", &e);
                                     }
                                 }
                             }
@@ -222,6 +1447,7 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
                             skill_name,
                             e
                         );
+                        show_error_panel(state, "Failed to build skill context", &e);
                     }
                 }
             }
@@ -236,16 +1462,1420 @@ fn handle_event(event: InputEvent, state: &mut AppState) {
             tracing::debug!("Entered Insert mode");
         }
         InputEvent::EnterNormalMode => {
+            state.push_search_history();
             state.enter_normal_mode();
             tracing::debug!("Entered Normal mode");
         }
         InputEvent::ToggleFavorite => {
-            // TODO: Implement favorite toggling in Task 6
-            tracing::debug!("Toggle favorite (not yet implemented)");
+            if let Some((name, now_favorite)) = state.toggle_selected_skill_favorite() {
+                let translations = state.translations();
+                let change = if now_favorite {
+                    translations.status_skill_favorited
+                } else {
+                    translations.status_skill_unfavorited
+                };
+                state.set_status_message(format!("{} {}", name, change));
+            }
         }
+        InputEvent::ShowCategories => state.open_category_picker(),
+        InputEvent::ShowTags => state.open_tag_picker(),
+        InputEvent::ReloadConfig => reload_config(state),
+        InputEvent::EditManifest => edit_selected_manifest(state),
+        InputEvent::ToggleDetailPane => state.toggle_detail_pane(),
+        InputEvent::ToggleSkillDisabled => {
+            if let Some((name, now_disabled)) = state.toggle_selected_skill_disabled() {
+                let translations = state.translations();
+                let change = if now_disabled {
+                    translations.status_skill_disabled
+                } else {
+                    translations.status_skill_enabled
+                };
+                state.set_status_message(format!("{} {}", name, change));
+            }
+        }
+        InputEvent::EnterArgs => state.start_args_input(),
+        InputEvent::CopySkillId => copy_selected(state, CopyTarget::Id, &mut SystemClipboard),
+        InputEvent::CopyManifestPath => {
+            copy_selected(state, CopyTarget::ManifestPath, &mut SystemClipboard)
+        }
+        InputEvent::JumpToPrefix(ch) => state.jump_to_prefix(ch),
+        // Resizes were already handled before modal routing; output search
+        // keys only apply while the output panel is open
+        InputEvent::Resize(..) | InputEvent::OutputSearchNext | InputEvent::OutputSearchPrev => {}
         InputEvent::ShowHelp => {
             // TODO: Implement help overlay in Story 6.3
             tracing::debug!("Show help (not yet implemented)");
         }
+        // Home/End move the search cursor while typing
+        InputEvent::JumpToTop if state.is_insert_mode() => state.move_search_cursor_to_start(),
+        InputEvent::JumpToBottom if state.is_insert_mode() => state.move_search_cursor_to_end(),
+        InputEvent::JumpToTop | InputEvent::JumpToBottom => {
+            // Only used by the output panel in Normal mode for now
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::skills::{manifest::UiMode, SkillSource};
+    use crate::state::{InputMode, ViewMode};
+    use crate::test_support::test_skill;
+    use std::path::PathBuf;
+
+    fn create_test_skill(id: &str, hidden: bool) -> Skill {
+        let mut skill = test_skill(id);
+        skill.manifest.hidden = hidden;
+        skill
+    }
+
+    /// Clipboard that records copied text, or fails every copy
+    #[derive(Default)]
+    struct RecordingClipboard {
+        copied: Vec<String>,
+        fail: bool,
+        terminal_only: bool,
+    }
+
+    impl Clipboard for RecordingClipboard {
+        fn set_text(&mut self, text: &str) -> Result<ClipboardDelivery> {
+            if self.fail {
+                anyhow::bail!("no clipboard available");
+            }
+            self.copied.push(text.to_string());
+            if self.terminal_only {
+                Ok(ClipboardDelivery::SentToTerminal)
+            } else {
+                Ok(ClipboardDelivery::Copied)
+            }
+        }
+    }
+
+    #[test]
+    fn test_copy_selected_copies_id_and_manifest_path() {
+        // Arrange
+        let mut skill = create_test_skill("deploy", false);
+        skill.manifest_path = PathBuf::from("/work/.pane/skills/deploy/pane-skill.yaml");
+        let mut state = AppState::new(vec![skill], Config::default());
+        let mut clipboard = RecordingClipboard::default();
+
+        // Act
+        copy_selected(&mut state, CopyTarget::Id, &mut clipboard);
+        copy_selected(&mut state, CopyTarget::ManifestPath, &mut clipboard);
+
+        // Assert
+        assert_eq!(
+            clipboard.copied,
+            vec!["deploy", "/work/.pane/skills/deploy/pane-skill.yaml"]
+        );
+        assert_eq!(
+            state.status_message(),
+            Some("Copied: /work/.pane/skills/deploy/pane-skill.yaml")
+        );
+    }
+
+    #[test]
+    fn test_copy_selected_empty_list_does_nothing() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        let mut clipboard = RecordingClipboard::default();
+
+        // Act
+        copy_selected(&mut state, CopyTarget::Id, &mut clipboard);
+
+        // Assert
+        assert!(clipboard.copied.is_empty());
+        assert_eq!(state.status_message(), None);
+    }
+
+    #[test]
+    fn test_copy_selected_terminal_fallback_does_not_claim_copied() {
+        // Arrange
+        let mut state = AppState::new(vec![create_test_skill("deploy", false)], Config::default());
+        let mut clipboard = RecordingClipboard {
+            terminal_only: true,
+            ..Default::default()
+        };
+
+        // Act
+        copy_selected(&mut state, CopyTarget::Id, &mut clipboard);
+
+        // Assert
+        assert_eq!(
+            state.status_message(),
+            Some("Sent to terminal clipboard: deploy")
+        );
+    }
+
+    #[test]
+    fn test_copy_selected_failure_sets_error_toast() {
+        // Arrange
+        let mut state = AppState::new(vec![create_test_skill("deploy", false)], Config::default());
+        let mut clipboard = RecordingClipboard {
+            fail: true,
+            ..Default::default()
+        };
+
+        // Act
+        copy_selected(&mut state, CopyTarget::Id, &mut clipboard);
+
+        // Assert
+        assert_eq!(
+            state.status_message(),
+            Some("Copy failed: no clipboard available")
+        );
+        assert!(state.status_is_error());
+    }
+
+    #[test]
+    fn test_find_skill_returns_hidden_skill_by_id() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("visible", false),
+            create_test_skill("helper", true),
+        ];
+
+        // Act
+        let skill = find_skill(&skills, "helper");
+
+        // Assert
+        assert_eq!(skill.unwrap().manifest.id, "helper");
+    }
+
+    #[test]
+    fn test_find_skill_unknown_id_returns_error() {
+        // Arrange
+        let skills = vec![create_test_skill("visible", false)];
+
+        // Act
+        let result = find_skill(&skills, "missing");
+
+        // Assert
+        assert!(format!("{}", result.unwrap_err()).contains("Skill 'missing' not found"));
+    }
+
+    #[test]
+    fn test_show_error_panel_displays_full_error_chain() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        let error = anyhow::anyhow!("No such file or directory")
+            .context("Failed to determine current working directory");
+
+        // Act
+        show_error_panel(&mut state, "Failed to build skill context", &error);
+
+        // Assert
+        assert!(state.is_output_panel_visible());
+        let output = state.active_output().unwrap();
+        assert_eq!(output.exit_code, None);
+        assert_eq!(
+            output.stderr,
+            "Failed to build skill context: Failed to determine current working directory: No such file or directory"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_show_exit_status_nonzero_code_sets_error_toast() {
+        use std::os::unix::process::ExitStatusExt;
+
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+
+        // Act - a raw wait status of 256 is exit code 1
+        show_exit_status(&mut state, "Deploy", ExitStatus::from_raw(256));
+
+        // Assert
+        assert_eq!(state.status_message(), Some("Deploy exited with code 1"));
+        assert!(state.status_is_error());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_show_exit_status_success_sets_plain_toast() {
+        use std::os::unix::process::ExitStatusExt;
+
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+
+        // Act
+        show_exit_status(&mut state, "Deploy", ExitStatus::from_raw(0));
+
+        // Assert
+        assert_eq!(state.status_message(), Some("Deploy exited with code 0"));
+        assert!(!state.status_is_error());
+    }
+
+    #[test]
+    fn test_handle_event_resize_updates_viewport_heights() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        state.set_status_message("Configuration reloaded".to_string());
+
+        // Act
+        handle_event(InputEvent::Resize(100, 40), &mut state);
+
+        // Assert
+        let area = Rect::new(0, 0, 100, 40);
+        assert_eq!(
+            state.list_viewport_height(),
+            list_viewport_height(area, true)
+        );
+        assert_eq!(
+            state.output_viewport_height(),
+            output_panel::content_viewport_height(area, 80)
+        );
+        assert_eq!(state.status_message(), Some("Configuration reloaded"));
+    }
+
+    #[test]
+    fn test_handle_event_resize_uses_configured_output_panel_ratio() {
+        // Arrange
+        let config = Config {
+            output_panel_ratio: 0.5,
+            ..Config::default()
+        };
+        let mut state = AppState::new(vec![], config);
+
+        // Act
+        handle_event(InputEvent::Resize(100, 40), &mut state);
+
+        // Assert - half of 40 rows, less border, header, footer, and indicators
+        assert_eq!(state.output_viewport_height(), 13);
+    }
+
+    #[test]
+    fn test_handle_event_output_search_next_and_prev_step_through_matches() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        state.show_output_panel(SkillOutput {
+            stdout: "no match\nneedle one\nskip\nneedle two".to_string(),
+            ..SkillOutput::error("")
+        });
+        handle_event(InputEvent::CharInput('/'), &mut state);
+        for c in "needle".chars() {
+            handle_event(InputEvent::CharInput(c), &mut state);
+        }
+        handle_event(InputEvent::Enter, &mut state);
+
+        // Act & Assert - n while typing went into the query, not to a match
+        assert_eq!(state.output_search_query(), "needle");
+        assert_eq!(state.current_output_match(), Some(1));
+        handle_event(InputEvent::OutputSearchNext, &mut state);
+        assert_eq!(state.current_output_match(), Some(3));
+        handle_event(InputEvent::OutputSearchPrev, &mut state);
+        assert_eq!(state.current_output_match(), Some(1));
+    }
+
+    #[test]
+    fn test_handle_event_enter_with_quit_after_run_quits_with_inline_output() {
+        // Arrange
+        let mut skill = create_test_skill("greet", false);
+        skill.manifest.exec = "echo".to_string();
+        skill.manifest.args = vec!["hello".to_string()];
+        skill.manifest.ui.mode = UiMode::Inline;
+        let config = Config {
+            quit_after_run: true,
+            ..Config::default()
+        };
+        let mut quitting = AppState::new(vec![skill.clone()], config);
+        let mut staying = AppState::new(vec![skill], Config::default());
+
+        // Act
+        handle_event(InputEvent::Enter, &mut quitting);
+        wait_for_inline_run(&mut quitting);
+        handle_event(InputEvent::Enter, &mut staying);
+        wait_for_inline_run(&mut staying);
+
+        // Assert
+        assert!(quitting.should_quit());
+        assert!(!quitting.is_output_panel_visible());
+        assert_eq!(quitting.take_exit_output().unwrap().stdout, "hello\n");
+        assert_eq!(quitting.usage_count("greet"), 1);
+        assert!(!staying.should_quit());
+        assert!(staying.is_output_panel_visible());
+        assert_eq!(staying.take_exit_output(), None);
+    }
+
+    fn create_sleeping_skill() -> Skill {
+        let mut skill = create_test_skill("slow", false);
+        skill.manifest.exec = "sleep".to_string();
+        skill.manifest.args = vec!["5".to_string()];
+        skill.manifest.ui.mode = UiMode::Inline;
+        skill
+    }
+
+    #[test]
+    fn test_handle_event_quit_while_executing_confirm_kills_and_quits() {
+        // Arrange
+        let mut state = AppState::new(vec![create_sleeping_skill()], Config::default());
+        handle_event(InputEvent::Enter, &mut state);
+        assert!(state.is_executing_inline());
+
+        // Act
+        handle_event(InputEvent::Quit, &mut state);
+
+        // Assert
+        assert!(!state.should_quit());
+        assert!(state.pending_confirmation().is_some());
+
+        // Act
+        handle_event(InputEvent::JumpToPrefix('y'), &mut state);
+
+        // Assert
+        assert!(state.should_quit());
+        assert!(!state.is_executing_inline());
+        assert!(state.pending_confirmation().is_none());
+    }
+
+    #[test]
+    fn test_handle_event_quit_while_executing_cancel_returns() {
+        // Arrange
+        let mut state = AppState::new(vec![create_sleeping_skill()], Config::default());
+        handle_event(InputEvent::Enter, &mut state);
+        handle_event(InputEvent::Quit, &mut state);
+
+        // Act
+        handle_event(InputEvent::JumpToPrefix('n'), &mut state);
+
+        // Assert - the skill keeps running and a second Enter does not start another
+        assert!(!state.should_quit());
+        assert!(state.pending_confirmation().is_none());
+        assert!(state.is_executing_inline());
+        handle_event(InputEvent::Enter, &mut state);
+        assert_eq!(state.inline_execution_status(), Some("Running slow..."));
+
+        // Kill the skill rather than leave it sleeping
+        state.request_quit();
+        state.confirm_pending();
+    }
+
+    /// Wait for the inline skill started by Enter and show its output
+    fn wait_for_inline_run(state: &mut AppState) {
+        while state.is_executing_inline() {
+            thread::sleep(Duration::from_millis(10));
+            receive_inline_output(state);
+        }
+    }
+
+    fn type_text(state: &mut AppState, text: &str) {
+        for c in text.chars() {
+            handle_event(InputEvent::CharInput(c), state);
+        }
+    }
+
+    #[test]
+    fn test_handle_event_args_prompt_appends_split_args_for_one_run() {
+        // Arrange
+        let mut skill = create_test_skill("show", false);
+        skill.manifest.exec = "printf".to_string();
+        skill.manifest.args = vec!["[%s]".to_string(), "base".to_string()];
+        skill.manifest.ui.mode = UiMode::Inline;
+        let mut state = AppState::new(vec![skill], Config::default());
+
+        // Act
+        handle_event(InputEvent::EnterArgs, &mut state);
+        type_text(&mut state, "--name 'big world'");
+        handle_event(InputEvent::Enter, &mut state);
+        wait_for_inline_run(&mut state);
+        let with_args = state.active_output().unwrap().stdout.clone();
+        handle_event(InputEvent::Quit, &mut state);
+        handle_event(InputEvent::Enter, &mut state);
+        wait_for_inline_run(&mut state);
+        let without_args = state.active_output().unwrap().stdout.clone();
+
+        // Assert
+        assert_eq!(with_args, "[base][--name][big world]");
+        assert_eq!(without_args, "[base]");
+        assert!(!state.is_args_input_active());
+        assert_eq!(state.input_mode(), &InputMode::Normal);
+    }
+
+    #[test]
+    fn test_handle_event_args_prompt_escape_cancels() {
+        // Arrange
+        let mut skill = create_test_skill("show", false);
+        skill.manifest.ui.mode = UiMode::Inline;
+        let mut state = AppState::new(vec![skill], Config::default());
+
+        // Act
+        handle_event(InputEvent::EnterArgs, &mut state);
+        type_text(&mut state, "-v");
+        handle_event(InputEvent::EnterNormalMode, &mut state);
+
+        // Assert
+        assert!(!state.is_args_input_active());
+        assert_eq!(state.input_mode(), &InputMode::Normal);
+        assert_eq!(state.take_pending_args(), None);
+        assert_eq!(state.usage_count("show"), 0);
+    }
+
+    #[test]
+    fn test_handle_event_args_prompt_unterminated_quote_shows_error() {
+        // Arrange
+        let mut skill = create_test_skill("show", false);
+        skill.manifest.exec = "echo".to_string();
+        skill.manifest.ui.mode = UiMode::Inline;
+        let mut state = AppState::new(vec![skill], Config::default());
+
+        // Act
+        handle_event(InputEvent::EnterArgs, &mut state);
+        type_text(&mut state, "'oops");
+        handle_event(InputEvent::Enter, &mut state);
+
+        // Assert
+        let output = state.active_output().unwrap();
+        assert!(output.stderr.contains("Invalid extra arguments"));
+        assert_eq!(state.usage_count("show"), 0);
+    }
+
+    #[test]
+    fn test_handle_event_enter_with_quit_after_run_stays_open_on_failure() {
+        // Arrange
+        let mut skill = create_test_skill("broken", false);
+        skill.manifest.exec = "nonexistent-command-12345".to_string();
+        skill.manifest.ui.mode = UiMode::Inline;
+        let config = Config {
+            quit_after_run: true,
+            ..Config::default()
+        };
+        let mut state = AppState::new(vec![skill], config);
+
+        // Act
+        handle_event(InputEvent::Enter, &mut state);
+        wait_for_inline_run(&mut state);
+
+        // Assert - the error stays on screen instead of vanishing with the launcher
+        assert!(!state.should_quit());
+        assert!(state.is_output_panel_visible());
+    }
+
+    #[test]
+    fn test_handle_event_enter_with_quit_after_run_stays_open_on_nonzero_exit() {
+        // Arrange
+        let mut skill = create_test_skill("fails", false);
+        skill.manifest.exec = "sh".to_string();
+        skill.manifest.args = vec!["-c".to_string(), "echo nope; exit 3".to_string()];
+        skill.manifest.ui.mode = UiMode::Inline;
+        let config = Config {
+            quit_after_run: true,
+            ..Config::default()
+        };
+        let mut state = AppState::new(vec![skill], config);
+
+        // Act
+        handle_event(InputEvent::Enter, &mut state);
+        wait_for_inline_run(&mut state);
+
+        // Assert
+        assert!(!state.should_quit());
+        assert_eq!(state.take_exit_output(), None);
+        assert_eq!(state.active_output().unwrap().exit_code, Some(3));
+    }
+
+    #[test]
+    fn test_handle_event_esc_steps_back_before_quitting() {
+        // Arrange - Tab to Favorites, then search from Normal mode
+        let skills = vec![
+            create_test_skill("git", false),
+            create_test_skill("docker", false),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        handle_event(InputEvent::Tab, &mut state);
+        handle_event(InputEvent::EnterInsertMode, &mut state);
+        handle_event(InputEvent::CharInput('g'), &mut state);
+        handle_event(InputEvent::EnterNormalMode, &mut state);
+
+        // Act & Assert - first Esc clears the search
+        handle_event(InputEvent::Quit, &mut state);
+        assert_eq!(state.search_query(), "");
+        assert_eq!(state.view_mode(), &ViewMode::Favorites);
+
+        // Act & Assert - second Esc returns to the All view
+        handle_event(InputEvent::Quit, &mut state);
+        assert_eq!(state.view_mode(), &ViewMode::All);
+        assert!(!state.should_quit());
+
+        // Act & Assert - third Esc quits
+        handle_event(InputEvent::Quit, &mut state);
+        assert!(state.should_quit());
+    }
+
+    #[test]
+    fn test_handle_event_clear_search_keeps_insert_mode() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("git", false),
+            create_test_skill("docker", false),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        state.enter_insert_mode();
+        state.set_search_query("git".to_string());
+        assert_eq!(state.filtered_count(), 1);
+
+        // Act
+        handle_event(InputEvent::ClearSearch, &mut state);
+
+        // Assert
+        assert_eq!(state.search_query(), "");
+        assert_eq!(state.search_cursor(), 0);
+        assert_eq!(state.filtered_count(), 2);
+        assert!(state.is_insert_mode());
+    }
+
+    #[test]
+    fn test_handle_event_scroll_detail_ignored_when_pane_hidden() {
+        // Arrange
+        let config = Config {
+            show_detail_pane: false,
+            ..Config::default()
+        };
+        let mut state = AppState::new(vec![create_test_skill("git", false)], config);
+
+        // Act
+        handle_event(InputEvent::ScrollDetailDown, &mut state);
+        let hidden_offset = state.detail_scroll_offset();
+        state.toggle_detail_pane();
+        handle_event(InputEvent::ScrollDetailDown, &mut state);
+
+        // Assert
+        assert_eq!(hidden_offset, 0);
+        assert_eq!(state.detail_scroll_offset(), 1);
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_handle_event_insert_mode_up_recalls_previous_search() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("git", false),
+            create_test_skill("docker", false),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        state.enter_insert_mode();
+        state.set_search_query("git".to_string());
+        handle_event(InputEvent::EnterNormalMode, &mut state);
+        state.enter_insert_mode();
+        state.set_search_query(String::new());
+
+        // Act
+        handle_event(InputEvent::MoveUp, &mut state);
+
+        // Assert
+        assert_eq!(state.search_query(), "git");
+
+        // Act
+        handle_event(InputEvent::MoveDown, &mut state);
+
+        // Assert
+        assert_eq!(state.search_query(), "");
+    }
+
+    #[test]
+    fn test_handle_event_insert_mode_arrows_navigate_while_typing() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("git", false),
+            create_test_skill("gist", false),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        state.set_search_query("docker".to_string());
+        state.push_search_history();
+        state.enter_insert_mode();
+        state.set_search_query(String::new());
+        handle_event(InputEvent::CharInput('g'), &mut state);
+
+        // Act
+        handle_event(InputEvent::MoveDown, &mut state);
+
+        // Assert
+        assert_eq!(state.search_query(), "g");
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_stream_ndjson_writes_chunks_and_exit_event() {
+        // Arrange
+        let mut skill = create_test_skill("streamer", false);
+        skill.manifest.exec = "sh".to_string();
+        skill.manifest.args = vec![
+            "-c".to_string(),
+            "printf 'out \"1\"\\n'; printf 'err\\n' >&2; exit 7".to_string(),
+        ];
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+        let mut out = Vec::new();
+
+        // Act
+        let code = stream_ndjson(
+            &skill,
+            &context,
+            &Translations::load(Language::En),
+            &mut out,
+        )
+        .unwrap();
+
+        // Assert - stream chunks may interleave, but the exit event comes last
+        assert_eq!(code, 7);
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3, "unexpected output: {}", text);
+        assert!(lines.contains(&r#"{"stream":"stdout","data":"out \"1\"\n"}"#));
+        assert!(lines.contains(&r#"{"stream":"stderr","data":"err\n"}"#));
+        assert!(lines[2].starts_with(r#"{"event":"exit","code":7,"ms":"#));
+        assert!(lines[2].ends_with('}'));
+    }
+
+    #[test]
+    fn test_stream_ndjson_streams_post_run_output() {
+        // Arrange
+        let mut skill = create_test_skill("cleaner", false);
+        skill.manifest.exec = "true".to_string();
+        skill.manifest.post_run = Some("echo cleaned".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+        let mut out = Vec::new();
+
+        // Act
+        let code = stream_ndjson(
+            &skill,
+            &context,
+            &Translations::load(Language::En),
+            &mut out,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(code, 0);
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], r#"{"stream":"stdout","data":"cleaned\n"}"#);
+        assert!(lines[1].starts_with(r#"{"event":"exit","code":0,"ms":"#));
+    }
+
+    #[test]
+    fn test_stream_ndjson_rejects_pty_and_retries() {
+        // Arrange
+        let mut pty = create_test_skill("pty", false);
+        pty.manifest.pty = true;
+        let mut retried = create_test_skill("retried", false);
+        retried.manifest.retries = 2;
+        let context = SkillContext::build(&pty, &Config::default()).unwrap();
+
+        // Act
+        let results: Vec<String> = [&pty, &retried]
+            .iter()
+            .map(|skill| {
+                let mut out = Vec::new();
+                let error =
+                    stream_ndjson(skill, &context, &Translations::load(Language::En), &mut out)
+                        .unwrap_err();
+                assert!(out.is_empty());
+                error.to_string()
+            })
+            .collect();
+
+        // Assert
+        assert!(results[0].contains("sets pty"));
+        assert!(results[1].contains("sets retries"));
+    }
+
+    #[test]
+    fn test_long_version_contains_crate_version() {
+        // Arrange & Act
+        let version = LONG_VERSION;
+
+        // Assert
+        assert!(!version.is_empty());
+        assert!(version.starts_with(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn test_profiler_does_not_change_discovered_skills() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("hello");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("pane-skill.yaml"),
+            "id: hello\nname: Hello\ndescription: Says hello\nversion: 1.0.0\n\
+             exec: echo\nui:\n  mode: inline\n",
+        )
+        .unwrap();
+        let config = Config::default();
+        let skill_paths = vec![temp_dir.path().to_path_buf()];
+        // Discovery order is unspecified, so compare sorted IDs
+        let discover = || {
+            let mut ids: Vec<String> = discover_skills_with_collisions(&config, &skill_paths)
+                .unwrap()
+                .skills
+                .into_iter()
+                .map(|skill| skill.manifest.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        // Act
+        let mut disabled = Profiler::new(false);
+        let unprofiled = disabled.time("discovery", discover);
+        let mut enabled = Profiler::new(true);
+        let profiled = enabled.time("discovery", discover);
+
+        // Assert
+        assert_eq!(profiled, unprofiled);
+        assert!(profiled.contains(&"hello".to_string()));
+        assert_eq!(disabled.report(), None);
+        let report = enabled.report().unwrap();
+        assert!(report.contains("discovery"));
+        assert!(report.ends_with("ms\n"));
+    }
+
+    #[test]
+    fn test_spawn_discovery_sends_discovered_skills() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("hello");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("pane-skill.yaml"),
+            "id: hello\nname: Hello\ndescription: Says hello\nversion: 1.0.0\n\
+             exec: echo\nui:\n  mode: inline\n",
+        )
+        .unwrap();
+
+        // Act
+        let receiver =
+            spawn_discovery(Config::default(), vec![temp_dir.path().to_path_buf()]).unwrap();
+        let discovery = receiver
+            .recv_timeout(Duration::from_secs(10))
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert!(discovery
+            .skills
+            .iter()
+            .any(|skill| skill.manifest.id == "hello"));
+    }
+
+    #[test]
+    fn test_receive_discovery_moves_state_from_loading_to_loaded() {
+        // Arrange
+        let (tx, rx) = mpsc::channel();
+        let mut state = AppState::new_loading(Config::default());
+        let paths = StartupPaths {
+            usage: None,
+            disabled: None,
+            favorites: None,
+            session: None,
+        };
+
+        // Act
+        let before = receive_discovery(&rx, &mut state, &paths).unwrap();
+        let loading_count = state.filtered_count();
+        tx.send(Ok(Discovery {
+            skills: vec![
+                create_test_skill("build", false),
+                create_test_skill("deploy", false),
+            ],
+            ..Discovery::default()
+        }))
+        .unwrap();
+        let after = receive_discovery(&rx, &mut state, &paths).unwrap();
+
+        // Assert
+        assert!(!before);
+        assert_eq!(loading_count, 0);
+        assert!(after);
+        assert!(!state.is_loading());
+        assert_eq!(state.filtered_count(), 2);
+    }
+
+    #[test]
+    fn test_apply_discovery_restores_saved_session_when_enabled() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let session_path = dir.path().join("session.yaml");
+        session::save_session(
+            &session_path,
+            &session::Session {
+                view_mode: ViewMode::All,
+                search_query: "deploy".to_string(),
+                selected_skill: Some("deploy-staging".to_string()),
+                ..session::Session::default()
+            },
+        )
+        .unwrap();
+        let discovery = || Discovery {
+            skills: vec![
+                create_test_skill("build", false),
+                create_test_skill("deploy-prod", false),
+                create_test_skill("deploy-staging", false),
+            ],
+            ..Discovery::default()
+        };
+        let paths = StartupPaths {
+            usage: None,
+            disabled: None,
+            favorites: None,
+            session: Some(&session_path),
+        };
+        let mut enabled = AppState::new_loading(Config {
+            restore_session: true,
+            ..Config::default()
+        });
+        let mut disabled = AppState::new_loading(Config::default());
+
+        // Act
+        apply_discovery(&mut enabled, discovery(), &paths);
+        apply_discovery(&mut disabled, discovery(), &paths);
+
+        // Assert
+        assert_eq!(enabled.search_query(), "deploy");
+        assert_eq!(
+            enabled.selected_skill().unwrap().manifest.id,
+            "deploy-staging"
+        );
+        assert_eq!(disabled.search_query(), "");
+    }
+
+    #[test]
+    fn test_receive_discovery_reports_unreadable_paths() {
+        // Arrange
+        let (tx, rx) = mpsc::channel();
+        let mut state = AppState::new_loading(Config::default());
+        tx.send(Ok(Discovery {
+            errors: vec![crate::skills::DiscoveryError {
+                path: PathBuf::from("/mnt/skills"),
+                message: "permission denied".to_string(),
+            }],
+            ..Discovery::default()
+        }))
+        .unwrap();
+
+        // Act
+        let received = receive_discovery(
+            &rx,
+            &mut state,
+            &StartupPaths {
+                usage: None,
+                disabled: None,
+                favorites: None,
+                session: None,
+            },
+        )
+        .unwrap();
+
+        // Assert
+        assert!(received);
+        assert!(state
+            .status_message()
+            .unwrap()
+            .contains("/mnt/skills (permission denied)"));
+    }
+
+    #[test]
+    fn test_apply_discovery_reports_manifest_warnings() {
+        // Arrange
+        let mut piped = create_test_skill("piped", false);
+        piped.manifest.args = vec!["log.txt | grep error".to_string()];
+        let mut state = AppState::new_loading(Config::default());
+
+        // Act
+        apply_discovery(
+            &mut state,
+            Discovery {
+                skills: vec![create_test_skill("plain", false), piped],
+                ..Discovery::default()
+            },
+            &StartupPaths {
+                usage: None,
+                disabled: None,
+                favorites: None,
+                session: None,
+            },
+        );
+
+        // Assert
+        let message = state.status_message().unwrap();
+        assert!(message.contains("piped"));
+        assert!(!message.contains("plain"));
+    }
+
+    #[test]
+    fn test_handle_event_toggle_favorite_marks_selected_skill() {
+        // Arrange
+        let mut state = AppState::new(
+            vec![
+                create_test_skill("build", false),
+                create_test_skill("deploy", false),
+            ],
+            Config::default(),
+        );
+        state.move_selection_down();
+
+        // Act
+        handle_event(InputEvent::ToggleFavorite, &mut state);
+
+        // Assert
+        assert!(state.favorites().contains("deploy"));
+        assert!(state
+            .status_message()
+            .unwrap()
+            .ends_with("added to favorites"));
+
+        // Act - pressing f again removes it
+        handle_event(InputEvent::ToggleFavorite, &mut state);
+
+        // Assert
+        assert!(state.favorites().is_empty());
+        assert!(state
+            .status_message()
+            .unwrap()
+            .ends_with("removed from favorites"));
+    }
+
+    #[test]
+    fn test_handle_event_toggle_favorite_pins_skill_first_in_all_view() {
+        // Arrange
+        let mut state = AppState::new(
+            vec![
+                create_test_skill("build", false),
+                create_test_skill("deploy", false),
+                create_test_skill("test", false),
+            ],
+            Config {
+                favorites_first: true,
+                ..Config::default()
+            },
+        );
+        state.move_selection_down();
+        state.move_selection_down();
+
+        // Act
+        handle_event(InputEvent::ToggleFavorite, &mut state);
+
+        // Assert - the favorite moves to the top and stays selected
+        let listed: Vec<&str> = state
+            .filtered_skills()
+            .map(|skill| skill.manifest.id.as_str())
+            .collect();
+        assert_eq!(listed, vec!["test", "build", "deploy"]);
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "test");
+
+        // Assert - once another row is selected, the favorite shows its star
+        state.move_selection_down();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| render(frame, &state)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(screen.matches('★').count(), 1);
+    }
+
+    #[test]
+    fn test_session_round_trips_favorite_toggled_in_app() {
+        // Arrange - star a skill and switch to the Favorites view
+        let dir = tempfile::TempDir::new().unwrap();
+        let session_path = dir.path().join("session.yaml");
+        let skills = || {
+            vec![
+                create_test_skill("build", false),
+                create_test_skill("deploy", false),
+            ]
+        };
+        let config = Config {
+            restore_session: true,
+            ..Config::default()
+        };
+        let mut state = AppState::new(skills(), config.clone());
+        state.move_selection_down();
+        handle_event(InputEvent::ToggleFavorite, &mut state);
+        handle_event(InputEvent::Tab, &mut state);
+
+        // Act
+        session::save_session(&session_path, &state.session()).unwrap();
+        let mut restored = AppState::new_loading(config);
+        apply_discovery(
+            &mut restored,
+            Discovery {
+                skills: skills(),
+                ..Discovery::default()
+            },
+            &StartupPaths {
+                usage: None,
+                disabled: None,
+                favorites: None,
+                session: Some(&session_path),
+            },
+        );
+
+        // Assert
+        assert!(restored.favorites().contains("deploy"));
+        assert_eq!(restored.view_mode(), &crate::state::ViewMode::Favorites);
+        assert_eq!(restored.filtered_count(), 1);
+        assert_eq!(restored.selected_skill().unwrap().manifest.id, "deploy");
+    }
+
+    #[test]
+    fn test_apply_discovery_restores_saved_favorites() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let favorites_path = dir.path().join("favorites.yaml");
+        favorites::save_favorites(
+            &favorites_path,
+            &["deploy".to_string()].into_iter().collect(),
+        )
+        .unwrap();
+        let mut state = AppState::new_loading(Config::default());
+
+        // Act
+        apply_discovery(
+            &mut state,
+            Discovery {
+                skills: vec![
+                    create_test_skill("build", false),
+                    create_test_skill("deploy", false),
+                ],
+                ..Discovery::default()
+            },
+            &StartupPaths {
+                usage: None,
+                disabled: None,
+                favorites: Some(&favorites_path),
+                session: None,
+            },
+        );
+        handle_event(InputEvent::Tab, &mut state);
+
+        // Assert
+        assert_eq!(state.view_mode(), &crate::state::ViewMode::Favorites);
+        assert_eq!(state.filtered_count(), 1);
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "deploy");
+    }
+
+    #[test]
+    fn test_receive_discovery_propagates_discovery_failure() {
+        // Arrange
+        let (tx, rx) = mpsc::channel();
+        let mut state = AppState::new_loading(Config::default());
+        tx.send(Err(anyhow::anyhow!("disk vanished"))).unwrap();
+        let paths = StartupPaths {
+            usage: None,
+            disabled: None,
+            favorites: None,
+            session: None,
+        };
+
+        // Act
+        let result = receive_discovery(&rx, &mut state, &paths);
+
+        // Assert
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Failed to discover skills"));
+        assert!(message.contains("disk vanished"));
+        assert!(state.is_loading());
+    }
+
+    #[test]
+    fn test_receive_discovery_errors_when_thread_exits_without_result() {
+        // Arrange
+        let (tx, rx) = mpsc::channel::<Result<Discovery>>();
+        drop(tx);
+        let mut state = AppState::new_loading(Config::default());
+        let paths = StartupPaths {
+            usage: None,
+            disabled: None,
+            favorites: None,
+            session: None,
+        };
+
+        // Act
+        let result = receive_discovery(&rx, &mut state, &paths);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_handle_event_while_loading_only_allows_quit() {
+        // Arrange
+        let mut state = AppState::new_loading(Config::default());
+
+        // Act
+        handle_event(InputEvent::EnterInsertMode, &mut state);
+        let quit_after_other_key = state.should_quit();
+        handle_event(InputEvent::Quit, &mut state);
+
+        // Assert
+        assert!(!quit_after_other_key);
+        assert!(!state.is_insert_mode());
+        assert!(state.should_quit());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_init_config_creates_config_and_skills_dir() {
+        // Arrange
+        let home = tempfile::TempDir::new().unwrap();
+        let original_home = std::env::var_os("HOME");
+        std::env::set_var("HOME", home.path());
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Act
+        let result = init_config(false);
+        if let Some(original) = original_home {
+            std::env::set_var("HOME", original);
+        }
+
+        // Assert
+        let report = result.unwrap();
+        let config_path = home.path().join(".config/pane/config.toml");
+        let written = std::fs::read_to_string(&config_path).unwrap();
+        assert_eq!(
+            toml::from_str::<Config>(&written).unwrap(),
+            Config::default()
+        );
+        assert!(home.path().join(".config/pane/skills").is_dir());
+        assert!(report.contains("Created"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_init_config_refuses_to_overwrite_without_force() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(&config_path, "language = \"ko\"\n").unwrap();
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let refused = init_config(false);
+        let kept = std::fs::read_to_string(&config_path).unwrap();
+        let forced = init_config(true);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(refused.unwrap_err().to_string().contains("--force"));
+        assert_eq!(kept, "language = \"ko\"\n");
+        assert!(forced.unwrap().contains("Overwrote"));
+        assert!(std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("language = \"en\""));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_doctor_report_counts_skills_and_invalid_manifests() {
+        // Arrange - only the --skill-path directory is searched
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "language = \"ko\"\nenable_project_skills = false\nenable_user_skills = false\n\
+             enable_system_skills = false\nenable_builtin_skills = false\n",
+        )
+        .unwrap();
+        let skills_dir = temp_dir.path().join("skills");
+        for (dir, contents) in [
+            (
+                "one",
+                "id: one\nname: One\ndescription: First\nexec: echo\nui:\n  mode: inline\n",
+            ),
+            (
+                "two",
+                "id: two\nname: Two\ndescription: Second\nexec: echo\nui:\n  mode: inline\n",
+            ),
+            ("broken", "id: [unclosed\n"),
+        ] {
+            std::fs::create_dir_all(skills_dir.join(dir)).unwrap();
+            std::fs::write(skills_dir.join(dir).join("pane-skill.yaml"), contents).unwrap();
+        }
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let (report, problems) = doctor_report(std::slice::from_ref(&skills_dir));
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(report.contains(&format!("Config: {} (ok)", config_path.display())));
+        assert!(report.contains("Language: ko"));
+        assert!(report.contains("Skills discovered: 2 (2 override)"));
+        assert!(report.contains("Invalid manifests: 1"));
+        assert!(report.contains("broken"));
+        assert!(report.contains("project  ./.pane/skills (disabled)"));
+        assert_eq!(problems, 1);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_export_skills_writes_all_discovered_skills() {
+        // Arrange - only the --skill-path directory is searched
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "enable_project_skills = false\nenable_user_skills = false\n\
+             enable_system_skills = false\nenable_builtin_skills = false\n",
+        )
+        .unwrap();
+        let skills_dir = temp_dir.path().join("skills");
+        for id in ["alpha", "beta", "gamma"] {
+            std::fs::create_dir_all(skills_dir.join(id)).unwrap();
+            std::fs::write(
+                skills_dir.join(id).join("pane-skill.yaml"),
+                format!(
+                    "id: {}\nname: {}\ndescription: Test\nexec: echo\nui:\n  mode: inline\n",
+                    id, id
+                ),
+            )
+            .unwrap();
+        }
+        let out = temp_dir.path().join("bundle.yaml");
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let report = export_skills(Some(&out), None, std::slice::from_ref(&skills_dir));
+        let filtered = export_skills(
+            None,
+            Some(&SkillSource::Project),
+            std::slice::from_ref(&skills_dir),
+        );
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert_eq!(
+            report.unwrap(),
+            format!("Exported 3 skills to {}\n", out.display())
+        );
+        let bundle: Vec<crate::skills::bundle::BundledSkill> =
+            serde_yaml::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+        let mut ids: Vec<&str> = bundle.iter().map(|b| b.manifest.id.as_str()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["alpha", "beta", "gamma"]);
+        assert!(bundle.iter().all(|b| b.source == SkillSource::Override));
+        assert_eq!(filtered.unwrap().trim(), "[]");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_doctor_report_lists_manifest_warnings() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "enable_project_skills = false\nenable_user_skills = false\n\
+             enable_system_skills = false\nenable_builtin_skills = false\n",
+        )
+        .unwrap();
+        let skill_dir = temp_dir.path().join("skills").join("logs");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("pane-skill.yaml"),
+            "id: logs\nname: Logs\ndescription: Tail logs\nexec: tail\n\
+             args: [\"log.txt | grep error\"]\nui:\n  mode: inline\n",
+        )
+        .unwrap();
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let (report, problems) = doctor_report(&[temp_dir.path().join("skills")]);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(report.contains("Manifest warnings: 1"));
+        assert!(report.contains("shell operator '|'"));
+        assert_eq!(problems, 0);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_doctor_report_flags_invalid_config() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "enable_mouse = [not toml").unwrap();
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let (report, problems) = doctor_report(&[]);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(report.contains("PROBLEM: Config file corrupted"));
+        assert!(problems >= 1);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_doctor_report_lists_config_warnings() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "poll_interval_ms = 5000\n").unwrap();
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+
+        // Act
+        let (report, _) = doctor_report(&[]);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(report.contains("(warning: poll_interval_ms = 5000 is outside"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_reload_config_shows_warnings_as_status() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "output_panel_ratio = 0.1\n").unwrap();
+        std::env::set_var("PANE_CONFIG_PATH", &config_path);
+        let mut state = AppState::new(vec![], Config::default());
+
+        // Act
+        reload_config(&mut state);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        let status = state.status_message().unwrap();
+        assert!(status.starts_with("Config warning: output_panel_ratio = 0.1"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_reload_config_without_config_file_reports_success() {
+        // Arrange
+        std::env::set_var("PANE_CONFIG_PATH", "/nonexistent/pane/config.toml");
+        let mut state = AppState::new(vec![], Config::default());
+
+        // Act
+        reload_config(&mut state);
+        std::env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert_eq!(state.status_message(), Some("Configuration reloaded"));
+    }
+
+    #[test]
+    fn test_about_text_lists_languages_and_directories() {
+        // Arrange & Act
+        let about = about_text(&[PathBuf::from("/tmp/dev-skills")]);
+
+        // Assert
+        assert!(about.starts_with(&format!("pane {}", LONG_VERSION)));
+        assert!(about.contains("Languages: en, ko"));
+        assert!(about.contains("Config: "));
+        assert!(about.contains("project"));
+        assert!(about.contains("user"));
+        assert!(about.contains("/usr/local/share/pane/skills"));
+        assert!(about.contains("override /tmp/dev-skills"));
     }
 }
@@ -0,0 +1,164 @@
+//! Copying text to the system clipboard
+//!
+//! Pane has no clipboard library dependency. `SystemClipboard` pipes the text
+//! to the platform's copy command (`pbcopy`, `wl-copy`, `xclip`, `xsel` or
+//! `clip`) and, when none of them works, asks the terminal to set the
+//! clipboard with an OSC 52 escape sequence, which also works over SSH in
+//! most modern terminals.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Context, Result};
+
+/// How copied text was handed off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardDelivery {
+    /// A clipboard command accepted the text, so it is on the clipboard
+    Copied,
+    /// The text was sent to the terminal as an OSC 52 sequence; whether it
+    /// reached the clipboard depends on the terminal, which gives no reply
+    SentToTerminal,
+}
+
+/// Something that can receive copied text
+///
+/// Abstracted so key handlers can be tested without touching the real
+/// clipboard.
+pub trait Clipboard {
+    /// Replace the clipboard contents with `text`
+    ///
+    /// # Returns
+    ///
+    /// How the text was delivered
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text could not be handed to the clipboard
+    fn set_text(&mut self, text: &str) -> Result<ClipboardDelivery>;
+}
+
+/// The clipboard of the machine (or terminal) Pane runs in
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn set_text(&mut self, text: &str) -> Result<ClipboardDelivery> {
+        for (program, args) in copy_commands() {
+            match pipe_to_command(program, args, text) {
+                Ok(()) => return Ok(ClipboardDelivery::Copied),
+                Err(e) => tracing::debug!("Clipboard command '{}' failed: {:#}", program, e),
+            }
+        }
+
+        // No copy command worked; let the terminal set the clipboard
+        let mut stdout = io::stdout();
+        stdout
+            .write_all(osc52_sequence(text).as_bytes())
+            .and_then(|()| stdout.flush())
+            .context("Failed to write clipboard escape sequence")?;
+        Ok(ClipboardDelivery::SentToTerminal)
+    }
+}
+
+/// Copy commands to try on this platform, in order of preference
+fn copy_commands() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(windows) {
+        vec![("clip", &[])]
+    } else {
+        let mut commands: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            commands.push(("wl-copy", &[]));
+        }
+        if std::env::var_os("DISPLAY").is_some() {
+            commands.push(("xclip", &["-selection", "clipboard"]));
+            commands.push(("xsel", &["--clipboard", "--input"]));
+        }
+        commands
+    }
+}
+
+/// Run `program` with `text` on its stdin and wait for it to succeed
+fn pipe_to_command(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to start '{}'", program))?;
+
+    // Dropping stdin after writing closes it so the command sees end-of-file
+    child
+        .stdin
+        .take()
+        .context("Failed to open stdin")?
+        .write_all(text.as_bytes())
+        .with_context(|| format!("Failed to write to '{}'", program))?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("'{}' exited with {}", program, status);
+    }
+    Ok(())
+}
+
+/// Build the OSC 52 escape sequence that sets the clipboard to `text`
+///
+/// # Arguments
+///
+/// * `text` - The text to copy
+///
+/// # Returns
+///
+/// `ESC ] 52 ; c ; <base64 text> BEL`
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))
+}
+
+/// Standard base64 encoding with padding
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (i, &byte)| {
+            acc | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                encoded.push(char::from(ALPHABET[index as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_pads_partial_groups() {
+        // Arrange & Act & Assert
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode("상태".as_bytes()), "7IOB7YOc");
+    }
+
+    #[test]
+    fn test_osc52_sequence_wraps_encoded_text() {
+        // Act
+        let sequence = osc52_sequence("claude-tips");
+
+        // Assert
+        assert_eq!(sequence, "\x1b]52;c;Y2xhdWRlLXRpcHM=\x07");
+    }
+}
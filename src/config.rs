@@ -3,16 +3,32 @@
 // warnings are expected and suppressed until integration occurs.
 #![allow(dead_code)]
 
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::Language;
+use crate::skills::{ManifestLimits, SkillSource};
 use crate::state::ViewMode;
 use crate::ui::theme::ThemeConfig;
 
+/// Shortest allowed event loop poll interval, in milliseconds
+pub const MIN_POLL_INTERVAL_MS: u64 = 10;
+
+/// Longest allowed event loop poll interval, in milliseconds
+pub const MAX_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Smallest share of the terminal height the output panel may cover
+pub const MIN_OUTPUT_PANEL_RATIO: f64 = 0.2;
+
+/// Largest share of the terminal height the output panel may cover
+pub const MAX_OUTPUT_PANEL_RATIO: f64 = 0.9;
+
 /// User configuration loaded from `~/.config/pane/config.toml`
 ///
 /// Provides customization options for skill discovery, UI behavior, and logging.
@@ -31,6 +47,17 @@ pub struct Config {
     #[serde(default)]
     pub theme: Option<ThemeConfig>,
 
+    /// External theme file (TOML) loaded into `theme`; takes precedence over
+    /// an inline `[theme]` table (default: None)
+    #[serde(default)]
+    pub theme_file: Option<PathBuf>,
+
+    /// Use a monochrome theme (bold and reverse video, no colors, no skill
+    /// accents) instead of `theme`; the `NO_COLOR` environment variable does
+    /// the same (default: false)
+    #[serde(default)]
+    pub no_color: bool,
+
     /// Maximum number of recent skills to track (default: 10)
     #[serde(default = "default_max_recent_skills")]
     pub max_recent_skills: usize,
@@ -50,6 +77,124 @@ pub struct Config {
     /// UI language (en, ko)
     #[serde(default = "default_language")]
     pub language: String,
+
+    /// Start in Insert (search) mode instead of Normal mode (default: false)
+    #[serde(default)]
+    pub start_in_search: bool,
+
+    /// Remember the view mode, search query, selected skill, favorites, and
+    /// recent skills on exit and restore them on the next launch (default: false)
+    #[serde(default)]
+    pub restore_session: bool,
+
+    /// Hide skills whose executable cannot be found during discovery (default: false)
+    #[serde(default)]
+    pub strict_discovery: bool,
+
+    /// Wrap selection from the last skill to the first and vice versa (default: true)
+    #[serde(default = "default_wrap_navigation")]
+    pub wrap_navigation: bool,
+
+    /// While a search query is active, Enter runs the highest-scored match
+    /// even if the selection was moved off it (default: false)
+    #[serde(default)]
+    pub enter_runs_top_match: bool,
+
+    /// Exit the launcher once a skill run from it finishes successfully; an
+    /// inline skill's output is printed to the terminal instead of the output
+    /// panel (default: false)
+    #[serde(default)]
+    pub quit_after_run: bool,
+
+    /// Discover skills from the project directory (default: true)
+    #[serde(default = "default_source_enabled")]
+    pub enable_project_skills: bool,
+
+    /// Discover skills from the user directory (default: true)
+    #[serde(default = "default_source_enabled")]
+    pub enable_user_skills: bool,
+
+    /// Discover skills from the system-wide directory (default: true)
+    #[serde(default = "default_source_enabled")]
+    pub enable_system_skills: bool,
+
+    /// Discover skills bundled with pane (default: true)
+    #[serde(default = "default_source_enabled")]
+    pub enable_builtin_skills: bool,
+
+    /// List the most-run skills first when no search query is active (default: false)
+    #[serde(default)]
+    pub sort_by_usage: bool,
+
+    /// Parse `estimated_time` values such as "1-3 min" or "30s" into
+    /// durations, showing them in a normalized form; unrecognized values are
+    /// shown as written (default: false)
+    #[serde(default)]
+    pub parse_estimated_time: bool,
+
+    /// List the quickest skills first when no search query is active; needs
+    /// `parse_estimated_time`, and skills without a parsed estimate come last.
+    /// `sort_by_usage` takes precedence (default: false)
+    #[serde(default)]
+    pub sort_by_estimated_time: bool,
+
+    /// In the All view, list favorite skills ahead of the rest, keeping the
+    /// current order within each group; search results keep their relevance
+    /// order (default: false)
+    #[serde(default)]
+    pub favorites_first: bool,
+
+    /// Show how many times a skill has been run in the detail pane (default: true)
+    #[serde(default = "default_show_usage_count")]
+    pub show_usage_count: bool,
+
+    /// Show the detail pane next to (or below) the skill list; `D` toggles it
+    /// at runtime, giving the list the full width when hidden (default: true)
+    #[serde(default = "default_show_detail_pane")]
+    pub show_detail_pane: bool,
+
+    /// File names recognized as skill manifests during discovery
+    /// (default: ["pane-skill.yaml"])
+    #[serde(default = "default_manifest_filenames")]
+    pub manifest_filenames: Vec<String>,
+
+    /// How long the event loop waits for input before redrawing, in
+    /// milliseconds (default: 250, allowed: 10–1000). Raise it to save
+    /// battery; lower it for snappier redraws.
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Share of the terminal height covered by the output panel
+    /// (default: 0.8, allowed: 0.2–0.9)
+    #[serde(default = "default_output_panel_ratio")]
+    pub output_panel_ratio: f64,
+
+    /// ID of a skill to pre-select on launch; ignored if no discovered skill
+    /// has this ID (default: none, the first skill is selected)
+    #[serde(default)]
+    pub default_skill: Option<String>,
+
+    /// Fuzzy search tuning (`[search]` table)
+    #[serde(default)]
+    pub search: SearchConfig,
+
+    /// Maximum manifest `id`/`name`/`description` lengths; longer manifests
+    /// are rejected (`[manifest_limits]` table, defaults: 64/100/500)
+    #[serde(default)]
+    pub manifest_limits: ManifestLimits,
+}
+
+/// Fuzzy search settings from the `[search]` table of `config.toml`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// Matches scoring below this are hidden; raise it to drop weak
+    /// matches for short queries (default: 0, keep every match)
+    #[serde(default)]
+    pub min_score: u32,
+    /// Search terms mapped to the word they stand for (e.g. `k8s =
+    /// "kubernetes"`); typing either one also matches skills using the other
+    #[serde(default)]
+    pub synonyms: BTreeMap<String, String>,
 }
 
 // Helper functions for serde defaults
@@ -78,6 +223,34 @@ fn default_language() -> String {
     "en".to_string()
 }
 
+fn default_wrap_navigation() -> bool {
+    true
+}
+
+fn default_source_enabled() -> bool {
+    true
+}
+
+fn default_show_usage_count() -> bool {
+    true
+}
+
+fn default_show_detail_pane() -> bool {
+    true
+}
+
+fn default_poll_interval_ms() -> u64 {
+    250
+}
+
+fn default_output_panel_ratio() -> f64 {
+    0.8
+}
+
+fn default_manifest_filenames() -> Vec<String> {
+    vec!["pane-skill.yaml".to_string()]
+}
+
 impl Default for Config {
     /// Creates a Config with sensible default values
     ///
@@ -85,29 +258,126 @@ impl Default for Config {
     /// - default_view_mode: All
     /// - enable_mouse: true
     /// - theme: None
+    /// - theme_file: None
+    /// - no_color: false
     /// - max_recent_skills: 10
     /// - debug_log_enabled: false
     /// - debug_log_path: ~/.config/pane/logs/pane-debug.log
     /// - skill_paths: [./.pane/skills/, ~/.config/pane/skills/, /usr/local/share/pane/skills/]
+    /// - language: en
+    /// - start_in_search: false
+    /// - restore_session: false
+    /// - strict_discovery: false
+    /// - wrap_navigation: true
+    /// - enter_runs_top_match: false
+    /// - quit_after_run: false
+    /// - enable_project_skills / enable_user_skills / enable_system_skills /
+    ///   enable_builtin_skills: true
+    /// - sort_by_usage: false
+    /// - parse_estimated_time: false
+    /// - sort_by_estimated_time: false
+    /// - favorites_first: false
+    /// - show_usage_count: true
+    /// - show_detail_pane: true
+    /// - manifest_filenames: [pane-skill.yaml]
+    /// - poll_interval_ms: 250
+    /// - output_panel_ratio: 0.8
+    /// - default_skill: None
+    /// - search.min_score: 0
+    /// - search.synonyms: empty
+    /// - manifest_limits: 64/100/500 characters for id/name/description
     fn default() -> Self {
         Config {
             default_view_mode: ViewMode::default(),
             enable_mouse: default_enable_mouse(),
             theme: None,
+            theme_file: None,
+            no_color: false,
             max_recent_skills: default_max_recent_skills(),
             debug_log_enabled: false,
             debug_log_path: default_debug_log_path(),
             skill_paths: default_skill_paths(),
             language: default_language(),
+            start_in_search: false,
+            restore_session: false,
+            strict_discovery: false,
+            wrap_navigation: default_wrap_navigation(),
+            enter_runs_top_match: false,
+            quit_after_run: false,
+            enable_project_skills: default_source_enabled(),
+            enable_user_skills: default_source_enabled(),
+            enable_system_skills: default_source_enabled(),
+            enable_builtin_skills: default_source_enabled(),
+            sort_by_usage: false,
+            parse_estimated_time: false,
+            sort_by_estimated_time: false,
+            favorites_first: false,
+            show_usage_count: default_show_usage_count(),
+            show_detail_pane: default_show_detail_pane(),
+            manifest_filenames: default_manifest_filenames(),
+            poll_interval_ms: default_poll_interval_ms(),
+            output_panel_ratio: default_output_panel_ratio(),
+            default_skill: None,
+            search: SearchConfig::default(),
+            manifest_limits: ManifestLimits::default(),
         }
     }
 }
 
 impl Config {
+    /// Event loop poll timeout from `poll_interval_ms`
+    ///
+    /// # Returns
+    ///
+    /// The configured interval, clamped to the allowed 10–1000ms range
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.poll_interval_ms
+                .clamp(MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS),
+        )
+    }
+
+    /// Output panel height as a percentage of the terminal height
+    ///
+    /// # Returns
+    ///
+    /// `output_panel_ratio` clamped to the allowed 0.2–0.9 range, in whole
+    /// percent; the default when the ratio is not a finite number
+    pub fn output_panel_percent(&self) -> u16 {
+        let ratio = if self.output_panel_ratio.is_finite() {
+            self.output_panel_ratio
+                .clamp(MIN_OUTPUT_PANEL_RATIO, MAX_OUTPUT_PANEL_RATIO)
+        } else {
+            default_output_panel_ratio()
+        };
+        (ratio * 100.0).round() as u16
+    }
+
+    /// Whether skills from the given source should be discovered
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The skill source to check
+    ///
+    /// # Returns
+    ///
+    /// The matching `enable_*_skills` flag; `--skill-path` directories are
+    /// always enabled
+    pub fn source_enabled(&self, source: &SkillSource) -> bool {
+        match source {
+            SkillSource::Override => true,
+            SkillSource::Project => self.enable_project_skills,
+            SkillSource::User => self.enable_user_skills,
+            SkillSource::System => self.enable_system_skills,
+            SkillSource::Builtin => self.enable_builtin_skills,
+        }
+    }
+
     /// Validates the configuration
     ///
     /// Checks that:
     /// - skill_paths are not empty
+    /// - language is a recognized code (warns on stderr otherwise)
     /// - debug_log_path parent directory exists if debug logging is enabled
     ///
     /// # Errors
@@ -119,6 +389,14 @@ impl Config {
             anyhow::bail!("Configuration error: skill_paths cannot be empty");
         }
 
+        // Unknown language codes fall back to English; point out likely typos
+        if !Language::is_recognized(&self.language) {
+            eprintln!(
+                "Warning: Unknown language {:?}, using English (supported: en, ko)",
+                self.language
+            );
+        }
+
         // Validate debug log path parent exists if debug logging enabled
         if self.debug_log_enabled {
             let expanded_path = expand_tilde(&self.debug_log_path.to_string_lossy());
@@ -137,11 +415,18 @@ impl Config {
 ///
 /// If the config file doesn't exist, returns default configuration.
 /// If the config file exists but is invalid, returns an error with helpful context.
+/// Problems that do not stop the config from loading, such as an out-of-range
+/// value that was clamped, are returned as warnings instead of printed, so the
+/// caller can show them where they won't corrupt the TUI.
 ///
 /// # Environment Variables
 ///
 /// - `PANE_CONFIG_PATH`: Override the default config file location
 ///
+/// # Returns
+///
+/// The loaded configuration and any warnings about it
+///
 /// # Errors
 ///
 /// Returns an error if:
@@ -154,32 +439,271 @@ impl Config {
 /// use pane::config::load_config;
 ///
 /// fn example() -> anyhow::Result<()> {
-///     let config = load_config()?;
+///     let (config, warnings) = load_config()?;
 ///     println!("Loaded {} skill paths", config.skill_paths.len());
+///     for warning in warnings {
+///         eprintln!("Warning: {}", warning);
+///     }
 ///     Ok(())
 /// }
 /// ```
-pub fn load_config() -> Result<Config> {
+pub fn load_config() -> Result<(Config, Vec<String>)> {
     let config_path = get_config_path();
 
     // If config file doesn't exist, return defaults
     if !config_path.exists() {
-        eprintln!("Config file not found at {:?}, using defaults", config_path);
-        return Ok(Config::default());
+        tracing::debug!("Config file not found at {:?}, using defaults", config_path);
+        let mut config = Config::default();
+        apply_no_color_env(&mut config);
+        return Ok((config, Vec::new()));
     }
 
     // Read and parse config file
     let contents = fs::read_to_string(&config_path)
         .with_context(|| format!("Failed to read config file: {:?}", config_path))?;
 
-    let config: Config = toml::from_str(&contents).with_context(|| {
+    let mut config: Config = toml::from_str(&contents).with_context(|| {
         format!(
             "Config file corrupted, using defaults. Check {:?}",
             config_path
         )
     })?;
 
-    Ok(config)
+    let mut warnings = Vec::new();
+    apply_theme_file(&mut config, &mut warnings);
+    apply_no_color_env(&mut config);
+
+    if !(MIN_POLL_INTERVAL_MS..=MAX_POLL_INTERVAL_MS).contains(&config.poll_interval_ms) {
+        let clamped = config.poll_interval().as_millis() as u64;
+        warnings.push(format!(
+            "poll_interval_ms = {} is outside {}-{}; using {}",
+            config.poll_interval_ms, MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS, clamped
+        ));
+        config.poll_interval_ms = clamped;
+    }
+
+    if !(MIN_OUTPUT_PANEL_RATIO..=MAX_OUTPUT_PANEL_RATIO).contains(&config.output_panel_ratio) {
+        let clamped = f64::from(config.output_panel_percent()) / 100.0;
+        warnings.push(format!(
+            "output_panel_ratio = {} is outside {}-{}; using {}",
+            config.output_panel_ratio, MIN_OUTPUT_PANEL_RATIO, MAX_OUTPUT_PANEL_RATIO, clamped
+        ));
+        config.output_panel_ratio = clamped;
+    }
+
+    Ok((config, warnings))
+}
+
+/// Turns on `config.no_color` when the `NO_COLOR` environment variable is set
+///
+/// Per the convention at no-color.org, any non-empty value counts. Resolving
+/// it here keeps the rest of the app reading only `Config`.
+fn apply_no_color_env(config: &mut Config) {
+    if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        config.no_color = true;
+    }
+}
+
+/// Loads `config.theme_file`, if set, into `config.theme`
+///
+/// The external theme replaces an inline `[theme]` table, with a warning when
+/// both are set. A missing or invalid theme file is reported as a warning and
+/// the inline theme (or the default) is kept, so startup never fails on it.
+///
+/// # Arguments
+///
+/// * `config` - The parsed configuration to update
+/// * `warnings` - Collects the warnings described above
+fn apply_theme_file(config: &mut Config, warnings: &mut Vec<String>) {
+    let Some(theme_file) = &config.theme_file else {
+        return;
+    };
+    let path = expand_tilde(&theme_file.to_string_lossy());
+
+    match load_theme_file(&path) {
+        Ok(theme) => {
+            if config.theme.is_some() {
+                warnings.push(format!(
+                    "both theme and theme_file are set; using theme file {:?}",
+                    path
+                ));
+            }
+            config.theme = Some(theme);
+        }
+        Err(e) => {
+            warnings.push(format!("{:#}; ignoring theme_file", e));
+        }
+    }
+}
+
+/// Reads and parses a theme file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is not a valid theme
+fn load_theme_file(path: &Path) -> Result<ThemeConfig> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read theme file {:?}", path))?;
+    toml::from_str(&contents).with_context(|| format!("Invalid theme file {:?}", path))
+}
+
+/// Comment written above each setting in the file generated by `pane init`
+///
+/// Keyed by the setting name, or by the `[table]` header for tables.
+const CONFIG_COMMENTS: &[(&str, &str)] = &[
+    (
+        "default_view_mode",
+        "View shown at startup: All, Favorites, Recent, or Disabled",
+    ),
+    ("enable_mouse", "Select and scroll with the mouse"),
+    (
+        "no_color",
+        "Use a monochrome theme with no colors (also set by NO_COLOR)",
+    ),
+    (
+        "max_recent_skills",
+        "How many recently run skills the Recent view keeps",
+    ),
+    ("debug_log_enabled", "Write a debug log to debug_log_path"),
+    ("debug_log_path", "Where the debug log is written"),
+    (
+        "skill_paths",
+        "Skill discovery paths in search order (project, user, system)",
+    ),
+    ("language", "UI language: en or ko"),
+    (
+        "start_in_search",
+        "Start with the search box focused (Insert mode)",
+    ),
+    (
+        "restore_session",
+        "Restore the last view, search, selection, favorites, and recent skills on launch",
+    ),
+    (
+        "strict_discovery",
+        "Hide skills whose executable cannot be found",
+    ),
+    (
+        "wrap_navigation",
+        "Wrap the selection from the last skill to the first and back",
+    ),
+    (
+        "enter_runs_top_match",
+        "While searching, Enter runs the best match even if the selection moved",
+    ),
+    (
+        "quit_after_run",
+        "Exit after a skill runs, printing inline output to the terminal",
+    ),
+    ("enable_project_skills", "Discover skills in ./.pane/skills"),
+    (
+        "enable_user_skills",
+        "Discover skills in ~/.config/pane/skills",
+    ),
+    (
+        "enable_system_skills",
+        "Discover skills in /usr/local/share/pane/skills",
+    ),
+    (
+        "enable_builtin_skills",
+        "Discover the skills bundled with pane",
+    ),
+    (
+        "sort_by_usage",
+        "List the most-run skills first when not searching",
+    ),
+    (
+        "parse_estimated_time",
+        "Parse estimated_time values such as \"1-3 min\" into durations",
+    ),
+    (
+        "sort_by_estimated_time",
+        "List the quickest skills first when not searching (needs parse_estimated_time)",
+    ),
+    (
+        "favorites_first",
+        "In the All view, list favorite skills first when not searching",
+    ),
+    (
+        "show_usage_count",
+        "Show how many times a skill has been run in the detail pane",
+    ),
+    (
+        "show_detail_pane",
+        "Show the detail pane; D toggles it at runtime",
+    ),
+    (
+        "manifest_filenames",
+        "File names recognized as skill manifests",
+    ),
+    (
+        "poll_interval_ms",
+        "Milliseconds to wait for input before redrawing (10-1000)",
+    ),
+    (
+        "output_panel_ratio",
+        "Share of the terminal height covered by the output panel (0.2-0.9)",
+    ),
+    ("[search]", "Fuzzy search tuning"),
+    ("min_score", "Hide matches scoring below this"),
+    (
+        "[search.synonyms]",
+        "Query aliases, e.g. k8s = \"kubernetes\"",
+    ),
+    (
+        "[manifest_limits]",
+        "Longest allowed manifest fields, in characters",
+    ),
+    ("max_id_length", "Longest skill id"),
+    ("max_name_length", "Longest skill name"),
+    ("max_description_length", "Longest skill description"),
+];
+
+/// Optional settings with no default value, written commented out by `pane init`
+const OPTIONAL_SETTINGS: &str = "\
+# Optional settings (remove the leading # to use):
+# Pre-select this skill on launch
+# default_skill = \"claude-tips\"
+# Load colors from a separate theme file
+# theme_file = \"~/.config/pane/theme.toml\"
+";
+
+/// The default configuration as TOML, with a comment above each setting
+///
+/// Used by `pane init` to give new users a config file documenting every
+/// option. Parsing the result yields `Config::default()`.
+///
+/// # Errors
+///
+/// Returns an error if the default configuration cannot be serialized
+pub fn default_config_toml() -> Result<String> {
+    let body = toml::to_string_pretty(&Config::default())
+        .context("Failed to serialize the default configuration")?;
+
+    let mut text =
+        String::from("# pane configuration\n# Every setting below is the default value.\n");
+    let mut optional_written = false;
+    for line in body.lines() {
+        if line.starts_with('[') && !optional_written {
+            text.push('\n');
+            text.push_str(OPTIONAL_SETTINGS);
+            optional_written = true;
+        }
+        let key = if line.starts_with('[') {
+            Some(line.trim())
+        } else {
+            line.split_once(" = ").map(|(key, _)| key)
+        };
+        if let Some((_, comment)) = CONFIG_COMMENTS.iter().find(|(name, _)| Some(*name) == key) {
+            if !text.ends_with("\n\n") {
+                text.push('\n');
+            }
+            text.push_str(&format!("# {}\n", comment));
+        }
+        text.push_str(line);
+        text.push('\n');
+    }
+    Ok(text)
 }
 
 /// Resolves the config file path, checking environment variable override first
@@ -187,7 +711,7 @@ pub fn load_config() -> Result<Config> {
 /// Priority:
 /// 1. `PANE_CONFIG_PATH` environment variable
 /// 2. `~/.config/pane/config.toml` (default)
-fn get_config_path() -> PathBuf {
+pub fn get_config_path() -> PathBuf {
     if let Ok(path) = env::var("PANE_CONFIG_PATH") {
         return expand_tilde(&path);
     }
@@ -222,6 +746,8 @@ mod tests {
         assert_eq!(config.default_view_mode, ViewMode::All);
         assert!(config.enable_mouse);
         assert_eq!(config.theme, None);
+        assert_eq!(config.theme_file, None);
+        assert!(!config.no_color);
         assert_eq!(config.max_recent_skills, 10);
         assert!(!config.debug_log_enabled);
         assert_eq!(
@@ -238,6 +764,29 @@ mod tests {
             config.skill_paths[2],
             PathBuf::from("/usr/local/share/pane/skills/")
         );
+        assert!(!config.start_in_search);
+        assert!(!config.restore_session);
+        assert!(!config.strict_discovery);
+        assert!(config.wrap_navigation);
+        assert!(!config.enter_runs_top_match);
+        assert!(!config.quit_after_run);
+        assert!(config.enable_project_skills);
+        assert!(config.enable_user_skills);
+        assert!(config.enable_system_skills);
+        assert!(config.enable_builtin_skills);
+        assert_eq!(
+            config.manifest_filenames,
+            vec!["pane-skill.yaml".to_string()]
+        );
+        assert_eq!(config.default_skill, None);
+        assert!(!config.parse_estimated_time);
+        assert!(!config.sort_by_estimated_time);
+        assert!(!config.favorites_first);
+        assert!(config.show_detail_pane);
+        assert_eq!(config.search.min_score, 0);
+        assert!(config.search.synonyms.is_empty());
+        assert_eq!(config.manifest_limits, ManifestLimits::default());
+        assert_eq!(config.output_panel_ratio, 0.8);
     }
 
     #[test]
@@ -260,14 +809,40 @@ mod tests {
 
         // Assert
         assert!(result.is_ok());
-        let config = result.unwrap();
+        let (config, warnings) = result.unwrap();
         assert!(config.enable_mouse);
         assert_eq!(config.max_recent_skills, 10);
+        assert!(warnings.is_empty());
 
         // Cleanup
         env::remove_var("PANE_CONFIG_PATH");
     }
 
+    #[test]
+    #[serial]
+    fn test_load_config_no_color_env_sets_no_color() {
+        // Arrange
+        let previous = env::var_os("NO_COLOR");
+        env::set_var("PANE_CONFIG_PATH", "/nonexistent/path/config.toml");
+
+        // Act
+        env::set_var("NO_COLOR", "1");
+        let (with_env, _) = load_config().unwrap();
+        env::set_var("NO_COLOR", "");
+        let (empty_env, _) = load_config().unwrap();
+
+        // Cleanup
+        match previous {
+            Some(value) => env::set_var("NO_COLOR", value),
+            None => env::remove_var("NO_COLOR"),
+        }
+        env::remove_var("PANE_CONFIG_PATH");
+
+        // Assert
+        assert!(with_env.no_color);
+        assert!(!empty_env.no_color);
+    }
+
     #[test]
     #[serial]
     fn test_load_config_valid_toml_parses_correctly() {
@@ -282,7 +857,7 @@ mod tests {
 
         // Assert
         assert!(result.is_ok());
-        let config = result.unwrap();
+        let (config, _) = result.unwrap();
         assert!(!config.enable_mouse);
         assert_eq!(config.max_recent_skills, 20);
         assert!(config.debug_log_enabled);
@@ -346,6 +921,49 @@ mod tests {
         assert!(error_msg.contains("skill_paths cannot be empty"));
     }
 
+    #[test]
+    fn test_config_search_synonyms_parse_from_toml() {
+        // Arrange
+        let toml_str = "[search]\nsynonyms = { k8s = \"kubernetes\", tf = \"terraform\" }\n";
+
+        // Act
+        let config: Config = toml::from_str(toml_str).unwrap();
+
+        // Assert
+        assert_eq!(config.search.synonyms.len(), 2);
+        assert_eq!(config.search.synonyms["k8s"], "kubernetes");
+        assert_eq!(config.search.min_score, 0);
+    }
+
+    #[test]
+    fn test_config_manifest_limits_parse_partially_from_toml() {
+        // Arrange
+        let toml_str = "[manifest_limits]\nmax_name_length = 40\n";
+
+        // Act
+        let config: Config = toml::from_str(toml_str).unwrap();
+
+        // Assert
+        assert_eq!(config.manifest_limits.max_name_length, 40);
+        assert_eq!(
+            config.manifest_limits.max_description_length,
+            ManifestLimits::default().max_description_length
+        );
+    }
+
+    #[test]
+    fn test_config_search_min_score_parses_from_toml() {
+        // Arrange
+        let toml_str = "[search]\nmin_score = 40\n";
+
+        // Act
+        let config: Config = toml::from_str(toml_str).unwrap();
+
+        // Assert
+        assert_eq!(config.search.min_score, 40);
+        assert!(config.wrap_navigation);
+    }
+
     #[test]
     fn test_config_validate_valid_config_passes() {
         // Arrange
@@ -399,7 +1017,7 @@ mod tests {
 
         // Assert
         assert!(result.is_ok());
-        let config = result.unwrap();
+        let (config, _) = result.unwrap();
         assert!(config.theme.is_some());
 
         // Cleanup
@@ -417,4 +1035,179 @@ mod tests {
         // Assert
         assert!(theme_option.is_none());
     }
+
+    const THEME_TOML: &str = r#"
+primary = "Magenta"
+secondary = "Blue"
+highlight = "DarkGray"
+border = "Gray"
+text = "White"
+text_dim = "DarkGray"
+tag_bg = "Blue"
+tag_fg = "White"
+border_style = "Plain"
+"#;
+
+    /// Write `config.toml` with the given contents and point `PANE_CONFIG_PATH` at it
+    fn write_config(dir: &Path, contents: &str) {
+        let config_path = dir.join("config.toml");
+        fs::write(&config_path, contents).unwrap();
+        env::set_var("PANE_CONFIG_PATH", config_path.to_str().unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_theme_file_overrides_inline_theme() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let theme_path = dir.path().join("mine.toml");
+        fs::write(&theme_path, THEME_TOML).unwrap();
+        let inline_theme = fs::read_to_string("tests/fixtures/configs/with_theme.toml").unwrap();
+        write_config(
+            dir.path(),
+            &format!("theme_file = {:?}\n{}", theme_path, inline_theme),
+        );
+
+        // Act
+        let (config, warnings) = load_config().unwrap();
+
+        // Assert
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("both theme and theme_file are set"));
+        let theme = config.theme.unwrap();
+        assert_eq!(theme.primary, ratatui::style::Color::Magenta);
+        assert_eq!(theme.border_style, ratatui::widgets::BorderType::Plain);
+
+        // Cleanup
+        env::remove_var("PANE_CONFIG_PATH");
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_missing_theme_file_falls_back() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write_config(
+            dir.path(),
+            "theme_file = \"/nonexistent/pane/theme.toml\"\n",
+        );
+
+        // Act
+        let result = load_config();
+
+        // Assert
+        let (config, warnings) = result.unwrap();
+        assert_eq!(config.theme, None);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("ignoring theme_file"));
+
+        // Cleanup
+        env::remove_var("PANE_CONFIG_PATH");
+    }
+
+    #[test]
+    fn test_config_poll_interval_uses_configured_value() {
+        // Arrange
+        let config = Config {
+            poll_interval_ms: 500,
+            ..Config::default()
+        };
+
+        // Act & Assert
+        assert_eq!(config.poll_interval(), Duration::from_millis(500));
+        assert_eq!(
+            Config::default().poll_interval(),
+            Duration::from_millis(250)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_clamps_out_of_range_poll_interval() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "poll_interval_ms = 5000\n");
+
+        // Act
+        let (config, warnings) = load_config().unwrap();
+
+        // Assert
+        assert_eq!(config.poll_interval_ms, MAX_POLL_INTERVAL_MS);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("poll_interval_ms = 5000"));
+
+        // Cleanup
+        env::remove_var("PANE_CONFIG_PATH");
+    }
+
+    #[test]
+    fn test_config_output_panel_percent_clamps_ratio() {
+        // Arrange
+        let with_ratio = |output_panel_ratio| Config {
+            output_panel_ratio,
+            ..Config::default()
+        };
+
+        // Act & Assert
+        assert_eq!(Config::default().output_panel_percent(), 80);
+        assert_eq!(with_ratio(0.5).output_panel_percent(), 50);
+        assert_eq!(with_ratio(0.05).output_panel_percent(), 20);
+        assert_eq!(with_ratio(1.5).output_panel_percent(), 90);
+        assert_eq!(with_ratio(f64::NAN).output_panel_percent(), 80);
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_clamps_out_of_range_output_panel_ratio() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "output_panel_ratio = 0.1\n");
+
+        // Act
+        let (config, warnings) = load_config().unwrap();
+
+        // Assert
+        assert_eq!(config.output_panel_ratio, MIN_OUTPUT_PANEL_RATIO);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("output_panel_ratio = 0.1"));
+
+        // Cleanup
+        env::remove_var("PANE_CONFIG_PATH");
+    }
+
+    #[test]
+    fn test_default_config_toml_parses_back_to_defaults() {
+        // Act
+        let text = default_config_toml().unwrap();
+
+        // Assert
+        let parsed: Config = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, Config::default());
+        assert!(text.contains("# default_skill = "));
+    }
+
+    #[test]
+    fn test_default_config_toml_comments_every_setting() {
+        // Arrange
+        let text = default_config_toml().unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        // Act - settings and table headers without a comment right above them
+        let uncommented: Vec<&str> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| {
+                line.starts_with('[') || (!line.starts_with('#') && line.contains(" = "))
+            })
+            .filter(|(i, _)| !lines[i - 1].starts_with("# "))
+            .map(|(_, line)| *line)
+            .collect();
+
+        // Assert
+        assert!(
+            uncommented.is_empty(),
+            "missing comments: {:?}",
+            uncommented
+        );
+    }
 }
@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
 use crate::config::Config;
+use crate::json;
 use crate::skills::{manifest::ContextConfig, Skill};
 
 /// Context information passed to skills during execution
@@ -25,7 +26,8 @@ pub struct SkillContext {
     pub project_name: Option<String>,
     /// Path to Pane's config file
     pub config_path: PathBuf,
-    /// Additional arguments passed to the skill
+    /// Arguments passed to the skill: the manifest args, plus any extra args
+    /// given for a single run from the TUI
     pub args: Vec<String>,
 }
 
@@ -92,7 +94,8 @@ impl SkillContext {
     /// Prepare environment variables for skill execution
     ///
     /// Creates PANE_* environment variables based on the context and
-    /// the skill's context configuration flags.
+    /// the skill's context configuration flags. With `pass_any_context`
+    /// turned off, nothing is passed and the map is empty.
     ///
     /// # Arguments
     ///
@@ -118,6 +121,11 @@ impl SkillContext {
     pub fn prepare_environment(&self, context_config: &ContextConfig) -> HashMap<String, String> {
         let mut env_vars = HashMap::new();
 
+        // Sandboxed skills opt out of every PANE_* variable
+        if !context_config.pass_any_context {
+            return env_vars;
+        }
+
         // Always pass skill ID and name
         env_vars.insert("PANE_ID".to_string(), self.skill_id.clone());
         env_vars.insert("PANE_NAME".to_string(), self.skill_name.clone());
@@ -151,8 +159,61 @@ impl SkillContext {
             }
         }
 
+        if context_config.pass_context_json {
+            env_vars.insert("PANE_CONTEXT_JSON".to_string(), self.to_json());
+        }
+
         env_vars
     }
+
+    /// Serialize the full context as a single-line JSON object
+    ///
+    /// Every field is included regardless of the `pass_*` flags; missing git
+    /// root and project name are encoded as `null`. Used for `PANE_CONTEXT_JSON`.
+    ///
+    /// # Returns
+    ///
+    /// A JSON object with `id`, `name`, `cwd`, `git_root`, `project_name`,
+    /// `config_path`, and `args` keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::PathBuf;
+    /// # use pane::context::SkillContext;
+    /// let context = SkillContext {
+    ///     skill_id: "list".to_string(),
+    ///     skill_name: "List".to_string(),
+    ///     cwd: PathBuf::from("/tmp"),
+    ///     git_root: None,
+    ///     project_name: None,
+    ///     config_path: PathBuf::from("/tmp/config.toml"),
+    ///     args: vec!["-l".to_string()],
+    /// };
+    /// assert_eq!(
+    ///     context.to_json(),
+    ///     r#"{"id":"list","name":"List","cwd":"/tmp","git_root":null,"project_name":null,"config_path":"/tmp/config.toml","args":["-l"]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let optional =
+            |value: Option<String>| value.map_or("null".to_string(), |v| json::quote(&v));
+
+        format!(
+            "{{\"id\":{},\"name\":{},\"cwd\":{},\"git_root\":{},\"project_name\":{},\"config_path\":{},\"args\":{}}}",
+            json::quote(&self.skill_id),
+            json::quote(&self.skill_name),
+            json::quote(&self.cwd.to_string_lossy()),
+            optional(
+                self.git_root
+                    .as_ref()
+                    .map(|root| root.to_string_lossy().to_string())
+            ),
+            optional(self.project_name.clone()),
+            json::quote(&self.config_path.to_string_lossy()),
+            json::quote_all(&self.args)
+        )
+    }
 }
 
 /// Detect git repository root from the given directory
@@ -253,33 +314,15 @@ fn expand_tilde(path: &str) -> PathBuf {
 mod tests {
     use super::*;
     use crate::config::Config;
-    use crate::skills::{
-        manifest::{SkillManifest, UiConfig, UiMode},
-        SkillSource,
-    };
+    use crate::test_support::test_skill;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
     fn create_test_skill(id: &str, name: &str) -> Skill {
-        Skill {
-            manifest: SkillManifest {
-                id: id.to_string(),
-                name: name.to_string(),
-                description: "Test skill".to_string(),
-                version: "1.0.0".to_string(),
-                exec: "test".to_string(),
-                args: vec!["--flag".to_string(), "value".to_string()],
-                tags: vec![],
-                estimated_time: None,
-                ui: UiConfig {
-                    mode: UiMode::Tui,
-                    fullscreen: true,
-                },
-                context: ContextConfig::default(),
-            },
-            source: SkillSource::Project,
-            manifest_path: PathBuf::from("test.yaml"),
-        }
+        let mut skill = test_skill(id);
+        skill.manifest.name = name.to_string();
+        skill.manifest.args = vec!["--flag".to_string(), "value".to_string()];
+        skill
     }
 
     #[test]
@@ -289,10 +332,12 @@ mod tests {
         let skill = create_test_skill("test-skill", "Test Skill");
         let config = Config::default();
         let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Act
         let result = SkillContext::build(&skill, &config);
+        env::set_current_dir(original_dir).unwrap();
 
         // Assert
         assert!(result.is_ok());
@@ -417,10 +462,12 @@ mod tests {
             args: vec![],
         };
         let context_config = ContextConfig {
+            pass_any_context: true,
             pass_cwd: false,
             pass_git_root: false,
             pass_project_name: false,
             pass_stdin_json: false,
+            pass_context_json: false,
         };
 
         // Act
@@ -488,4 +535,111 @@ mod tests {
         // Assert
         assert_eq!(path, PathBuf::from("/absolute/path/config.toml"));
     }
+
+    fn create_test_context() -> SkillContext {
+        SkillContext {
+            skill_id: "test-skill".to_string(),
+            skill_name: "Test \"Quoted\" Skill".to_string(),
+            cwd: PathBuf::from("/home/user/project"),
+            git_root: Some(PathBuf::from("/home/user/project")),
+            project_name: None,
+            config_path: PathBuf::from("/home/user/.config/pane/config.toml"),
+            args: vec!["--flag".to_string(), "a b".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_to_json_contains_expected_fields_and_is_valid() {
+        // Arrange
+        let context = create_test_context();
+
+        // Act
+        let json = context.to_json();
+
+        // Assert
+        assert!(crate::json::pretty_print(&json).is_ok());
+        assert!(json.contains(r#""id":"test-skill""#));
+        assert!(json.contains(r#""name":"Test \"Quoted\" Skill""#));
+        assert!(json.contains(r#""cwd":"/home/user/project""#));
+        assert!(json.contains(r#""git_root":"/home/user/project""#));
+        assert!(json.contains(r#""project_name":null"#));
+        assert!(json.contains(r#""config_path":"/home/user/.config/pane/config.toml""#));
+        assert!(json.contains(r#""args":["--flag","a b"]"#));
+    }
+
+    #[test]
+    fn test_prepare_environment_includes_context_json_when_enabled() {
+        // Arrange
+        let context = create_test_context();
+        let context_config = ContextConfig {
+            pass_context_json: true,
+            ..ContextConfig::default()
+        };
+
+        // Act
+        let env_vars = context.prepare_environment(&context_config);
+
+        // Assert
+        assert_eq!(env_vars.get("PANE_CONTEXT_JSON"), Some(&context.to_json()));
+        assert!(env_vars.contains_key("PANE_CWD"));
+    }
+
+    #[test]
+    fn test_prepare_environment_passes_nothing_without_any_context() {
+        // Arrange
+        let context = create_test_context();
+        let context_config = ContextConfig {
+            pass_any_context: false,
+            pass_stdin_json: true,
+            pass_context_json: true,
+            ..ContextConfig::default()
+        };
+
+        // Act
+        let env_vars = context.prepare_environment(&context_config);
+
+        // Assert
+        assert!(
+            env_vars.keys().all(|key| !key.starts_with("PANE_")),
+            "unexpected variables: {:?}",
+            env_vars.keys()
+        );
+        assert!(env_vars.is_empty());
+        assert!(!context_config.sends_stdin_json());
+    }
+
+    #[test]
+    fn test_prepare_environment_with_any_context_matches_default() {
+        // Arrange
+        let context = create_test_context();
+        let explicit = ContextConfig {
+            pass_any_context: true,
+            ..ContextConfig::default()
+        };
+
+        // Act
+        let env_vars = context.prepare_environment(&explicit);
+
+        // Assert
+        assert_eq!(
+            env_vars,
+            context.prepare_environment(&ContextConfig::default())
+        );
+        assert!(env_vars.contains_key("PANE_ID"));
+        assert!(env_vars.contains_key("PANE_NAME"));
+        assert!(env_vars.contains_key("PANE_CONFIG_PATH"));
+        assert!(env_vars.contains_key("PANE_CWD"));
+    }
+
+    #[test]
+    fn test_prepare_environment_omits_context_json_by_default() {
+        // Arrange
+        let context = create_test_context();
+
+        // Act
+        let env_vars = context.prepare_environment(&ContextConfig::default());
+
+        // Assert
+        assert!(!env_vars.contains_key("PANE_CONTEXT_JSON"));
+    }
 }
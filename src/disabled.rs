@@ -0,0 +1,111 @@
+//! Persistence for skills the user has disabled
+//!
+//! Disabled skill IDs are stored as a sorted YAML list so a skill switched
+//! off in the launcher stays off across restarts. IDs are kept even when no
+//! matching skill is discovered, since project skills depend on the working
+//! directory. A missing or unreadable file means nothing is disabled.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::usage::state_dir;
+
+/// File name of the disabled skills file inside the Pane state directory
+const DISABLED_FILE: &str = "disabled.yaml";
+
+/// Returns the default location of the disabled skills file
+///
+/// Uses `$XDG_STATE_HOME/pane/disabled.yaml`, falling back to
+/// `~/.local/state/pane/disabled.yaml`.
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set
+pub fn default_disabled_path() -> Option<PathBuf> {
+    Some(state_dir()?.join(DISABLED_FILE))
+}
+
+/// Load disabled skill IDs from `path`
+///
+/// # Arguments
+///
+/// * `path` - Location of the disabled skills file
+///
+/// # Returns
+///
+/// The disabled IDs, or an empty set if the file is missing or corrupt
+pub fn load_disabled(path: &Path) -> HashSet<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<HashSet<String>>(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Save disabled skill IDs to `path`, creating parent directories
+///
+/// IDs are written sorted so the file is stable between saves.
+///
+/// # Arguments
+///
+/// * `path` - Location of the disabled skills file
+/// * `disabled` - IDs of the disabled skills
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file cannot be written
+pub fn save_disabled(path: &Path, disabled: &HashSet<String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory {:?}", parent))?;
+    }
+
+    let sorted: BTreeSet<&String> = disabled.iter().collect();
+    let contents = serde_yaml::to_string(&sorted).context("Failed to serialize disabled skills")?;
+
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write disabled skills to {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_round_trip() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(DISABLED_FILE);
+        let disabled: HashSet<String> = ["deploy".to_string(), "tips".to_string()]
+            .into_iter()
+            .collect();
+
+        // Act
+        save_disabled(&path, &disabled).unwrap();
+        let loaded = load_disabled(&path);
+
+        // Assert
+        assert_eq!(loaded, disabled);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "- deploy\n- tips\n"
+        );
+    }
+
+    #[test]
+    fn test_load_disabled_missing_or_corrupt_file_is_empty() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let corrupt = dir.path().join("corrupt.yaml");
+        std::fs::write(&corrupt, "deploy: [not a list").unwrap();
+
+        // Act
+        let missing = load_disabled(&dir.path().join(DISABLED_FILE));
+        let corrupt = load_disabled(&corrupt);
+
+        // Assert
+        assert!(missing.is_empty());
+        assert!(corrupt.is_empty());
+    }
+}
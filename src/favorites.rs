@@ -0,0 +1,111 @@
+//! Persistence for skills the user has marked as favorites
+//!
+//! Favorite skill IDs are stored as a sorted YAML list so a skill starred in
+//! the launcher stays in the Favorites view across restarts. IDs are kept even
+//! when no matching skill is discovered, since project skills depend on the
+//! working directory. A missing or unreadable file means there are no favorites.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::usage::state_dir;
+
+/// File name of the favorites file inside the Pane state directory
+const FAVORITES_FILE: &str = "favorites.yaml";
+
+/// Returns the default location of the favorites file
+///
+/// Uses `$XDG_STATE_HOME/pane/favorites.yaml`, falling back to
+/// `~/.local/state/pane/favorites.yaml`.
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set
+pub fn default_favorites_path() -> Option<PathBuf> {
+    Some(state_dir()?.join(FAVORITES_FILE))
+}
+
+/// Load favorite skill IDs from `path`
+///
+/// # Arguments
+///
+/// * `path` - Location of the favorites file
+///
+/// # Returns
+///
+/// The favorite IDs, or an empty set if the file is missing or corrupt
+pub fn load_favorites(path: &Path) -> HashSet<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<HashSet<String>>(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Save favorite skill IDs to `path`, creating parent directories
+///
+/// IDs are written sorted so the file is stable between saves.
+///
+/// # Arguments
+///
+/// * `path` - Location of the favorites file
+/// * `favorites` - IDs of the favorite skills
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file cannot be written
+pub fn save_favorites(path: &Path, favorites: &HashSet<String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory {:?}", parent))?;
+    }
+
+    let sorted: BTreeSet<&String> = favorites.iter().collect();
+    let contents = serde_yaml::to_string(&sorted).context("Failed to serialize favorites")?;
+
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write favorites to {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_favorites_round_trip() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(FAVORITES_FILE);
+        let favorites: HashSet<String> = ["deploy".to_string(), "tips".to_string()]
+            .into_iter()
+            .collect();
+
+        // Act
+        save_favorites(&path, &favorites).unwrap();
+        let loaded = load_favorites(&path);
+
+        // Assert
+        assert_eq!(loaded, favorites);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "- deploy\n- tips\n"
+        );
+    }
+
+    #[test]
+    fn test_load_favorites_missing_or_corrupt_file_is_empty() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let corrupt = dir.path().join("corrupt.yaml");
+        std::fs::write(&corrupt, "deploy: [not a list").unwrap();
+
+        // Act
+        let missing = load_favorites(&dir.path().join(FAVORITES_FILE));
+        let corrupt = load_favorites(&corrupt);
+
+        // Assert
+        assert!(missing.is_empty());
+        assert!(corrupt.is_empty());
+    }
+}
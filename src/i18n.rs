@@ -29,10 +29,13 @@ pub enum Language {
 }
 
 impl Language {
+    /// Every supported language, in display order
+    pub const ALL: [Language; 2] = [Language::En, Language::Ko];
+
     /// Parse language code with fallback to English
     ///
     /// Accepts various formats (case-insensitive):
-    /// - "en", "EN", "english" → English
+    /// - "en", "EN", "eng", "english" → English
     /// - "ko", "KO", "kor", "korean" → Korean
     /// - Any other value → English (fallback)
     ///
@@ -60,6 +63,35 @@ impl Language {
         }
     }
 
+    /// Check whether a code names a supported language rather than falling back
+    ///
+    /// `from_code` maps unknown codes to English; this tells a known English
+    /// alias such as "eng" apart from a typo such as "xx".
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Language code string (case-insensitive)
+    ///
+    /// # Returns
+    ///
+    /// `true` if `code` is one of the aliases accepted by `from_code`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::i18n::Language;
+    ///
+    /// assert!(Language::is_recognized("eng"));
+    /// assert!(Language::is_recognized("Korean"));
+    /// assert!(!Language::is_recognized("xx"));
+    /// ```
+    pub fn is_recognized(code: &str) -> bool {
+        matches!(
+            code.trim().to_lowercase().as_str(),
+            "en" | "eng" | "english" | "ko" | "kor" | "korean" | "한국어"
+        )
+    }
+
     /// Get the standard language code
     ///
     /// # Returns
@@ -103,6 +135,8 @@ pub struct Translations {
     pub footer_insert_hints: &'static str,
     /// Insert mode indicator text
     pub footer_insert_mode: &'static str,
+    /// Normal mode indicator text
+    pub footer_normal_mode: &'static str,
 
     // Footer - View modes
     /// "All" view mode label
@@ -111,10 +145,106 @@ pub struct Translations {
     pub footer_view_favorites: &'static str,
     /// "Recent" view mode label
     pub footer_view_recent: &'static str,
+    /// "Disabled" view mode label
+    pub footer_view_disabled: &'static str,
+    /// Label preceding the active category filter
+    pub footer_category_label: &'static str,
+
+    // Category picker
+    /// Category picker overlay title
+    pub category_picker_title: &'static str,
+    /// Picker entry that clears the category filter
+    pub category_all_label: &'static str,
+
+    // Tag picker
+    /// Tag picker overlay title
+    pub tag_picker_title: &'static str,
+    /// Picker entry that clears all selected tags
+    pub tag_clear_label: &'static str,
+    /// Label preceding the active tag filter in the footer
+    pub footer_tags_label: &'static str,
+
+    // Status toasts
+    /// Prefix for the list of duplicate skill IDs found during discovery
+    pub status_duplicate_skill_ids: &'static str,
+    /// Prefix for the list of skill IDs whose manifests have warnings
+    pub status_manifest_warnings: &'static str,
+    /// Prefix for the list of skill paths that could not be read during discovery
+    pub status_unreadable_skill_paths: &'static str,
+    /// Placed between a TUI skill's name and its exit code after it exits
+    pub status_skill_exited_with_code: &'static str,
+    /// Placed after a TUI skill's name when it was stopped by a signal
+    pub status_skill_terminated: &'static str,
+    /// Prefix for the warning shown when the configured language is unknown
+    pub status_unknown_language: &'static str,
+    /// Prefix for the toast shown after copying a value to the clipboard
+    pub status_copied: &'static str,
+    /// Prefix for the toast shown when a value could only be sent to the
+    /// terminal's clipboard (OSC 52), which may ignore it
+    pub status_sent_to_terminal: &'static str,
+    /// Prefix for the toast shown when copying to the clipboard fails
+    pub status_copy_failed: &'static str,
+    /// Placed after a skill's name when the user disables it
+    pub status_skill_disabled: &'static str,
+    /// Placed after a skill's name when the user re-enables it
+    pub status_skill_enabled: &'static str,
+    /// Placed after a skill's name when the user marks it as a favorite
+    pub status_skill_favorited: &'static str,
+    /// Placed after a skill's name when the user unmarks it as a favorite
+    pub status_skill_unfavorited: &'static str,
+    /// Shown after the configuration is reloaded successfully
+    pub status_config_reloaded: &'static str,
+    /// Prefix for the error shown when reloading the configuration fails
+    pub status_config_reload_failed: &'static str,
+    /// Prefix for problems found while loading the configuration, such as clamped values
+    pub status_config_warning: &'static str,
+    /// Shown after an edited skill manifest is reloaded
+    pub status_manifest_reloaded: &'static str,
+    /// Prefix for the error shown when the editor fails to run
+    pub status_editor_failed: &'static str,
+    /// Prefix for the error shown when an edited manifest cannot be reloaded
+    pub status_manifest_reload_failed: &'static str,
+    /// Shown before the name of an inline skill while it runs
+    pub status_skill_running: &'static str,
+
+    // Confirmation dialog
+    /// Confirmation dialog title
+    pub confirm_title: &'static str,
+    /// Prompt shown when quitting while an inline skill is executing
+    pub confirm_quit_while_executing: &'static str,
+
+    // Launch arguments prompt
+    /// Title of the prompt for extra arguments to run a skill with
+    pub args_prompt_title: &'static str,
+    /// Hint below the extra arguments input
+    pub args_prompt_hint: &'static str,
+
+    // Output preview
+    /// Title of the detail pane section showing a skill's last output
+    pub output_preview_title: &'static str,
 
     // Skill list
     /// Message shown when no skills are available
     pub empty_skills_message: &'static str,
+    /// Message shown when the Favorites view has no skills
+    pub empty_favorites_message: &'static str,
+    /// Message shown when the Recent view has no skills
+    pub empty_recent_message: &'static str,
+    /// Message shown when the Disabled view has no skills
+    pub empty_disabled_message: &'static str,
+    /// Message shown while skills are discovered in the background
+    pub loading_skills_message: &'static str,
+
+    // First-run guidance
+    /// Title of the panel shown when no skills were discovered at all
+    pub first_run_title: &'static str,
+    /// Headline explaining that no skills were found
+    pub first_run_no_skills: &'static str,
+    /// Lead-in for the list of configured skill paths
+    pub first_run_search_paths_label: &'static str,
+    /// Instructions for adding a first skill; `{manifest}` is replaced with
+    /// the configured manifest file name(s)
+    pub first_run_add_skill_hint: &'static str,
 
     // Detail pane
     /// Detail pane title
@@ -143,6 +273,25 @@ pub struct Translations {
     pub output_panel_execution_time_label: &'static str,
     /// Hint for closing output panel
     pub output_panel_close_hint: &'static str,
+    /// "Status:" label in the output panel header
+    pub output_panel_status_label: &'static str,
+    /// Status shown when the skill exited with code 0
+    pub output_panel_status_completed: &'static str,
+    /// Status shown when the skill exited with a non-zero code
+    pub output_panel_status_failed: &'static str,
+    /// Status shown when the skill could not be run or was killed
+    pub output_panel_status_error: &'static str,
+    /// "Attempts:" label shown after retries
+    pub output_panel_attempts_label: &'static str,
+    /// Flag shown in the output summary line when output was cut off
+    pub output_summary_truncated: &'static str,
+    /// Label before the current/total line numbers in the output stats line
+    pub output_stats_line_label: &'static str,
+    /// Note appended to inline output cut off at the size limit; `{limit}`
+    /// is replaced with the limit (see `output_truncated_message`)
+    pub output_truncated_warning: &'static str,
+    /// Prefix for the error appended to inline output when `post_run` fails
+    pub post_run_failed: &'static str,
 }
 
 impl Translations {
@@ -174,6 +323,57 @@ impl Translations {
         }
     }
 
+    /// Format the truncation note for output cut off at `limit_bytes`
+    ///
+    /// # Arguments
+    ///
+    /// * `limit_bytes` - The output size limit that was exceeded
+    ///
+    /// # Returns
+    ///
+    /// `output_truncated_warning` with the limit shown in megabytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::i18n::{Language, Translations};
+    ///
+    /// let en = Translations::load(Language::En);
+    /// assert_eq!(
+    ///     en.output_truncated_message(10 * 1024 * 1024),
+    ///     "[Output truncated - exceeded 10MB limit]"
+    /// );
+    /// ```
+    pub fn output_truncated_message(&self, limit_bytes: usize) -> String {
+        let limit = format!("{}MB", limit_bytes / (1024 * 1024));
+        self.output_truncated_warning.replace("{limit}", &limit)
+    }
+
+    /// Build the first-run hint naming the configured manifest files
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_filenames` - The `manifest_filenames` setting; several
+    ///   names are joined with " / "
+    ///
+    /// # Returns
+    ///
+    /// `first_run_add_skill_hint` with `{manifest}` filled in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::i18n::{Language, Translations};
+    ///
+    /// let en = Translations::load(Language::En);
+    /// let hint = en.first_run_add_skill_message(&["skill.yaml".to_string()]);
+    /// assert!(hint.contains("a skill.yaml manifest"));
+    /// ```
+    pub fn first_run_add_skill_message(&self, manifest_filenames: &[String]) -> String {
+        self.first_run_add_skill_hint
+            .replace("{manifest}", &manifest_filenames.join(" / "))
+    }
+
     /// English translations
     fn english() -> Self {
         Translations {
@@ -185,14 +385,69 @@ impl Translations {
 
             // Footer
             footer_normal_hints: "j/k Move | / Search | Enter Run | Esc Quit",
-            footer_insert_hints: "Type to search | Esc Normal mode",
+            footer_insert_hints: "Type to search | Ctrl-u Clear | Esc Normal mode",
             footer_insert_mode: "-- INSERT --",
+            footer_normal_mode: "-- NORMAL --",
             footer_view_all: "All",
             footer_view_favorites: "Favorites",
             footer_view_recent: "Recent",
+            footer_view_disabled: "Disabled",
+            footer_category_label: "Category:",
+
+            // Category picker
+            category_picker_title: "Categories",
+            category_all_label: "All categories",
+
+            // Tag picker
+            tag_picker_title: "Tags (Enter toggles)",
+            tag_clear_label: "Clear tags",
+            footer_tags_label: "Tags:",
+
+            // Status toasts
+            status_duplicate_skill_ids: "Duplicate skill IDs ignored:",
+            status_manifest_warnings: "Manifest warnings (see pane doctor):",
+            status_unreadable_skill_paths: "Some skill paths could not be read:",
+            status_skill_exited_with_code: "exited with code",
+            status_skill_terminated: "was terminated by a signal",
+            status_unknown_language: "Unknown language code, using English:",
+            status_copied: "Copied:",
+            status_sent_to_terminal: "Sent to terminal clipboard:",
+            status_copy_failed: "Copy failed:",
+            status_skill_disabled: "disabled",
+            status_skill_enabled: "enabled",
+            status_skill_favorited: "added to favorites",
+            status_skill_unfavorited: "removed from favorites",
+            status_config_reloaded: "Configuration reloaded",
+            status_config_reload_failed: "Failed to reload configuration:",
+            status_config_warning: "Config warning:",
+            status_manifest_reloaded: "Manifest reloaded",
+            status_editor_failed: "Editor failed:",
+            status_manifest_reload_failed: "Failed to reload manifest:",
+            status_skill_running: "Running",
+
+            // Confirmation dialog
+            confirm_title: "Confirm",
+            confirm_quit_while_executing: "A skill is running. Quit and kill it? (y/n)",
+
+            // Launch arguments prompt
+            args_prompt_title: "Run with extra arguments",
+            args_prompt_hint: "Enter to run · Esc to cancel",
+
+            // Output preview
+            output_preview_title: "Last output",
 
             // Skill list
             empty_skills_message: "No skills available",
+            empty_favorites_message: "No favorites yet — press f to add",
+            empty_recent_message: "No recently used skills",
+            empty_disabled_message: "No disabled skills",
+            loading_skills_message: "Discovering skills…",
+
+            // First-run guidance
+            first_run_title: "Welcome to Pane",
+            first_run_no_skills: "No skills were found.",
+            first_run_search_paths_label: "Pane looks for skills in:",
+            first_run_add_skill_hint: "To add a skill, create a directory containing a {manifest} manifest in one of these paths, then restart Pane.",
 
             // Detail pane
             detail_pane_title: "Details",
@@ -209,6 +464,15 @@ impl Translations {
             output_panel_exit_code_label: "Exit Code:",
             output_panel_execution_time_label: "Execution Time:",
             output_panel_close_hint: "Press Esc to close",
+            output_panel_status_label: "Status:",
+            output_panel_status_completed: "Completed ✓",
+            output_panel_status_failed: "Failed ✗",
+            output_panel_status_error: "Error",
+            output_panel_attempts_label: "Attempts:",
+            output_summary_truncated: "truncated",
+            output_stats_line_label: "line",
+            output_truncated_warning: "[Output truncated - exceeded {limit} limit]",
+            post_run_failed: "post_run failed:",
         }
     }
 
@@ -223,14 +487,69 @@ impl Translations {
 
             // Footer
             footer_normal_hints: "j/k 이동 | / 검색 | Enter 실행 | Esc 종료",
-            footer_insert_hints: "검색어 입력 | Esc 일반 모드",
+            footer_insert_hints: "검색어 입력 | Ctrl-u 지우기 | Esc 일반 모드",
             footer_insert_mode: "-- 입력 --",
+            footer_normal_mode: "-- 일반 --",
             footer_view_all: "전체",
             footer_view_favorites: "즐겨찾기",
             footer_view_recent: "최근",
+            footer_view_disabled: "비활성",
+            footer_category_label: "카테고리:",
+
+            // Category picker
+            category_picker_title: "카테고리",
+            category_all_label: "전체 카테고리",
+
+            // Tag picker
+            tag_picker_title: "태그 (Enter로 선택)",
+            tag_clear_label: "태그 선택 해제",
+            footer_tags_label: "태그:",
+
+            // Status toasts
+            status_duplicate_skill_ids: "중복된 스킬 ID 무시됨:",
+            status_manifest_warnings: "매니페스트 경고 (pane doctor 참고):",
+            status_unreadable_skill_paths: "일부 스킬 경로를 읽을 수 없습니다:",
+            status_skill_exited_with_code: "종료 코드",
+            status_skill_terminated: "시그널로 종료됨",
+            status_unknown_language: "알 수 없는 언어 코드입니다. 영어를 사용합니다:",
+            status_copied: "복사됨:",
+            status_sent_to_terminal: "터미널 클립보드로 보냄:",
+            status_copy_failed: "복사 실패:",
+            status_skill_disabled: "비활성화됨",
+            status_skill_enabled: "활성화됨",
+            status_skill_favorited: "즐겨찾기에 추가됨",
+            status_skill_unfavorited: "즐겨찾기에서 제거됨",
+            status_config_reloaded: "설정을 다시 불러왔습니다",
+            status_config_reload_failed: "설정을 다시 불러오지 못했습니다:",
+            status_config_warning: "설정 경고:",
+            status_manifest_reloaded: "매니페스트를 다시 불러왔습니다",
+            status_editor_failed: "편집기 실행 실패:",
+            status_manifest_reload_failed: "매니페스트를 다시 불러오지 못했습니다:",
+            status_skill_running: "실행 중:",
+
+            // Confirmation dialog
+            confirm_title: "확인",
+            confirm_quit_while_executing: "스킬이 실행 중입니다. 종료하고 중단할까요? (y/n)",
+
+            // Launch arguments prompt
+            args_prompt_title: "추가 인자로 실행",
+            args_prompt_hint: "Enter 실행 · Esc 취소",
+
+            // Output preview
+            output_preview_title: "최근 출력",
 
             // Skill list
             empty_skills_message: "사용 가능한 스킬이 없습니다",
+            empty_favorites_message: "아직 즐겨찾기가 없습니다 — f를 눌러 추가하세요",
+            empty_recent_message: "최근 사용한 스킬이 없습니다",
+            empty_disabled_message: "비활성화된 스킬이 없습니다",
+            loading_skills_message: "스킬을 찾는 중…",
+
+            // First-run guidance
+            first_run_title: "페인에 오신 것을 환영합니다",
+            first_run_no_skills: "스킬을 찾지 못했습니다.",
+            first_run_search_paths_label: "페인은 다음 경로에서 스킬을 찾습니다:",
+            first_run_add_skill_hint: "스킬을 추가하려면 위 경로 중 하나에 {manifest} 매니페스트가 있는 디렉터리를 만든 뒤 페인을 다시 시작하세요.",
 
             // Detail pane
             detail_pane_title: "상세 정보",
@@ -247,6 +566,15 @@ impl Translations {
             output_panel_exit_code_label: "종료 코드:",
             output_panel_execution_time_label: "실행 시간:",
             output_panel_close_hint: "Esc를 눌러 닫기",
+            output_panel_status_label: "상태:",
+            output_panel_status_completed: "완료 ✓",
+            output_panel_status_failed: "실패 ✗",
+            output_panel_status_error: "오류",
+            output_panel_attempts_label: "시도 횟수:",
+            output_summary_truncated: "잘림",
+            output_stats_line_label: "줄",
+            output_truncated_warning: "[출력이 잘렸습니다 - {limit} 제한 초과]",
+            post_run_failed: "post_run 실패:",
         }
     }
 }
@@ -283,6 +611,28 @@ mod tests {
         assert_eq!(Language::from_code("invalid"), Language::En);
     }
 
+    #[test]
+    fn test_language_is_recognized_aliases() {
+        // Arrange & Act & Assert
+        assert!(Language::is_recognized("en"));
+        assert!(Language::is_recognized("EN"));
+        assert!(Language::is_recognized("eng"));
+        assert!(Language::is_recognized(" english "));
+        assert!(Language::is_recognized("ko"));
+        assert!(Language::is_recognized("kor"));
+        assert!(Language::is_recognized("Korean"));
+        assert!(Language::is_recognized("한국어"));
+    }
+
+    #[test]
+    fn test_language_is_recognized_rejects_unknown_codes() {
+        // Arrange & Act & Assert
+        assert!(!Language::is_recognized("xx"));
+        assert!(!Language::is_recognized("fr"));
+        assert!(!Language::is_recognized("engl"));
+        assert!(!Language::is_recognized(""));
+    }
+
     #[test]
     fn test_language_code() {
         // Arrange & Act & Assert
@@ -308,6 +658,7 @@ mod tests {
         assert_eq!(translations.app_title, "Pane");
         assert_eq!(translations.search_placeholder, "Type to search...");
         assert_eq!(translations.footer_insert_mode, "-- INSERT --");
+        assert_eq!(translations.footer_normal_mode, "-- NORMAL --");
         assert_eq!(translations.footer_view_all, "All");
         assert_eq!(translations.empty_skills_message, "No skills available");
     }
@@ -321,6 +672,7 @@ mod tests {
         assert_eq!(translations.app_title, "페인");
         assert_eq!(translations.search_placeholder, "검색어를 입력하세요...");
         assert_eq!(translations.footer_insert_mode, "-- 입력 --");
+        assert_eq!(translations.footer_normal_mode, "-- 일반 --");
         assert_eq!(translations.footer_view_all, "전체");
         assert_eq!(
             translations.empty_skills_message,
@@ -339,10 +691,58 @@ mod tests {
         assert!(!t.footer_normal_hints.is_empty());
         assert!(!t.footer_insert_hints.is_empty());
         assert!(!t.footer_insert_mode.is_empty());
+        assert!(!t.footer_normal_mode.is_empty());
         assert!(!t.footer_view_all.is_empty());
         assert!(!t.footer_view_favorites.is_empty());
         assert!(!t.footer_view_recent.is_empty());
+        assert!(!t.footer_view_disabled.is_empty());
+        assert!(!t.status_skill_disabled.is_empty());
+        assert!(!t.status_copied.is_empty());
+        assert!(!t.status_sent_to_terminal.is_empty());
+        assert!(!t.output_summary_truncated.is_empty());
+        assert!(!t.output_panel_status_label.is_empty());
+        assert!(!t.output_panel_attempts_label.is_empty());
+        assert!(!t.output_stats_line_label.is_empty());
+        assert!(!t.status_copy_failed.is_empty());
+        assert!(!t.status_unknown_language.is_empty());
+        assert!(!t.status_skill_enabled.is_empty());
+        assert!(!t.status_skill_favorited.is_empty());
+        assert!(!t.status_skill_unfavorited.is_empty());
+        assert!(!t.footer_category_label.is_empty());
+        assert!(!t.category_picker_title.is_empty());
+        assert!(!t.category_all_label.is_empty());
+        assert!(!t.tag_picker_title.is_empty());
+        assert!(!t.tag_clear_label.is_empty());
+        assert!(!t.footer_tags_label.is_empty());
+        assert!(!t.status_duplicate_skill_ids.is_empty());
+        assert!(!t.status_manifest_warnings.is_empty());
+        assert!(!t.status_config_reloaded.is_empty());
+        assert!(t.output_truncated_warning.contains("{limit}"));
+        assert!(!t.post_run_failed.is_empty());
+        assert!(t.first_run_add_skill_hint.contains("{manifest}"));
+        assert!(!t.status_skill_exited_with_code.is_empty());
+        assert!(!t.status_skill_terminated.is_empty());
+        assert!(!t.status_unreadable_skill_paths.is_empty());
+        assert!(!t.status_config_reload_failed.is_empty());
+        assert!(!t.status_config_warning.is_empty());
+        assert!(!t.status_manifest_reloaded.is_empty());
+        assert!(!t.status_editor_failed.is_empty());
+        assert!(!t.status_manifest_reload_failed.is_empty());
+        assert!(!t.status_skill_running.is_empty());
+        assert!(!t.confirm_title.is_empty());
+        assert!(!t.confirm_quit_while_executing.is_empty());
+        assert!(!t.args_prompt_title.is_empty());
+        assert!(!t.args_prompt_hint.is_empty());
+        assert!(!t.output_preview_title.is_empty());
         assert!(!t.empty_skills_message.is_empty());
+        assert!(!t.empty_favorites_message.is_empty());
+        assert!(!t.empty_recent_message.is_empty());
+        assert!(!t.empty_disabled_message.is_empty());
+        assert!(!t.loading_skills_message.is_empty());
+        assert!(!t.first_run_title.is_empty());
+        assert!(!t.first_run_no_skills.is_empty());
+        assert!(!t.first_run_search_paths_label.is_empty());
+        assert!(!t.first_run_add_skill_hint.is_empty());
         assert!(!t.detail_pane_title.is_empty());
         assert!(!t.output_panel_title.is_empty());
     }
@@ -358,11 +758,74 @@ mod tests {
         assert!(!t.footer_normal_hints.is_empty());
         assert!(!t.footer_insert_hints.is_empty());
         assert!(!t.footer_insert_mode.is_empty());
+        assert!(!t.footer_normal_mode.is_empty());
         assert!(!t.footer_view_all.is_empty());
         assert!(!t.footer_view_favorites.is_empty());
         assert!(!t.footer_view_recent.is_empty());
+        assert!(!t.footer_view_disabled.is_empty());
+        assert!(!t.status_skill_disabled.is_empty());
+        assert!(!t.status_copied.is_empty());
+        assert!(!t.status_sent_to_terminal.is_empty());
+        assert!(!t.output_summary_truncated.is_empty());
+        assert!(!t.output_panel_status_label.is_empty());
+        assert!(!t.output_panel_attempts_label.is_empty());
+        assert!(!t.output_stats_line_label.is_empty());
+        assert!(!t.status_copy_failed.is_empty());
+        assert!(!t.status_unknown_language.is_empty());
+        assert!(!t.status_skill_enabled.is_empty());
+        assert!(!t.status_skill_favorited.is_empty());
+        assert!(!t.status_skill_unfavorited.is_empty());
+        assert!(!t.footer_category_label.is_empty());
+        assert!(!t.category_picker_title.is_empty());
+        assert!(!t.category_all_label.is_empty());
+        assert!(!t.tag_picker_title.is_empty());
+        assert!(!t.tag_clear_label.is_empty());
+        assert!(!t.footer_tags_label.is_empty());
+        assert!(!t.status_duplicate_skill_ids.is_empty());
+        assert!(!t.status_manifest_warnings.is_empty());
+        assert!(!t.status_config_reloaded.is_empty());
+        assert!(t.output_truncated_warning.contains("{limit}"));
+        assert!(!t.post_run_failed.is_empty());
+        assert!(t.first_run_add_skill_hint.contains("{manifest}"));
+        assert!(!t.status_skill_exited_with_code.is_empty());
+        assert!(!t.status_skill_terminated.is_empty());
+        assert!(!t.status_unreadable_skill_paths.is_empty());
+        assert!(!t.status_config_reload_failed.is_empty());
+        assert!(!t.status_config_warning.is_empty());
+        assert!(!t.status_manifest_reloaded.is_empty());
+        assert!(!t.status_editor_failed.is_empty());
+        assert!(!t.status_manifest_reload_failed.is_empty());
+        assert!(!t.status_skill_running.is_empty());
+        assert!(!t.confirm_title.is_empty());
+        assert!(!t.confirm_quit_while_executing.is_empty());
+        assert!(!t.args_prompt_title.is_empty());
+        assert!(!t.args_prompt_hint.is_empty());
+        assert!(!t.output_preview_title.is_empty());
         assert!(!t.empty_skills_message.is_empty());
+        assert!(!t.empty_favorites_message.is_empty());
+        assert!(!t.empty_recent_message.is_empty());
+        assert!(!t.empty_disabled_message.is_empty());
+        assert!(!t.loading_skills_message.is_empty());
+        assert!(!t.first_run_title.is_empty());
+        assert!(!t.first_run_no_skills.is_empty());
+        assert!(!t.first_run_search_paths_label.is_empty());
+        assert!(!t.first_run_add_skill_hint.is_empty());
         assert!(!t.detail_pane_title.is_empty());
         assert!(!t.output_panel_title.is_empty());
     }
+
+    #[test]
+    fn test_output_truncated_message_is_localized() {
+        // Arrange
+        let en = Translations::load(Language::En);
+        let ko = Translations::load(Language::Ko);
+
+        // Act
+        let en_message = en.output_truncated_message(10 * 1024 * 1024);
+        let ko_message = ko.output_truncated_message(10 * 1024 * 1024);
+
+        // Assert
+        assert_eq!(en_message, "[Output truncated - exceeded 10MB limit]");
+        assert_eq!(ko_message, "[출력이 잘렸습니다 - 10MB 제한 초과]");
+    }
 }
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 use crate::state::InputMode;
 
@@ -20,8 +20,17 @@ pub enum InputEvent {
     MoveDown,
     /// Character input for search (any char in Insert mode)
     CharInput(char),
-    /// Backspace key (delete character from search)
+    /// Jump to the next skill starting with this letter (unbound letters and
+    /// digits in Normal mode)
+    JumpToPrefix(char),
+    /// Backspace key (delete character before the search cursor)
     Backspace,
+    /// Delete key (delete character under the search cursor)
+    Delete,
+    /// Move the search cursor left (← in Insert mode)
+    CursorLeft,
+    /// Move the search cursor right (→ in Insert mode)
+    CursorRight,
     /// Enter key (execute selected skill)
     Enter,
     /// Tab key (toggle view mode)
@@ -30,6 +39,20 @@ pub enum InputEvent {
     PageUp,
     /// Page Down key (scroll down by page)
     PageDown,
+    /// Jump to the first line (g or Home)
+    JumpToTop,
+    /// Jump to the last line (G or End)
+    JumpToBottom,
+    /// Scroll down by half a viewport (Ctrl-d)
+    HalfPageDown,
+    /// Scroll up by half a viewport (Ctrl-u in Normal mode)
+    HalfPageUp,
+    /// Clear the search query and stay in Insert mode (Ctrl-u in Insert mode)
+    ClearSearch,
+    /// Scroll the detail pane down one row (Ctrl-j in Normal mode)
+    ScrollDetailDown,
+    /// Scroll the detail pane up one row (Ctrl-k in Normal mode)
+    ScrollDetailUp,
     /// Enter Insert mode for search (/ key in Normal mode)
     EnterInsertMode,
     /// Enter Normal mode for navigation (Esc key in Insert mode)
@@ -38,6 +61,34 @@ pub enum InputEvent {
     ToggleFavorite,
     /// Show help overlay (? key in Normal mode)
     ShowHelp,
+    /// Open the category picker (c key in Normal mode)
+    ShowCategories,
+    /// Open the tag picker (t key in Normal mode)
+    ShowTags,
+    /// Re-read config.toml and apply it (Ctrl-r)
+    ReloadConfig,
+    /// Open the selected skill's manifest in $EDITOR (e key in Normal mode)
+    EditManifest,
+    /// Show or hide the detail pane (D key in Normal mode)
+    ToggleDetailPane,
+    /// Disable or re-enable the selected skill (x key in Normal mode)
+    ToggleSkillDisabled,
+    /// Copy the selected skill's ID to the clipboard (I key in Normal mode)
+    CopySkillId,
+    /// Copy the selected skill's manifest path to the clipboard (P key in
+    /// Normal mode)
+    CopyManifestPath,
+    /// Enter extra arguments and run the selected skill with them (A key in
+    /// Normal mode)
+    EnterArgs,
+    /// Jump to the next output search match (n key while the output panel is
+    /// open)
+    OutputSearchNext,
+    /// Jump to the previous output search match (N key while the output panel
+    /// is open)
+    OutputSearchPrev,
+    /// The terminal was resized to the given width and height
+    Resize(u16, u16),
 }
 
 /// Poll for an input event with a timeout
@@ -50,6 +101,8 @@ pub enum InputEvent {
 ///
 /// * `timeout` - Maximum time to wait for an event
 /// * `input_mode` - Current input mode (Normal or Insert) for mode-aware key mapping
+/// * `output_panel_keys` - Whether output panel keys apply: the panel is open
+///   and no output search query is being typed
 ///
 /// # Returns
 ///
@@ -69,32 +122,57 @@ pub enum InputEvent {
 /// use pane::state::InputMode;
 ///
 /// let mode = InputMode::Normal;
-/// let event = poll_event(Duration::from_millis(250), &mode).unwrap();
+/// let event = poll_event(Duration::from_millis(250), &mode, false).unwrap();
 /// ```
-pub fn poll_event(timeout: Duration, input_mode: &InputMode) -> Result<Option<InputEvent>> {
+pub fn poll_event(
+    timeout: Duration,
+    input_mode: &InputMode,
+    output_panel_keys: bool,
+) -> Result<Option<InputEvent>> {
     // Check if an event is available within the timeout
     if !event::poll(timeout)? {
         return Ok(None);
     }
 
-    // Read the event
+    // Read the event and map it based on current mode
     let event = event::read()?;
+    Ok(map_event(event, input_mode, output_panel_keys))
+}
 
-    // Map crossterm events to InputEvent based on current mode
-    let input_event = match event {
-        Event::Key(key_event) => {
-            // Only process key press events (ignore key release on some terminals)
-            if key_event.kind != KeyEventKind::Press {
-                return Ok(None);
-            }
-
-            map_key_event(key_event, input_mode)
-        }
+/// Map a crossterm Event to an InputEvent based on input mode
+///
+/// Key presses go through `map_output_panel_key` while output panel keys
+/// apply, then `map_key_event`; resizes become `InputEvent::Resize` so
+/// viewport-dependent state is updated immediately.
+fn map_event(event: Event, input_mode: &InputMode, output_panel_keys: bool) -> Option<InputEvent> {
+    match event {
+        // Only process key press events (ignore key release on some terminals)
+        Event::Key(key_event) if key_event.kind == KeyEventKind::Press => output_panel_keys
+            .then(|| map_output_panel_key(key_event))
+            .flatten()
+            .or_else(|| map_key_event(key_event, input_mode)),
+        Event::Resize(width, height) => Some(InputEvent::Resize(width, height)),
         // Mouse and other events are ignored for now
         _ => None,
-    };
+    }
+}
 
-    Ok(input_event)
+/// Map keys that only mean something in the output panel
+///
+/// `n`/`N` step through output search matches in either input mode, so they
+/// never reach skill jumping or the search query while the panel is open.
+fn map_output_panel_key(key_event: KeyEvent) -> Option<InputEvent> {
+    if key_event
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    {
+        return None;
+    }
+    match key_event.code {
+        KeyCode::Char('n') => Some(InputEvent::OutputSearchNext),
+        KeyCode::Char('N') => Some(InputEvent::OutputSearchPrev),
+        _ => None,
+    }
 }
 
 /// Map a crossterm KeyEvent to an InputEvent based on input mode
@@ -119,13 +197,53 @@ pub fn poll_event(timeout: Duration, input_mode: &InputMode) -> Result<Option<In
 /// - `/` → Enter Insert mode
 /// - `f` → Toggle favorite
 /// - `?` → Show help
-/// - `Esc` → Quit (or clear search if present, handled in app.rs)
+/// - `c` → Open category picker
+/// - `t` → Open tag picker
+/// - `e` → Edit the selected skill's manifest in `$EDITOR`
+/// - `D` → Show or hide the detail pane
+/// - `x` → Disable or re-enable the selected skill
+/// - `g`/`G` → Jump to top/bottom
+/// - `Ctrl-j`/`Ctrl-k` → Scroll the detail pane down/up (the selection stays put)
+/// - Other letters/digits → Jump to the next skill starting with that character
+/// - `Esc` → Step back (close output, clear search, undo a view switch), then
+///   quit; see `AppState::go_back`
 ///
 /// **Insert Mode:**
 /// - All characters (including j/k//) → CharInput for search
 /// - `Esc` → Enter Normal mode
-/// - `Backspace` → Remove character from search
+/// - `Backspace`/`Delete` → Remove character before/under the search cursor
+/// - `←`/`→` → Move the search cursor
+/// - `↑`/`↓` → Navigate, or recall past searches when the query is empty or was
+///   itself recalled (typing ends recall, so arrows navigate the results again)
+/// - `Ctrl-u` → Clear the search query (stays in Insert mode)
+///
+/// **Both Modes:**
+/// - `Home`/`End` → Jump to top/bottom
+/// - `Ctrl-d` → Half-page down; `Ctrl-u` → half-page up in Normal mode
+///   (never treated as search input)
+/// - `Ctrl-r` → Reload configuration
 fn map_key_event(key_event: KeyEvent, input_mode: &InputMode) -> Option<InputEvent> {
+    // Control combinations are commands in both modes, so they are mapped
+    // before any character handling and never reach the search query
+    if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key_event.code {
+            KeyCode::Char('d') => Some(InputEvent::HalfPageDown),
+            KeyCode::Char('u') => match input_mode {
+                InputMode::Normal => Some(InputEvent::HalfPageUp),
+                InputMode::Insert => Some(InputEvent::ClearSearch),
+            },
+            KeyCode::Char('r') => Some(InputEvent::ReloadConfig),
+            // Detail scrolling is a Normal-mode command; inert while typing
+            KeyCode::Char('j') if *input_mode == InputMode::Normal => {
+                Some(InputEvent::ScrollDetailDown)
+            }
+            KeyCode::Char('k') if *input_mode == InputMode::Normal => {
+                Some(InputEvent::ScrollDetailUp)
+            }
+            _ => None,
+        };
+    }
+
     match input_mode {
         InputMode::Normal => {
             // Normal mode: Navigation and commands
@@ -135,6 +253,16 @@ fn map_key_event(key_event: KeyEvent, input_mode: &InputMode) -> Option<InputEve
                 KeyCode::Char('/') => Some(InputEvent::EnterInsertMode),
                 KeyCode::Char('f') => Some(InputEvent::ToggleFavorite),
                 KeyCode::Char('?') => Some(InputEvent::ShowHelp),
+                KeyCode::Char('c') => Some(InputEvent::ShowCategories),
+                KeyCode::Char('t') => Some(InputEvent::ShowTags),
+                KeyCode::Char('e') => Some(InputEvent::EditManifest),
+                KeyCode::Char('D') => Some(InputEvent::ToggleDetailPane),
+                KeyCode::Char('x') => Some(InputEvent::ToggleSkillDisabled),
+                KeyCode::Char('I') => Some(InputEvent::CopySkillId),
+                KeyCode::Char('P') => Some(InputEvent::CopyManifestPath),
+                KeyCode::Char('A') => Some(InputEvent::EnterArgs),
+                KeyCode::Char('g') => Some(InputEvent::JumpToTop),
+                KeyCode::Char('G') => Some(InputEvent::JumpToBottom),
                 KeyCode::Up => Some(InputEvent::MoveUp),
                 KeyCode::Down => Some(InputEvent::MoveDown),
                 KeyCode::Esc => Some(InputEvent::Quit),
@@ -142,7 +270,11 @@ fn map_key_event(key_event: KeyEvent, input_mode: &InputMode) -> Option<InputEve
                 KeyCode::Tab => Some(InputEvent::Tab),
                 KeyCode::PageUp => Some(InputEvent::PageUp),
                 KeyCode::PageDown => Some(InputEvent::PageDown),
-                // Other character keys ignored in Normal mode
+                KeyCode::Home => Some(InputEvent::JumpToTop),
+                KeyCode::End => Some(InputEvent::JumpToBottom),
+                // Remaining letters and digits jump to skills by first letter
+                KeyCode::Char(c) if c.is_alphanumeric() => Some(InputEvent::JumpToPrefix(c)),
+                // Other keys ignored in Normal mode
                 _ => None,
             }
         }
@@ -151,14 +283,21 @@ fn map_key_event(key_event: KeyEvent, input_mode: &InputMode) -> Option<InputEve
             match key_event.code {
                 KeyCode::Esc => Some(InputEvent::EnterNormalMode),
                 KeyCode::Backspace => Some(InputEvent::Backspace),
+                KeyCode::Delete => Some(InputEvent::Delete),
                 KeyCode::Char(c) => Some(InputEvent::CharInput(c)),
-                // Arrow keys still work for navigation in Insert mode (optional UX decision)
+                // Left/Right edit the query rather than navigate
+                KeyCode::Left => Some(InputEvent::CursorLeft),
+                KeyCode::Right => Some(InputEvent::CursorRight),
+                // Arrow keys still work for navigation in Insert mode; app.rs turns
+                // them into search history recall while the query is empty or recalled
                 KeyCode::Up => Some(InputEvent::MoveUp),
                 KeyCode::Down => Some(InputEvent::MoveDown),
                 KeyCode::Enter => Some(InputEvent::Enter),
                 KeyCode::Tab => Some(InputEvent::Tab),
                 KeyCode::PageUp => Some(InputEvent::PageUp),
                 KeyCode::PageDown => Some(InputEvent::PageDown),
+                KeyCode::Home => Some(InputEvent::JumpToTop),
+                KeyCode::End => Some(InputEvent::JumpToBottom),
                 _ => None,
             }
         }
@@ -174,6 +313,10 @@ mod tests {
         KeyEvent::new(code, KeyModifiers::NONE)
     }
 
+    fn create_ctrl_key_event(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
     // Tests for Normal mode key mapping
     #[test]
     fn test_esc_key_quits_in_normal_mode() {
@@ -308,6 +451,35 @@ mod tests {
         assert_eq!(result, Some(InputEvent::Backspace));
     }
 
+    #[test]
+    fn test_delete_in_insert_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Delete);
+
+        // Act
+        let result = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(result, Some(InputEvent::Delete));
+    }
+
+    #[test]
+    fn test_left_right_move_cursor_in_insert_mode() {
+        // Arrange
+        let left_event = create_key_event(KeyCode::Left);
+        let right_event = create_key_event(KeyCode::Right);
+
+        // Act & Assert
+        assert_eq!(
+            map_key_event(left_event, &InputMode::Insert),
+            Some(InputEvent::CursorLeft)
+        );
+        assert_eq!(
+            map_key_event(right_event, &InputMode::Insert),
+            Some(InputEvent::CursorRight)
+        );
+    }
+
     // Tests for arrow keys (work in both modes)
     #[test]
     fn test_arrow_up_in_normal_mode() {
@@ -409,15 +581,266 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_c_key_shows_categories_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('c'));
+
+        // Act
+        let result = map_key_event(key_event, &InputMode::Normal);
+
+        // Assert
+        assert_eq!(result, Some(InputEvent::ShowCategories));
+    }
+
+    #[test]
+    fn test_resize_event_maps_to_resize() {
+        // Arrange
+        let event = Event::Resize(120, 40);
+
+        // Act
+        let normal = map_event(event.clone(), &InputMode::Normal, false);
+        let insert = map_event(event, &InputMode::Insert, false);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::Resize(120, 40)));
+        assert_eq!(insert, Some(InputEvent::Resize(120, 40)));
+    }
+
+    #[test]
+    fn test_key_release_event_is_ignored() {
+        // Arrange
+        let mut key_event = create_key_event(KeyCode::Char('j'));
+        key_event.kind = KeyEventKind::Release;
+
+        // Act
+        let result = map_event(Event::Key(key_event), &InputMode::Normal, false);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_unbound_letter_jumps_to_prefix_in_normal_mode() {
+        // Arrange
+        let letter = create_key_event(KeyCode::Char('d'));
+        let reserved = create_key_event(KeyCode::Char('j'));
+
+        // Act
+        let normal = map_key_event(letter, &InputMode::Normal);
+        let insert = map_key_event(letter, &InputMode::Insert);
+        let reserved = map_key_event(reserved, &InputMode::Normal);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::JumpToPrefix('d')));
+        assert_eq!(insert, Some(InputEvent::CharInput('d')));
+        assert_eq!(reserved, Some(InputEvent::MoveDown));
+    }
+
+    #[test]
+    fn test_e_key_edits_manifest_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('e'));
+
+        // Act
+        let normal = map_key_event(key_event, &InputMode::Normal);
+        let insert = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::EditManifest));
+        assert_eq!(insert, Some(InputEvent::CharInput('e')));
+    }
+
+    #[test]
+    fn test_shift_d_toggles_detail_pane_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('D'));
+
+        // Act
+        let normal = map_key_event(key_event, &InputMode::Normal);
+        let insert = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::ToggleDetailPane));
+        assert_eq!(insert, Some(InputEvent::CharInput('D')));
+    }
+
+    #[test]
+    fn test_uppercase_a_enters_args_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('A'));
+
+        // Act & Assert
+        assert_eq!(
+            map_key_event(key_event, &InputMode::Normal),
+            Some(InputEvent::EnterArgs)
+        );
+        assert_eq!(
+            map_key_event(key_event, &InputMode::Insert),
+            Some(InputEvent::CharInput('A'))
+        );
+    }
+
+    #[test]
+    fn test_uppercase_i_and_p_copy_in_normal_mode() {
+        // Arrange
+        let id_key = create_key_event(KeyCode::Char('I'));
+        let path_key = create_key_event(KeyCode::Char('P'));
+
+        // Act & Assert
+        assert_eq!(
+            map_key_event(id_key, &InputMode::Normal),
+            Some(InputEvent::CopySkillId)
+        );
+        assert_eq!(
+            map_key_event(path_key, &InputMode::Normal),
+            Some(InputEvent::CopyManifestPath)
+        );
+        assert_eq!(
+            map_key_event(path_key, &InputMode::Insert),
+            Some(InputEvent::CharInput('P'))
+        );
+    }
+
+    #[test]
+    fn test_x_key_toggles_skill_disabled_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('x'));
+
+        // Act
+        let normal = map_key_event(key_event, &InputMode::Normal);
+        let insert = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::ToggleSkillDisabled));
+        assert_eq!(insert, Some(InputEvent::CharInput('x')));
+    }
+
+    #[test]
+    fn test_t_key_shows_tags_in_normal_mode() {
+        // Arrange
+        let key_event = create_key_event(KeyCode::Char('t'));
+
+        // Act
+        let normal = map_key_event(key_event, &InputMode::Normal);
+        let insert = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::ShowTags));
+        assert_eq!(insert, Some(InputEvent::CharInput('t')));
+    }
+
+    #[test]
+    fn test_g_keys_jump_in_normal_mode() {
+        // Arrange
+        let top_event = create_key_event(KeyCode::Char('g'));
+        let bottom_event = create_key_event(KeyCode::Char('G'));
+
+        // Act & Assert
+        assert_eq!(
+            map_key_event(top_event, &InputMode::Normal),
+            Some(InputEvent::JumpToTop)
+        );
+        assert_eq!(
+            map_key_event(bottom_event, &InputMode::Normal),
+            Some(InputEvent::JumpToBottom)
+        );
+    }
+
+    #[test]
+    fn test_home_end_in_both_modes() {
+        // Arrange
+        let home_event = create_key_event(KeyCode::Home);
+        let end_event = create_key_event(KeyCode::End);
+
+        // Act & Assert - works in both modes
+        for mode in [InputMode::Normal, InputMode::Insert] {
+            assert_eq!(
+                map_key_event(home_event, &mode),
+                Some(InputEvent::JumpToTop)
+            );
+            assert_eq!(
+                map_key_event(end_event, &mode),
+                Some(InputEvent::JumpToBottom)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ctrl_d_half_page_down_in_both_modes() {
+        // Arrange
+        let down_event = create_ctrl_key_event('d');
+
+        // Act & Assert - works in both modes
+        for mode in [InputMode::Normal, InputMode::Insert] {
+            assert_eq!(
+                map_key_event(down_event, &mode),
+                Some(InputEvent::HalfPageDown)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ctrl_u_half_page_up_in_normal_mode_clears_search_in_insert_mode() {
+        // Arrange
+        let up_event = create_ctrl_key_event('u');
+
+        // Act
+        let normal = map_key_event(up_event, &InputMode::Normal);
+        let insert = map_key_event(up_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::HalfPageUp));
+        assert_eq!(insert, Some(InputEvent::ClearSearch));
+    }
+
+    #[test]
+    fn test_ctrl_j_k_scroll_detail_in_normal_mode() {
+        // Act
+        let down = map_key_event(create_ctrl_key_event('j'), &InputMode::Normal);
+        let up = map_key_event(create_ctrl_key_event('k'), &InputMode::Normal);
+
+        // Assert - plain j/k keep moving the list selection
+        assert_eq!(down, Some(InputEvent::ScrollDetailDown));
+        assert_eq!(up, Some(InputEvent::ScrollDetailUp));
+        assert_eq!(
+            map_key_event(create_key_event(KeyCode::Char('j')), &InputMode::Normal),
+            Some(InputEvent::MoveDown)
+        );
+    }
+
+    #[test]
+    fn test_ctrl_j_k_inert_in_insert_mode() {
+        // Act
+        let down = map_key_event(create_ctrl_key_event('j'), &InputMode::Insert);
+        let up = map_key_event(create_ctrl_key_event('k'), &InputMode::Insert);
+
+        // Assert
+        assert_eq!(down, None);
+        assert_eq!(up, None);
+    }
+
+    #[test]
+    fn test_ctrl_char_not_captured_as_search_input_in_insert_mode() {
+        // Arrange
+        let key_event = create_ctrl_key_event('x');
+
+        // Act
+        let result = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert - unmapped control combinations are ignored, not typed
+        assert_eq!(result, None);
+    }
+
     #[test]
     fn test_regular_char_ignored_in_normal_mode() {
         // Arrange
-        let key_event = create_key_event(KeyCode::Char('a'));
+        let key_event = create_key_event(KeyCode::Char('@'));
 
         // Act
         let result = map_key_event(key_event, &InputMode::Normal);
 
-        // Assert - regular chars (not j/k/f/?) are ignored in Normal mode
+        // Assert - unbound symbols are ignored in Normal mode (letters jump to skills)
         assert_eq!(result, None);
     }
 
@@ -432,4 +855,52 @@ mod tests {
         // Assert
         assert_eq!(result, Some(InputEvent::CharInput('a')));
     }
+
+    #[test]
+    fn test_ctrl_r_reloads_config_in_both_modes() {
+        // Arrange
+        let key_event = create_ctrl_key_event('r');
+
+        // Act
+        let normal = map_key_event(key_event, &InputMode::Normal);
+        let insert = map_key_event(key_event, &InputMode::Insert);
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::ReloadConfig));
+        assert_eq!(insert, Some(InputEvent::ReloadConfig));
+    }
+
+    #[test]
+    fn test_y_n_keys_jump_to_prefix_in_normal_mode() {
+        // Arrange & Act
+        let yes = map_key_event(create_key_event(KeyCode::Char('y')), &InputMode::Normal);
+        let no = map_key_event(create_key_event(KeyCode::Char('n')), &InputMode::Normal);
+
+        // Assert
+        assert_eq!(yes, Some(InputEvent::JumpToPrefix('y')));
+        assert_eq!(no, Some(InputEvent::JumpToPrefix('n')));
+    }
+
+    #[test]
+    fn test_n_keys_step_output_search_while_output_panel_keys_apply() {
+        // Arrange
+        let next = Event::Key(create_key_event(KeyCode::Char('n')));
+        let prev = Event::Key(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT));
+
+        // Act
+        let normal = map_event(next.clone(), &InputMode::Normal, true);
+        let insert = map_event(prev.clone(), &InputMode::Insert, true);
+        let outside = map_event(next, &InputMode::Normal, false);
+        let other = map_event(
+            Event::Key(create_key_event(KeyCode::Char('j'))),
+            &InputMode::Normal,
+            true,
+        );
+
+        // Assert
+        assert_eq!(normal, Some(InputEvent::OutputSearchNext));
+        assert_eq!(insert, Some(InputEvent::OutputSearchPrev));
+        assert_eq!(outside, Some(InputEvent::JumpToPrefix('n')));
+        assert_eq!(other, Some(InputEvent::MoveDown));
+    }
 }
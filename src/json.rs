@@ -0,0 +1,361 @@
+//! JSON encoding and formatting without a serde_json dependency
+//!
+//! Headless commands (`--json`, `--ndjson`, `--dry-run`) build their output
+//! with `quote` and `quote_all`, and the output panel pretty-prints JSON skill
+//! output with `pretty_print`.
+
+/// Indentation used for each nesting level of pretty-printed JSON
+const JSON_INDENT: &str = "  ";
+
+/// Encode a string as a JSON string literal
+///
+/// # Examples
+///
+/// ```
+/// use pane::json::quote;
+///
+/// assert_eq!(quote("say \"hi\"\n"), r#""say \"hi\"\n""#);
+/// ```
+pub fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Encode strings as a JSON array of string literals
+///
+/// # Examples
+///
+/// ```
+/// use pane::json::quote_all;
+///
+/// assert_eq!(quote_all(&["-l".to_string(), "a b".to_string()]), r#"["-l","a b"]"#);
+/// assert_eq!(quote_all(&[]), "[]");
+/// ```
+pub fn quote_all(values: &[String]) -> String {
+    let items: Vec<String> = values.iter().map(|value| quote(value)).collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Pretty-print a JSON document with two-space indentation
+///
+/// Validates the input while formatting it, so malformed JSON is reported
+/// instead of being partially reformatted.
+///
+/// # Arguments
+///
+/// * `input` - The JSON text to format
+///
+/// # Returns
+///
+/// The indented JSON, or a short description of the first syntax error
+///
+/// # Examples
+///
+/// ```
+/// use pane::json::pretty_print;
+///
+/// let pretty = pretty_print(r#"{"ok":true,"items":[1,2]}"#).unwrap();
+/// assert_eq!(pretty, "{\n  \"ok\": true,\n  \"items\": [\n    1,\n    2\n  ]\n}");
+/// assert!(pretty_print("{\"ok\":").is_err());
+/// ```
+pub fn pretty_print(input: &str) -> Result<String, String> {
+    let mut printer = JsonPrinter {
+        chars: input.chars().collect(),
+        pos: 0,
+        out: String::with_capacity(input.len() * 2),
+    };
+
+    printer.value(0)?;
+    printer.skip_whitespace();
+    if printer.pos < printer.chars.len() {
+        return Err(format!(
+            "unexpected trailing characters at position {}",
+            printer.pos
+        ));
+    }
+
+    Ok(printer.out)
+}
+
+/// Recursive-descent JSON validator that writes an indented copy as it parses
+struct JsonPrinter {
+    chars: Vec<char>,
+    pos: usize,
+    out: String,
+}
+
+impl JsonPrinter {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn newline(&mut self, depth: usize) {
+        self.out.push('\n');
+        for _ in 0..depth {
+            self.out.push_str(JSON_INDENT);
+        }
+    }
+
+    fn unexpected(&self) -> String {
+        match self.peek() {
+            Some(c) => format!("unexpected character '{}' at position {}", c, self.pos),
+            None => "unexpected end of input".to_string(),
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.container(depth, '}', true),
+            Some('[') => self.container(depth, ']', false),
+            Some('"') => self.string(),
+            Some('t') => self.literal("true"),
+            Some('f') => self.literal("false"),
+            Some('n') => self.literal("null"),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Parse an object (`is_object`) or array, writing one member per line
+    fn container(&mut self, depth: usize, close: char, is_object: bool) -> Result<(), String> {
+        self.out.push(self.chars[self.pos]);
+        self.pos += 1;
+
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            self.out.push(close);
+            return Ok(());
+        }
+
+        loop {
+            self.newline(depth + 1);
+
+            if is_object {
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.unexpected());
+                }
+                self.string()?;
+                self.skip_whitespace();
+                if self.peek() != Some(':') {
+                    return Err(self.unexpected());
+                }
+                self.pos += 1;
+                self.out.push_str(": ");
+            }
+
+            self.value(depth + 1)?;
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.pos += 1;
+                    self.out.push(',');
+                }
+                Some(c) if c == close => {
+                    self.pos += 1;
+                    self.newline(depth);
+                    self.out.push(close);
+                    return Ok(());
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<(), String> {
+        // Opening quote
+        self.out.push('"');
+        self.pos += 1;
+
+        loop {
+            let Some(c) = self.peek() else {
+                return Err("unterminated string".to_string());
+            };
+            self.pos += 1;
+            self.out.push(c);
+
+            match c {
+                '"' => return Ok(()),
+                '\\' => {
+                    let Some(escaped) = self.peek() else {
+                        return Err("unterminated string".to_string());
+                    };
+                    if !"\"\\/bfnrtu".contains(escaped) {
+                        return Err(format!(
+                            "invalid escape '\\{}' at position {}",
+                            escaped, self.pos
+                        ));
+                    }
+                    self.pos += 1;
+                    self.out.push(escaped);
+                    if escaped == 'u' {
+                        for _ in 0..4 {
+                            match self.peek() {
+                                Some(h) if h.is_ascii_hexdigit() => {
+                                    self.pos += 1;
+                                    self.out.push(h);
+                                }
+                                _ => return Err(self.unexpected()),
+                            }
+                        }
+                    }
+                }
+                c if (c as u32) < 0x20 => {
+                    return Err(format!(
+                        "control character in string at position {}",
+                        self.pos - 1
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn literal(&mut self, word: &str) -> Result<(), String> {
+        for expected in word.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.unexpected());
+            }
+            self.pos += 1;
+        }
+        self.out.push_str(word);
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<(), String> {
+        let start = self.pos;
+
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+
+        // Integer part: a single zero or a non-zero digit followed by digits
+        match self.peek() {
+            Some('0') => self.pos += 1,
+            Some(c) if c.is_ascii_digit() => self.digits(),
+            _ => return Err(self.unexpected()),
+        }
+
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            self.required_digits()?;
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.pos += 1;
+            }
+            self.required_digits()?;
+        }
+
+        self.out.extend(&self.chars[start..self.pos]);
+        Ok(())
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+    }
+
+    fn required_digits(&mut self) -> Result<(), String> {
+        if !matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            return Err(self.unexpected());
+        }
+        self.digits();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_escapes_control_characters() {
+        // Act
+        let quoted = quote("tab\there\u{1}");
+
+        // Assert
+        assert_eq!(quoted, r#""tab\there\u0001""#);
+        assert!(pretty_print(&quoted).is_ok());
+    }
+
+    #[test]
+    fn test_pretty_print_indents_nested_values() {
+        // Arrange
+        let input = r#"{"name":"pane","tags":["tui", "cli"],"meta":{"stars":-1.5e3,"empty":{},"none":null}}"#;
+
+        // Act
+        let result = pretty_print(input);
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            r#"{
+  "name": "pane",
+  "tags": [
+    "tui",
+    "cli"
+  ],
+  "meta": {
+    "stars": -1.5e3,
+    "empty": {},
+    "none": null
+  }
+}"#
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_preserves_string_escapes() {
+        // Arrange
+        let input = r#"["a \"quoted\" é value"]"#;
+
+        // Act
+        let result = pretty_print(input);
+
+        // Assert
+        assert_eq!(result.unwrap(), "[\n  \"a \\\"quoted\\\" é value\"\n]");
+    }
+
+    #[test]
+    fn test_pretty_print_rejects_invalid_input() {
+        // Arrange & Act & Assert
+        assert_eq!(
+            pretty_print(r#"{"a": 1,}"#).unwrap_err(),
+            "unexpected character '}' at position 8"
+        );
+        assert_eq!(
+            pretty_print(r#"{"a": 1"#).unwrap_err(),
+            "unexpected end of input"
+        );
+        assert!(pretty_print("01").is_err());
+        assert!(pretty_print("[1] [2]").is_err());
+        assert!(pretty_print("\"unterminated").is_err());
+        assert!(pretty_print("").is_err());
+    }
+}
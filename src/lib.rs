@@ -3,15 +3,24 @@
 /// This library provides the core functionality for skill discovery,
 /// configuration management, and manifest parsing.
 pub mod app;
+pub mod clipboard;
 pub mod config;
 pub mod context;
+pub mod disabled;
+pub mod favorites;
 pub mod i18n;
 pub mod input;
+pub mod json;
 pub mod search;
+pub mod session;
 pub mod skills;
 pub mod state;
 pub mod terminal;
 pub mod ui;
+pub mod usage;
+
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Re-export commonly used types
 pub use config::{load_config, Config};
@@ -1,18 +1,111 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
 use pane::app;
+use pane::SkillSource;
 
 /// Pane - A blazing-fast TUI skill launcher for developers
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Cli {}
+#[command(author, version = app::LONG_VERSION, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Also search DIR for skills, ahead of config and built-in sources
+    /// (repeatable; `~` is expanded)
+    #[arg(long = "skill-path", value_name = "DIR", global = true)]
+    skill_paths: Vec<PathBuf>,
+
+    /// Print config loading, skill discovery, and first render timings to
+    /// stderr on exit
+    #[arg(long)]
+    profile: bool,
+}
+
+/// Commands that run without the TUI
+#[derive(Subcommand, Debug, PartialEq)]
+enum Commands {
+    /// Run a skill by ID without opening the launcher
+    Run {
+        /// ID of the skill to run
+        id: String,
+
+        /// Print the resolved command and PANE_* environment without executing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Print the dry run, or the result of an inline run, as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Stream an inline run's output as JSON lines, one per chunk, then an exit event
+        #[arg(long, conflicts_with_all = ["dry_run", "json"])]
+        ndjson: bool,
+    },
+    /// Show version, build info, languages, and config/skill locations
+    About,
+    /// Check the config, skill directories, and manifests for problems
+    Doctor,
+    /// Write a commented default config file and create the skills directory
+    Init {
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
+    },
+    /// Write all discovered skill manifests, with their sources, as one YAML bundle
+    Export {
+        /// File to write the bundle to (printed to stdout if omitted)
+        #[arg(long, value_name = "FILE")]
+        out: Option<PathBuf>,
+
+        /// Only export skills from this source (builtin, system, user,
+        /// project, or override)
+        #[arg(long)]
+        source: Option<SkillSource>,
+    },
+}
 
 fn main() -> Result<()> {
-    let _cli = Cli::parse();
+    let cli = Cli::parse();
 
-    // Launch the TUI application
-    app::run()?;
+    match cli.command {
+        Some(Commands::Run {
+            id,
+            dry_run,
+            json,
+            ndjson,
+        }) => {
+            let code = app::run_skill(&id, dry_run, json, ndjson, &cli.skill_paths)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+        Some(Commands::About) => {
+            print!("{}", app::about_text(&cli.skill_paths));
+        }
+        Some(Commands::Init { force }) => {
+            print!("{}", app::init_config(force)?);
+        }
+        Some(Commands::Export { out, source }) => {
+            print!(
+                "{}",
+                app::export_skills(out.as_deref(), source.as_ref(), &cli.skill_paths)?
+            );
+        }
+        Some(Commands::Doctor) => {
+            let (report, problems) = app::doctor_report(&cli.skill_paths);
+            print!("{}", report);
+            if problems > 0 {
+                std::process::exit(1);
+            }
+        }
+        None => {
+            // Launch the TUI application
+            app::run(&cli.skill_paths, cli.profile)?;
+        }
+    }
 
     Ok(())
 }
@@ -40,6 +133,72 @@ mod tests {
         assert_eq!(version, "0.1.0");
     }
 
+    #[test]
+    fn test_cli_version_includes_build_info() {
+        // Arrange & Act
+        let result = Cli::try_parse_from(vec!["pane", "--version"]);
+
+        // Assert
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::DisplayVersion);
+        let rendered = err.to_string();
+        assert!(rendered.contains(env!("CARGO_PKG_VERSION")));
+        assert!(rendered.contains(env!("PANE_GIT_HASH")));
+    }
+
+    #[test]
+    fn test_cli_about_parses() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec!["pane", "about"]);
+
+        // Assert
+        assert_eq!(cli.unwrap().command, Some(Commands::About));
+    }
+
+    #[test]
+    fn test_cli_doctor_parses_with_skill_path() {
+        // Arrange & Act
+        let cli =
+            Cli::try_parse_from(vec!["pane", "doctor", "--skill-path", "/tmp/skills"]).unwrap();
+
+        // Assert
+        assert_eq!(cli.command, Some(Commands::Doctor));
+        assert_eq!(cli.skill_paths, vec![PathBuf::from("/tmp/skills")]);
+    }
+
+    #[test]
+    fn test_cli_init_force_parses() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec!["pane", "init", "--force"]);
+
+        // Assert
+        assert_eq!(cli.unwrap().command, Some(Commands::Init { force: true }));
+    }
+
+    #[test]
+    fn test_cli_export_parses_out_and_source() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec![
+            "pane",
+            "export",
+            "--out",
+            "bundle.yaml",
+            "--source",
+            "project",
+        ]);
+        let invalid = Cli::try_parse_from(vec!["pane", "export", "--source", "vendor"]);
+
+        // Assert
+        assert_eq!(
+            cli.unwrap().command,
+            Some(Commands::Export {
+                out: Some(PathBuf::from("bundle.yaml")),
+                source: Some(SkillSource::Project),
+            })
+        );
+        assert!(invalid.is_err());
+    }
+
     #[test]
     fn test_cli_help_flag_parsing() {
         // Arrange & Act
@@ -72,4 +231,97 @@ mod tests {
         // The main function handles the "no TUI yet" message
         assert!(cli.is_ok());
     }
+
+    #[test]
+    fn test_cli_skill_path_is_repeatable_and_global() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec![
+            "pane",
+            "--skill-path",
+            "./dev-skills",
+            "--skill-path",
+            "~/more-skills",
+        ])
+        .unwrap();
+        let run = Cli::try_parse_from(vec!["pane", "run", "demo", "--skill-path", "./dev-skills"])
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            cli.skill_paths,
+            vec![
+                PathBuf::from("./dev-skills"),
+                PathBuf::from("~/more-skills")
+            ]
+        );
+        assert_eq!(run.skill_paths, vec![PathBuf::from("./dev-skills")]);
+    }
+
+    #[test]
+    fn test_cli_profile_flag_parses() {
+        // Arrange & Act
+        let profiled = Cli::try_parse_from(vec!["pane", "--profile"]).unwrap();
+        let plain = Cli::try_parse_from(vec!["pane"]).unwrap();
+
+        // Assert
+        assert!(profiled.profile);
+        assert!(!plain.profile);
+    }
+
+    #[test]
+    fn test_cli_run_dry_run_json_parses() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec!["pane", "run", "claude-tips", "--dry-run", "--json"]);
+
+        // Assert
+        assert_eq!(
+            cli.unwrap().command,
+            Some(Commands::Run {
+                id: "claude-tips".to_string(),
+                dry_run: true,
+                json: true,
+                ndjson: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_cli_run_ndjson_parses_and_conflicts_with_json() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec!["pane", "run", "claude-tips", "--ndjson"]);
+        let conflicting =
+            Cli::try_parse_from(vec!["pane", "run", "claude-tips", "--ndjson", "--json"]);
+
+        // Assert
+        assert_eq!(
+            cli.unwrap().command,
+            Some(Commands::Run {
+                id: "claude-tips".to_string(),
+                dry_run: false,
+                json: false,
+                ndjson: true,
+            })
+        );
+        assert_eq!(
+            conflicting.unwrap_err().kind(),
+            clap::error::ErrorKind::ArgumentConflict
+        );
+    }
+
+    #[test]
+    fn test_cli_run_json_without_dry_run_parses() {
+        // Arrange & Act
+        let cli = Cli::try_parse_from(vec!["pane", "run", "claude-tips", "--json"]);
+
+        // Assert
+        assert_eq!(
+            cli.unwrap().command,
+            Some(Commands::Run {
+                id: "claude-tips".to_string(),
+                dry_run: false,
+                json: true,
+                ndjson: false,
+            })
+        );
+    }
 }
@@ -3,6 +3,8 @@
 /// This module provides fast fuzzy matching capabilities using the nucleo crate.
 /// It supports searching across skill names, IDs, tags, and descriptions with
 /// case-insensitive matching and score-based ranking.
+use std::collections::{BTreeMap, HashSet};
+
 use crate::skills::Skill;
 use nucleo_matcher::{
     pattern::{CaseMatching, Pattern},
@@ -41,6 +43,72 @@ use nucleo_matcher::{
 /// // filtered_indices contains indices of skills matching "clau"
 /// ```
 pub fn filter_skills(query: &str, skills: &[Skill]) -> Vec<usize> {
+    filter_skills_with_min_score(query, skills, 0)
+}
+
+/// Filter skills based on a fuzzy search query, dropping weak matches
+///
+/// Same as `filter_skills`, but skills whose best field score is below
+/// `min_score` are excluded. Used with `search.min_score` from the config.
+///
+/// # Arguments
+///
+/// * `query` - The search query string
+/// * `skills` - Slice of skills to search through
+/// * `min_score` - Minimum match score to keep (0 keeps every match)
+///
+/// # Returns
+///
+/// Vector of indices into the skills slice, sorted by match score (highest first).
+/// Returns all indices (0..skills.len()) if query is empty, regardless of `min_score`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use pane::skills::Skill;
+/// # use pane::search::filter_skills_with_min_score;
+/// # let skills: Vec<Skill> = vec![];
+/// let strong_matches = filter_skills_with_min_score("dk", &skills, 40);
+/// ```
+pub fn filter_skills_with_min_score(query: &str, skills: &[Skill], min_score: u32) -> Vec<usize> {
+    filter_skills_with_synonyms(query, skills, min_score, &BTreeMap::new())
+}
+
+/// Filter skills based on a fuzzy search query, expanding search synonyms
+///
+/// Same as `filter_skills_with_min_score`, but each query word with an entry
+/// in `synonyms` (see `expand_synonyms`) may match as either term. A skill's
+/// score is its best score across the expanded queries, so synonyms slot into
+/// the usual ranking.
+///
+/// # Arguments
+///
+/// * `query` - The search query string
+/// * `skills` - Slice of skills to search through
+/// * `min_score` - Minimum match score to keep (0 keeps every match)
+/// * `synonyms` - Search terms mapped to the word they stand for
+///
+/// # Returns
+///
+/// Vector of indices into the skills slice, sorted by match score (highest first).
+/// Returns all indices (0..skills.len()) if query is empty.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::collections::{BTreeMap, HashSet};
+/// # use pane::skills::Skill;
+/// # use pane::search::filter_skills_with_synonyms;
+/// # let skills: Vec<Skill> = vec![];
+/// let synonyms = BTreeMap::from([("k8s".to_string(), "kubernetes".to_string())]);
+/// let kubernetes_skills = filter_skills_with_synonyms("k8s", &skills, 0, &synonyms);
+/// ```
+pub fn filter_skills_with_synonyms(
+    query: &str,
+    skills: &[Skill],
+    min_score: u32,
+    synonyms: &BTreeMap<String, String>,
+) -> Vec<usize> {
     // Empty query returns all skills
     if query.is_empty() {
         return (0..skills.len()).collect();
@@ -49,25 +117,184 @@ pub fn filter_skills(query: &str, skills: &[Skill]) -> Vec<usize> {
     // Create nucleo matcher with case-insensitive configuration
     let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
 
-    // Create pattern for matching (case-insensitive)
-    let pattern = Pattern::parse(query, CaseMatching::Ignore);
+    // Create one pattern per synonym expansion of the query (case-insensitive)
+    let patterns: Vec<Pattern> = expand_synonyms(query, synonyms)
+        .iter()
+        .map(|variant| Pattern::parse(variant, CaseMatching::Ignore))
+        .collect();
 
-    // Score each skill and collect (index, score) pairs
+    // Score each skill by its best variant and collect (index, score) pairs
     let mut scored: Vec<(usize, u32)> = skills
         .iter()
         .enumerate()
         .filter_map(|(idx, skill)| {
-            score_skill(&pattern, &mut matcher, skill).map(|score| (idx, score))
+            patterns
+                .iter()
+                .filter_map(|pattern| score_skill(pattern, &mut matcher, skill))
+                .max()
+                .map(|score| (idx, score))
         })
+        .filter(|&(_, score)| score >= min_score)
         .collect();
 
     // Sort by score descending (highest score first)
-    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored.sort_by_key(|b| std::cmp::Reverse(b.1));
 
     // Return sorted indices
     scored.into_iter().map(|(idx, _)| idx).collect()
 }
 
+/// Most query variants `expand_synonyms` produces, bounding the work done
+/// for queries where many words have synonyms
+const MAX_QUERY_VARIANTS: usize = 16;
+
+/// Expand a query into the variants to search for, one per synonym choice
+///
+/// Each whitespace-separated word is looked up case-insensitively in both
+/// directions: a key (`k8s`) also searches for its value (`kubernetes`), and
+/// a value also searches for every key mapping to it. The variants combine
+/// every choice of word, up to a fixed limit.
+///
+/// # Arguments
+///
+/// * `query` - The search query string
+/// * `synonyms` - Search terms mapped to the word they stand for
+///
+/// # Returns
+///
+/// The query variants, starting with the query itself (unchanged when no
+/// word has a synonym)
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::{BTreeMap, HashSet};
+/// # use pane::search::expand_synonyms;
+/// let synonyms = BTreeMap::from([("k8s".to_string(), "kubernetes".to_string())]);
+/// assert_eq!(
+///     expand_synonyms("k8s logs", &synonyms),
+///     vec!["k8s logs", "kubernetes logs"]
+/// );
+/// ```
+pub fn expand_synonyms(query: &str, synonyms: &BTreeMap<String, String>) -> Vec<String> {
+    let words: Vec<&str> = query.split_whitespace().collect();
+    if synonyms.is_empty() || words.is_empty() {
+        return vec![query.to_string()];
+    }
+
+    let mut variants = vec![Vec::new()];
+    for word in words {
+        let lowered = word.to_lowercase();
+        let mut choices = vec![word];
+        for (alias, canonical) in synonyms {
+            if alias.to_lowercase() == lowered {
+                choices.push(canonical);
+            } else if canonical.to_lowercase() == lowered {
+                choices.push(alias);
+            }
+        }
+        let mut seen = HashSet::new();
+        choices.retain(|choice| seen.insert(choice.to_lowercase()));
+
+        variants = variants
+            .into_iter()
+            .flat_map(|prefix: Vec<&str>| {
+                choices.iter().map(move |choice| {
+                    let mut variant = prefix.clone();
+                    variant.push(choice);
+                    variant
+                })
+            })
+            .take(MAX_QUERY_VARIANTS)
+            .collect();
+    }
+
+    let mut expanded: Vec<String> = variants.iter().map(|words| words.join(" ")).collect();
+    // Keep the query exactly as typed as the first variant
+    expanded[0] = query.to_string();
+    expanded
+}
+
+/// Check whether a single skill matches a fuzzy search query
+///
+/// Uses the same case-insensitive matching as `filter_skills`, but skips
+/// ranking so callers (e.g. headless commands) can filter skills one at a time.
+///
+/// # Arguments
+///
+/// * `query` - The search query string
+/// * `skill` - The skill to test
+///
+/// # Returns
+///
+/// `true` if any searchable field matches the query. An empty query matches every skill.
+///
+/// # Example
+///
+/// ```no_run
+/// # use pane::skills::Skill;
+/// # use pane::search::skill_matches;
+/// # let skills: Vec<Skill> = vec![];
+/// let docker_skills: Vec<&Skill> = skills
+///     .iter()
+///     .filter(|skill| skill_matches("docker", skill))
+///     .collect();
+/// ```
+pub fn skill_matches(query: &str, skill: &Skill) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+
+    let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
+    let pattern = Pattern::parse(query, CaseMatching::Ignore);
+
+    score_skill(&pattern, &mut matcher, skill).is_some()
+}
+
+/// Find the characters of `text` matched by a fuzzy search query
+///
+/// Uses the same case-insensitive matching as `filter_skills`, so the result
+/// explains why a skill's name matched and can be used to highlight it.
+///
+/// # Arguments
+///
+/// * `query` - The search query string
+/// * `text` - The text to match against (e.g. a skill's display name)
+///
+/// # Returns
+///
+/// Sorted, deduplicated character indices into `text`. Empty if the query is
+/// empty or does not match `text`.
+///
+/// # Example
+///
+/// ```
+/// # use pane::search::match_indices;
+/// assert_eq!(match_indices("dkb", "Docker Build"), vec![0, 3, 7]);
+/// assert!(match_indices("xyz", "Docker Build").is_empty());
+/// ```
+pub fn match_indices(query: &str, text: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
+    let pattern = Pattern::parse(query, CaseMatching::Ignore);
+    let mut buf = Vec::new();
+    let mut indices = Vec::new();
+
+    if pattern
+        .indices(Utf32Str::new(text, &mut buf), &mut matcher, &mut indices)
+        .is_none()
+    {
+        return Vec::new();
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+    indices.into_iter().map(|idx| idx as usize).collect()
+}
+
 /// Calculate fuzzy match score for a single skill
 ///
 /// Searches across all searchable fields (name, id, tags, description) and returns
@@ -131,32 +358,14 @@ fn score_skill(pattern: &Pattern, matcher: &mut Matcher, skill: &Skill) -> Optio
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::skills::{
-        manifest::{ContextConfig, SkillManifest, UiConfig, UiMode},
-        SkillSource,
-    };
-    use std::path::PathBuf;
+    use crate::test_support::test_skill;
 
     fn create_test_skill(id: &str, name: &str, description: &str, tags: Vec<String>) -> Skill {
-        Skill {
-            manifest: SkillManifest {
-                id: id.to_string(),
-                name: name.to_string(),
-                description: description.to_string(),
-                version: "1.0.0".to_string(),
-                exec: "test".to_string(),
-                args: vec![],
-                tags,
-                estimated_time: None,
-                ui: UiConfig {
-                    mode: UiMode::Tui,
-                    fullscreen: true,
-                },
-                context: ContextConfig::default(),
-            },
-            source: SkillSource::Project,
-            manifest_path: PathBuf::from("test.yaml"),
-        }
+        let mut skill = test_skill(id);
+        skill.manifest.name = name.to_string();
+        skill.manifest.description = description.to_string();
+        skill.manifest.tags = tags;
+        skill
     }
 
     #[test]
@@ -287,6 +496,46 @@ mod tests {
         assert!(result.len() <= 3);
     }
 
+    fn score_of(query: &str, skill: &Skill) -> u32 {
+        let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
+        let pattern = Pattern::parse(query, CaseMatching::Ignore);
+        score_skill(&pattern, &mut matcher, skill).unwrap()
+    }
+
+    #[test]
+    fn test_filter_skills_min_score_excludes_borderline_match() {
+        // Arrange - "dock" matches "Docker" contiguously and "Daily Ops Check" scattered
+        let skills = vec![
+            create_test_skill("docker", "Docker", "Container tools", vec![]),
+            create_test_skill("ops", "Daily Ops Check", "Morning routine", vec![]),
+        ];
+        let borderline = score_of("dock", &skills[1]);
+        assert!(score_of("dock", &skills[0]) > borderline);
+
+        // Act
+        let lenient = filter_skills_with_min_score("dock", &skills, borderline);
+        let strict = filter_skills_with_min_score("dock", &skills, borderline + 1);
+
+        // Assert
+        assert_eq!(lenient, vec![0, 1]);
+        assert_eq!(strict, vec![0]);
+    }
+
+    #[test]
+    fn test_filter_skills_min_score_keeps_all_for_empty_query() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("docker", "Docker", "Container tools", vec![]),
+            create_test_skill("desk", "Desk Lookup", "Find a free seat", vec![]),
+        ];
+
+        // Act
+        let result = filter_skills_with_min_score("", &skills, u32::MAX);
+
+        // Assert
+        assert_eq!(result, vec![0, 1]);
+    }
+
     #[test]
     fn test_filter_skills_no_match_returns_empty() {
         // Arrange
@@ -301,4 +550,152 @@ mod tests {
         // Assert
         assert!(result.is_empty());
     }
+
+    fn create_synonyms() -> BTreeMap<String, String> {
+        BTreeMap::from([("k8s".to_string(), "kubernetes".to_string())])
+    }
+
+    fn create_synonym_test_skills() -> Vec<Skill> {
+        vec![
+            create_test_skill(
+                "pods",
+                "List Pods",
+                "Show running pods",
+                vec!["kubernetes".to_string()],
+            ),
+            create_test_skill(
+                "docker-build",
+                "Docker Build",
+                "Container builder",
+                vec!["docker".to_string()],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_filter_skills_with_synonyms_finds_canonical_tag() {
+        // Arrange
+        let skills = create_synonym_test_skills();
+
+        // Act
+        let without = filter_skills_with_min_score("k8s", &skills, 0);
+        let with = filter_skills_with_synonyms("k8s", &skills, 0, &create_synonyms());
+        let uppercase = filter_skills_with_synonyms("K8S", &skills, 0, &create_synonyms());
+
+        // Assert
+        assert!(without.is_empty());
+        assert_eq!(with, vec![0]);
+        assert_eq!(uppercase, vec![0]);
+    }
+
+    #[test]
+    fn test_filter_skills_with_synonyms_leaves_other_queries_unchanged() {
+        // Arrange
+        let skills = create_synonym_test_skills();
+
+        for query in ["docker", "pods", "kube", "xyz123nonexistent"] {
+            // Act
+            let result = filter_skills_with_synonyms(query, &skills, 0, &create_synonyms());
+
+            // Assert
+            assert_eq!(result, filter_skills(query, &skills), "query {:?}", query);
+        }
+    }
+
+    #[test]
+    fn test_expand_synonyms_works_in_both_directions() {
+        // Arrange
+        let synonyms = create_synonyms();
+
+        // Act & Assert
+        assert_eq!(expand_synonyms("k8s", &synonyms), vec!["k8s", "kubernetes"]);
+        assert_eq!(
+            expand_synonyms("Kubernetes", &synonyms),
+            vec!["Kubernetes", "k8s"]
+        );
+        assert_eq!(expand_synonyms("docker  ps", &synonyms), vec!["docker  ps"]);
+        assert_eq!(expand_synonyms("k8s", &BTreeMap::new()), vec!["k8s"]);
+    }
+
+    #[test]
+    fn test_expand_synonyms_caps_variant_count() {
+        // Arrange
+        let synonyms = create_synonyms();
+        let query = ["k8s"; 10].join(" ");
+
+        // Act
+        let variants = expand_synonyms(&query, &synonyms);
+
+        // Assert
+        assert_eq!(variants.len(), MAX_QUERY_VARIANTS);
+        assert_eq!(variants[0], query);
+    }
+
+    #[test]
+    fn test_skill_matches_empty_query_matches_everything() {
+        // Arrange
+        let skill = create_test_skill("skill1", "First Skill", "Description", vec![]);
+
+        // Act & Assert
+        assert!(skill_matches("", &skill));
+    }
+
+    #[test]
+    fn test_skill_matches_checks_tags_case_insensitively() {
+        // Arrange
+        let skill = create_test_skill("skill1", "Build", "Description", vec!["docker".to_string()]);
+
+        // Act & Assert
+        assert!(skill_matches("DOCKER", &skill));
+        assert!(!skill_matches("xyz123nonexistent", &skill));
+    }
+
+    #[test]
+    fn test_skill_matches_agrees_with_filter_skills_membership() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips", "Helpful tips", vec![]),
+            create_test_skill(
+                "docker-build",
+                "Docker Build",
+                "Container builder",
+                vec!["docker".to_string()],
+            ),
+            create_test_skill("git-status", "Git Status", "Repository status", vec![]),
+        ];
+
+        for query in ["", "clau", "docker", "status", "tips", "xyz123nonexistent"] {
+            // Act
+            let filtered = filter_skills(query, &skills);
+
+            // Assert
+            for (idx, skill) in skills.iter().enumerate() {
+                assert_eq!(
+                    skill_matches(query, skill),
+                    filtered.contains(&idx),
+                    "query {:?} disagrees for skill {}",
+                    query,
+                    skill.manifest.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_match_indices_returns_char_positions_of_match() {
+        // Arrange & Act
+        let contiguous = match_indices("build", "Docker Build");
+        let unicode = match_indices("상태", "깃 상태 확인");
+
+        // Assert
+        assert_eq!(contiguous, vec![7, 8, 9, 10, 11]);
+        assert_eq!(unicode, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_match_indices_empty_for_empty_or_unmatched_query() {
+        // Arrange & Act & Assert
+        assert!(match_indices("", "Docker Build").is_empty());
+        assert!(match_indices("xyz123", "Docker Build").is_empty());
+    }
 }
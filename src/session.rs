@@ -0,0 +1,146 @@
+//! Persistence for the launcher's last view between sessions
+//!
+//! With `restore_session` enabled, the view mode, search query, selected
+//! skill ID, favorites, and recently run skills are written to a YAML file on
+//! exit and restored on the next launch, so a restored Favorites or Recent
+//! view has its contents. A missing or unreadable file means there is
+//! nothing to restore.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::state::ViewMode;
+use crate::usage::state_dir;
+
+/// File name of the session file inside the Pane state directory
+const SESSION_FILE: &str = "session.yaml";
+
+/// The parts of the launcher view that are remembered between sessions
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Session {
+    /// View mode that was active on exit
+    #[serde(default)]
+    pub view_mode: ViewMode,
+    /// Search query that was active on exit
+    #[serde(default)]
+    pub search_query: String,
+    /// ID of the skill that was selected on exit, if any
+    #[serde(default)]
+    pub selected_skill: Option<String>,
+    /// IDs of skills marked as favorites, sorted
+    #[serde(default)]
+    pub favorites: Vec<String>,
+    /// IDs of recently run skills, most recent first
+    #[serde(default)]
+    pub recent: Vec<String>,
+}
+
+/// Returns the default location of the session file
+///
+/// Uses `$XDG_STATE_HOME/pane/session.yaml`, falling back to
+/// `~/.local/state/pane/session.yaml`.
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set
+pub fn default_session_path() -> Option<PathBuf> {
+    Some(state_dir()?.join(SESSION_FILE))
+}
+
+/// Load the saved session from `path`
+///
+/// # Arguments
+///
+/// * `path` - Location of the session file
+///
+/// # Returns
+///
+/// The saved session, or `None` if the file is missing or corrupt
+pub fn load_session(path: &Path) -> Option<Session> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<Session>(&contents).ok())
+}
+
+/// Save the session to `path`, creating parent directories
+///
+/// # Arguments
+///
+/// * `path` - Location of the session file
+/// * `session` - The view to remember
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file cannot be written
+pub fn save_session(path: &Path, session: &Session) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory {:?}", parent))?;
+    }
+
+    let contents = serde_yaml::to_string(session).context("Failed to serialize session")?;
+
+    std::fs::write(path, contents).with_context(|| format!("Failed to write session to {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(SESSION_FILE);
+        let session = Session {
+            view_mode: ViewMode::Recent,
+            search_query: "deploy prod".to_string(),
+            selected_skill: Some("deploy".to_string()),
+            favorites: vec!["deploy".to_string(), "tips".to_string()],
+            recent: vec!["tips".to_string()],
+        };
+
+        // Act
+        save_session(&path, &session).unwrap();
+        let loaded = load_session(&path);
+
+        // Assert
+        assert_eq!(loaded, Some(session));
+    }
+
+    #[test]
+    fn test_load_session_missing_or_corrupt_file_is_none() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let corrupt = dir.path().join("corrupt.yaml");
+        std::fs::write(&corrupt, "view_mode: [not a mode").unwrap();
+
+        // Act
+        let missing = load_session(&dir.path().join(SESSION_FILE));
+        let corrupt = load_session(&corrupt);
+
+        // Assert
+        assert_eq!(missing, None);
+        assert_eq!(corrupt, None);
+    }
+
+    #[test]
+    fn test_load_session_defaults_missing_fields() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SESSION_FILE);
+        std::fs::write(&path, "search_query: git\n").unwrap();
+
+        // Act
+        let loaded = load_session(&path).unwrap();
+
+        // Assert
+        assert_eq!(loaded.view_mode, ViewMode::All);
+        assert_eq!(loaded.search_query, "git");
+        assert_eq!(loaded.selected_skill, None);
+        assert!(loaded.favorites.is_empty());
+        assert!(loaded.recent.is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! Skill bundles written by `pane export`
+//!
+//! A bundle is a YAML list of discovered manifests, each tagged with the
+//! source it was found in, so a skill setup can be shared, inspected, or
+//! read back with `serde_yaml`.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::skills::manifest::SkillManifest;
+use crate::skills::model::{Skill, SkillSource};
+
+/// One skill in an exported bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundledSkill {
+    /// Where the skill was discovered (e.g. `project`)
+    pub source: SkillSource,
+    /// Path of the manifest file, so relative `exec` and `post_run` entries
+    /// can be resolved against its directory after re-import
+    pub manifest_path: PathBuf,
+    /// The skill's manifest as loaded
+    pub manifest: SkillManifest,
+}
+
+/// Serialize skills into a YAML bundle
+///
+/// Skills are written in the order given, optionally keeping only those from
+/// one source.
+///
+/// # Arguments
+///
+/// * `skills` - The discovered skills to export
+/// * `source` - Only export skills from this source, if set
+///
+/// # Returns
+///
+/// The YAML document and the number of skills it contains
+///
+/// # Errors
+///
+/// Returns an error if a manifest cannot be serialized
+///
+/// # Examples
+///
+/// ```
+/// use pane::skills::bundle::{bundle_yaml, BundledSkill};
+///
+/// let (yaml, count) = bundle_yaml(&[], None).unwrap();
+/// assert_eq!(count, 0);
+/// assert!(serde_yaml::from_str::<Vec<BundledSkill>>(&yaml).unwrap().is_empty());
+/// ```
+pub fn bundle_yaml(skills: &[Skill], source: Option<&SkillSource>) -> Result<(String, usize)> {
+    let bundle: Vec<BundledSkill> = skills
+        .iter()
+        .filter(|skill| match source {
+            Some(source) => skill.source == *source,
+            None => true,
+        })
+        .map(|skill| BundledSkill {
+            source: skill.source.clone(),
+            manifest_path: skill.manifest_path.clone(),
+            manifest: skill.manifest.clone(),
+        })
+        .collect();
+    let yaml = serde_yaml::to_string(&bundle).context("Failed to serialize skill bundle")?;
+    Ok((yaml, bundle.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_skill;
+
+    fn create_test_skill(id: &str, source: SkillSource) -> Skill {
+        let mut skill = test_skill(id);
+        skill.source = source;
+        skill.manifest_path = PathBuf::from(format!("/skills/{}/pane-skill.yaml", id));
+        skill
+    }
+
+    #[test]
+    fn test_bundle_yaml_round_trips_manifests_with_source() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("one", SkillSource::User),
+            create_test_skill("two", SkillSource::Project),
+        ];
+
+        // Act
+        let (yaml, count) = bundle_yaml(&skills, None).unwrap();
+
+        // Assert
+        let bundle: Vec<BundledSkill> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(count, 2);
+        assert!(yaml.contains("source: project"));
+        assert_eq!(bundle[0].source, SkillSource::User);
+        assert_eq!(bundle[0].manifest, skills[0].manifest);
+        assert_eq!(
+            bundle[0].manifest_path,
+            PathBuf::from("/skills/one/pane-skill.yaml")
+        );
+        assert_eq!(bundle[1].manifest, skills[1].manifest);
+    }
+
+    #[test]
+    fn test_bundle_yaml_filters_by_source() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("one", SkillSource::User),
+            create_test_skill("two", SkillSource::Project),
+        ];
+
+        // Act
+        let (yaml, count) = bundle_yaml(&skills, Some(&SkillSource::Project)).unwrap();
+
+        // Assert
+        let bundle: Vec<BundledSkill> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(bundle[0].manifest.id, "two");
+    }
+}
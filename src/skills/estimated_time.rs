@@ -0,0 +1,202 @@
+//! Structured parsing of a manifest's free-form `estimated_time`
+//!
+//! Recognizes a single duration or a range with a unit, such as `30s`,
+//! `2h`, `1-3 min` or `~5 minutes`. Anything else is left unparsed; callers
+//! keep showing the original string and sort such skills last.
+
+use std::fmt;
+use std::time::Duration;
+
+/// A parsed estimated run time, stored as an inclusive range
+///
+/// A single value such as `30s` has `min == max`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use pane::skills::EstimatedTime;
+///
+/// let estimate = EstimatedTime::parse("1-3 min").unwrap();
+/// assert_eq!(estimate.min, Duration::from_secs(60));
+/// assert_eq!(estimate.max, Duration::from_secs(180));
+/// assert_eq!(estimate.to_string(), "1-3 min");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EstimatedTime {
+    /// Shortest expected run time
+    pub min: Duration,
+    /// Longest expected run time
+    pub max: Duration,
+}
+
+impl EstimatedTime {
+    /// Parse an `estimated_time` string
+    ///
+    /// Accepts an optional leading `~`, a number or `a-b` range (decimals
+    /// allowed, `-`, `–` or `to` as the separator), and a unit of seconds
+    /// (`s`, `sec`, `seconds`), minutes (`m`, `min`, `minutes`) or hours
+    /// (`h`, `hr`, `hours`). Each side of a range may carry its own unit;
+    /// otherwise the trailing unit applies to both.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The manifest's `estimated_time` value
+    ///
+    /// # Returns
+    ///
+    /// The parsed range, or `None` if the format is not recognized
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().trim_start_matches('~').trim().to_lowercase();
+        let (low, high) = match split_range(&text) {
+            Some((low, high)) => (low, Some(high)),
+            None => (text.as_str(), None),
+        };
+
+        let (min, max) = match high {
+            Some(high) => {
+                let (high_value, high_unit) = parse_amount(high)?;
+                let high_unit = high_unit?;
+                let (low_value, low_unit) = parse_amount(low)?;
+                let min = low_value * low_unit.unwrap_or(high_unit);
+                (min, high_value * high_unit)
+            }
+            None => {
+                let (value, unit) = parse_amount(low)?;
+                let seconds = value * unit?;
+                (seconds, seconds)
+            }
+        };
+
+        if !min.is_finite() || !max.is_finite() || min > max {
+            return None;
+        }
+        Some(EstimatedTime {
+            min: Duration::from_secs_f64(min),
+            max: Duration::from_secs_f64(max),
+        })
+    }
+}
+
+impl fmt::Display for EstimatedTime {
+    /// Formats the range in one unit chosen from the upper bound,
+    /// e.g. "30 sec", "1-3 min" or "1.5 h"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max = self.max.as_secs_f64();
+        let (unit_seconds, unit) = if max >= 3600.0 {
+            (3600.0, "h")
+        } else if max >= 60.0 {
+            (60.0, "min")
+        } else {
+            (1.0, "sec")
+        };
+
+        let low = format_amount(self.min.as_secs_f64() / unit_seconds);
+        let high = format_amount(max / unit_seconds);
+        if low == high {
+            write!(f, "{} {}", high, unit)
+        } else {
+            write!(f, "{}-{} {}", low, high, unit)
+        }
+    }
+}
+
+/// Split `text` at the first range separator, if any
+fn split_range(text: &str) -> Option<(&str, &str)> {
+    ["-", "–", " to "].iter().find_map(|separator| {
+        text.split_once(separator)
+            .map(|(low, high)| (low.trim(), high.trim()))
+    })
+}
+
+/// Parse a number with an optional unit suffix, e.g. "3", "3 min" or "30s"
+///
+/// Returns the number and the unit's length in seconds, if a unit was given.
+fn parse_amount(text: &str) -> Option<(f64, Option<f64>)> {
+    let number_end = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let value: f64 = text[..number_end].parse().ok()?;
+    let unit = text[number_end..].trim();
+    if unit.is_empty() {
+        return Some((value, None));
+    }
+    Some((value, Some(unit_seconds(unit)?)))
+}
+
+/// Length of a time unit in seconds
+fn unit_seconds(unit: &str) -> Option<f64> {
+    match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1.0),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60.0),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(3600.0),
+        _ => None,
+    }
+}
+
+/// Format an amount with at most one decimal place, dropping a trailing ".0"
+fn format_amount(value: f64) -> String {
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as u64)
+    } else {
+        format!("{:.1}", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minute_range() {
+        // Act
+        let estimate = EstimatedTime::parse("1-3 min").unwrap();
+
+        // Assert
+        assert_eq!(estimate.min, Duration::from_secs(60));
+        assert_eq!(estimate.max, Duration::from_secs(180));
+        assert_eq!(estimate.to_string(), "1-3 min");
+    }
+
+    #[test]
+    fn test_parse_seconds_without_space() {
+        // Act
+        let estimate = EstimatedTime::parse("30s").unwrap();
+
+        // Assert
+        assert_eq!(estimate.min, Duration::from_secs(30));
+        assert_eq!(estimate.max, Duration::from_secs(30));
+        assert_eq!(estimate.to_string(), "30 sec");
+    }
+
+    #[test]
+    fn test_parse_hours() {
+        // Act
+        let estimate = EstimatedTime::parse("2h").unwrap();
+
+        // Assert
+        assert_eq!(estimate.min, Duration::from_secs(7200));
+        assert_eq!(estimate.to_string(), "2 h");
+    }
+
+    #[test]
+    fn test_parse_mixed_units_normalizes_display() {
+        // Act
+        let estimate = EstimatedTime::parse("~30 seconds to 2 minutes").unwrap();
+
+        // Assert
+        assert_eq!(estimate.min, Duration::from_secs(30));
+        assert_eq!(estimate.max, Duration::from_secs(120));
+        assert_eq!(estimate.to_string(), "0.5-2 min");
+    }
+
+    #[test]
+    fn test_parse_unrecognized_values_return_none() {
+        // Act & Assert
+        assert_eq!(EstimatedTime::parse("a while"), None);
+        assert_eq!(EstimatedTime::parse("5"), None);
+        assert_eq!(EstimatedTime::parse("3-1 min"), None);
+        assert_eq!(EstimatedTime::parse("2 fortnights"), None);
+    }
+}
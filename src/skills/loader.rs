@@ -1,12 +1,56 @@
 use std::collections::HashMap;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use walkdir::WalkDir;
 
+use crate::config::Config;
 use crate::skills::manifest::SkillManifest;
 use crate::skills::model::{Skill, SkillSource};
+use crate::skills::runner::validate_executable;
+
+/// A skill ID defined by more than one manifest within the same source
+///
+/// Discovery keeps the first manifest found (in file name order) and records
+/// the conflicting one here so the collision can be surfaced to skill authors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillCollision {
+    /// The duplicated skill ID
+    pub id: String,
+    /// Source directory in which both manifests were found
+    pub source: SkillSource,
+    /// Manifest path of the skill that was kept
+    pub kept_path: PathBuf,
+    /// Manifest path of the skill that was ignored
+    pub conflicting_path: PathBuf,
+}
+
+/// A path inside a skill directory that could not be read during discovery
+///
+/// Discovery keeps going past these (e.g. permission denied on a
+/// subdirectory) so the readable skills are still returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveryError {
+    /// Path that could not be read
+    pub path: PathBuf,
+    /// Description of the I/O error
+    pub message: String,
+}
+
+/// Result of skill discovery: unique skills plus any same-source ID collisions,
+/// unreadable paths, and invalid manifests
+#[derive(Debug, Default)]
+pub struct Discovery {
+    /// Discovered skills with unique IDs
+    pub skills: Vec<Skill>,
+    /// Duplicate IDs found within a single source directory
+    pub collisions: Vec<SkillCollision>,
+    /// Paths that could not be read while walking the skill directories
+    pub errors: Vec<DiscoveryError>,
+    /// Manifests that were found but failed to parse or validate
+    pub invalid_manifests: Vec<DiscoveryError>,
+}
 
 /// Discover skills from project, user, and system locations
 ///
@@ -17,6 +61,15 @@ use crate::skills::model::{Skill, SkillSource};
 ///
 /// When skills with duplicate IDs are found, the skill from the higher precedence
 /// source is used. Missing directories are skipped gracefully without errors.
+/// Duplicate IDs within the same source are logged; use
+/// `discover_skills_with_collisions` to inspect them. Uses the default
+/// configuration: every source is enabled and executables are not checked,
+/// so unresolvable skills are still listed.
+///
+/// # Arguments
+///
+/// * `extra_paths` - Directories searched ahead of every other source, e.g.
+///   from `--skill-path`
 ///
 /// # Returns
 ///
@@ -27,22 +80,122 @@ use crate::skills::model::{Skill, SkillSource};
 /// Returns an error only if critical failures occur. Individual skill loading
 /// failures are logged as warnings and skipped.
 #[allow(dead_code)]
-pub fn discover_skills() -> Result<Vec<Skill>> {
-    let mut skill_map: HashMap<String, Skill> = HashMap::new();
+pub fn discover_skills(extra_paths: &[PathBuf]) -> Result<Vec<Skill>> {
+    Ok(discover_skills_with_collisions(&Config::default(), extra_paths)?.skills)
+}
 
-    // Define discovery paths in precedence order (process in reverse for HashMap)
-    let discovery_paths = vec![
+/// Directories searched for skills, in precedence order (highest first)
+///
+/// # Arguments
+///
+/// * `extra_paths` - Directories from `--skill-path`, searched first so
+///   their skills override every configured and built-in source
+///
+/// # Returns
+///
+/// Each directory paired with the source it represents: the extra paths,
+/// project, user, system, then builtin (when the running executable's
+/// location is known). The user directory and extra paths have `~` expanded.
+pub fn discovery_directories(extra_paths: &[PathBuf]) -> Vec<(PathBuf, SkillSource)> {
+    let mut directories: Vec<(PathBuf, SkillSource)> = extra_paths
+        .iter()
+        .map(|path| {
+            let expanded = path
+                .to_str()
+                .map(expand_tilde)
+                .unwrap_or_else(|| path.clone());
+            (expanded, SkillSource::Override)
+        })
+        .collect();
+    directories.extend([
+        (PathBuf::from("./.pane/skills"), SkillSource::Project),
+        (expand_tilde("~/.config/pane/skills"), SkillSource::User),
         (
             PathBuf::from("/usr/local/share/pane/skills"),
             SkillSource::System,
         ),
-        (expand_tilde("~/.config/pane/skills"), SkillSource::User),
-        (PathBuf::from("./.pane/skills"), SkillSource::Project),
-    ];
+    ]);
+    if let Some(builtin) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| builtin_skills_directory(&exe))
+    {
+        directories.push((builtin, SkillSource::Builtin));
+    }
+    directories
+}
+
+/// Directory holding the skills bundled with pane
+///
+/// The install script puts them in `share/pane/builtin-skills` under the same
+/// prefix as the binary, e.g. `/usr/local/bin/pane` →
+/// `/usr/local/share/pane/builtin-skills`.
+///
+/// # Arguments
+///
+/// * `exe` - Path of the pane executable
+///
+/// # Returns
+///
+/// The builtin skills directory, or `None` if `exe` has no prefix directory
+fn builtin_skills_directory(exe: &Path) -> Option<PathBuf> {
+    let prefix = exe.parent()?.parent()?;
+    Some(prefix.join("share").join("pane").join("builtin-skills"))
+}
+
+/// Discover skills and report duplicate IDs within a single source
+///
+/// Behaves like `discover_skills`, but also returns every same-source ID
+/// collision. Within a source the first manifest found is kept; overrides
+/// across sources are expected and are not reported as collisions.
+///
+/// # Arguments
+///
+/// * `config` - Supplies `strict_discovery` (skip skills whose executable
+///   cannot be found) and the `enable_*_skills` source flags
+/// * `extra_paths` - Directories searched ahead of every other source
+///
+/// # Returns
+///
+/// * `Result<Discovery>` - Discovered skills and any same-source collisions
+///
+/// # Errors
+///
+/// Returns an error only if critical failures occur. Individual skill loading
+/// failures are logged as warnings and skipped.
+pub fn discover_skills_with_collisions(
+    config: &Config,
+    extra_paths: &[PathBuf],
+) -> Result<Discovery> {
+    Ok(discover_in_directories(
+        discovery_directories(extra_paths),
+        config,
+    ))
+}
+
+/// Discover skills from the given directories, in precedence order (highest first)
+///
+/// Directories whose source is disabled in `config` are skipped entirely, so
+/// their skills neither appear nor override skills from other sources.
+fn discover_in_directories(directories: Vec<(PathBuf, SkillSource)>, config: &Config) -> Discovery {
+    let mut skill_map: HashMap<String, Skill> = HashMap::new();
+    let mut collisions = Vec::new();
+    let mut errors = Vec::new();
+    let mut invalid_manifests = Vec::new();
+
+    // Process lowest precedence first so higher precedence sources overwrite it
+    for (path, source) in directories.into_iter().rev() {
+        if !config.source_enabled(&source) {
+            tracing::debug!("Skill source {:?} disabled, skipping {:?}", source, path);
+            continue;
+        }
+
+        let (discovered, source_errors, source_invalid) =
+            discover_in_directory(path, source, config);
+        errors.extend(source_errors);
+        invalid_manifests.extend(source_invalid);
+        let (skills, source_collisions) = dedupe_within_source(discovered);
+        collisions.extend(source_collisions);
 
-    // Discover skills from each location
-    for (path, source) in discovery_paths {
-        let skills = discover_in_directory(path, source);
         for skill in skills {
             let id = skill.manifest.id.clone();
             if let Some(existing) = skill_map.get(&id) {
@@ -57,56 +210,153 @@ pub fn discover_skills() -> Result<Vec<Skill>> {
         }
     }
 
-    Ok(skill_map.into_values().collect())
+    Discovery {
+        skills: skill_map.into_values().collect(),
+        collisions,
+        errors,
+        invalid_manifests,
+    }
+}
+
+/// Remove duplicate IDs from skills discovered in a single source
+///
+/// Keeps the first skill for each ID and records a `SkillCollision` for every
+/// later manifest that reuses it.
+///
+/// # Arguments
+///
+/// * `skills` - Skills discovered in one source directory, in discovery order
+///
+/// # Returns
+///
+/// Tuple of (unique skills, collisions)
+fn dedupe_within_source(skills: Vec<Skill>) -> (Vec<Skill>, Vec<SkillCollision>) {
+    let mut kept: Vec<Skill> = Vec::with_capacity(skills.len());
+    let mut collisions = Vec::new();
+
+    for skill in skills {
+        match kept.iter().find(|k| k.manifest.id == skill.manifest.id) {
+            Some(existing) => {
+                tracing::warn!(
+                    "Duplicate skill ID '{}' in {:?}: {:?} ignored, keeping {:?}",
+                    skill.manifest.id,
+                    skill.source,
+                    skill.manifest_path,
+                    existing.manifest_path
+                );
+                collisions.push(SkillCollision {
+                    id: skill.manifest.id.clone(),
+                    source: skill.source.clone(),
+                    kept_path: existing.manifest_path.clone(),
+                    conflicting_path: skill.manifest_path.clone(),
+                });
+            }
+            None => kept.push(skill),
+        }
+    }
+
+    (kept, collisions)
 }
 
 /// Discover skills in a specific directory
 ///
-/// Recursively searches the given directory for `pane-skill.yaml` files,
-/// loads and validates each manifest, and returns a vector of discovered skills.
+/// Recursively searches the given directory for manifest files (named as in
+/// `config.manifest_filenames`, `pane-skill.yaml` by default), loads and
+/// validates each manifest, and returns a vector of discovered skills.
 ///
 /// # Arguments
 ///
 /// * `path` - Directory path to search
 /// * `source` - Source type for discovered skills
+/// * `config` - Supplies the recognized manifest filenames and
+///   `strict_discovery` (skip, with a warning, skills whose executable cannot be found)
 ///
 /// # Returns
 ///
-/// Vector of successfully loaded skills. Parse failures are logged and skipped.
+/// Tuple of (successfully loaded skills, unreadable paths, invalid manifests).
+/// I/O errors while walking (e.g. permission denied) and manifests that fail
+/// to parse are skipped with a warning and returned so they can be shown to
+/// the user.
 #[allow(dead_code)]
-fn discover_in_directory(path: PathBuf, source: SkillSource) -> Vec<Skill> {
+fn discover_in_directory(
+    path: PathBuf,
+    source: SkillSource,
+    config: &Config,
+) -> (Vec<Skill>, Vec<DiscoveryError>, Vec<DiscoveryError>) {
     // Check if directory exists
     if !path.exists() {
         tracing::debug!("Skill directory not found: {:?}, skipping", path);
-        return Vec::new();
+        return (Vec::new(), Vec::new(), Vec::new());
     }
 
     let mut skills = Vec::new();
+    let mut errors = Vec::new();
+    let mut invalid = Vec::new();
 
-    // Recursively walk directory looking for pane-skill.yaml files
-    for entry in WalkDir::new(&path).into_iter().filter_map(|e| e.ok()) {
+    // Recursively walk directory looking for manifest files
+    // (sorted so "first found" is deterministic across platforms)
+    for entry in WalkDir::new(&path).sort_by_file_name() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let error_path = e.path().unwrap_or(&path).to_path_buf();
+                tracing::warn!("Failed to read {:?} during discovery: {}", error_path, e);
+                errors.push(DiscoveryError {
+                    path: error_path,
+                    message: e
+                        .io_error()
+                        .map(|io| io.to_string())
+                        .unwrap_or_else(|| e.to_string()),
+                });
+                continue;
+            }
+        };
         let entry_path = entry.path();
 
-        // Only process files named pane-skill.yaml
-        if entry_path.is_file()
-            && entry_path.file_name().and_then(|n| n.to_str()) == Some("pane-skill.yaml")
-        {
-            match SkillManifest::from_yaml_file(entry_path.to_path_buf()) {
+        // Only process files with a recognized manifest name
+        let is_manifest = entry_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| config.manifest_filenames.iter().any(|f| f == name));
+        if entry_path.is_file() && is_manifest {
+            match SkillManifest::from_yaml_file_with_limits(
+                entry_path.to_path_buf(),
+                &config.manifest_limits,
+            ) {
+                Ok(manifest)
+                    if config.strict_discovery
+                        && validate_executable(manifest.resolved_exec(), entry_path.parent())
+                            .is_err() =>
+                {
+                    tracing::warn!(
+                        "Skipping skill '{}' from {:?}: executable '{}' not found",
+                        manifest.id,
+                        entry_path,
+                        manifest.resolved_exec()
+                    );
+                }
                 Ok(manifest) => {
-                    skills.push(Skill {
+                    let mut skill = Skill {
                         manifest,
                         source: source.clone(),
                         manifest_path: entry_path.to_path_buf(),
-                    });
+                        estimated_duration: None,
+                    };
+                    skill.parse_estimated_time(config.parse_estimated_time);
+                    skills.push(skill);
                 }
                 Err(e) => {
                     tracing::warn!("Failed to load skill manifest from {:?}: {}", entry_path, e);
+                    invalid.push(DiscoveryError {
+                        path: entry_path.to_path_buf(),
+                        message: format!("{:#}", e),
+                    });
                 }
             }
         }
     }
 
-    skills
+    (skills, errors, invalid)
 }
 
 /// Expand tilde (~) in path to user's home directory
@@ -169,7 +419,11 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
 
         // Act
-        let skills = discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::Project);
+        let (skills, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::Project,
+            &Config::default(),
+        );
 
         // Assert
         assert_eq!(skills.len(), 0);
@@ -181,7 +435,8 @@ mod tests {
         let nonexistent_path = PathBuf::from("/tmp/nonexistent-pane-test-dir-12345");
 
         // Act
-        let skills = discover_in_directory(nonexistent_path, SkillSource::Project);
+        let (skills, _, _) =
+            discover_in_directory(nonexistent_path, SkillSource::Project, &Config::default());
 
         // Assert
         assert_eq!(skills.len(), 0);
@@ -206,7 +461,11 @@ ui:
         .unwrap();
 
         // Act
-        let skills = discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::Project);
+        let (skills, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::Project,
+            &Config::default(),
+        );
 
         // Assert
         assert_eq!(skills.len(), 1);
@@ -228,10 +487,16 @@ invalid yaml syntax: [unclosed
         .unwrap();
 
         // Act
-        let skills = discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::User);
+        let (skills, _, invalid) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
 
-        // Assert
+        // Assert - skipped, but reported with its path
         assert_eq!(skills.len(), 0);
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].path, temp_dir.path().join("pane-skill.yaml"));
     }
 
     #[test]
@@ -268,7 +533,11 @@ ui:
         .unwrap();
 
         // Act
-        let skills = discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::System);
+        let (skills, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::System,
+            &Config::default(),
+        );
 
         // Assert
         assert_eq!(skills.len(), 2);
@@ -317,8 +586,10 @@ ui:
 
         // Act - We can't easily test discover_skills() without changing directories
         // so we test the logic directly
-        let user_skills = discover_in_directory(user_dir, SkillSource::User);
-        let project_skills = discover_in_directory(project_dir, SkillSource::Project);
+        let (user_skills, _, _) =
+            discover_in_directory(user_dir, SkillSource::User, &Config::default());
+        let (project_skills, _, _) =
+            discover_in_directory(project_dir, SkillSource::Project, &Config::default());
 
         // Assert
         assert_eq!(user_skills.len(), 1);
@@ -339,4 +610,430 @@ ui:
         assert_eq!(final_skill.manifest.name, "Project Skill");
         assert_eq!(final_skill.source, SkillSource::Project);
     }
+
+    #[test]
+    fn test_dedupe_within_source_reports_same_id_collision() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let first_dir = temp_dir.path().join("a-skill");
+        let second_dir = temp_dir.path().join("b-skill");
+        fs::create_dir_all(&first_dir).unwrap();
+        fs::create_dir_all(&second_dir).unwrap();
+
+        for (dir, name) in [(&first_dir, "First Skill"), (&second_dir, "Second Skill")] {
+            fs::write(
+                dir.join("pane-skill.yaml"),
+                format!(
+                    r#"
+id: same-id
+name: {}
+description: Duplicate ID
+exec: ./run.sh
+ui:
+  mode: tui
+"#,
+                    name
+                ),
+            )
+            .unwrap();
+        }
+
+        // Act
+        let (discovered, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
+        let (skills, collisions) = dedupe_within_source(discovered);
+
+        // Assert
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].manifest.name, "First Skill");
+        assert_eq!(
+            collisions,
+            vec![SkillCollision {
+                id: "same-id".to_string(),
+                source: SkillSource::User,
+                kept_path: first_dir.join("pane-skill.yaml"),
+                conflicting_path: second_dir.join("pane-skill.yaml"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dedupe_within_source_unique_ids_have_no_collisions() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pane-skill.yaml"),
+            r#"
+id: only-skill
+name: Only Skill
+description: Unique ID
+exec: ./run.sh
+ui:
+  mode: tui
+"#,
+        )
+        .unwrap();
+
+        // Act
+        let (discovered, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
+        let (skills, collisions) = dedupe_within_source(discovered);
+
+        // Assert
+        assert_eq!(skills.len(), 1);
+        assert!(collisions.is_empty());
+    }
+
+    fn write_skill_with_exec(dir: &std::path::Path, id: &str, exec: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join("pane-skill.yaml"),
+            format!(
+                r#"
+id: {}
+name: {}
+description: Exec check
+exec: {}
+ui:
+  mode: tui
+"#,
+                id, id, exec
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_discover_in_directory_strict_skips_unresolvable_exec() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        write_skill_with_exec(&temp_dir.path().join("real"), "real-skill", "ls");
+        write_skill_with_exec(
+            &temp_dir.path().join("bogus"),
+            "bogus-skill",
+            "nonexistent-command-12345",
+        );
+
+        // Act
+        let config = Config {
+            strict_discovery: true,
+            ..Config::default()
+        };
+        let (skills, _, _) =
+            discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::User, &config);
+
+        // Assert
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].manifest.id, "real-skill");
+    }
+
+    #[test]
+    fn test_discover_in_directory_non_strict_keeps_unresolvable_exec() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        write_skill_with_exec(&temp_dir.path().join("real"), "real-skill", "ls");
+        write_skill_with_exec(
+            &temp_dir.path().join("bogus"),
+            "bogus-skill",
+            "nonexistent-command-12345",
+        );
+
+        // Act
+        let (skills, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
+
+        // Assert
+        let ids: Vec<&str> = skills.iter().map(|s| s.manifest.id.as_str()).collect();
+        assert_eq!(ids, vec!["bogus-skill", "real-skill"]);
+    }
+
+    /// Write a minimal manifest with the given ID and name into `dir`
+    fn write_skill(dir: &std::path::Path, id: &str, name: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join("pane-skill.yaml"),
+            format!(
+                "id: {}\nname: {}\ndescription: Test\nexec: ./run.sh\nui:\n  mode: tui\n",
+                id, name
+            ),
+        )
+        .unwrap();
+    }
+
+    /// Project, user, and system directories: the project and user both define
+    /// `shared`, and each source also has a skill of its own
+    fn create_source_dirs(base: &std::path::Path) -> Vec<(PathBuf, SkillSource)> {
+        let project = base.join("project");
+        let user = base.join("user");
+        let system = base.join("system");
+        write_skill(&project.join("shared"), "shared", "Project Shared");
+        write_skill(&project.join("own"), "project-only", "Project Only");
+        write_skill(&user.join("shared"), "shared", "User Shared");
+        write_skill(&user.join("own"), "user-only", "User Only");
+        write_skill(&system.join("own"), "system-only", "System Only");
+        vec![
+            (project, SkillSource::Project),
+            (user, SkillSource::User),
+            (system, SkillSource::System),
+        ]
+    }
+
+    fn find<'a>(discovery: &'a Discovery, id: &str) -> Option<&'a Skill> {
+        discovery
+            .skills
+            .iter()
+            .find(|skill| skill.manifest.id == id)
+    }
+
+    #[test]
+    fn test_discover_in_directories_disabled_source_is_skipped() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let directories = create_source_dirs(temp_dir.path());
+        let config = Config {
+            enable_system_skills: false,
+            ..Config::default()
+        };
+
+        // Act
+        let discovery = discover_in_directories(directories, &config);
+
+        // Assert
+        assert!(find(&discovery, "system-only").is_none());
+        assert!(find(&discovery, "project-only").is_some());
+        assert!(find(&discovery, "user-only").is_some());
+        assert_eq!(discovery.skills.len(), 3);
+    }
+
+    #[test]
+    fn test_discover_in_directories_disabled_source_no_longer_overrides() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let directories = create_source_dirs(temp_dir.path());
+        let config = Config {
+            enable_project_skills: false,
+            ..Config::default()
+        };
+
+        // Act
+        let discovery = discover_in_directories(directories, &config);
+
+        // Assert - the user skill the project would have overridden is used
+        let shared = find(&discovery, "shared").unwrap();
+        assert_eq!(shared.manifest.name, "User Shared");
+        assert_eq!(shared.source, SkillSource::User);
+        assert!(find(&discovery, "project-only").is_none());
+        assert!(find(&discovery, "system-only").is_some());
+    }
+
+    #[test]
+    fn test_discover_in_directories_all_sources_enabled_by_default() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let directories = create_source_dirs(temp_dir.path());
+
+        // Act
+        let discovery = discover_in_directories(directories, &Config::default());
+
+        // Assert
+        assert_eq!(discovery.skills.len(), 4);
+        assert_eq!(
+            find(&discovery, "shared").unwrap().source,
+            SkillSource::Project
+        );
+    }
+
+    #[test]
+    fn test_discover_in_directories_builtin_has_lowest_precedence() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let mut directories = create_source_dirs(temp_dir.path());
+        let builtin = temp_dir.path().join("builtin");
+        write_skill(&builtin.join("shared"), "system-only", "Builtin Shadowed");
+        write_skill(&builtin.join("own"), "builtin-only", "Builtin Only");
+        directories.push((builtin, SkillSource::Builtin));
+
+        // Act
+        let discovery = discover_in_directories(directories, &Config::default());
+
+        // Assert
+        let shadowed = find(&discovery, "system-only").unwrap();
+        assert_eq!(shadowed.manifest.name, "System Only");
+        assert_eq!(shadowed.source, SkillSource::System);
+        assert_eq!(
+            find(&discovery, "builtin-only").unwrap().source,
+            SkillSource::Builtin
+        );
+    }
+
+    #[test]
+    fn test_discover_in_directories_skill_path_override_takes_precedence() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let override_dir = temp_dir.path().join("dev-skills");
+        write_skill(&override_dir.join("shared"), "shared", "Dev Shared");
+        write_skill(&override_dir.join("own"), "dev-only", "Dev Only");
+        let mut directories = discovery_directories(&[override_dir]);
+        directories.truncate(1);
+        directories.extend(create_source_dirs(temp_dir.path()));
+        let config = Config {
+            enable_project_skills: false,
+            ..Config::default()
+        };
+
+        // Act
+        let discovery = discover_in_directories(directories, &config);
+
+        // Assert
+        let shared = find(&discovery, "shared").unwrap();
+        assert_eq!(shared.manifest.name, "Dev Shared");
+        assert_eq!(shared.source, SkillSource::Override);
+        assert!(find(&discovery, "dev-only").is_some());
+        assert!(find(&discovery, "user-only").is_some());
+    }
+
+    #[test]
+    fn test_discovery_directories_puts_skill_paths_first_with_tilde_expanded() {
+        // Arrange
+        let original_home = env::var("HOME").ok();
+        env::set_var("HOME", "/home/tester");
+
+        // Act
+        let directories = discovery_directories(&[PathBuf::from("~/dev-skills")]);
+
+        // Assert
+        assert_eq!(
+            directories[0],
+            (
+                PathBuf::from("/home/tester/dev-skills"),
+                SkillSource::Override
+            )
+        );
+        assert_eq!(directories[1].1, SkillSource::Project);
+
+        // Cleanup
+        if let Some(home) = original_home {
+            env::set_var("HOME", home);
+        }
+    }
+
+    #[test]
+    fn test_builtin_skills_directory_is_under_install_prefix() {
+        // Arrange
+        let exe = Path::new("/usr/local/bin/pane");
+
+        // Act
+        let directory = builtin_skills_directory(exe);
+
+        // Assert
+        assert_eq!(
+            directory,
+            Some(PathBuf::from("/usr/local/share/pane/builtin-skills"))
+        );
+    }
+
+    #[test]
+    fn test_discover_in_directory_custom_manifest_filename() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        write_skill(&temp_dir.path().join("default"), "default-name", "Default");
+        let hidden_dir = temp_dir.path().join("hidden");
+        fs::create_dir_all(&hidden_dir).unwrap();
+        fs::write(
+            hidden_dir.join(".pane-skill.yaml"),
+            "id: hidden-name\nname: Hidden\ndescription: Test\nexec: ./run.sh\nui:\n  mode: tui\n",
+        )
+        .unwrap();
+        let config = Config {
+            manifest_filenames: vec![".pane-skill.yaml".to_string()],
+            ..Config::default()
+        };
+
+        // Act
+        let (skills, _, _) =
+            discover_in_directory(temp_dir.path().to_path_buf(), SkillSource::User, &config);
+
+        // Assert
+        let ids: Vec<&str> = skills.iter().map(|s| s.manifest.id.as_str()).collect();
+        assert_eq!(ids, vec!["hidden-name"]);
+    }
+
+    #[test]
+    fn test_discover_in_directory_default_matches_only_pane_skill_yaml() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        write_skill(&temp_dir.path().join("default"), "default-name", "Default");
+        let other_dir = temp_dir.path().join("other");
+        fs::create_dir_all(&other_dir).unwrap();
+        for name in ["skill.yaml", ".pane-skill.yaml"] {
+            fs::copy(
+                temp_dir.path().join("default/pane-skill.yaml"),
+                other_dir.join(name),
+            )
+            .unwrap();
+        }
+
+        // Act
+        let (skills, _, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
+
+        // Assert
+        assert_eq!(skills.len(), 1);
+        assert_eq!(
+            skills[0].manifest_path,
+            temp_dir.path().join("default/pane-skill.yaml")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[ignore = "needs directory permissions to be enforced, so not as root; run with --ignored"]
+    fn test_discover_in_directory_reports_unreadable_entries() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        write_skill(&temp_dir.path().join("readable"), "readable", "Readable");
+        let locked = temp_dir.path().join("locked");
+        write_skill(&locked, "locked", "Locked");
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+        let enforced = fs::read_dir(&locked).is_err();
+        if !enforced {
+            fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        assert!(
+            enforced,
+            "directory permissions are not enforced (running as root?)"
+        );
+
+        // Act
+        let (skills, errors, _) = discover_in_directory(
+            temp_dir.path().to_path_buf(),
+            SkillSource::User,
+            &Config::default(),
+        );
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        // Assert - readable skills are still returned alongside the error
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].manifest.id, "readable");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, locked);
+        assert!(errors[0]
+            .message
+            .to_lowercase()
+            .contains("permission denied"));
+    }
 }
@@ -1,8 +1,15 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
+/// Shell operators that do nothing useful in args passed without a shell
+const SHELL_OPERATORS: &[&str] = &["|", "||", "&&", ";"];
+
+/// Executables that run their args through a shell (e.g. `sh -c "a | b"`)
+const SHELL_EXECUTABLES: &[&str] = &["sh", "bash", "zsh", "dash", "ksh", "fish"];
+
 /// UI interaction mode for skills
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -13,14 +20,33 @@ pub enum UiMode {
     Inline,
 }
 
+/// How an inline skill's stdout should be rendered in the output panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Plain text, shown as-is
+    #[default]
+    Text,
+    /// JSON, pretty-printed (with a note if it fails to parse)
+    Json,
+    /// Basic markdown (headings, bold, lists)
+    Markdown,
+}
+
 /// UI configuration for skill display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UiConfig {
     /// How the skill interacts with the terminal
     pub mode: UiMode,
-    /// Whether skill uses fullscreen mode (default: true)
+    /// Whether a TUI skill takes over the cleared launcher screen (default: true).
+    /// When false, the skill runs on the primary terminal screen instead.
     #[serde(default = "default_fullscreen")]
     pub fullscreen: bool,
+    /// Also capture a TUI skill's stdout/stderr for the output panel
+    /// (default: false). Only suited to skills that print and exit; see
+    /// `runner::execute_tui_captured` for the limitations.
+    #[serde(default)]
+    pub capture: bool,
 }
 
 fn default_fullscreen() -> bool {
@@ -31,6 +57,9 @@ fn default_fullscreen() -> bool {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ContextConfig {
+    /// Whether to pass any context at all; when false the skill gets no
+    /// PANE_* variables or stdin JSON, whatever the other flags say
+    pub pass_any_context: bool,
     /// Whether to pass current working directory
     pub pass_cwd: bool,
     /// Whether to detect and pass git root
@@ -39,15 +68,30 @@ pub struct ContextConfig {
     pub pass_project_name: bool,
     /// Whether to send full context as JSON to stdin
     pub pass_stdin_json: bool,
+    /// Whether to expose the full context as JSON in `PANE_CONTEXT_JSON`
+    pub pass_context_json: bool,
+}
+
+impl ContextConfig {
+    /// Whether the full context should be sent as JSON on stdin
+    ///
+    /// # Returns
+    ///
+    /// `true` only if `pass_stdin_json` is set and `pass_any_context` allows it
+    pub fn sends_stdin_json(&self) -> bool {
+        self.pass_any_context && self.pass_stdin_json
+    }
 }
 
 impl Default for ContextConfig {
     fn default() -> Self {
         Self {
+            pass_any_context: true,
             pass_cwd: true,
             pass_git_root: true,
             pass_project_name: true,
             pass_stdin_json: false,
+            pass_context_json: false,
         }
     }
 }
@@ -64,12 +108,15 @@ pub struct SkillManifest {
     /// Semantic version of the skill
     #[serde(default = "default_version")]
     pub version: String,
-    /// Executable or script name/path to run
+    /// Executable or script name/path to run (may be omitted when every
+    /// platform the skill supports has its own `exec_<os>`)
+    #[serde(default)]
     pub exec: String,
-    /// Command-line arguments to pass to executable
+    /// Command-line arguments to pass to executable (`${PANE_*}` references are
+    /// substituted from the skill context at run time)
     #[serde(default)]
     pub args: Vec<String>,
-    /// Searchable tags for filtering
+    /// Searchable tags for filtering (lowercased and deduplicated on load)
     #[serde(default)]
     pub tags: Vec<String>,
     /// Human-readable time estimate (e.g., "1–3 min")
@@ -79,15 +126,138 @@ pub struct SkillManifest {
     /// Context configuration
     #[serde(default)]
     pub context: ContextConfig,
+    /// Optional category for grouping skills (e.g., "git", "docker")
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Hide the skill from the All view and search (still shown in
+    /// Favorites/Recent and runnable by ID)
+    #[serde(default)]
+    pub hidden: bool,
+    /// How inline output is rendered: text, json, or markdown (default: text)
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    /// Longer help text shown in the detail pane below the description
+    /// (`description` stays the one-line list summary)
+    #[serde(default)]
+    pub long_description: Option<String>,
+    /// Manifest format version; manifests newer than
+    /// `SUPPORTED_SCHEMA_VERSION` are rejected (default: 1)
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    /// Executable used instead of `exec` on Windows
+    #[serde(default)]
+    pub exec_windows: Option<String>,
+    /// Executable used instead of `exec` on macOS
+    #[serde(default)]
+    pub exec_macos: Option<String>,
+    /// Executable used instead of `exec` on Linux
+    #[serde(default)]
+    pub exec_linux: Option<String>,
+    /// Command run after the main exec finishes, whether or not it succeeded
+    /// (e.g. `./cleanup.sh`), with the same environment as the skill. Split
+    /// into words like a shell command line, but not run through a shell
+    #[serde(default)]
+    pub post_run: Option<String>,
+    /// Color for the skill's list marker and detail title, using the theme's
+    /// color names (e.g. `Red`); the theme colors are used when absent
+    #[serde(default, with = "crate::ui::theme::option_color_serde")]
+    pub accent: Option<Color>,
+    /// Extra runs of an inline skill that exits non-zero, with a short
+    /// backoff between attempts (default: 0, run once; at most `MAX_RETRIES`)
+    #[serde(default)]
+    pub retries: u32,
+    /// Run an inline skill under a pseudo-terminal so it sees a TTY and keeps
+    /// its colors and progress output (Unix only; default: false, plain pipes)
+    #[serde(default)]
+    pub pty: bool,
+    /// Usage tip from the skill author shown in the detail pane, e.g. how to
+    /// exit a TUI skill ("Press q inside to exit")
+    #[serde(default)]
+    pub hint: Option<String>,
+}
+
+/// Highest manifest `schema_version` this build of pane understands
+pub const SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// Largest accepted `retries`; retries block the launcher while they run
+pub const MAX_RETRIES: u32 = 5;
+
+/// Maximum lengths, in characters, of manifest text fields
+///
+/// Overlong values are rejected during validation so a runaway manifest
+/// cannot break the layout. Set from the `[manifest_limits]` table of
+/// `config.toml`; missing entries keep their defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ManifestLimits {
+    /// Longest accepted `id` (default: 64)
+    pub max_id_length: usize,
+    /// Longest accepted `name` (default: 100)
+    pub max_name_length: usize,
+    /// Longest accepted `description` (default: 500)
+    pub max_description_length: usize,
+}
+
+impl Default for ManifestLimits {
+    fn default() -> Self {
+        Self {
+            max_id_length: 64,
+            max_name_length: 100,
+            max_description_length: 500,
+        }
+    }
 }
 
 fn default_version() -> String {
     "0.1.0".to_string()
 }
 
+fn default_schema_version() -> u32 {
+    1
+}
+
 // These methods will be used in future stories for skill discovery
 #[allow(dead_code)]
 impl SkillManifest {
+    /// Executable to launch on the current platform
+    ///
+    /// Uses the `exec_<os>` override for `std::env::consts::OS` when set,
+    /// otherwise the base `exec`.
+    pub fn resolved_exec(&self) -> &str {
+        self.exec_for_os(std::env::consts::OS)
+    }
+
+    /// Executable to launch on the given platform
+    ///
+    /// # Arguments
+    ///
+    /// * `os` - A `std::env::consts::OS` value such as `"linux"`, `"macos"`, or `"windows"`
+    ///
+    /// # Returns
+    ///
+    /// The non-empty `exec_<os>` override if present, otherwise the base `exec`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pane::skills::SkillManifest;
+    /// let yaml = "id: fmt\nname: Format\ndescription: Format code\nexec: ./fmt.sh\nexec_windows: fmt.bat\nui:\n  mode: inline\n";
+    /// let manifest = SkillManifest::from_yaml_str(yaml).unwrap();
+    /// assert_eq!(manifest.exec_for_os("windows"), "fmt.bat");
+    /// assert_eq!(manifest.exec_for_os("linux"), "./fmt.sh");
+    /// ```
+    pub fn exec_for_os(&self, os: &str) -> &str {
+        let platform_exec = match os {
+            "windows" => self.exec_windows.as_deref(),
+            "macos" => self.exec_macos.as_deref(),
+            "linux" => self.exec_linux.as_deref(),
+            _ => None,
+        };
+        platform_exec
+            .filter(|exec| !exec.is_empty())
+            .unwrap_or(&self.exec)
+    }
+
     /// Parse a skill manifest from a YAML string
     ///
     /// # Arguments
@@ -105,9 +275,10 @@ impl SkillManifest {
     /// - Required fields are missing
     /// - Validation fails
     pub fn from_yaml_str(yaml_str: &str) -> Result<Self> {
-        let manifest: SkillManifest =
+        let mut manifest: SkillManifest =
             serde_yaml::from_str(yaml_str).context("Failed to parse YAML manifest")?;
         manifest.validate()?;
+        manifest.normalize_tags();
         Ok(manifest)
     }
 
@@ -129,14 +300,64 @@ impl SkillManifest {
     /// - Required fields are missing
     /// - Validation fails
     pub fn from_yaml_file(path: PathBuf) -> Result<Self> {
+        Self::from_yaml_file_with_limits(path, &ManifestLimits::default())
+    }
+
+    /// Parse a skill manifest from a YAML file, checking field lengths
+    /// against the given limits
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the manifest file
+    /// * `limits` - Maximum field lengths (usually from the config)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SkillManifest>` - Parsed and validated manifest
+    ///
+    /// # Errors
+    ///
+    /// Same as `from_yaml_file`
+    pub fn from_yaml_file_with_limits(path: PathBuf, limits: &ManifestLimits) -> Result<Self> {
         let file = std::fs::File::open(&path)
             .with_context(|| format!("Failed to open manifest file: {:?}", path))?;
-        let manifest: SkillManifest = serde_yaml::from_reader(file)
+        let mut manifest: SkillManifest = serde_yaml::from_reader(file)
             .with_context(|| format!("Failed to parse manifest file: {:?}", path))?;
-        manifest.validate()?;
+        manifest.validate_with_limits(limits)?;
+        manifest.normalize_tags();
         Ok(manifest)
     }
 
+    /// Lowercase, trim, and deduplicate `tags`
+    ///
+    /// Keeps the first occurrence of each tag in its original position and
+    /// drops blank tags, so `Docker` and `docker` filter and search as one
+    /// tag. Called when a manifest is parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::skills::manifest::SkillManifest;
+    ///
+    /// let mut manifest = SkillManifest::from_yaml_str(
+    ///     "id: build\nname: Build\ndescription: Build it\nexec: make\nui:\n  mode: inline\n",
+    /// )
+    /// .unwrap();
+    /// manifest.tags = vec!["Docker".into(), " ci ".into(), "DOCKER".into()];
+    /// manifest.normalize_tags();
+    /// assert_eq!(manifest.tags, ["docker", "ci"]);
+    /// ```
+    pub fn normalize_tags(&mut self) {
+        let mut normalized: Vec<String> = Vec::with_capacity(self.tags.len());
+        for tag in &self.tags {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+        self.tags = normalized;
+    }
+
     /// Validate the manifest fields
     ///
     /// # Returns
@@ -146,9 +367,41 @@ impl SkillManifest {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Required fields are empty
+    /// - `schema_version` is newer than `SUPPORTED_SCHEMA_VERSION`
+    /// - Required fields are empty (`exec` counts as set when the current
+    ///   platform has an `exec_<os>` override)
     /// - ID format is invalid (must be lowercase alphanumeric + hyphens)
+    /// - `id`, `name`, or `description` is longer than the default
+    ///   `ManifestLimits`
+    /// - `retries` is larger than `MAX_RETRIES`
     pub fn validate(&self) -> Result<()> {
+        self.validate_with_limits(&ManifestLimits::default())
+    }
+
+    /// Validate the manifest fields against the given length limits
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Maximum field lengths (usually from the config)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Ok if validation passes
+    ///
+    /// # Errors
+    ///
+    /// Same as `validate`, with the length checks using `limits`
+    pub fn validate_with_limits(&self, limits: &ManifestLimits) -> Result<()> {
+        // Reject manifests written for a newer format before checking fields
+        if self.schema_version > SUPPORTED_SCHEMA_VERSION {
+            anyhow::bail!(
+                "Skill '{}' requires manifest schema version {}, but this pane supports up to {}; please upgrade pane",
+                self.id,
+                self.schema_version,
+                SUPPORTED_SCHEMA_VERSION
+            );
+        }
+
         // Validate required fields are non-empty
         if self.id.is_empty() {
             anyhow::bail!("Skill id cannot be empty");
@@ -159,8 +412,40 @@ impl SkillManifest {
         if self.description.is_empty() {
             anyhow::bail!("Skill description cannot be empty");
         }
-        if self.exec.is_empty() {
-            anyhow::bail!("Skill exec cannot be empty");
+        if self.resolved_exec().is_empty() {
+            anyhow::bail!(
+                "Skill exec cannot be empty (set exec or exec_{} for this platform)",
+                std::env::consts::OS
+            );
+        }
+
+        // Reject overlong text before it reaches the layout
+        for (field, value, max) in [
+            ("id", &self.id, limits.max_id_length),
+            ("name", &self.name, limits.max_name_length),
+            (
+                "description",
+                &self.description,
+                limits.max_description_length,
+            ),
+        ] {
+            let length = value.chars().count();
+            if length > max {
+                anyhow::bail!(
+                    "Skill {} is too long ({} characters, maximum {})",
+                    field,
+                    length,
+                    max
+                );
+            }
+        }
+
+        if self.retries > MAX_RETRIES {
+            anyhow::bail!(
+                "Skill retries is too large ({}, maximum {})",
+                self.retries,
+                MAX_RETRIES
+            );
         }
 
         // Validate id format: lowercase alphanumeric + hyphens only
@@ -173,31 +458,86 @@ impl SkillManifest {
             );
         }
 
+        // Suspicious but possibly intended; report without rejecting the manifest
+        for warning in self.warnings() {
+            tracing::warn!("{}", warning);
+        }
+
         Ok(())
     }
+
+    /// Find likely manifest mistakes that do not make it invalid
+    ///
+    /// Skills are spawned directly, not through a shell, so args containing
+    /// shell operators such as `|`, `;` or `&&` are passed literally. Each
+    /// such arg gets a warning suggesting `exec: sh` with `args: ["-c", ...]`
+    /// instead. A lone `;` arg (as `find -exec` takes) is fine, and manifests
+    /// whose exec already is a shell are not checked.
+    ///
+    /// # Returns
+    ///
+    /// One message per suspicious arg; empty when nothing looks wrong
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::skills::manifest::SkillManifest;
+    ///
+    /// let manifest = SkillManifest::from_yaml_str(
+    ///     "id: logs\nname: Logs\ndescription: Tail logs\nexec: tail\nargs: [\"log.txt | grep error\"]\nui:\n  mode: inline\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(manifest.warnings().len(), 1);
+    /// ```
+    pub fn warnings(&self) -> Vec<String> {
+        let exec = self.resolved_exec();
+        let program = std::path::Path::new(exec)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(exec);
+        if SHELL_EXECUTABLES.contains(&program) {
+            return Vec::new();
+        }
+
+        self.args
+            .iter()
+            .filter_map(|arg| {
+                // A lone `;` is a real argument (e.g. `find ... -exec rm {} ;`)
+                if arg == ";" {
+                    return None;
+                }
+                let operator = arg.split_whitespace().find_map(|word| {
+                    if SHELL_OPERATORS.contains(&word) {
+                        Some(word)
+                    } else if word.len() > 1 && word.ends_with(';') {
+                        // `cd app; make`
+                        Some(";")
+                    } else {
+                        None
+                    }
+                })?;
+                Some(format!(
+                    "Skill '{}' arg '{}' contains the shell operator '{}', but args are passed to '{}' without a shell; use exec: sh with args: [\"-c\", \"...\"] to run it through a shell",
+                    self.id, arg, operator, exec
+                ))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::test_manifest;
     use rstest::rstest;
 
     fn create_valid_manifest() -> SkillManifest {
-        SkillManifest {
-            id: "test-skill".to_string(),
-            name: "Test Skill".to_string(),
-            description: "A test skill".to_string(),
-            version: "0.1.0".to_string(),
-            exec: "./test.sh".to_string(),
-            args: vec![],
-            tags: vec![],
-            estimated_time: None,
-            ui: UiConfig {
-                mode: UiMode::Tui,
-                fullscreen: true,
-            },
-            context: ContextConfig::default(),
-        }
+        let mut manifest = test_manifest("test-skill");
+        manifest.name = "Test Skill".to_string();
+        manifest.description = "A test skill".to_string();
+        manifest.version = "0.1.0".to_string();
+        manifest.exec = "./test.sh".to_string();
+        manifest
     }
 
     #[test]
@@ -223,10 +563,19 @@ ui:
         assert_eq!(manifest.description, "A test skill");
         assert_eq!(manifest.exec, "./test.sh");
         assert_eq!(manifest.version, "0.1.0"); // default
-        assert_eq!(manifest.ui.fullscreen, true); // default
+        assert!(manifest.ui.fullscreen); // default
         assert!(manifest.args.is_empty());
         assert!(manifest.tags.is_empty());
         assert_eq!(manifest.estimated_time, None);
+        assert_eq!(manifest.category, None);
+        assert!(!manifest.hidden);
+        assert_eq!(manifest.output_format, OutputFormat::Text);
+        assert_eq!(manifest.long_description, None);
+        assert_eq!(manifest.schema_version, 1); // default
+        assert_eq!(manifest.post_run, None);
+        assert_eq!(manifest.accent, None);
+        assert!(!manifest.pty);
+        assert_eq!(manifest.hint, None);
     }
 
     #[test]
@@ -246,6 +595,17 @@ tags:
   - development
   - automation
 estimated_time: 2-5 min
+category: automation
+hidden: true
+output_format: json
+post_run: ./cleanup.sh
+accent: Red
+pty: true
+hint: Press q inside to exit
+long_description: |
+  Runs the full automation suite.
+
+  Pass --verbose for detailed logs.
 ui:
   mode: tui
   fullscreen: true
@@ -272,12 +632,43 @@ context:
             vec!["productivity", "development", "automation"]
         );
         assert_eq!(manifest.estimated_time, Some("2-5 min".to_string()));
+        assert_eq!(manifest.category, Some("automation".to_string()));
+        assert!(manifest.hidden);
+        assert_eq!(manifest.output_format, OutputFormat::Json);
+        assert_eq!(manifest.post_run.as_deref(), Some("./cleanup.sh"));
+        assert_eq!(manifest.accent, Some(Color::Red));
+        assert!(manifest.pty);
+        assert_eq!(manifest.hint.as_deref(), Some("Press q inside to exit"));
+        assert_eq!(
+            manifest.long_description.as_deref(),
+            Some("Runs the full automation suite.\n\nPass --verbose for detailed logs.\n")
+        );
         assert_eq!(manifest.ui.mode, UiMode::Tui);
-        assert_eq!(manifest.ui.fullscreen, true);
-        assert_eq!(manifest.context.pass_cwd, true);
-        assert_eq!(manifest.context.pass_git_root, true);
-        assert_eq!(manifest.context.pass_project_name, true);
-        assert_eq!(manifest.context.pass_stdin_json, false);
+        assert!(manifest.ui.fullscreen);
+        assert!(manifest.context.pass_cwd);
+        assert!(manifest.context.pass_git_root);
+        assert!(manifest.context.pass_project_name);
+        assert!(!manifest.context.pass_stdin_json);
+    }
+
+    #[test]
+    fn test_from_yaml_str_collapses_tags_differing_in_case() {
+        // Arrange
+        let yaml = r#"
+id: docker-build
+name: Docker Build
+description: Build the image
+exec: docker
+tags: ["Docker", "docker", "DOCKER", "CI", "docker "]
+ui:
+  mode: inline
+"#;
+
+        // Act
+        let manifest = SkillManifest::from_yaml_str(yaml).unwrap();
+
+        // Assert - first occurrence order is kept
+        assert_eq!(manifest.tags, vec!["docker".to_string(), "ci".to_string()]);
     }
 
     #[test]
@@ -316,6 +707,97 @@ invalid yaml syntax: [unclosed bracket
         assert!(error.to_string().contains(expected_msg));
     }
 
+    #[rstest]
+    #[case(&["foo | bar"], Some("|"))]
+    #[case(&["build", "&&", "deploy"], Some("&&"))]
+    #[case(&["cd app; make"], Some(";"))]
+    #[case(&["-exec", "rm", "{}", ";"], None)]
+    #[case(&["--pattern=a|b", "--flag"], None)]
+    fn test_warnings_flag_shell_operators_in_args(
+        #[case] args: &[&str],
+        #[case] operator: Option<&str>,
+    ) {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.args = args.iter().map(|arg| arg.to_string()).collect();
+
+        // Act
+        let warnings = manifest.warnings();
+
+        // Assert
+        match operator {
+            Some(operator) => {
+                assert_eq!(warnings.len(), 1);
+                assert!(warnings[0].contains(&format!("shell operator '{}'", operator)));
+                assert!(warnings[0].contains("exec: sh"));
+                assert!(manifest.validate().is_ok());
+            }
+            None => assert!(warnings.is_empty()),
+        }
+    }
+
+    #[test]
+    fn test_warnings_skip_shell_executables() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.exec = "/bin/sh".to_string();
+        manifest.args = vec!["-c".to_string(), "foo | bar".to_string()];
+
+        // Act
+        let warnings = manifest.warnings();
+
+        // Assert
+        assert!(warnings.is_empty());
+    }
+
+    #[rstest]
+    #[case(100, true)]
+    #[case(99, true)]
+    #[case(101, false)]
+    #[case(10_000, false)]
+    fn test_validate_name_length_limit(#[case] length: usize, #[case] valid: bool) {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.name = "n".repeat(length);
+
+        // Act
+        let result = manifest.validate();
+
+        // Assert
+        assert_eq!(result.is_ok(), valid);
+        if !valid {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "Skill name is too long ({} characters, maximum 100)",
+                    length
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_with_limits_uses_custom_limits() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.description = "한".repeat(20);
+        let limits = ManifestLimits {
+            max_description_length: 10,
+            ..ManifestLimits::default()
+        };
+
+        // Act
+        let strict = manifest.validate_with_limits(&limits);
+        let default = manifest.validate();
+
+        // Assert - lengths count characters, not bytes
+        assert!(strict
+            .unwrap_err()
+            .to_string()
+            .contains("description is too long (20 characters, maximum 10)"));
+        assert!(default.is_ok());
+    }
+
     #[rstest]
     #[case("", "id cannot be empty")]
     fn test_validate_empty_id_fails(#[case] empty_id: &str, #[case] expected_msg: &str) {
@@ -332,6 +814,58 @@ invalid yaml syntax: [unclosed bracket
         assert!(error.to_string().contains(expected_msg));
     }
 
+    #[test]
+    fn test_validate_supported_schema_version_succeeds() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.schema_version = SUPPORTED_SCHEMA_VERSION;
+
+        // Act
+        let result = manifest.validate();
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_retries_above_maximum_fails() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.retries = MAX_RETRIES;
+        let at_max = manifest.validate();
+        manifest.retries = MAX_RETRIES + 1;
+
+        // Act
+        let result = manifest.validate();
+
+        // Assert
+        assert!(at_max.is_ok());
+        assert!(result.unwrap_err().to_string().contains("retries"));
+    }
+
+    #[test]
+    fn test_from_yaml_str_newer_schema_version_fails() {
+        // Arrange
+        let yaml = r#"
+schema_version: 99
+id: future-skill
+name: Future Skill
+description: Written for a newer pane
+exec: ./future.sh
+ui:
+  mode: inline
+"#;
+
+        // Act
+        let result = SkillManifest::from_yaml_str(yaml);
+
+        // Assert
+        assert!(result.is_err());
+        let error_msg = result.unwrap_err().to_string();
+        assert!(error_msg.contains("schema version 99"));
+        assert!(error_msg.contains("upgrade pane"));
+    }
+
     #[test]
     fn test_validate_empty_name_fails() {
         // Arrange
@@ -377,16 +911,107 @@ invalid yaml syntax: [unclosed bracket
         assert!(error.to_string().contains("exec cannot be empty"));
     }
 
+    #[test]
+    fn test_resolved_exec_uses_base_exec_without_platform_override() {
+        // Arrange
+        let manifest = create_valid_manifest();
+
+        // Act
+        let exec = manifest.resolved_exec();
+
+        // Assert
+        assert_eq!(exec, "./test.sh");
+    }
+
+    #[test]
+    fn test_resolved_exec_prefers_current_platform_override() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.exec_windows = Some("test-windows.bat".to_string());
+        manifest.exec_macos = Some("./test-macos.sh".to_string());
+        manifest.exec_linux = Some("./test-linux.sh".to_string());
+
+        // Act
+        let exec = manifest.resolved_exec();
+
+        // Assert
+        let expected = match std::env::consts::OS {
+            "windows" => "test-windows.bat",
+            "macos" => "./test-macos.sh",
+            "linux" => "./test-linux.sh",
+            _ => "./test.sh",
+        };
+        assert_eq!(exec, expected);
+    }
+
+    #[rstest]
+    #[case("windows", "fmt.bat")]
+    #[case("macos", "./fmt-mac.sh")]
+    #[case("linux", "./fmt.sh")]
+    #[case("freebsd", "./fmt.sh")]
+    fn test_exec_for_os_selects_platform_exec(#[case] os: &str, #[case] expected: &str) {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.exec = "./fmt.sh".to_string();
+        manifest.exec_windows = Some("fmt.bat".to_string());
+        manifest.exec_macos = Some("./fmt-mac.sh".to_string());
+
+        // Act
+        let exec = manifest.exec_for_os(os);
+
+        // Assert
+        assert_eq!(exec, expected);
+    }
+
+    #[test]
+    fn test_validate_platform_exec_without_base_exec_succeeds() {
+        // Arrange
+        let mut manifest = create_valid_manifest();
+        manifest.exec = String::new();
+        manifest.exec_windows = Some("test.bat".to_string());
+        manifest.exec_macos = Some("./test.sh".to_string());
+        manifest.exec_linux = Some("./test.sh".to_string());
+
+        // Act
+        let result = manifest.validate();
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_other_platform_exec_only_fails() {
+        // Arrange - only a platform that is not the current one has an exec
+        let mut manifest = create_valid_manifest();
+        manifest.exec = String::new();
+        if std::env::consts::OS == "windows" {
+            manifest.exec_linux = Some("./test.sh".to_string());
+        } else {
+            manifest.exec_windows = Some("test.bat".to_string());
+        }
+
+        // Act
+        let result = manifest.validate();
+
+        // Assert
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("exec cannot be empty"));
+    }
+
     #[test]
     fn test_context_config_defaults() {
         // Arrange & Act
         let context = ContextConfig::default();
 
         // Assert
-        assert_eq!(context.pass_cwd, true);
-        assert_eq!(context.pass_git_root, true);
-        assert_eq!(context.pass_project_name, true);
-        assert_eq!(context.pass_stdin_json, false);
+        assert!(context.pass_cwd);
+        assert!(context.pass_git_root);
+        assert!(context.pass_project_name);
+        assert!(!context.pass_stdin_json);
+        assert!(!context.pass_context_json);
     }
 
     #[test]
@@ -407,13 +1032,35 @@ ui:
         // Assert
         assert!(result.is_ok());
         let manifest = result.unwrap();
-        assert_eq!(manifest.ui.fullscreen, true);
+        assert!(manifest.ui.fullscreen);
+        assert!(!manifest.ui.capture);
+    }
+
+    #[test]
+    fn test_ui_config_capture_parses() {
+        // Arrange
+        let yaml = r#"
+id: test-skill
+name: Test Skill
+description: A test skill
+exec: ./test.sh
+ui:
+  mode: tui
+  capture: true
+"#;
+
+        // Act
+        let manifest = SkillManifest::from_yaml_str(yaml).unwrap();
+
+        // Assert
+        assert!(manifest.ui.capture);
     }
 
     #[test]
     fn test_from_yaml_file_valid_manifest_succeeds() {
         // Arrange
-        let path = PathBuf::from("tests/fixtures/skills/minimal-valid.yaml");
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/skills/minimal-valid.yaml");
 
         // Act
         let result = SkillManifest::from_yaml_file(path);
@@ -1,18 +1,28 @@
 /// Skills module - handles skill manifest parsing and management
+pub mod bundle;
+pub mod estimated_time;
 pub mod loader;
 pub mod manifest;
 pub mod model;
 pub mod output;
+pub(crate) mod pty;
 pub mod runner;
 
 // Re-export manifest types
 #[allow(unused_imports)]
-pub use manifest::{ContextConfig, SkillManifest, UiConfig, UiMode};
+pub use manifest::{ContextConfig, ManifestLimits, OutputFormat, SkillManifest, UiConfig, UiMode};
+
+// Re-export estimated time parsing
+#[allow(unused_imports)]
+pub use estimated_time::EstimatedTime;
 
 // Re-export model types
 #[allow(unused_imports)]
-pub use model::{Skill, SkillSource};
+pub use model::{Skill, SkillSource, UNCATEGORIZED_LABEL};
 
 // Re-export loader functions
 #[allow(unused_imports)]
-pub use loader::discover_skills;
+pub use loader::{
+    discover_skills, discover_skills_with_collisions, discovery_directories, Discovery,
+    DiscoveryError, SkillCollision,
+};
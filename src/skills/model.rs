@@ -1,23 +1,81 @@
+use std::borrow::Cow;
 use std::path::PathBuf;
+use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
+use crate::skills::estimated_time::EstimatedTime;
 use crate::skills::manifest::SkillManifest;
 
+/// Category label used for skills whose manifest has no `category`
+pub const UNCATEGORIZED_LABEL: &str = "Uncategorized";
+
 /// Indicates where a skill was discovered (for override precedence)
 ///
-/// Skills are discovered from three locations with the following precedence:
-/// Project > User > System
+/// Skills are discovered from up to five locations with the following precedence:
+/// Override > Project > User > System > Builtin
 ///
 /// When skills with duplicate IDs are found, the skill from the higher
-/// precedence source is used.
-#[derive(Debug, Clone, PartialEq)]
+/// precedence source is used. Variants are ordered by precedence, so sorting
+/// puts builtin skills first and project skills last.
+///
+/// # Examples
+///
+/// ```
+/// use pane::SkillSource;
+///
+/// assert!(SkillSource::Builtin < SkillSource::System);
+/// assert!(SkillSource::User < SkillSource::Project);
+/// assert_eq!(SkillSource::Builtin.to_string(), "Builtin");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 #[allow(dead_code)]
 pub enum SkillSource {
-    /// Discovered in /usr/local/share/pane/skills/ (lowest precedence)
+    /// Bundled with pane, found in `share/pane/builtin-skills/` next to the
+    /// installed binary (lowest precedence)
+    Builtin,
+    /// Discovered in /usr/local/share/pane/skills/ (low precedence)
     System,
     /// Discovered in ~/.config/pane/skills/ (medium precedence)
     User,
-    /// Discovered in ./.pane/skills/ (highest precedence)
+    /// Discovered in ./.pane/skills/ (high precedence)
     Project,
+    /// Discovered in a directory passed with `--skill-path` (highest precedence)
+    Override,
+}
+
+impl std::fmt::Display for SkillSource {
+    /// Formats the source name as shown in the detail pane (e.g. "Builtin")
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SkillSource::Builtin => "Builtin",
+            SkillSource::System => "System",
+            SkillSource::User => "User",
+            SkillSource::Project => "Project",
+            SkillSource::Override => "Override",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for SkillSource {
+    type Err = anyhow::Error;
+
+    /// Parses a source name case-insensitively (e.g. "project" or "Project")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "builtin" => Ok(SkillSource::Builtin),
+            "system" => Ok(SkillSource::System),
+            "user" => Ok(SkillSource::User),
+            "project" => Ok(SkillSource::Project),
+            "override" => Ok(SkillSource::Override),
+            _ => anyhow::bail!(
+                "Unknown skill source {:?}; expected builtin, system, user, project, or override",
+                s
+            ),
+        }
+    }
 }
 
 /// Represents a discovered skill with its manifest and source metadata
@@ -33,4 +91,169 @@ pub struct Skill {
     pub source: SkillSource,
     /// Absolute path to the source pane-skill.yaml file
     pub manifest_path: PathBuf,
+    /// `estimated_time` parsed at load when `parse_estimated_time` is enabled;
+    /// `None` if parsing is off or the value was not recognized
+    pub estimated_duration: Option<EstimatedTime>,
+}
+
+impl Skill {
+    /// Get the category this skill is grouped under
+    ///
+    /// # Returns
+    ///
+    /// The manifest's `category`, or `UNCATEGORIZED_LABEL` if none is set
+    pub fn category_label(&self) -> &str {
+        self.manifest
+            .category
+            .as_deref()
+            .unwrap_or(UNCATEGORIZED_LABEL)
+    }
+
+    /// Get the estimated time shown in the list and detail pane
+    ///
+    /// # Returns
+    ///
+    /// The normalized parsed estimate if there is one, otherwise the
+    /// manifest's `estimated_time` as written, or `None` if it has none
+    pub fn estimated_time_label(&self) -> Option<Cow<'_, str>> {
+        match self.estimated_duration {
+            Some(estimate) => Some(Cow::Owned(estimate.to_string())),
+            None => self.manifest.estimated_time.as_deref().map(Cow::Borrowed),
+        }
+    }
+
+    /// Parse the manifest's `estimated_time` into `estimated_duration`
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - The `parse_estimated_time` setting; when false the
+    ///   estimate is cleared so the raw string is shown
+    pub fn parse_estimated_time(&mut self, enabled: bool) {
+        self.estimated_duration = if enabled {
+            self.manifest
+                .estimated_time
+                .as_deref()
+                .and_then(EstimatedTime::parse)
+        } else {
+            None
+        };
+    }
+
+    /// Get the name shown for this skill in lists
+    ///
+    /// # Returns
+    ///
+    /// The trimmed manifest `name`, or the skill `id` if the name is blank
+    pub fn display_name(&self) -> &str {
+        let name = self.manifest.name.trim();
+        if name.is_empty() {
+            &self.manifest.id
+        } else {
+            name
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skill_source_display_names() {
+        // Arrange & Act & Assert
+        assert_eq!(SkillSource::Builtin.to_string(), "Builtin");
+        assert_eq!(SkillSource::System.to_string(), "System");
+        assert_eq!(SkillSource::User.to_string(), "User");
+        assert_eq!(SkillSource::Project.to_string(), "Project");
+        assert_eq!(SkillSource::Override.to_string(), "Override");
+    }
+
+    #[test]
+    fn test_skill_source_from_str_is_case_insensitive() {
+        // Arrange & Act & Assert
+        assert_eq!(
+            "project".parse::<SkillSource>().unwrap(),
+            SkillSource::Project
+        );
+        assert_eq!(
+            "Builtin".parse::<SkillSource>().unwrap(),
+            SkillSource::Builtin
+        );
+        assert!("vendor".parse::<SkillSource>().is_err());
+    }
+
+    fn create_skill_with_estimate(estimated_time: &str) -> Skill {
+        let manifest = SkillManifest::from_yaml_str(&format!(
+            "id: demo\nname: Demo\ndescription: Demo skill\nversion: 1.0.0\n\
+             exec: echo\nui:\n  mode: inline\nestimated_time: \"{}\"\n",
+            estimated_time
+        ))
+        .unwrap();
+        Skill {
+            manifest,
+            source: SkillSource::User,
+            manifest_path: PathBuf::from("test.yaml"),
+            estimated_duration: None,
+        }
+    }
+
+    #[test]
+    fn test_estimated_time_label_normalizes_parsed_estimate() {
+        // Arrange
+        let mut skill = create_skill_with_estimate("~1 to 3 minutes");
+
+        // Act
+        skill.parse_estimated_time(true);
+
+        // Assert
+        assert!(skill.estimated_duration.is_some());
+        assert_eq!(skill.estimated_time_label().as_deref(), Some("1-3 min"));
+    }
+
+    #[test]
+    fn test_estimated_time_label_keeps_original_when_unparsed() {
+        // Arrange
+        let mut parsing_off = create_skill_with_estimate("1 - 3 minutes");
+        let mut unrecognized = create_skill_with_estimate("a while");
+
+        // Act
+        parsing_off.parse_estimated_time(false);
+        unrecognized.parse_estimated_time(true);
+
+        // Assert
+        assert_eq!(
+            parsing_off.estimated_time_label().as_deref(),
+            Some("1 - 3 minutes")
+        );
+        assert_eq!(unrecognized.estimated_duration, None);
+        assert_eq!(
+            unrecognized.estimated_time_label().as_deref(),
+            Some("a while")
+        );
+    }
+
+    #[test]
+    fn test_skill_source_orders_by_precedence() {
+        // Arrange
+        let mut sources = vec![
+            SkillSource::Project,
+            SkillSource::Builtin,
+            SkillSource::User,
+            SkillSource::System,
+        ];
+
+        // Act
+        sources.sort();
+
+        // Assert - builtin has the lowest precedence
+        assert_eq!(
+            sources,
+            vec![
+                SkillSource::Builtin,
+                SkillSource::System,
+                SkillSource::User,
+                SkillSource::Project,
+            ]
+        );
+    }
 }
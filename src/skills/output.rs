@@ -1,5 +1,10 @@
 use std::time::Duration;
 
+use serde::{Serialize, Serializer};
+
+use crate::json;
+use crate::skills::manifest::OutputFormat;
+
 /// Maximum allowed output size (10MB)
 ///
 /// If a skill's combined stdout + stderr exceeds this limit,
@@ -15,6 +20,7 @@ pub const MAX_OUTPUT_SIZE: usize = 10 * 1024 * 1024; // 10MB
 /// # Examples
 ///
 /// ```no_run
+/// use pane::skills::manifest::OutputFormat;
 /// use pane::skills::output::SkillOutput;
 /// use std::time::Duration;
 ///
@@ -24,12 +30,14 @@ pub const MAX_OUTPUT_SIZE: usize = 10 * 1024 * 1024; // 10MB
 ///     exit_code: Some(0),
 ///     truncated: false,
 ///     execution_time: Duration::from_millis(42),
+///     format: OutputFormat::Text,
+///     attempts: 1,
 /// };
 ///
 /// assert!(output.exit_code == Some(0));
 /// assert!(!output.truncated);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SkillOutput {
     /// Captured stdout from the skill process
     pub stdout: String,
@@ -39,8 +47,90 @@ pub struct SkillOutput {
     pub exit_code: Option<i32>,
     /// Whether output was truncated due to exceeding size limit
     pub truncated: bool,
-    /// Time taken to execute the skill
+    /// Time taken to execute the skill (serialized as `execution_time_ms`)
+    #[serde(rename = "execution_time_ms", serialize_with = "serialize_millis")]
     pub execution_time: Duration,
+    /// Rendering hint for stdout, taken from the skill manifest
+    #[serde(skip)]
+    pub format: OutputFormat,
+    /// How many times the skill was run; above 1 when the manifest's
+    /// `retries` re-ran a failing skill
+    pub attempts: u32,
+}
+
+/// Serialize a `Duration` as whole milliseconds
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+}
+
+impl SkillOutput {
+    /// Create an output describing a failure to run a skill
+    ///
+    /// The message is placed in stderr and the exit code is left unset, so the
+    /// output panel shows it with an "Error" status.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Error message to display (typically an `anyhow` chain via `{:#}`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::skills::output::SkillOutput;
+    ///
+    /// let output = SkillOutput::error("Executable 'foo' not found");
+    /// assert_eq!(output.stderr, "Executable 'foo' not found");
+    /// assert_eq!(output.exit_code, None);
+    /// ```
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            stdout: String::new(),
+            stderr: message.into(),
+            exit_code: None,
+            truncated: false,
+            execution_time: Duration::from_secs(0),
+            format: OutputFormat::Text,
+            attempts: 1,
+        }
+    }
+
+    /// Format the output of a completed run as a single-line JSON object
+    ///
+    /// Used by `pane run <id> --json` so CI can consume results. Carries the
+    /// same fields as the `Serialize` impl, plus `id`, without needing a JSON
+    /// library. The exit code is `null` if the process was killed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - ID of the skill that produced this output
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::skills::output::SkillOutput;
+    ///
+    /// let output = SkillOutput::error("boom");
+    /// assert_eq!(
+    ///     output.to_json("build"),
+    ///     r#"{"id":"build","exit_code":null,"execution_time_ms":0,"truncated":false,"attempts":1,"stdout":"","stderr":"boom"}"#
+    /// );
+    /// ```
+    pub fn to_json(&self, id: &str) -> String {
+        let exit_code = self
+            .exit_code
+            .map_or_else(|| "null".to_string(), |code| code.to_string());
+
+        format!(
+            "{{\"id\":{},\"exit_code\":{},\"execution_time_ms\":{},\"truncated\":{},\"attempts\":{},\"stdout\":{},\"stderr\":{}}}",
+            json::quote(id),
+            exit_code,
+            self.execution_time.as_millis(),
+            self.truncated,
+            self.attempts,
+            json::quote(&self.stdout),
+            json::quote(&self.stderr)
+        )
+    }
 }
 
 /// Buffer for capturing output with size limit enforcement
@@ -57,8 +147,8 @@ pub struct SkillOutput {
 /// let mut buffer = OutputBuffer::new();
 /// assert!(buffer.is_empty());
 ///
-/// buffer.append(b"Hello ").unwrap();
-/// buffer.append(b"world!").unwrap();
+/// buffer.append(b"Hello ");
+/// buffer.append(b"world!");
 ///
 /// assert_eq!(buffer.to_string(), "Hello world!");
 /// assert!(!buffer.is_truncated());
@@ -121,21 +211,28 @@ impl OutputBuffer {
     ///
     /// * `data` - Bytes to append to the buffer
     ///
+    /// # Returns
+    ///
+    /// The number of bytes actually stored. A value smaller than `data.len()`
+    /// means the buffer is saturated and the rest was discarded.
+    ///
     /// # Examples
     ///
     /// ```
     /// use pane::skills::output::OutputBuffer;
     ///
     /// let mut buffer = OutputBuffer::with_limit(10);
-    /// buffer.append(b"Hello");
-    /// buffer.append(b" World!"); // Exceeds limit
+    /// assert_eq!(buffer.append(b"Hello"), 5);
+    /// assert_eq!(buffer.append(b" World!"), 5); // Exceeds limit
     /// assert!(buffer.is_truncated());
     /// ```
-    pub fn append(&mut self, data: &[u8]) {
+    pub fn append(&mut self, data: &[u8]) -> usize {
         // Check if we're already at capacity
         if self.buffer.len() >= self.size_limit {
-            self.truncated = true;
-            return;
+            if !data.is_empty() {
+                self.truncated = true;
+            }
+            return 0;
         }
 
         // Calculate how much space is left
@@ -144,10 +241,12 @@ impl OutputBuffer {
         if data.len() <= remaining {
             // All data fits
             self.buffer.extend_from_slice(data);
+            data.len()
         } else {
             // Partial data fits - take only what we can
             self.buffer.extend_from_slice(&data[..remaining]);
             self.truncated = true;
+            remaining
         }
     }
 
@@ -165,7 +264,7 @@ impl OutputBuffer {
     /// use pane::skills::output::OutputBuffer;
     ///
     /// let mut buffer = OutputBuffer::new();
-    /// buffer.append(b"Hello, world!").unwrap();
+    /// buffer.append(b"Hello, world!");
     /// assert_eq!(buffer.to_string(), "Hello, world!");
     /// ```
     #[allow(clippy::inherent_to_string)]
@@ -185,7 +284,7 @@ impl OutputBuffer {
     /// use pane::skills::output::OutputBuffer;
     ///
     /// let mut buffer = OutputBuffer::with_limit(5);
-    /// buffer.append(b"Hello world").unwrap();
+    /// buffer.append(b"Hello world");
     /// assert!(buffer.is_truncated());
     /// ```
     pub fn is_truncated(&self) -> bool {
@@ -222,7 +321,7 @@ impl OutputBuffer {
     /// use pane::skills::output::OutputBuffer;
     ///
     /// let mut buffer = OutputBuffer::new();
-    /// buffer.append(b"Hello").unwrap();
+    /// buffer.append(b"Hello");
     /// assert_eq!(buffer.len(), 5);
     /// ```
     pub fn len(&self) -> usize {
@@ -236,10 +335,193 @@ impl Default for OutputBuffer {
     }
 }
 
+/// Which output stream of a skill process some text came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    /// The process's standard output
+    Stdout,
+    /// The process's standard error
+    Stderr,
+}
+
+impl OutputStream {
+    /// Lowercase stream name, as used in JSON output
+    ///
+    /// # Returns
+    ///
+    /// `"stdout"` or `"stderr"`
+    pub fn name(self) -> &'static str {
+        match self {
+            OutputStream::Stdout => "stdout",
+            OutputStream::Stderr => "stderr",
+        }
+    }
+}
+
+/// Incremental UTF-8 decoder for output read in chunks
+///
+/// A multibyte character can be split across two reads. The decoder keeps an
+/// incomplete trailing sequence until the next chunk arrives, so each call
+/// only yields complete characters and text can be shown as it streams in.
+/// Invalid bytes become the Unicode replacement character, like
+/// `String::from_utf8_lossy`.
+///
+/// # Examples
+///
+/// ```
+/// use pane::skills::output::Utf8StreamDecoder;
+///
+/// let mut decoder = Utf8StreamDecoder::new();
+/// let bytes = "é".as_bytes();
+/// assert_eq!(decoder.decode(&bytes[..1]), "");
+/// assert_eq!(decoder.decode(&bytes[1..]), "é");
+/// assert_eq!(decoder.finish(), "");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Utf8StreamDecoder {
+    /// Bytes of an incomplete character carried over from the last chunk
+    pending: Vec<u8>,
+}
+
+impl Utf8StreamDecoder {
+    /// Create a decoder with nothing pending
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the next chunk of bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The bytes read since the last call
+    ///
+    /// # Returns
+    ///
+    /// The complete characters available so far; an incomplete sequence at
+    /// the end is held back for the next call
+    pub fn decode(&mut self, data: &[u8]) -> String {
+        let mut bytes = std::mem::take(&mut self.pending);
+        bytes.extend_from_slice(data);
+
+        let mut text = String::with_capacity(bytes.len());
+        let mut rest = bytes.as_slice();
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    rest = &[];
+                    break;
+                }
+                Err(e) => {
+                    let (valid, after) = rest.split_at(e.valid_up_to());
+                    // `valid_up_to` marks the end of a valid prefix
+                    text.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                    match e.error_len() {
+                        Some(len) => {
+                            text.push(char::REPLACEMENT_CHARACTER);
+                            rest = &after[len..];
+                        }
+                        // Incomplete sequence at the end: wait for more bytes
+                        None => {
+                            rest = after;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.pending = rest.to_vec();
+        text
+    }
+
+    /// Flush any incomplete sequence left at the end of the stream
+    ///
+    /// # Returns
+    ///
+    /// A replacement character if bytes were still pending, otherwise an empty string
+    pub fn finish(&mut self) -> String {
+        let pending = std::mem::take(&mut self.pending);
+        String::from_utf8_lossy(&pending).into_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
 
+    fn create_test_output() -> SkillOutput {
+        SkillOutput {
+            stdout: "built 3 targets\n".to_string(),
+            stderr: "warning: \"unused\"".to_string(),
+            exit_code: Some(2),
+            truncated: true,
+            execution_time: Duration::from_millis(1500),
+            format: OutputFormat::Json,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn test_skill_output_serializes_expected_fields() {
+        // Arrange
+        let output = create_test_output();
+
+        // Act
+        let value = serde_yaml::to_value(&output).unwrap();
+
+        // Assert
+        let mapping = value.as_mapping().unwrap();
+        let keys: Vec<&str> = mapping.keys().filter_map(|key| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "stdout",
+                "stderr",
+                "exit_code",
+                "truncated",
+                "execution_time_ms",
+                "attempts"
+            ]
+        );
+        assert_eq!(value["execution_time_ms"].as_u64(), Some(1500));
+        assert_eq!(value["exit_code"].as_i64(), Some(2));
+    }
+
+    #[test]
+    fn test_skill_output_to_json_matches_serialized_fields() {
+        // Arrange
+        let output = create_test_output();
+
+        // Act
+        let mut serialized: BTreeMap<String, serde_yaml::Value> =
+            serde_yaml::from_value(serde_yaml::to_value(&output).unwrap()).unwrap();
+        let json: BTreeMap<String, serde_yaml::Value> =
+            serde_yaml::from_str(&output.to_json("build")).unwrap();
+
+        // Assert - to_json is the Serialize impl plus the skill id
+        serialized.insert("id".to_string(), "build".into());
+        assert_eq!(json, serialized);
+    }
+
+    #[test]
+    fn test_skill_output_to_json_round_trips_through_parser() {
+        // Arrange
+        let output = create_test_output();
+
+        // Act
+        let json = output.to_json("build");
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"id":"build","exit_code":2,"execution_time_ms":1500,"truncated":true,"attempts":1,"stdout":"built 3 targets\n","stderr":"warning: \"unused\""}"#
+        );
+        assert!(crate::json::pretty_print(&json).is_ok());
+    }
+
     #[test]
     fn test_output_buffer_new_initializes_empty() {
         // Arrange & Act
@@ -330,6 +612,40 @@ mod tests {
         assert_eq!(buffer.to_string(), "Hello");
     }
 
+    #[test]
+    fn test_output_buffer_append_returns_stored_bytes_within_limit() {
+        // Arrange
+        let mut buffer = OutputBuffer::with_limit(10);
+
+        // Act
+        let first = buffer.append(b"Hello");
+        let second = buffer.append(b"World");
+
+        // Assert
+        assert_eq!(first, 5);
+        assert_eq!(second, 5);
+        assert_eq!(buffer.len(), first + second);
+        assert!(!buffer.is_truncated());
+    }
+
+    #[test]
+    fn test_output_buffer_append_returns_stored_bytes_when_truncating() {
+        // Arrange
+        let mut buffer = OutputBuffer::with_limit(8);
+
+        // Act
+        let first = buffer.append(b"Hello");
+        let second = buffer.append(b" world");
+        let third = buffer.append(b"!");
+
+        // Assert
+        assert_eq!(first, 5);
+        assert_eq!(second, 3);
+        assert_eq!(third, 0);
+        assert_eq!(buffer.len(), first + second + third);
+        assert!(buffer.is_truncated());
+    }
+
     #[test]
     fn test_output_buffer_to_string_converts_correctly() {
         // Arrange
@@ -368,6 +684,8 @@ mod tests {
             exit_code: Some(0),
             truncated: false,
             execution_time: Duration::from_millis(123),
+            format: OutputFormat::Text,
+            attempts: 1,
         };
 
         // Assert
@@ -387,6 +705,8 @@ mod tests {
             exit_code: Some(0),
             truncated: true,
             execution_time: Duration::from_secs(1),
+            format: OutputFormat::Text,
+            attempts: 1,
         };
 
         // Assert
@@ -402,9 +722,75 @@ mod tests {
             exit_code: None,
             truncated: false,
             execution_time: Duration::from_millis(50),
+            format: OutputFormat::Text,
+            attempts: 1,
         };
 
         // Assert
         assert!(output.exit_code.is_none());
     }
+
+    #[test]
+    fn test_skill_output_error_populates_stderr() {
+        // Arrange & Act
+        let output = SkillOutput::error("Failed to build context");
+
+        // Assert
+        assert!(output.stdout.is_empty());
+        assert_eq!(output.stderr, "Failed to build context");
+        assert_eq!(output.exit_code, None);
+        assert!(!output.truncated);
+    }
+
+    #[test]
+    fn test_utf8_stream_decoder_joins_character_split_byte_by_byte() {
+        // Arrange
+        let text = "상태 ✓ 🚀 ok";
+        let mut decoder = Utf8StreamDecoder::new();
+
+        // Act - feed one byte per call, recording what each call emits
+        let pieces: Vec<String> = text
+            .as_bytes()
+            .iter()
+            .map(|byte| decoder.decode(std::slice::from_ref(byte)))
+            .collect();
+
+        // Assert - every emitted piece is whole characters, in order
+        assert_eq!(pieces.concat(), text);
+        assert!(pieces.iter().all(|piece| piece.chars().count() <= 1));
+        assert_eq!(
+            pieces.iter().filter(|piece| !piece.is_empty()).count(),
+            text.chars().count()
+        );
+        assert_eq!(decoder.finish(), "");
+    }
+
+    #[test]
+    fn test_utf8_stream_decoder_holds_partial_sequence_across_chunks() {
+        // Arrange
+        let bytes = "a한b".as_bytes();
+        let mut decoder = Utf8StreamDecoder::new();
+
+        // Act
+        let first = decoder.decode(&bytes[..2]);
+        let second = decoder.decode(&bytes[2..]);
+
+        // Assert
+        assert_eq!(first, "a");
+        assert_eq!(second, "한b");
+    }
+
+    #[test]
+    fn test_utf8_stream_decoder_replaces_invalid_and_dangling_bytes() {
+        // Arrange
+        let mut decoder = Utf8StreamDecoder::new();
+
+        // Act
+        let invalid = decoder.decode(b"ok\xFFgo\xE2\x9C");
+        let dangling = decoder.finish();
+
+        // Assert
+        assert_eq!(invalid, "ok\u{FFFD}go");
+        assert_eq!(dangling, "\u{FFFD}");
+    }
 }
@@ -0,0 +1,196 @@
+//! Pseudo-terminal support for inline skills
+//!
+//! Many tools only emit colors and progress bars when stdout is a TTY. A
+//! skill with `pty: true` gets a pseudo-terminal as its stdout and stderr so
+//! it behaves as it would in a shell, while the launcher still captures
+//! everything it writes.
+//!
+//! Only Unix platforms (Linux and macOS) are supported; elsewhere spawning a
+//! pty skill returns an error and pipe mode remains the only option.
+
+use std::fs::File;
+use std::io::Read;
+use std::process::{Child, Command};
+
+use anyhow::Result;
+
+/// Terminal size reported to the skill: the width of a typical shell
+/// window, tall enough that nothing pages
+#[cfg(unix)]
+const PTY_SIZE: (u16, u16) = (120, 40);
+
+/// Spawn `cmd` with a pseudo-terminal as its stdout and stderr
+///
+/// The child starts a new session with the pty as its controlling terminal
+/// and reads stdin from `/dev/null`, so a skill that prompts cannot block the
+/// launcher. Output post-processing (`\n` to `\r\n`) is turned off so the
+/// captured text matches what pipe mode would produce. Both streams arrive
+/// interleaved on the returned reader, as they would in a terminal.
+///
+/// # Arguments
+///
+/// * `cmd` - The fully configured command; its stdio settings are replaced
+///
+/// # Returns
+///
+/// The child process and the pty's master side for reading its output. Once
+/// the child exits, reads return end-of-file.
+///
+/// # Errors
+///
+/// Returns an error if the pty cannot be opened or configured, the process
+/// cannot be spawned, or the platform has no pty support
+#[cfg(unix)]
+pub(crate) fn spawn_in_pty(mut cmd: Command) -> Result<(Child, PtyReader)> {
+    use std::io;
+    use std::os::unix::process::CommandExt;
+    use std::process::Stdio;
+
+    use anyhow::Context;
+
+    let (master, slave) = open_pty().context("Failed to open a pseudo-terminal")?;
+    disable_output_processing(&slave).context("Failed to configure the pseudo-terminal")?;
+
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::from(slave.try_clone()?));
+    cmd.stderr(Stdio::from(slave));
+    // SAFETY: `setsid` and `ioctl` are async-signal-safe and touch only the
+    // child's own process state between fork and exec.
+    unsafe {
+        cmd.pre_exec(|| {
+            if libc::setsid() == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCSCTTY as _, 0) == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+
+    let child = cmd.spawn()?;
+    // Dropping `cmd` closes the launcher's copies of the slave side, so the
+    // master sees end-of-file once the child (and its children) exit.
+    drop(cmd);
+
+    Ok((child, PtyReader(File::from(master))))
+}
+
+/// Spawning under a pty needs Unix pseudo-terminals
+#[cfg(not(unix))]
+pub(crate) fn spawn_in_pty(_cmd: Command) -> Result<(Child, PtyReader)> {
+    anyhow::bail!("pty mode is only supported on Unix platforms")
+}
+
+/// Read side of a pseudo-terminal
+///
+/// Linux reports `EIO` instead of end-of-file when the last slave
+/// descriptor closes; this reader turns that into a normal end-of-file.
+pub(crate) struct PtyReader(File);
+
+impl Read for PtyReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.0.read(buf) {
+            #[cfg(unix)]
+            Err(e) if e.raw_os_error() == Some(libc::EIO) => Ok(0),
+            result => result,
+        }
+    }
+}
+
+/// Open a master/slave pseudo-terminal pair sized to `PTY_SIZE`
+///
+/// Both descriptors are close-on-exec, so only the copies installed as the
+/// child's stdio survive into it; otherwise the master would leak into the
+/// child and its descendants and the pty would never see a hangup.
+#[cfg(unix)]
+fn open_pty() -> std::io::Result<(std::os::fd::OwnedFd, std::os::fd::OwnedFd)> {
+    use std::os::fd::{FromRawFd, OwnedFd};
+
+    let (cols, rows) = PTY_SIZE;
+    let mut size = libc::winsize {
+        ws_row: rows,
+        ws_col: cols,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    let mut master = -1;
+    let mut slave = -1;
+    // SAFETY: the out-pointers are valid for the duration of the call and
+    // the name buffer and termios are optional (null). macOS declares the
+    // size as `*mut`, Linux as `*const`; a `*mut` pointer suits both.
+    let result = unsafe {
+        libc::openpty(
+            &mut master,
+            &mut slave,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::addr_of_mut!(size),
+        )
+    };
+    if result == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // SAFETY: `openpty` succeeded, so both descriptors are open and owned here.
+    let (master, slave) = unsafe { (OwnedFd::from_raw_fd(master), OwnedFd::from_raw_fd(slave)) };
+    set_cloexec(&master)?;
+    set_cloexec(&slave)?;
+    Ok((master, slave))
+}
+
+/// Mark a descriptor close-on-exec
+#[cfg(unix)]
+fn set_cloexec(fd: &std::os::fd::OwnedFd) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let fd = fd.as_raw_fd();
+    // SAFETY: `fd` is open for both calls, and F_GETFD/F_SETFD only touch
+    // its descriptor flags.
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFD);
+        if flags == -1 || libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC) == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Stop the pty from rewriting `\n` as `\r\n` in the child's output
+#[cfg(unix)]
+fn disable_output_processing(slave: &std::os::fd::OwnedFd) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let fd = slave.as_raw_fd();
+    // SAFETY: `termios` is plain data fully initialized by `tcgetattr`, and
+    // `fd` stays open for both calls.
+    unsafe {
+        let mut termios = std::mem::zeroed::<libc::termios>();
+        if libc::tcgetattr(fd, &mut termios) == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+        termios.c_oflag &= !libc::ONLCR;
+        if libc::tcsetattr(fd, libc::TCSANOW, &termios) == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::fd::AsRawFd;
+
+    #[test]
+    fn test_open_pty_descriptors_are_close_on_exec() {
+        // Act
+        let (master, slave) = open_pty().unwrap();
+
+        // Assert
+        for fd in [&master, &slave] {
+            // SAFETY: the descriptor is open while the pair is in scope
+            let flags = unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_GETFD) };
+            assert_ne!(flags & libc::FD_CLOEXEC, 0);
+        }
+    }
+}
@@ -1,13 +1,22 @@
-use std::io::{self, BufReader, Read};
-use std::process::{Command, ExitStatus, Stdio};
-use std::time::Instant;
+use std::collections::BTreeMap;
+use std::io::{self, BufReader, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::{bail, Context, Result};
 use crossterm::terminal;
 
 use crate::context::SkillContext;
-use crate::skills::manifest::UiMode;
-use crate::skills::output::{OutputBuffer, SkillOutput};
+use crate::i18n::{Language, Translations};
+use crate::json;
+use crate::skills::manifest::{OutputFormat, UiMode, MAX_RETRIES};
+use crate::skills::output::{
+    OutputBuffer, OutputStream, SkillOutput, Utf8StreamDecoder, MAX_OUTPUT_SIZE,
+};
+use crate::skills::pty;
 use crate::skills::Skill;
 
 /// Execute a skill based on its UI mode (TUI or inline)
@@ -52,8 +61,8 @@ pub fn execute_skill(skill: &Skill, context: SkillContext) -> Result<ExitStatus>
     match skill.manifest.ui.mode {
         UiMode::Tui => execute_tui(skill, context),
         UiMode::Inline => {
-            // Execute inline and capture output
-            let _output = execute_inline(skill, &context)?;
+            // Execute inline and capture output (discarded, so the default language suffices)
+            let _output = execute_inline(skill, &context, &Translations::load(Language::En))?;
 
             // NOTE: Output storage and display is handled in Story 3.2
             // For now, we just execute and discard the output
@@ -72,11 +81,506 @@ pub fn execute_skill(skill: &Skill, context: SkillContext) -> Result<ExitStatus>
     }
 }
 
+/// Fully resolved command for a skill, as it would be spawned
+///
+/// Captures the executable, arguments, and PANE_* environment variables that
+/// the runners pass to the skill process. Used for dry runs and as the single
+/// source of truth when building the process `Command`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedCommand {
+    /// Executable name or path from the manifest
+    pub exec: String,
+    /// Arguments passed to the executable
+    pub args: Vec<String>,
+    /// Environment variables added to the process (sorted by name)
+    pub env: BTreeMap<String, String>,
+}
+
+impl ResolvedCommand {
+    /// Render the command as a single shell-quoted command line
+    ///
+    /// Arguments containing whitespace or shell metacharacters are wrapped in
+    /// single quotes so the line can be pasted into a shell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use pane::skills::runner::ResolvedCommand;
+    /// let resolved = ResolvedCommand {
+    ///     exec: "echo".to_string(),
+    ///     args: vec!["hello world".to_string()],
+    ///     env: BTreeMap::new(),
+    /// };
+    /// assert_eq!(resolved.command_line(), "echo 'hello world'");
+    /// ```
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.exec)
+            .chain(self.args.iter())
+            .map(|part| shell_quote(part))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Render the command and environment as a JSON object
+    ///
+    /// The object has `exec`, `args`, `command`, and `env` keys, suitable for
+    /// consumption by scripts and editor tooling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use pane::skills::runner::ResolvedCommand;
+    /// let resolved = ResolvedCommand {
+    ///     exec: "ls".to_string(),
+    ///     args: vec![],
+    ///     env: BTreeMap::from([("PANE_ID".to_string(), "list".to_string())]),
+    /// };
+    /// assert_eq!(
+    ///     resolved.to_json(),
+    ///     r#"{"exec":"ls","args":[],"command":"ls","env":{"PANE_ID":"list"}}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let env = self
+            .env
+            .iter()
+            .map(|(key, value)| format!("{}:{}", json::quote(key), json::quote(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "{{\"exec\":{},\"args\":{},\"command\":{},\"env\":{{{}}}}}",
+            json::quote(&self.exec),
+            json::quote_all(&self.args),
+            json::quote(&self.command_line()),
+            env
+        )
+    }
+
+    /// Build a process `Command` with the resolved executable, args, and environment
+    fn to_command(&self) -> Command {
+        let mut cmd = Command::new(&self.exec);
+        cmd.args(&self.args);
+        cmd.envs(&self.env);
+        cmd
+    }
+}
+
+impl std::fmt::Display for ResolvedCommand {
+    /// Formats the command line followed by one `KEY=value` line per variable
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.command_line())?;
+        for (key, value) in &self.env {
+            writeln!(f, "{}={}", key, shell_quote(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Resolve the command a skill would run without executing it
+///
+/// Applies the same exec/args resolution and `prepare_environment` call used
+/// by the runners, so the result matches what would actually be spawned.
+/// Args are taken from `context.args` (the manifest args plus any extra args
+/// given at launch). `${VAR}` references in args are replaced with the prepared PANE_* values;
+/// unresolved variables expand to an empty string and log a warning.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to resolve
+/// * `context` - Context information to pass via environment variables
+///
+/// # Returns
+///
+/// The resolved executable, arguments, and environment variables
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pane::skills::Skill;
+/// # use pane::context::SkillContext;
+/// # use pane::skills::runner::resolve_command;
+/// # fn example(skill: &Skill, context: &SkillContext) {
+/// let resolved = resolve_command(skill, context);
+/// println!("{}", resolved.command_line());
+/// # }
+/// ```
+pub fn resolve_command(skill: &Skill, context: &SkillContext) -> ResolvedCommand {
+    let env: BTreeMap<String, String> = context
+        .prepare_environment(&skill.manifest.context)
+        .into_iter()
+        .collect();
+
+    let args = context
+        .args
+        .iter()
+        .map(|arg| substitute_context_vars(arg, &env))
+        .collect();
+
+    ResolvedCommand {
+        exec: resolve_exec_path(skill.manifest.resolved_exec(), skill.manifest_path.parent()),
+        args,
+        env,
+    }
+}
+
+/// Replace `${VAR}` references in an argument with context environment values
+///
+/// Variables missing from `env` (e.g. `PANE_GIT_ROOT` outside a repository)
+/// expand to an empty string with a warning. An unterminated `${` is kept as-is.
+///
+/// # Arguments
+///
+/// * `arg` - The manifest argument, possibly containing `${VAR}` references
+/// * `env` - The prepared PANE_* environment variables
+///
+/// # Returns
+///
+/// The argument with all references substituted
+fn substitute_context_vars(arg: &str, env: &BTreeMap<String, String>) -> String {
+    let mut result = String::with_capacity(arg.len());
+    let mut rest = arg;
+
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+
+        result.push_str(&rest[..start]);
+        let name = &rest[start + 2..start + 2 + len];
+        match env.get(name) {
+            Some(value) => result.push_str(value),
+            None => tracing::warn!(
+                "Unresolved variable '${{{}}}' in skill args expanded to empty",
+                name
+            ),
+        }
+        rest = &rest[start + 2 + len + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Execute a skill headlessly with inherited stdio
+///
+/// Used by the `pane run` command outside the TUI: no terminal handoff is
+/// needed, so the process simply inherits the caller's stdin/stdout/stderr.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+///
+/// # Returns
+///
+/// ExitStatus from the skill process
+///
+/// # Errors
+///
+/// Returns an error if executable validation or process execution fails
+pub fn execute_headless(skill: &Skill, context: &SkillContext) -> Result<ExitStatus> {
+    let exec = skill.manifest.resolved_exec();
+    validate_executable(exec, skill.manifest_path.parent())
+        .with_context(|| format!("Failed to validate executable '{}'", exec))?;
+
+    resolve_command(skill, context)
+        .to_command()
+        .status()
+        .with_context(|| format!("Failed to execute skill '{}'", skill.manifest.name))
+}
+
+/// Execute a skill headlessly, handing its output over as it is produced
+///
+/// Stdout and stderr are piped and read on separate threads, decoded as
+/// UTF-8 without splitting characters, and passed to `on_chunk` in the order
+/// they arrive. Chunks from one stream keep their relative order. Stdin is
+/// inherited. Nothing is buffered beyond a single read, so there is no size
+/// limit.
+///
+/// The manifest's `post_run` command runs once the skill exits, and its
+/// captured output is passed on the same way as for `execute_inline` (stdout
+/// goes to stderr for JSON skills). Skills that set `pty` or `retries` are
+/// rejected, since streamed output cannot be re-run or shared with a terminal.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the post_run failure message
+/// * `on_chunk` - Called on the current thread for each piece of output
+///
+/// # Returns
+///
+/// ExitStatus from the skill process, once both streams are closed
+///
+/// # Errors
+///
+/// Returns an error if the skill sets `pty` or `retries`, if executable
+/// validation, spawning, reading, or waiting fails, or if `on_chunk` returns
+/// an error; in that case the process is killed
+pub fn execute_streaming<F>(
+    skill: &Skill,
+    context: &SkillContext,
+    translations: &Translations,
+    mut on_chunk: F,
+) -> Result<ExitStatus>
+where
+    F: FnMut(OutputStream, &str) -> Result<()>,
+{
+    if skill.manifest.pty {
+        bail!(
+            "Skill '{}' sets pty, which cannot be combined with streamed output",
+            skill.manifest.id
+        );
+    }
+    if skill.manifest.retries > 0 {
+        bail!(
+            "Skill '{}' sets retries, which cannot be combined with streamed output",
+            skill.manifest.id
+        );
+    }
+
+    let exec = skill.manifest.resolved_exec();
+    validate_executable(exec, skill.manifest_path.parent())
+        .with_context(|| format!("Failed to validate executable '{}'", exec))?;
+
+    let mut cmd = resolve_command(skill, context).to_command();
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("Failed to spawn skill '{}'", skill.manifest.name))?;
+
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child
+        .stdout
+        .take()
+        .context("Failed to capture stdout handle")?;
+    let stderr = child
+        .stderr
+        .take()
+        .context("Failed to capture stderr handle")?;
+    let readers = [
+        spawn_stream_reader(stdout, OutputStream::Stdout, sender.clone()),
+        spawn_stream_reader(stderr, OutputStream::Stderr, sender),
+    ];
+
+    // The channel closes once both readers have hit end-of-file
+    for (stream, text) in receiver {
+        if let Err(e) = on_chunk(stream, &text) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(e);
+        }
+    }
+    for reader in readers {
+        reader
+            .join()
+            .map_err(|_| anyhow::anyhow!("Output reader thread panicked"))??;
+    }
+
+    let status = child.wait().with_context(|| {
+        format!(
+            "Failed to wait for skill '{}' completion",
+            skill.manifest.name
+        )
+    })?;
+
+    // Stream post_run output like execute_inline appends it
+    if let Some(result) = run_post_run(skill, context, true) {
+        let mut output = SkillOutput {
+            format: skill.manifest.output_format,
+            ..SkillOutput::error("")
+        };
+        match result {
+            Ok(post_run) => append_post_run_output(&mut output, post_run, translations),
+            Err(e) => output
+                .stderr
+                .push_str(&format!("[{} {:#}]\n", translations.post_run_failed, e)),
+        }
+        for (stream, text) in [
+            (OutputStream::Stdout, &output.stdout),
+            (OutputStream::Stderr, &output.stderr),
+        ] {
+            if !text.is_empty() {
+                on_chunk(stream, text)?;
+            }
+        }
+    }
+    Ok(status)
+}
+
+/// Read `stream` on a new thread, sending each decoded chunk to `sender`
+fn spawn_stream_reader<R: Read + Send + 'static>(
+    mut stream: R,
+    which: OutputStream,
+    sender: mpsc::Sender<(OutputStream, String)>,
+) -> thread::JoinHandle<Result<()>> {
+    thread::spawn(move || {
+        let mut decoder = Utf8StreamDecoder::new();
+        let mut chunk = vec![0u8; 8192];
+        loop {
+            let bytes_read = stream
+                .read(&mut chunk)
+                .with_context(|| format!("Failed to read {} from skill process", which.name()))?;
+            let text = if bytes_read == 0 {
+                decoder.finish()
+            } else {
+                decoder.decode(&chunk[..bytes_read])
+            };
+            // A closed receiver means the consumer gave up; stop reading
+            if !text.is_empty() && sender.send((which, text)).is_err() {
+                return Ok(());
+            }
+            if bytes_read == 0 {
+                return Ok(());
+            }
+        }
+    })
+}
+
+/// Quote a command line part for POSIX shells when needed
+fn shell_quote(part: &str) -> String {
+    let is_safe = !part.is_empty()
+        && part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+
+    if is_safe {
+        part.to_string()
+    } else {
+        format!("'{}'", part.replace('\'', "'\\''"))
+    }
+}
+
+/// Split a command line into words the way a POSIX shell would
+///
+/// Words are separated by unquoted whitespace. Single quotes keep their
+/// contents literally; double quotes allow `\"`, `\\`, `\$` and `` \` ``
+/// escapes; a backslash outside quotes escapes the next character. No
+/// variable expansion or globbing is done.
+///
+/// # Arguments
+///
+/// * `line` - The text to split, e.g. arguments typed in the TUI
+///
+/// # Returns
+///
+/// The words, with quotes and escapes removed
+///
+/// # Errors
+///
+/// Returns an error if a quote is left unterminated or the line ends with a
+/// lone backslash
+///
+/// # Examples
+///
+/// ```
+/// use pane::skills::runner::split_shell_words;
+///
+/// let words = split_shell_words(r#"--name "big world" -x 'a b'"#).unwrap();
+/// assert_eq!(words, vec!["--name", "big world", "-x", "a b"]);
+/// ```
+pub fn split_shell_words(line: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if let Some(done) = word.take() {
+                    words.push(done);
+                }
+            }
+            '\'' => {
+                let current = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated single quote in '{}'", line),
+                    }
+                }
+            }
+            '"' => {
+                let current = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => bail!("Unterminated double quote in '{}'", line),
+                        },
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated double quote in '{}'", line),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some(c) => word.get_or_insert_with(String::new).push(c),
+                None => bail!("Trailing backslash in '{}'", line),
+            },
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if let Some(done) = word {
+        words.push(done);
+    }
+    Ok(words)
+}
+
+/// How the terminal is handed to a TUI-mode skill
+///
+/// Chosen from the manifest's `ui.fullscreen` flag by `TerminalHandoff::for_skill`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalHandoff {
+    /// Clear the launcher's alternate screen and give the skill the whole terminal
+    /// (`fullscreen: true`)
+    AlternateScreen,
+    /// Leave the alternate screen so the skill runs on the primary screen like a
+    /// regular command; its output stays in the shell scrollback and the launcher
+    /// screen is restored afterwards (`fullscreen: false`)
+    PrimaryScreen,
+}
+
+impl TerminalHandoff {
+    /// Select the handoff for a skill based on its `ui.fullscreen` setting
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pane::skills::Skill;
+    /// # use pane::skills::runner::TerminalHandoff;
+    /// # fn example(skill: &Skill) {
+    /// if TerminalHandoff::for_skill(skill) == TerminalHandoff::PrimaryScreen {
+    ///     println!("{} keeps the shell history visible", skill.manifest.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn for_skill(skill: &Skill) -> Self {
+        if skill.manifest.ui.fullscreen {
+            TerminalHandoff::AlternateScreen
+        } else {
+            TerminalHandoff::PrimaryScreen
+        }
+    }
+}
+
 /// Execute a skill in TUI mode with terminal handoff
 ///
 /// Suspends the TUI, spawns the skill process with context environment variables,
 /// waits for completion, and restores the TUI. Uses RAII pattern for terminal
-/// state management to ensure cleanup even on panic.
+/// state management to ensure cleanup even on panic. The screen used by the skill
+/// depends on `ui.fullscreen` (see `TerminalHandoff`).
 ///
 /// # Arguments
 ///
@@ -96,22 +600,19 @@ pub fn execute_skill(skill: &Skill, context: SkillContext) -> Result<ExitStatus>
 /// - Process wait fails
 fn execute_tui(skill: &Skill, context: SkillContext) -> Result<ExitStatus> {
     // Validate that the executable exists before attempting to spawn
-    validate_executable(&skill.manifest.exec)
-        .with_context(|| format!("Failed to validate executable '{}'", skill.manifest.exec))?;
+    let exec = skill.manifest.resolved_exec();
+    validate_executable(exec, skill.manifest_path.parent())
+        .with_context(|| format!("Failed to validate executable '{}'", exec))?;
 
     // Suspend TUI before skill execution
-    suspend_tui().context("Failed to suspend TUI")?;
+    let handoff = TerminalHandoff::for_skill(skill);
+    suspend_tui(handoff).context("Failed to suspend TUI")?;
 
     // Create terminal guard to ensure restoration even on panic
-    let _guard = TerminalRestoreGuard;
-
-    // Prepare environment variables
-    let env_vars = context.prepare_environment(&skill.manifest.context);
+    let _guard = TerminalRestoreGuard(handoff);
 
-    // Build and spawn the command
-    let mut cmd = Command::new(&skill.manifest.exec);
-    cmd.args(&skill.manifest.args);
-    cmd.envs(env_vars);
+    // Build the command with resolved args and environment variables
+    let mut cmd = resolve_command(skill, &context).to_command();
 
     // For TUI mode: inherit stdin/stdout/stderr (skill takes over terminal)
     let status = cmd
@@ -119,19 +620,30 @@ fn execute_tui(skill: &Skill, context: SkillContext) -> Result<ExitStatus> {
         .with_context(|| format!("Failed to execute skill '{}'", skill.manifest.name))?;
 
     // Restore TUI after skill exits
-    restore_tui().context("Failed to restore TUI")?;
+    restore_tui(handoff).context("Failed to restore TUI")?;
+
+    // post_run output is ignored in TUI mode; failures are only logged
+    if let Some(Err(e)) = run_post_run(skill, &context, false) {
+        tracing::warn!("post_run for skill '{}' failed: {:#}", skill.manifest.id, e);
+    }
 
     Ok(status)
 }
 
-/// Execute a skill in inline mode with output capture
+/// Execute a TUI skill while capturing its output (`ui.capture: true`)
 ///
-/// Spawns the skill process with piped stdout/stderr, captures output into buffers
-/// with size limits, waits for completion, and returns structured output. The
-/// launcher TUI remains active during execution (output display in Story 3.2).
+/// Works like TUI mode (the launcher is suspended and stdin is inherited), but
+/// stdout and stderr are piped through pane: every chunk is written straight
+/// to the terminal and also kept, so the output can be shown in the output
+/// panel afterwards.
 ///
-/// Output is limited to 10MB total to prevent unbounded memory usage. If the limit
-/// is exceeded, output is truncated and the truncated flag is set.
+/// # Limitations
+///
+/// The skill's stdout/stderr are pipes rather than the terminal, so programs
+/// that check for a TTY may disable colors or paging, and full-screen TUIs
+/// will not render correctly. Use it for skills that print line-based output
+/// and exit. Captured output is limited to 10MB per stream like inline mode;
+/// output past the limit is still shown on the terminal.
 ///
 /// # Arguments
 ///
@@ -140,55 +652,51 @@ fn execute_tui(skill: &Skill, context: SkillContext) -> Result<ExitStatus> {
 ///
 /// # Returns
 ///
-/// SkillOutput containing stdout, stderr, exit code, execution time, and truncation status
+/// SkillOutput with the captured stdout/stderr, exit code, and execution time
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Executable validation fails
-/// - Process spawn fails
-/// - Output reading fails
-/// - Process wait fails
-pub fn execute_inline(skill: &Skill, context: &SkillContext) -> Result<SkillOutput> {
-    // Record start time for execution duration
+/// - Terminal suspend/restore fails
+/// - Process spawn, output reading, or wait fails
+pub fn execute_tui_captured(skill: &Skill, context: &SkillContext) -> Result<SkillOutput> {
     let start_time = Instant::now();
 
-    // Validate executable exists
-    validate_executable(&skill.manifest.exec)
-        .with_context(|| format!("Failed to validate executable '{}'", skill.manifest.exec))?;
+    let exec = skill.manifest.resolved_exec();
+    validate_executable(exec, skill.manifest_path.parent())
+        .with_context(|| format!("Failed to validate executable '{}'", exec))?;
 
-    // Prepare environment variables
-    let env_vars = context.prepare_environment(&skill.manifest.context);
+    let handoff = TerminalHandoff::for_skill(skill);
+    suspend_tui(handoff).context("Failed to suspend TUI")?;
+    let _guard = TerminalRestoreGuard(handoff);
 
-    // Build command with piped output
-    let mut cmd = Command::new(&skill.manifest.exec);
-    cmd.args(&skill.manifest.args);
-    cmd.envs(env_vars);
+    // Pipe output so it can be teed; stdin still comes from the terminal
+    let mut cmd = resolve_command(skill, context).to_command();
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
-
-    // Spawn the child process
     let mut child = cmd
         .spawn()
-        .with_context(|| format!("Failed to spawn inline skill '{}'", skill.manifest.name))?;
+        .with_context(|| format!("Failed to execute skill '{}'", skill.manifest.name))?;
 
-    // Capture stdout
     let stdout_handle = child
         .stdout
         .take()
         .context("Failed to capture stdout handle")?;
-    let stdout_result =
-        read_output_stream(stdout_handle).context("Failed to read stdout from skill process")?;
-
-    // Capture stderr
     let stderr_handle = child
         .stderr
         .take()
         .context("Failed to capture stderr handle")?;
-    let stderr_result =
-        read_output_stream(stderr_handle).context("Failed to read stderr from skill process")?;
 
-    // Wait for process to complete
+    // Tee stderr on its own thread so neither pipe can fill up and block the skill
+    let stderr_thread = std::thread::spawn(move || tee_output_stream(stderr_handle, io::stderr()));
+    let stdout_result = tee_output_stream(stdout_handle, io::stdout())
+        .context("Failed to read stdout from skill process")?;
+    let stderr_result = stderr_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("stderr capture thread panicked"))?
+        .context("Failed to read stderr from skill process")?;
+
     let status = child.wait().with_context(|| {
         format!(
             "Failed to wait for skill '{}' completion",
@@ -196,6 +704,367 @@ pub fn execute_inline(skill: &Skill, context: &SkillContext) -> Result<SkillOutp
         )
     })?;
 
+    restore_tui(handoff).context("Failed to restore TUI")?;
+
+    if let Some(Err(e)) = run_post_run(skill, context, false) {
+        tracing::warn!("post_run for skill '{}' failed: {:#}", skill.manifest.id, e);
+    }
+
+    Ok(SkillOutput {
+        stdout: stdout_result.0,
+        stderr: stderr_result.0,
+        exit_code: status.code(),
+        truncated: stdout_result.1 || stderr_result.1,
+        execution_time: start_time.elapsed(),
+        format: skill.manifest.output_format,
+        attempts: 1,
+    })
+}
+
+/// Execute a skill in inline mode with output capture
+///
+/// Spawns the skill process with piped stdout/stderr, captures output into buffers
+/// with size limits, waits for completion, and returns structured output. The
+/// launcher TUI remains active during execution (output display in Story 3.2).
+///
+/// Output is limited to 10MB total to prevent unbounded memory usage. If the limit
+/// is exceeded, output is truncated, the truncated flag is set, and a note in
+/// the active language is appended to the cut-off stream.
+///
+/// With the manifest's `pty` flag set, the skill runs under a pseudo-terminal
+/// instead of pipes so TTY checks succeed; its stdout and stderr are then
+/// captured together as stdout.
+///
+/// A skill that exits non-zero is re-run up to the manifest's `retries` times
+/// (capped at `MAX_RETRIES`), waiting a little longer before each attempt;
+/// the waits add up to at most `MAX_TOTAL_BACKOFF`. The last attempt's output is
+/// returned with `attempts` set; `post_run` runs once, after the last attempt.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the truncation note
+///
+/// # Returns
+///
+/// SkillOutput containing stdout, stderr, exit code, execution time, truncation
+/// status, and the number of attempts
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Executable validation fails
+/// - Process spawn fails
+/// - Output reading fails
+/// - Process wait fails
+pub fn execute_inline(
+    skill: &Skill,
+    context: &SkillContext,
+    translations: &Translations,
+) -> Result<SkillOutput> {
+    execute_inline_killable(skill, context, translations, &KillHandle::default())
+}
+
+/// Execute a skill in inline mode, stopping early if `kill` is used
+///
+/// Behaves like `execute_inline`, but each attempt's child process is parked
+/// in `kill` while it runs so another thread can kill it. A killed run is not
+/// retried and skips `post_run`; the output captured until the kill is returned.
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the truncation note
+/// * `kill` - Handle another thread can kill the run through
+///
+/// # Errors
+///
+/// Returns an error in the same cases as `execute_inline`
+pub fn execute_inline_killable(
+    skill: &Skill,
+    context: &SkillContext,
+    translations: &Translations,
+    kill: &KillHandle,
+) -> Result<SkillOutput> {
+    let max_attempts = skill.manifest.retries.min(MAX_RETRIES) + 1;
+    let mut backoff_left = MAX_TOTAL_BACKOFF;
+    let mut output = run_inline_attempt(skill, context, translations, kill)?;
+    while output.exit_code != Some(0) && output.attempts < max_attempts && !kill.is_killed() {
+        let attempt = output.attempts;
+        tracing::info!(
+            "Skill '{}' failed (attempt {} of {}), retrying",
+            skill.manifest.id,
+            attempt,
+            max_attempts
+        );
+        let delay = (RETRY_BACKOFF * attempt).min(backoff_left);
+        backoff_left -= delay;
+        thread::sleep(delay);
+        output = run_inline_attempt(skill, context, translations, kill)?;
+        output.attempts = attempt + 1;
+    }
+    if kill.is_killed() {
+        return Ok(output);
+    }
+
+    // Append post_run output; its failure is reported without touching the exit code
+    if let Some(result) = run_post_run(skill, context, true) {
+        match result {
+            Ok(post_run) => append_post_run_output(&mut output, post_run, translations),
+            Err(e) => output
+                .stderr
+                .push_str(&format!("\n[{} {:#}]", translations.post_run_failed, e)),
+        }
+    }
+
+    Ok(output)
+}
+
+/// Delay before the second attempt of a failing inline skill; each further
+/// retry waits one more step
+const RETRY_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Longest total wait between the attempts of one inline run
+const MAX_TOTAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// How often a parked child process is checked for exit
+const KILL_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Kill switch for an inline skill running on another thread
+///
+/// Each attempt's child process is parked here while its output is read, so
+/// `kill` can stop it from the event loop. Clones share the same switch.
+#[derive(Debug, Clone, Default)]
+pub struct KillHandle {
+    inner: Arc<Mutex<KillState>>,
+}
+
+/// The parked child process and whether the run was killed
+#[derive(Debug, Default)]
+struct KillState {
+    child: Option<Child>,
+    killed: bool,
+}
+
+impl KillHandle {
+    /// Kill the running child process, if any, and stop further attempts
+    pub fn kill(&self) {
+        let mut state = self.lock();
+        state.killed = true;
+        if let Some(child) = state.child.as_mut() {
+            // The child may already have exited on its own
+            let _ = child.kill();
+        }
+    }
+
+    /// Check whether `kill` has been called
+    pub fn is_killed(&self) -> bool {
+        self.lock().killed
+    }
+
+    fn lock(&self) -> MutexGuard<'_, KillState> {
+        // The state stays consistent even if a holder panicked
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Park a freshly spawned child, killing it at once if the run was killed
+    fn register(&self, mut child: Child) {
+        let mut state = self.lock();
+        if state.killed {
+            let _ = child.kill();
+        }
+        state.child = Some(child);
+    }
+
+    /// Wait for the parked child to exit, releasing the lock between checks
+    /// so `kill` is never blocked
+    fn wait(&self) -> io::Result<ExitStatus> {
+        loop {
+            {
+                let mut state = self.lock();
+                let child = state
+                    .child
+                    .as_mut()
+                    .ok_or_else(|| io::Error::other("No child to wait for"))?;
+                if let Some(status) = child.try_wait()? {
+                    state.child = None;
+                    return Ok(status);
+                }
+            }
+            thread::sleep(KILL_POLL_INTERVAL);
+        }
+    }
+}
+
+/// An inline skill running on a background thread
+///
+/// Created by `spawn_inline`. The launcher keeps drawing and reading keys
+/// while the skill runs, polls `try_output` for the result, and can `kill`
+/// the skill (e.g. when quitting mid-run).
+#[derive(Debug)]
+pub struct InlineRun {
+    skill_id: String,
+    skill_name: String,
+    kill: KillHandle,
+    receiver: mpsc::Receiver<Result<SkillOutput>>,
+}
+
+impl InlineRun {
+    /// ID of the running skill
+    pub fn skill_id(&self) -> &str {
+        &self.skill_id
+    }
+
+    /// Display name of the running skill
+    pub fn skill_name(&self) -> &str {
+        &self.skill_name
+    }
+
+    /// Kill the skill's process; the run then finishes without retries or `post_run`
+    pub fn kill(&self) {
+        self.kill.kill();
+    }
+
+    /// Take the run's result if it has finished
+    ///
+    /// # Returns
+    ///
+    /// `None` while the skill is still running, otherwise the result of
+    /// `execute_inline`
+    pub fn try_output(&self) -> Option<Result<SkillOutput>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => Some(Err(anyhow::anyhow!(
+                "Skill '{}' stopped without a result",
+                self.skill_id
+            ))),
+        }
+    }
+}
+
+/// Run an inline skill on a background thread
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the truncation note
+///
+/// # Returns
+///
+/// An `InlineRun` that yields the output of `execute_inline` once the skill finishes
+///
+/// # Errors
+///
+/// Returns an error if the thread cannot be spawned
+pub fn spawn_inline(
+    skill: Skill,
+    context: SkillContext,
+    translations: Translations,
+) -> Result<InlineRun> {
+    let (sender, receiver) = mpsc::channel();
+    let kill = KillHandle::default();
+    let run = InlineRun {
+        skill_id: skill.manifest.id.clone(),
+        skill_name: skill.display_name().to_string(),
+        kill: kill.clone(),
+        receiver,
+    };
+    thread::Builder::new()
+        .name(format!("inline-{}", skill.manifest.id))
+        .spawn(move || {
+            // The receiver is gone if the launcher already exited
+            let _ = sender.send(execute_inline_killable(
+                &skill,
+                &context,
+                &translations,
+                &kill,
+            ));
+        })
+        .with_context(|| format!("Failed to start inline skill '{}'", run.skill_name))?;
+    Ok(run)
+}
+
+/// Run an inline skill once, capturing its output
+///
+/// # Arguments
+///
+/// * `skill` - The skill to execute
+/// * `context` - Context information to pass via environment variables
+/// * `translations` - Translations for the truncation note
+/// * `kill` - Handle the child process is parked in while it runs
+///
+/// # Returns
+///
+/// The captured output of this single run, with `attempts` set to 1
+///
+/// # Errors
+///
+/// Returns an error if the executable is missing or the process cannot be
+/// spawned, read from, or waited on
+fn run_inline_attempt(
+    skill: &Skill,
+    context: &SkillContext,
+    translations: &Translations,
+    kill: &KillHandle,
+) -> Result<SkillOutput> {
+    // Record start time for execution duration
+    let start_time = Instant::now();
+
+    // Validate executable exists
+    let exec = skill.manifest.resolved_exec();
+    validate_executable(exec, skill.manifest_path.parent())
+        .with_context(|| format!("Failed to validate executable '{}'", exec))?;
+
+    let mut cmd = resolve_command(skill, context).to_command();
+    let (stdout_result, stderr_result) = if skill.manifest.pty {
+        // Under a pty both streams share the terminal and land in stdout
+        let (child, reader) = pty::spawn_in_pty(cmd)
+            .with_context(|| format!("Failed to spawn inline skill '{}'", skill.manifest.name))?;
+        kill.register(child);
+        let stdout_result = read_output_stream(reader)
+            .context("Failed to read output from skill pseudo-terminal")?;
+        (stdout_result, (String::new(), false))
+    } else {
+        // Build command with resolved environment and piped output
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        // Spawn the child process
+        let mut child = cmd
+            .spawn()
+            .with_context(|| format!("Failed to spawn inline skill '{}'", skill.manifest.name))?;
+
+        // Capture stdout
+        let stdout_handle = child
+            .stdout
+            .take()
+            .context("Failed to capture stdout handle")?;
+        let stderr_handle = child
+            .stderr
+            .take()
+            .context("Failed to capture stderr handle")?;
+        kill.register(child);
+
+        // Capture stdout, then stderr
+        let stdout_result = read_output_stream(stdout_handle)
+            .context("Failed to read stdout from skill process")?;
+        let stderr_result = read_output_stream(stderr_handle)
+            .context("Failed to read stderr from skill process")?;
+        (stdout_result, stderr_result)
+    };
+
+    // Wait for process to complete
+    let status = kill.wait().with_context(|| {
+        format!(
+            "Failed to wait for skill '{}' completion",
+            skill.manifest.name
+        )
+    })?;
+
     // Calculate execution time
     let execution_time = start_time.elapsed();
 
@@ -207,12 +1076,15 @@ pub fn execute_inline(skill: &Skill, context: &SkillContext) -> Result<SkillOutp
     let mut stderr = stderr_result.0;
 
     if truncated {
-        let warning = "\n[Output truncated - exceeded 10MB limit]";
+        let warning = format!(
+            "\n{}",
+            translations.output_truncated_message(MAX_OUTPUT_SIZE)
+        );
         if stdout_result.1 {
-            stdout.push_str(warning);
+            stdout.push_str(&warning);
         }
         if stderr_result.1 {
-            stderr.push_str(warning);
+            stderr.push_str(&warning);
         }
     }
 
@@ -222,9 +1094,156 @@ pub fn execute_inline(skill: &Skill, context: &SkillContext) -> Result<SkillOutp
         exit_code: status.code(),
         truncated,
         execution_time,
+        format: skill.manifest.output_format,
+        attempts: 1,
     })
 }
 
+/// Output captured from a `post_run` command
+///
+/// Each stream is read with the same size limit as the skill's own output.
+#[derive(Debug)]
+struct PostRunOutput {
+    /// Captured stdout and whether it was truncated
+    stdout: (String, bool),
+    /// Captured stderr and whether it was truncated
+    stderr: (String, bool),
+}
+
+/// Run the manifest's `post_run` command after the primary process exits
+///
+/// The command string is split into words like a shell command line (see
+/// `split_shell_words`) and runs with the same resolved environment as the
+/// skill. A relative program path is resolved against the manifest directory
+/// like `exec` (see `resolve_exec_path`). Stdin is closed; stdout/stderr are
+/// captured when `capture` is set and discarded otherwise.
+///
+/// # Arguments
+///
+/// * `skill` - The skill whose `post_run` command to run
+/// * `context` - Context information to pass via environment variables
+/// * `capture` - Whether to capture the command's output
+///
+/// # Returns
+///
+/// `None` if the skill has no (non-blank) `post_run`, otherwise the command's
+/// output (empty when not captured). A command that cannot be parsed or
+/// spawned, or exits non-zero, is an error; for a non-zero exit, any captured
+/// output is included in the error message.
+fn run_post_run(
+    skill: &Skill,
+    context: &SkillContext,
+    capture: bool,
+) -> Option<Result<PostRunOutput>> {
+    let post_run = skill.manifest.post_run.as_deref()?;
+    if post_run.trim().is_empty() {
+        return None;
+    }
+    Some(
+        spawn_post_run(post_run, skill, context, capture)
+            .with_context(|| format!("Failed to run post_run command '{}'", post_run)),
+    )
+}
+
+/// Spawn a non-blank `post_run` command and wait for it
+///
+/// # Errors
+///
+/// Returns an error if the command cannot be split, spawned, read from, or
+/// waited on, or if it exits non-zero
+fn spawn_post_run(
+    post_run: &str,
+    skill: &Skill,
+    context: &SkillContext,
+    capture: bool,
+) -> Result<PostRunOutput> {
+    let words = split_shell_words(post_run)?;
+    let (program, args) = words.split_first().context("post_run command is empty")?;
+
+    // A relative path like `./cleanup.sh` ships next to the manifest, as for `exec`
+    let mut cmd = Command::new(resolve_exec_path(program, skill.manifest_path.parent()));
+    cmd.args(args);
+    cmd.envs(&resolve_command(skill, context).env);
+    cmd.stdin(Stdio::null());
+
+    if !capture {
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+        let status = cmd.status()?;
+        if !status.success() {
+            bail!("post_run command exited with {}", status);
+        }
+        return Ok(PostRunOutput {
+            stdout: (String::new(), false),
+            stderr: (String::new(), false),
+        });
+    }
+
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    let mut child = cmd.spawn()?;
+    let stdout_handle = child
+        .stdout
+        .take()
+        .context("Failed to capture stdout handle")?;
+    let stdout = read_output_stream(stdout_handle)?;
+    let stderr_handle = child
+        .stderr
+        .take()
+        .context("Failed to capture stderr handle")?;
+    let stderr = read_output_stream(stderr_handle)?;
+    let status = child.wait()?;
+
+    if status.success() {
+        return Ok(PostRunOutput { stdout, stderr });
+    }
+    let captured = format!("{}{}", stdout.0, stderr.0);
+    if captured.trim().is_empty() {
+        bail!("post_run command exited with {}", status)
+    }
+    bail!(
+        "post_run command exited with {}:\n{}",
+        status,
+        captured.trim_end()
+    )
+}
+
+/// Add a `post_run` command's captured output to a run's output
+///
+/// Post-run stdout is appended to stderr for JSON skills, so stdout stays a
+/// single JSON document. A truncated stream gets the truncation note and marks
+/// the run as truncated.
+///
+/// # Arguments
+///
+/// * `output` - The skill run's output
+/// * `post_run` - Output captured from the `post_run` command
+/// * `translations` - Translations for the truncation note
+fn append_post_run_output(
+    output: &mut SkillOutput,
+    post_run: PostRunOutput,
+    translations: &Translations,
+) {
+    let note = format!(
+        "\n{}",
+        translations.output_truncated_message(MAX_OUTPUT_SIZE)
+    );
+    let append = |target: &mut String, (text, truncated): (String, bool)| {
+        target.push_str(&text);
+        if truncated {
+            target.push_str(&note);
+        }
+    };
+
+    output.truncated |= post_run.stdout.1 || post_run.stderr.1;
+    if output.format == OutputFormat::Json {
+        append(&mut output.stderr, post_run.stdout);
+    } else {
+        append(&mut output.stdout, post_run.stdout);
+    }
+    append(&mut output.stderr, post_run.stderr);
+}
+
 /// Read output from a process stream with size limit enforcement
 ///
 /// Reads from the provided stream into an OutputBuffer, enforcing the 10MB size limit.
@@ -255,17 +1274,101 @@ fn read_output_stream<R: Read>(stream: R) -> Result<(String, bool)> {
             break; // EOF
         }
 
-        buffer.append(&chunk[..bytes_read]);
+        // Stop reading once the buffer can no longer take the whole chunk
+        let accepted = buffer.append(&chunk[..bytes_read]);
+        if accepted < bytes_read {
+            break;
+        }
+    }
+
+    Ok((buffer.to_string(), buffer.is_truncated()))
+}
 
-        // Stop reading if we've hit the limit
-        if buffer.is_truncated() {
+/// Copy a process stream to `sink` while capturing it with the size limit
+///
+/// Every chunk is forwarded to `sink` as soon as it is read, even after the
+/// capture buffer is full, so the terminal always shows the complete output.
+///
+/// # Arguments
+///
+/// * `stream` - The output stream to read from (stdout or stderr handle)
+/// * `sink` - Where each chunk is echoed (the launcher's stdout or stderr)
+///
+/// # Returns
+///
+/// Tuple of (captured_output, was_truncated)
+///
+/// # Errors
+///
+/// Returns an error if reading from the stream fails
+fn tee_output_stream<R: Read, W: Write>(stream: R, mut sink: W) -> Result<(String, bool)> {
+    let mut buffer = OutputBuffer::new();
+    let mut reader = BufReader::new(stream);
+    let mut chunk = vec![0u8; 8192];
+
+    loop {
+        let bytes_read = reader
+            .read(&mut chunk)
+            .context("Failed to read from output stream")?;
+        if bytes_read == 0 {
             break;
         }
+
+        // The terminal copy is best effort; capture continues if it fails
+        let _ = sink.write_all(&chunk[..bytes_read]);
+        let _ = sink.flush();
+        buffer.append(&chunk[..bytes_read]);
     }
 
     Ok((buffer.to_string(), buffer.is_truncated()))
 }
 
+/// Resolve a relative executable path against the skill's manifest directory
+///
+/// An `exec` containing a path separator but not absolute (e.g. `./run.sh` or
+/// `bin/tool`) refers to a file shipped next to the manifest, so it is joined
+/// to `manifest_dir` rather than left relative to the launcher's working
+/// directory. Bare command names are left for the PATH lookup, and absolute
+/// paths are returned unchanged.
+///
+/// # Arguments
+///
+/// * `exec` - Executable name or path from the manifest
+/// * `manifest_dir` - Directory containing the manifest, if known
+///
+/// # Returns
+///
+/// The path to spawn, or `exec` unchanged when it needs no resolution
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use pane::skills::runner::resolve_exec_path;
+///
+/// let dir = Path::new("/skills/deploy");
+/// assert_eq!(
+///     resolve_exec_path("./run.sh", Some(dir)),
+///     Path::new("/skills/deploy/run.sh").to_string_lossy()
+/// );
+/// assert_eq!(resolve_exec_path("git", Some(dir)), "git");
+/// ```
+pub fn resolve_exec_path(exec: &str, manifest_dir: Option<&Path>) -> String {
+    let path = Path::new(exec);
+    let is_path = exec.contains('/') || exec.contains('\\');
+    match manifest_dir {
+        Some(dir) if is_path && path.is_relative() && !dir.as_os_str().is_empty() => {
+            // Drop `.` components so `./run.sh` becomes `<dir>/run.sh`
+            let relative: PathBuf = path
+                .components()
+                .filter(|component| !matches!(component, Component::CurDir))
+                .collect();
+            dir.join(relative).to_string_lossy().into_owned()
+        }
+        _ => exec.to_string(),
+    }
+}
+
 /// Validate that an executable exists in PATH or as an absolute/relative path
 ///
 /// Checks if the executable can be found before attempting to spawn a process,
@@ -274,6 +1377,8 @@ fn read_output_stream<R: Read>(stream: R) -> Result<(String, bool)> {
 /// # Arguments
 ///
 /// * `exec` - Executable name or path to validate
+/// * `manifest_dir` - Directory relative paths are resolved against (see
+///   [`resolve_exec_path`]); `None` leaves them relative to the working directory
 ///
 /// # Returns
 ///
@@ -285,43 +1390,128 @@ fn read_output_stream<R: Read>(stream: R) -> Result<(String, bool)> {
 /// - Executable is not found in PATH
 /// - Absolute/relative path does not exist
 /// - File exists but is not executable
-fn validate_executable(exec: &str) -> Result<()> {
+pub(crate) fn validate_executable(exec: &str, manifest_dir: Option<&Path>) -> Result<()> {
     // Check if it's an absolute or relative path
     if exec.contains('/') || exec.contains('\\') {
         // It's a path - check if it exists
-        let path = std::path::Path::new(exec);
+        let resolved = resolve_exec_path(exec, manifest_dir);
+        let path = Path::new(&resolved);
         if !path.exists() {
-            bail!("Executable not found at path: {}", exec);
+            bail!("Executable not found at path: {}", resolved);
         }
         // Check if it's a file (not a directory)
         if !path.is_file() {
-            bail!("Path is not a file: {}", exec);
+            bail!("Path is not a file: {}", resolved);
         }
         return Ok(());
     }
 
-    // It's a command name - check if it's in PATH
-    let path_var = std::env::var("PATH").unwrap_or_default();
-    let paths = std::env::split_paths(&path_var);
+    // It's a command name - check if it's in PATH
+    let path_var = std::env::var("PATH").unwrap_or_default();
+    let paths = std::env::split_paths(&path_var);
+
+    for dir in paths {
+        let full_path = dir.join(exec);
+        if full_path.exists() && full_path.is_file() {
+            return Ok(());
+        }
+    }
+
+    bail!("Executable '{}' not found in PATH or invalid path", exec);
+}
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set
+#[cfg(windows)]
+const FALLBACK_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const FALLBACK_EDITOR: &str = "vi";
+
+/// Resolve the editor command from the environment
+///
+/// Prefers `$VISUAL`, then `$EDITOR`, then a platform fallback (`vi`, or
+/// `notepad` on Windows).
+///
+/// # Returns
+///
+/// The editor command line, which may include arguments (e.g. `code -w`)
+pub fn editor_command() -> String {
+    select_editor(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok())
+}
+
+/// Pick the editor from `$VISUAL`/`$EDITOR` values, ignoring blank ones
+fn select_editor(visual: Option<String>, editor: Option<String>) -> String {
+    visual
+        .into_iter()
+        .chain(editor)
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| FALLBACK_EDITOR.to_string())
+}
+
+/// Open a file in the user's editor, suspending the launcher TUI meanwhile
+///
+/// The editor command from [`editor_command`] is split into words with
+/// [`split_shell_words`], so values like `code -w` and quoted paths with
+/// spaces work; the file path is appended as the last argument.
+///
+/// # Arguments
+///
+/// * `path` - The file to edit
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The editor command has an unterminated quote
+/// - Terminal suspend/restore fails
+/// - The editor cannot be spawned
+/// - The editor exits with a non-zero status
+pub fn open_in_editor(path: &Path) -> Result<()> {
+    let editor = editor_command();
+    let (program, args) = editor_argv(&editor)?;
+
+    let handoff = TerminalHandoff::AlternateScreen;
+    suspend_tui(handoff).context("Failed to suspend TUI")?;
+    let _guard = TerminalRestoreGuard(handoff);
+
+    let status = Command::new(&program)
+        .args(args)
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+
+    restore_tui(handoff).context("Failed to restore TUI")?;
 
-    for dir in paths {
-        let full_path = dir.join(exec);
-        if full_path.exists() && full_path.is_file() {
-            return Ok(());
-        }
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
     }
+    Ok(())
+}
 
-    bail!("Executable '{}' not found in PATH or invalid path", exec);
+/// Split an editor command line into the program and its arguments
+///
+/// Falls back to `FALLBACK_EDITOR` when the command has no words.
+fn editor_argv(editor: &str) -> Result<(String, Vec<String>)> {
+    let mut words = split_shell_words(editor)
+        .with_context(|| format!("Invalid editor command '{}'", editor))?
+        .into_iter();
+    let program = words.next().unwrap_or_else(|| FALLBACK_EDITOR.to_string());
+    Ok((program, words.collect()))
 }
 
 /// Suspend the TUI to hand terminal control to the skill
 ///
-/// Disables raw mode, clears the screen, and shows the cursor.
+/// Disables raw mode and shows the cursor. For `AlternateScreen` the launcher's
+/// screen is cleared for the skill; for `PrimaryScreen` the alternate screen is
+/// left so the skill writes to the normal terminal.
 /// Must be called before spawning a skill process.
 ///
 /// In test environments where raw mode is not enabled, silently continues
 /// without error.
 ///
+/// # Arguments
+///
+/// * `handoff` - Which screen the skill should run on
+///
 /// # Returns
 ///
 /// Ok(()) on success
@@ -329,28 +1519,40 @@ fn validate_executable(exec: &str) -> Result<()> {
 /// # Errors
 ///
 /// Returns an error if terminal operations fail (except in test environments)
-fn suspend_tui() -> Result<()> {
+fn suspend_tui(handoff: TerminalHandoff) -> Result<()> {
     // Attempt to disable raw mode - ignore error if not in raw mode (test environment)
     let _ = terminal::disable_raw_mode();
 
     let mut stdout = io::stdout();
-    crossterm::execute!(
-        stdout,
-        terminal::Clear(terminal::ClearType::All),
-        crossterm::cursor::Show
-    )
-    .context("Failed to clear terminal and show cursor")?;
+    match handoff {
+        TerminalHandoff::AlternateScreen => crossterm::execute!(
+            stdout,
+            terminal::Clear(terminal::ClearType::All),
+            crossterm::cursor::Show
+        )
+        .context("Failed to clear terminal and show cursor")?,
+        TerminalHandoff::PrimaryScreen => crossterm::execute!(
+            stdout,
+            terminal::LeaveAlternateScreen,
+            crossterm::cursor::Show
+        )
+        .context("Failed to leave alternate screen and show cursor")?,
+    }
 
     Ok(())
 }
 
 /// Restore the TUI after skill execution completes
 ///
-/// Re-enables raw mode, hides the cursor, and clears the screen
-/// to prepare for TUI re-rendering.
+/// Re-enables raw mode (re-entering the alternate screen for `PrimaryScreen`),
+/// hides the cursor, and clears the screen to prepare for TUI re-rendering.
 ///
 /// In test environments, attempts best-effort restoration without failing.
 ///
+/// # Arguments
+///
+/// * `handoff` - The handoff used when the TUI was suspended
+///
 /// # Returns
 ///
 /// Ok(()) on success
@@ -358,11 +1560,15 @@ fn suspend_tui() -> Result<()> {
 /// # Errors
 ///
 /// Returns an error if terminal operations fail (except in test environments)
-fn restore_tui() -> Result<()> {
+fn restore_tui(handoff: TerminalHandoff) -> Result<()> {
     // Attempt to enable raw mode - ignore error in test environment
     let _ = terminal::enable_raw_mode();
 
     let mut stdout = io::stdout();
+    if handoff == TerminalHandoff::PrimaryScreen {
+        crossterm::execute!(stdout, terminal::EnterAlternateScreen)
+            .context("Failed to re-enter alternate screen")?;
+    }
     crossterm::execute!(
         stdout,
         crossterm::cursor::Hide,
@@ -377,12 +1583,12 @@ fn restore_tui() -> Result<()> {
 ///
 /// Implements the Drop trait to call restore_tui() when the guard
 /// goes out of scope, ensuring terminal cleanup in all scenarios.
-struct TerminalRestoreGuard;
+struct TerminalRestoreGuard(TerminalHandoff);
 
 impl Drop for TerminalRestoreGuard {
     fn drop(&mut self) {
         // Best effort restoration - ignore errors since we might be panicking
-        let _ = restore_tui();
+        let _ = restore_tui(self.0);
     }
 }
 
@@ -390,34 +1596,18 @@ impl Drop for TerminalRestoreGuard {
 mod tests {
     use super::*;
     use crate::config::Config;
-    use crate::skills::{
-        manifest::{ContextConfig, SkillManifest, UiConfig, UiMode},
-        SkillSource,
-    };
+    use crate::skills::manifest::UiMode;
+    use crate::test_support::test_skill;
     use std::fs;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
     fn create_test_skill(id: &str, name: &str, exec: &str, args: Vec<String>) -> Skill {
-        Skill {
-            manifest: SkillManifest {
-                id: id.to_string(),
-                name: name.to_string(),
-                description: "Test skill".to_string(),
-                version: "1.0.0".to_string(),
-                exec: exec.to_string(),
-                args,
-                tags: vec![],
-                estimated_time: None,
-                ui: UiConfig {
-                    mode: UiMode::Tui,
-                    fullscreen: true,
-                },
-                context: ContextConfig::default(),
-            },
-            source: SkillSource::Project,
-            manifest_path: PathBuf::from("test.yaml"),
-        }
+        let mut skill = test_skill(id);
+        skill.manifest.name = name.to_string();
+        skill.manifest.exec = exec.to_string();
+        skill.manifest.args = args;
+        skill
     }
 
     /// RAII guard to ensure current directory is restored even if test panics
@@ -460,7 +1650,7 @@ mod tests {
         let exec = "ls"; // Available on Unix systems
 
         // Act
-        let result = validate_executable(exec);
+        let result = validate_executable(exec, None);
 
         // Assert
         assert!(result.is_ok());
@@ -472,7 +1662,7 @@ mod tests {
         let exec = "nonexistent-command-12345";
 
         // Act
-        let result = validate_executable(exec);
+        let result = validate_executable(exec, None);
 
         // Assert
         assert!(result.is_err());
@@ -488,7 +1678,7 @@ mod tests {
         fs::write(&script_path, "#!/bin/bash\necho test").unwrap();
 
         // Act
-        let result = validate_executable(&script_path.to_string_lossy());
+        let result = validate_executable(&script_path.to_string_lossy(), None);
 
         // Assert
         assert!(result.is_ok());
@@ -500,7 +1690,7 @@ mod tests {
         let nonexistent_path = "/tmp/nonexistent-script-12345.sh";
 
         // Act
-        let result = validate_executable(nonexistent_path);
+        let result = validate_executable(nonexistent_path, None);
 
         // Assert
         assert!(result.is_err());
@@ -515,7 +1705,7 @@ mod tests {
         let dir_path = temp_dir.path();
 
         // Act
-        let result = validate_executable(&dir_path.to_string_lossy());
+        let result = validate_executable(&dir_path.to_string_lossy(), None);
 
         // Assert
         assert!(result.is_err());
@@ -523,6 +1713,88 @@ mod tests {
         assert!(error_msg.contains("not a file"));
     }
 
+    #[test]
+    fn test_resolve_exec_path_joins_relative_path_to_manifest_dir() {
+        // Arrange
+        let dir = Path::new("/skills/deploy");
+
+        // Act & Assert
+        assert_eq!(
+            resolve_exec_path("./run.sh", Some(dir)),
+            dir.join("run.sh").to_string_lossy()
+        );
+        assert_eq!(
+            resolve_exec_path("bin/tool", Some(dir)),
+            dir.join("bin/tool").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_resolve_exec_path_leaves_names_and_absolute_paths_unchanged() {
+        // Arrange
+        let dir = Path::new("/skills/deploy");
+
+        // Act & Assert
+        assert_eq!(resolve_exec_path("git", Some(dir)), "git");
+        assert_eq!(resolve_exec_path("/usr/bin/env", Some(dir)), "/usr/bin/env");
+        assert_eq!(resolve_exec_path("./run.sh", None), "./run.sh");
+        assert_eq!(
+            resolve_exec_path("./run.sh", Some(Path::new(""))),
+            "./run.sh"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_validate_executable_resolves_relative_path_from_unrelated_cwd() {
+        // Arrange
+        let _dir_guard = DirGuard::new().unwrap();
+        let skill_dir = TempDir::new().unwrap();
+        let other_dir = TempDir::new().unwrap();
+        fs::write(skill_dir.path().join("run.sh"), "#!/bin/sh\necho ok").unwrap();
+        std::env::set_current_dir(other_dir.path()).unwrap();
+
+        // Act
+        let with_dir = validate_executable("./run.sh", Some(skill_dir.path()));
+        let without_dir = validate_executable("./run.sh", None);
+
+        // Assert
+        assert!(with_dir.is_ok());
+        assert!(without_dir.is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_execute_inline_runs_relative_exec_next_to_manifest() {
+        // Arrange
+        let _dir_guard = DirGuard::new().unwrap();
+        let skill_dir = TempDir::new().unwrap();
+        let other_dir = TempDir::new().unwrap();
+        let script_path = skill_dir.path().join("run.sh");
+        fs::write(&script_path, "#!/bin/sh\necho from manifest dir\n").unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&script_path).unwrap().permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&script_path, perms).unwrap();
+        }
+
+        let mut skill = create_test_skill("relative", "Relative", "./run.sh", vec![]);
+        skill.manifest_path = skill_dir.path().join("skill.yaml");
+        std::env::set_current_dir(other_dir.path()).unwrap();
+        let config = Config::default();
+        let context = SkillContext::build(&skill, &config).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert!(output.stdout.contains("from manifest dir"));
+        assert_eq!(output.exit_code, Some(0));
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_execute_skill_spawns_with_correct_args() {
@@ -670,95 +1942,432 @@ echo "Hello from stdout"
         let context = SkillContext::build(&skill, &config).unwrap();
 
         // Act
-        let result = execute_inline(&skill, &context);
+        let result = execute_inline(&skill, &context, &Translations::load(Language::En));
+
+        // Assert
+        assert!(result.is_ok());
+        let output = result.unwrap();
+        assert!(output.stdout.contains("Hello from stdout"));
+        assert_eq!(output.exit_code, Some(0));
+        assert!(!output.truncated);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_execute_inline_captures_stderr() {
+        // Arrange
+        let _dir_guard = DirGuard::new().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("echo-stderr.sh");
+
+        // Create script that outputs to stderr
+        let script_content = r#"#!/bin/bash
+echo "Error message" >&2
+"#;
+        fs::write(&script_path, script_content).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&script_path).unwrap().permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&script_path, perms).unwrap();
+        }
+
+        let skill = create_test_skill(
+            "stderr-test",
+            "Stderr Test",
+            &script_path.to_string_lossy(),
+            vec![],
+        );
+        let config = Config::default();
+        let context = SkillContext::build(&skill, &config).unwrap();
+
+        // Act
+        let result = execute_inline(&skill, &context, &Translations::load(Language::En));
+
+        // Assert
+        assert!(result.is_ok());
+        let output = result.unwrap();
+        assert!(output.stderr.contains("Error message"));
+        assert_eq!(output.exit_code, Some(0));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_execute_inline_captures_exit_code() {
+        // Arrange
+        let _dir_guard = DirGuard::new().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("exit-42.sh");
+
+        // Create script that exits with code 42
+        let script_content = r#"#!/bin/bash
+exit 42
+"#;
+        fs::write(&script_path, script_content).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&script_path).unwrap().permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&script_path, perms).unwrap();
+        }
+
+        let skill = create_test_skill(
+            "exit-test",
+            "Exit Test",
+            &script_path.to_string_lossy(),
+            vec![],
+        );
+        let config = Config::default();
+        let context = SkillContext::build(&skill, &config).unwrap();
+
+        // Act
+        let result = execute_inline(&skill, &context, &Translations::load(Language::En));
+
+        // Assert
+        assert!(result.is_ok());
+        let output = result.unwrap();
+        assert_eq!(output.exit_code, Some(42));
+    }
+
+    /// A skill that reports whether its stdout is a terminal
+    fn create_tty_check_skill() -> Skill {
+        create_test_skill(
+            "tty-check",
+            "TTY Check",
+            "sh",
+            vec![
+                "-c".to_string(),
+                "if [ -t 1 ]; then echo tty; else echo pipe; fi; echo done >&2; exit 3".to_string(),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_execute_inline_uses_pipes_by_default() {
+        // Arrange
+        let skill = create_tty_check_skill();
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.stdout, "pipe\n");
+        assert_eq!(output.stderr, "done\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_execute_inline_pty_mode_reports_a_tty() {
+        // Arrange
+        let mut skill = create_tty_check_skill();
+        skill.manifest.pty = true;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert - both streams share the terminal, without \r\n line endings
+        assert_eq!(output.stdout, "tty\ndone\n");
+        assert_eq!(output.stderr, "");
+        assert_eq!(output.exit_code, Some(3));
+    }
+
+    /// A skill that fails until its `succeed_on`th run, counting runs in a file
+    fn create_flaky_skill(counter: &Path, succeed_on: u32) -> Skill {
+        let script = format!(
+            "n=$(cat \"$1\" 2>/dev/null || echo 0); n=$((n + 1)); echo $n > \"$1\"; \
+             echo \"attempt $n\"; [ \"$n\" -ge {} ]",
+            succeed_on
+        );
+        create_test_skill(
+            "flaky",
+            "Flaky",
+            "sh",
+            vec![
+                "-c".to_string(),
+                script,
+                "sh".to_string(),
+                counter.to_string_lossy().to_string(),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_execute_inline_retries_until_success() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let counter = temp_dir.path().join("count");
+        let mut skill = create_flaky_skill(&counter, 3);
+        skill.manifest.retries = 3;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert - the last attempt's output is returned
+        assert_eq!(output.exit_code, Some(0));
+        assert_eq!(output.attempts, 3);
+        assert_eq!(output.stdout.trim(), "attempt 3");
+        assert_eq!(fs::read_to_string(&counter).unwrap().trim(), "3");
+    }
+
+    #[test]
+    fn test_execute_inline_gives_up_after_retries_run_out() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let counter = temp_dir.path().join("count");
+        let mut skill = create_flaky_skill(&counter, 5);
+        skill.manifest.retries = 1;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.exit_code, Some(1));
+        assert_eq!(output.attempts, 2);
+        assert_eq!(output.stdout.trim(), "attempt 2");
+    }
+
+    #[test]
+    fn test_execute_inline_caps_retries_at_maximum() {
+        // Arrange - built in code, so validation never saw the retry count
+        let temp_dir = TempDir::new().unwrap();
+        let counter = temp_dir.path().join("count");
+        let mut skill = create_flaky_skill(&counter, 100);
+        skill.manifest.retries = 50;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let started = Instant::now();
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.attempts, MAX_RETRIES + 1);
+        assert!(started.elapsed() < MAX_TOTAL_BACKOFF + Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_spawn_inline_kill_stops_running_skill_without_retrying() {
+        // Arrange
+        let mut skill = create_test_skill("slow", "Slow", "sleep", vec!["5".to_string()]);
+        skill.manifest.retries = 3;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+        let started = Instant::now();
+        let run = spawn_inline(skill, context, Translations::load(Language::En)).unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        // Act
+        run.kill();
+        let output = loop {
+            if let Some(result) = run.try_output() {
+                break result.unwrap();
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        // Assert - killed by a signal, so there is no exit code
+        assert_eq!(output.exit_code, None);
+        assert_eq!(output.attempts, 1);
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_spawn_inline_yields_output_of_finished_skill() {
+        // Arrange
+        let skill = create_test_skill("greet", "Greet", "echo", vec!["hi".to_string()]);
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+        let run = spawn_inline(skill, context, Translations::load(Language::En)).unwrap();
+
+        // Act
+        let output = loop {
+            if let Some(result) = run.try_output() {
+                break result.unwrap();
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        // Assert
+        assert_eq!(run.skill_id(), "greet");
+        assert_eq!(run.skill_name(), "Greet");
+        assert_eq!(output.stdout, "hi\n");
+        assert_eq!(output.exit_code, Some(0));
+    }
+
+    #[test]
+    fn test_execute_inline_without_retries_runs_once() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let counter = temp_dir.path().join("count");
+        let skill = create_flaky_skill(&counter, 2);
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.exit_code, Some(1));
+        assert_eq!(output.attempts, 1);
+        assert_eq!(fs::read_to_string(&counter).unwrap().trim(), "1");
+    }
+
+    #[test]
+    fn test_execute_inline_runs_post_run_after_success() {
+        // Arrange
+        let mut skill = create_test_skill("post-ok", "Post OK", "true", vec![]);
+        skill.manifest.post_run = Some("echo cleanup".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.stdout.trim(), "cleanup");
+        assert_eq!(output.exit_code, Some(0));
+    }
+
+    #[test]
+    fn test_execute_inline_runs_post_run_after_failure_and_keeps_exit_code() {
+        // Arrange
+        let mut skill = create_test_skill("post-fail", "Post Fail", "false", vec![]);
+        skill.manifest.post_run = Some("echo cleanup".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
+
+        // Assert
+        assert_eq!(output.stdout.trim(), "cleanup");
+        assert_eq!(output.exit_code, Some(1));
+    }
+
+    #[test]
+    fn test_execute_inline_reports_post_run_failure_without_masking_exit_code() {
+        // Arrange
+        let mut skill = create_test_skill("post-error", "Post Error", "true", vec![]);
+        skill.manifest.post_run = Some("false".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
 
         // Assert
-        assert!(result.is_ok());
-        let output = result.unwrap();
-        assert!(output.stdout.contains("Hello from stdout"));
+        assert!(output.stderr.contains("post_run failed"));
         assert_eq!(output.exit_code, Some(0));
-        assert!(!output.truncated);
     }
 
     #[test]
-    #[serial_test::serial]
-    fn test_execute_inline_captures_stderr() {
+    fn test_run_post_run_passes_skill_environment() {
         // Arrange
-        let _dir_guard = DirGuard::new().unwrap();
-        let temp_dir = TempDir::new().unwrap();
-        let script_path = temp_dir.path().join("echo-stderr.sh");
+        let mut skill = create_test_skill("post-env", "Post Env", "true", vec![]);
+        skill.manifest.post_run = Some("printenv PANE_ID".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
 
-        // Create script that outputs to stderr
-        let script_content = r#"#!/bin/bash
-echo "Error message" >&2
-"#;
-        fs::write(&script_path, script_content).unwrap();
+        // Act
+        let output = run_post_run(&skill, &context, true).unwrap().unwrap();
 
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&script_path).unwrap().permissions();
-            perms.set_mode(0o755);
-            fs::set_permissions(&script_path, perms).unwrap();
-        }
+        // Assert
+        assert_eq!(output.stdout.0.trim(), "post-env");
+    }
 
-        let skill = create_test_skill(
-            "stderr-test",
-            "Stderr Test",
-            &script_path.to_string_lossy(),
-            vec![],
+    #[test]
+    fn test_run_post_run_keeps_quoted_arguments_together() {
+        // Arrange
+        let mut skill = create_test_skill("post-quote", "Post Quote", "true", vec![]);
+        skill.manifest.post_run = Some(r#"printf "%s|" "two words" 'and more'"#.to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = run_post_run(&skill, &context, true).unwrap().unwrap();
+
+        // Assert
+        assert_eq!(output.stdout.0, "two words|and more|");
+    }
+
+    #[test]
+    fn test_execute_inline_keeps_post_run_output_out_of_json_stdout() {
+        // Arrange
+        let mut skill = create_test_skill(
+            "post-json",
+            "Post JSON",
+            "echo",
+            vec![r#"{"ok":true}"#.to_string()],
         );
-        let config = Config::default();
-        let context = SkillContext::build(&skill, &config).unwrap();
+        skill.manifest.output_format = OutputFormat::Json;
+        skill.manifest.post_run = Some("echo cleaned up".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
 
         // Act
-        let result = execute_inline(&skill, &context);
+        let output = execute_inline(&skill, &context, &Translations::load(Language::En)).unwrap();
 
         // Assert
-        assert!(result.is_ok());
-        let output = result.unwrap();
-        assert!(output.stderr.contains("Error message"));
-        assert_eq!(output.exit_code, Some(0));
+        assert_eq!(output.stdout.trim(), r#"{"ok":true}"#);
+        assert_eq!(output.stderr.trim(), "cleaned up");
     }
 
     #[test]
-    #[serial_test::serial]
-    fn test_execute_inline_captures_exit_code() {
+    fn test_append_post_run_output_reports_truncation() {
         // Arrange
-        let _dir_guard = DirGuard::new().unwrap();
+        let translations = Translations::load(Language::En);
+        let mut output = SkillOutput::error("");
+        output.format = OutputFormat::Text;
+        let post_run = PostRunOutput {
+            stdout: ("partial".to_string(), true),
+            stderr: (String::new(), false),
+        };
+
+        // Act
+        append_post_run_output(&mut output, post_run, &translations);
+
+        // Assert
+        assert!(output.truncated);
+        assert!(output.stdout.starts_with("partial\n"));
+        assert!(output
+            .stdout
+            .ends_with(&translations.output_truncated_message(MAX_OUTPUT_SIZE)));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_post_run_resolves_relative_path_against_manifest_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange - the launcher's working directory is not the skill directory
         let temp_dir = TempDir::new().unwrap();
-        let script_path = temp_dir.path().join("exit-42.sh");
+        let script = temp_dir.path().join("cleanup.sh");
+        fs::write(&script, "#!/bin/sh\necho cleaned\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let mut skill = create_test_skill("post-path", "Post Path", "true", vec![]);
+        skill.manifest_path = temp_dir.path().join("pane-skill.yaml");
+        skill.manifest.post_run = Some("./cleanup.sh".to_string());
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
 
-        // Create script that exits with code 42
-        let script_content = r#"#!/bin/bash
-exit 42
-"#;
-        fs::write(&script_path, script_content).unwrap();
+        // Act
+        let output = run_post_run(&skill, &context, true).unwrap().unwrap();
 
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&script_path).unwrap().permissions();
-            perms.set_mode(0o755);
-            fs::set_permissions(&script_path, perms).unwrap();
-        }
+        // Assert
+        assert_eq!(output.stdout.0.trim(), "cleaned");
+    }
 
-        let skill = create_test_skill(
-            "exit-test",
-            "Exit Test",
-            &script_path.to_string_lossy(),
-            vec![],
-        );
-        let config = Config::default();
-        let context = SkillContext::build(&skill, &config).unwrap();
+    #[test]
+    fn test_run_post_run_none_without_command() {
+        // Arrange
+        let mut skill = create_test_skill("no-post", "No Post", "true", vec![]);
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
 
         // Act
-        let result = execute_inline(&skill, &context);
+        let unset = run_post_run(&skill, &context, true).is_none();
+        skill.manifest.post_run = Some("   ".to_string());
+        let blank = run_post_run(&skill, &context, true).is_none();
 
         // Assert
-        assert!(result.is_ok());
-        let output = result.unwrap();
-        assert_eq!(output.exit_code, Some(42));
+        assert!(unset);
+        assert!(blank);
     }
 
     #[test]
@@ -795,7 +2404,7 @@ echo "Normal output"
         let context = SkillContext::build(&skill, &config).unwrap();
 
         // Act
-        let result = execute_inline(&skill, &context);
+        let result = execute_inline(&skill, &context, &Translations::load(Language::En));
 
         // Assert
         assert!(result.is_ok());
@@ -804,6 +2413,30 @@ echo "Normal output"
         assert!(!output.truncated);
     }
 
+    #[test]
+    fn test_execute_inline_truncation_note_uses_configured_language() {
+        // Arrange - write just past the 10MB limit
+        let skill = create_test_skill(
+            "big-output",
+            "Big Output",
+            "sh",
+            vec![
+                "-c".to_string(),
+                format!("head -c {} /dev/zero", MAX_OUTPUT_SIZE + 1024),
+            ],
+        );
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_inline(&skill, &context, &Translations::load(Language::Ko)).unwrap();
+
+        // Assert
+        assert!(output.truncated);
+        assert!(output
+            .stdout
+            .ends_with("\n[출력이 잘렸습니다 - 10MB 제한 초과]"));
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_execute_inline_handles_process_error() {
@@ -819,7 +2452,7 @@ echo "Normal output"
         let context = SkillContext::build(&skill, &config).unwrap();
 
         // Act
-        let result = execute_inline(&skill, &context);
+        let result = execute_inline(&skill, &context, &Translations::load(Language::En));
 
         // Assert
         assert!(result.is_err());
@@ -957,4 +2590,359 @@ exit 42
 
         // Directory will be restored automatically by DirGuard drop
     }
+
+    fn create_test_context() -> SkillContext {
+        SkillContext {
+            skill_id: "dry-skill".to_string(),
+            skill_name: "Dry Skill".to_string(),
+            cwd: PathBuf::from("/work/project"),
+            git_root: Some(PathBuf::from("/work/project")),
+            project_name: Some("project".to_string()),
+            config_path: PathBuf::from("/home/user/.config/pane/config.toml"),
+            args: vec![],
+        }
+    }
+
+    #[test]
+    fn test_resolve_command_uses_platform_exec_override() {
+        // Arrange
+        let mut skill = create_test_skill("dry-skill", "Dry Skill", "echo", vec![]);
+        let platform_exec = Some("printf".to_string());
+        skill.manifest.exec_windows = platform_exec.clone();
+        skill.manifest.exec_macos = platform_exec.clone();
+        skill.manifest.exec_linux = platform_exec;
+        let context = create_test_context();
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert - the base exec only applies on platforms without an override
+        let expected = match std::env::consts::OS {
+            "windows" | "macos" | "linux" => "printf",
+            _ => "echo",
+        };
+        assert_eq!(resolved.exec, expected);
+    }
+
+    #[test]
+    fn test_resolve_command_produces_expected_env_and_command() {
+        // Arrange
+        let skill = create_test_skill(
+            "dry-skill",
+            "Dry Skill",
+            "echo",
+            vec!["hello".to_string(), "big world".to_string()],
+        );
+        let context = SkillContext {
+            args: skill.manifest.args.clone(),
+            ..create_test_context()
+        };
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert
+        assert_eq!(resolved.exec, "echo");
+        assert_eq!(resolved.args, vec!["hello", "big world"]);
+        assert_eq!(resolved.command_line(), "echo hello 'big world'");
+        let expected_env: BTreeMap<String, String> = [
+            ("PANE_ID", "dry-skill"),
+            ("PANE_NAME", "Dry Skill"),
+            ("PANE_CONFIG_PATH", "/home/user/.config/pane/config.toml"),
+            ("PANE_CWD", "/work/project"),
+            ("PANE_GIT_ROOT", "/work/project"),
+            ("PANE_PROJECT_NAME", "project"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        assert_eq!(resolved.env, expected_env);
+    }
+
+    #[test]
+    fn test_resolve_command_respects_context_flags() {
+        // Arrange
+        let mut skill = create_test_skill("dry-skill", "Dry Skill", "ls", vec![]);
+        skill.manifest.context.pass_cwd = false;
+        skill.manifest.context.pass_git_root = false;
+        let context = create_test_context();
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert
+        assert!(!resolved.env.contains_key("PANE_CWD"));
+        assert!(!resolved.env.contains_key("PANE_GIT_ROOT"));
+        assert!(resolved.env.contains_key("PANE_ID"));
+    }
+
+    #[test]
+    fn test_resolved_command_to_json_escapes_values() {
+        // Arrange
+        let resolved = ResolvedCommand {
+            exec: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo \"hi\"".to_string()],
+            env: BTreeMap::from([("PANE_NAME".to_string(), "A\\B".to_string())]),
+        };
+
+        // Act
+        let json = resolved.to_json();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"exec":"sh","args":["-c","echo \"hi\""],"command":"sh -c 'echo \"hi\"'","env":{"PANE_NAME":"A\\B"}}"#
+        );
+    }
+
+    #[test]
+    fn test_resolved_command_display_lists_env_lines() {
+        // Arrange
+        let resolved = ResolvedCommand {
+            exec: "ls".to_string(),
+            args: vec![],
+            env: BTreeMap::from([
+                ("PANE_ID".to_string(), "list".to_string()),
+                ("PANE_NAME".to_string(), "List Files".to_string()),
+            ]),
+        };
+
+        // Act
+        let text = resolved.to_string();
+
+        // Assert
+        assert_eq!(text, "ls\nPANE_ID=list\nPANE_NAME='List Files'\n");
+    }
+
+    #[test]
+    fn test_resolve_command_substitutes_project_name_and_git_root() {
+        // Arrange
+        let skill = create_test_skill(
+            "dry-skill",
+            "Dry Skill",
+            "tool",
+            vec![
+                "--repo".to_string(),
+                "${PANE_PROJECT_NAME}".to_string(),
+                "--root=${PANE_GIT_ROOT}/src".to_string(),
+            ],
+        );
+        let context = SkillContext {
+            args: skill.manifest.args.clone(),
+            ..create_test_context()
+        };
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert
+        assert_eq!(
+            resolved.args,
+            vec!["--repo", "project", "--root=/work/project/src"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_command_missing_git_root_expands_to_empty() {
+        // Arrange
+        let skill = create_test_skill(
+            "dry-skill",
+            "Dry Skill",
+            "tool",
+            vec!["--root".to_string(), "${PANE_GIT_ROOT}".to_string()],
+        );
+        let context = SkillContext {
+            git_root: None,
+            args: skill.manifest.args.clone(),
+            ..create_test_context()
+        };
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert
+        assert_eq!(resolved.args, vec!["--root", ""]);
+    }
+
+    #[test]
+    fn test_split_shell_words_handles_quotes_and_escapes() {
+        // Act
+        let words =
+            split_shell_words(r#"  --name "big \"world\"" 'it''s' a\ b "" --path=~/x "\n"  "#)
+                .unwrap();
+
+        // Assert
+        assert_eq!(
+            words,
+            vec![
+                "--name",
+                "big \"world\"",
+                "its",
+                "a b",
+                "",
+                "--path=~/x",
+                "\\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_shell_words_rejects_unterminated_input() {
+        // Act & Assert
+        assert!(split_shell_words("--msg 'oops").is_err());
+        assert!(split_shell_words("--msg \"oops").is_err());
+        assert!(split_shell_words("trailing \\").is_err());
+        assert_eq!(split_shell_words("   ").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_resolve_command_appends_extra_context_args() {
+        // Arrange
+        let skill = create_test_skill("dry-skill", "Dry Skill", "tool", vec!["--base".to_string()]);
+        let context = SkillContext {
+            args: vec!["--base".to_string(), "--verbose".to_string()],
+            ..create_test_context()
+        };
+
+        // Act
+        let resolved = resolve_command(&skill, &context);
+
+        // Assert
+        assert_eq!(resolved.args, vec!["--base", "--verbose"]);
+    }
+
+    #[test]
+    fn test_substitute_context_vars_keeps_plain_and_unterminated_text() {
+        // Arrange
+        let env = BTreeMap::from([("PANE_ID".to_string(), "tips".to_string())]);
+
+        // Act & Assert
+        assert_eq!(substitute_context_vars("plain", &env), "plain");
+        assert_eq!(
+            substitute_context_vars("${PANE_ID}-${PANE_ID}", &env),
+            "tips-tips"
+        );
+        assert_eq!(substitute_context_vars("cost: $5", &env), "cost: $5");
+        assert_eq!(substitute_context_vars("${PANE_ID", &env), "${PANE_ID");
+    }
+
+    #[test]
+    fn test_terminal_handoff_follows_fullscreen_flag() {
+        // Arrange
+        let mut skill = create_test_skill("tui-skill", "TUI Skill", "ls", vec![]);
+
+        // Act & Assert - fullscreen skills take over the alternate screen
+        assert_eq!(
+            TerminalHandoff::for_skill(&skill),
+            TerminalHandoff::AlternateScreen
+        );
+
+        // Act & Assert - non-fullscreen skills run on the primary screen
+        skill.manifest.ui.fullscreen = false;
+        assert_eq!(
+            TerminalHandoff::for_skill(&skill),
+            TerminalHandoff::PrimaryScreen
+        );
+    }
+
+    #[test]
+    fn test_select_editor_prefers_visual_over_editor() {
+        // Arrange
+        let visual = Some("code -w".to_string());
+        let editor = Some("nano".to_string());
+
+        // Act
+        let selected = select_editor(visual, editor);
+
+        // Assert
+        assert_eq!(selected, "code -w");
+    }
+
+    #[test]
+    fn test_select_editor_uses_editor_when_visual_blank() {
+        // Arrange
+        let visual = Some("  ".to_string());
+        let editor = Some("nano".to_string());
+
+        // Act
+        let selected = select_editor(visual, editor);
+
+        // Assert
+        assert_eq!(selected, "nano");
+    }
+
+    #[test]
+    fn test_select_editor_falls_back_when_unset() {
+        // Act
+        let selected = select_editor(None, None);
+
+        // Assert
+        assert_eq!(selected, FALLBACK_EDITOR);
+    }
+
+    #[test]
+    fn test_editor_argv_keeps_quoted_paths_together() {
+        // Act
+        let (program, args) =
+            editor_argv(r#""/Applications/My Editor.app/bin/edit" --wait"#).unwrap();
+
+        // Assert
+        assert_eq!(program, "/Applications/My Editor.app/bin/edit");
+        assert_eq!(args, vec!["--wait"]);
+        assert!(editor_argv("'unterminated").is_err());
+    }
+
+    #[test]
+    fn test_execute_tui_captured_returns_output() {
+        // Arrange
+        let _dir_guard = DirGuard::new().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("print-and-exit.sh");
+        fs::write(
+            &script_path,
+            "#!/bin/bash\necho \"line one\"\necho \"line two\"\necho \"oops\" >&2\nexit 3\n",
+        )
+        .unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&script_path).unwrap().permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&script_path, perms).unwrap();
+        }
+
+        let mut skill = create_test_skill(
+            "capture-test",
+            "Capture Test",
+            &script_path.to_string_lossy(),
+            vec![],
+        );
+        skill.manifest.ui.capture = true;
+        let context = SkillContext::build(&skill, &Config::default()).unwrap();
+
+        // Act
+        let output = execute_tui_captured(&skill, &context).unwrap();
+
+        // Assert
+        assert_eq!(output.stdout, "line one\nline two\n");
+        assert_eq!(output.stderr, "oops\n");
+        assert_eq!(output.exit_code, Some(3));
+        assert!(!output.truncated);
+    }
+
+    #[test]
+    fn test_tee_output_stream_forwards_and_captures() {
+        // Arrange
+        let input: &[u8] = b"hello\nworld\n";
+        let mut sink = Vec::new();
+
+        // Act
+        let (captured, truncated) = tee_output_stream(input, &mut sink).unwrap();
+
+        // Assert
+        assert_eq!(captured, "hello\nworld\n");
+        assert_eq!(sink, b"hello\nworld\n");
+        assert!(!truncated);
+    }
 }
@@ -1,11 +1,37 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
 
+use anyhow::Result;
+use ratatui::text::Line;
 use serde::{Deserialize, Serialize};
 
 use crate::config::Config;
 use crate::i18n::{Language, Translations};
-use crate::search::filter_skills;
-use crate::skills::Skill;
+use crate::search::filter_skills_with_synonyms;
+use crate::session::Session;
+use crate::skills::runner::InlineRun;
+use crate::skills::{Skill, SkillManifest, SkillSource, UNCATEGORIZED_LABEL};
+use crate::ui::output_panel;
+use crate::ui::text::{line_text, wrapped_row_count};
+
+/// Output panel viewport height assumed until the first frame is rendered
+const DEFAULT_OUTPUT_VIEWPORT_HEIGHT: usize = 20;
+
+/// Output panel viewport width assumed until the first frame is rendered
+/// (no wrapping, so scroll bounds count plain lines)
+const DEFAULT_OUTPUT_VIEWPORT_WIDTH: usize = usize::MAX;
+
+/// Skill list viewport height (in items) assumed until the first frame is rendered
+const DEFAULT_LIST_VIEWPORT_HEIGHT: usize = 10;
+
+/// Maximum number of past search queries remembered for the session
+const MAX_SEARCH_HISTORY: usize = 50;
+
+/// Maximum bytes of stdout/stderr kept per skill for the last-output preview
+const MAX_PREVIEW_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// Maximum number of transitions Esc can step back through
+const MAX_VIEW_STACK_DEPTH: usize = 32;
 
 /// View mode for filtering the skill list
 ///
@@ -19,6 +45,8 @@ pub enum ViewMode {
     Favorites,
     /// Show only recently executed skills
     Recent,
+    /// Show only skills the user has disabled, so they can be re-enabled
+    Disabled,
 }
 
 /// Input mode for the TUI
@@ -49,6 +77,130 @@ pub enum InputMode {
     Insert,
 }
 
+/// Which output streams the output panel shows
+///
+/// Cycled with Tab while the output panel is open.
+///
+/// # Examples
+///
+/// ```
+/// use pane::state::OutputStreamView;
+///
+/// let view = OutputStreamView::default();
+/// assert_eq!(view.next(), OutputStreamView::Stdout);
+/// assert_eq!(view.next().next().next(), OutputStreamView::Combined);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputStreamView {
+    /// stdout followed by stderr under a separator
+    #[default]
+    Combined,
+    /// Only stdout
+    Stdout,
+    /// Only stderr
+    Stderr,
+}
+
+impl OutputStreamView {
+    /// The view after this one: Combined → Stdout → Stderr → Combined
+    pub fn next(self) -> Self {
+        match self {
+            OutputStreamView::Combined => OutputStreamView::Stdout,
+            OutputStreamView::Stdout => OutputStreamView::Stderr,
+            OutputStreamView::Stderr => OutputStreamView::Combined,
+        }
+    }
+
+    /// Whether stdout lines are shown
+    pub fn shows_stdout(self) -> bool {
+        self != OutputStreamView::Stderr
+    }
+
+    /// Whether stderr lines are shown
+    pub fn shows_stderr(self) -> bool {
+        self != OutputStreamView::Stdout
+    }
+}
+
+/// A prompt that must be answered (y/n) before an action proceeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confirmation {
+    /// Quit requested while an inline skill is still executing
+    QuitWhileExecuting,
+}
+
+/// A state change that Esc steps back out of
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewTransition {
+    /// Insert mode was entered to type a search query
+    EnteredSearch,
+    /// The output panel was opened
+    OpenedOutputPanel,
+    /// The view mode was switched away from the contained mode
+    SwitchedView(ViewMode),
+}
+
+/// Back-navigation history for Esc
+///
+/// Records transitions as they happen so Esc can undo them in reverse order.
+/// Holds at most `MAX_VIEW_STACK_DEPTH` entries, dropping the oldest first.
+///
+/// # Examples
+///
+/// ```
+/// use pane::state::{ViewStack, ViewTransition};
+///
+/// let mut stack = ViewStack::default();
+/// stack.push(ViewTransition::EnteredSearch);
+/// stack.push(ViewTransition::OpenedOutputPanel);
+/// assert_eq!(stack.pop(), Some(ViewTransition::OpenedOutputPanel));
+/// assert_eq!(stack.pop(), Some(ViewTransition::EnteredSearch));
+/// assert_eq!(stack.pop(), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct ViewStack {
+    entries: Vec<ViewTransition>,
+}
+
+impl ViewStack {
+    /// Record a transition
+    ///
+    /// Entering search or opening the output panel again while that is
+    /// already the latest transition records nothing, so one Esc undoes it.
+    /// Every view switch is recorded, so Esc retraces each Tab press.
+    pub fn push(&mut self, transition: ViewTransition) {
+        let repeated = self.entries.last() == Some(&transition)
+            && !matches!(transition, ViewTransition::SwitchedView(_));
+        if repeated {
+            return;
+        }
+        if self.entries.len() == MAX_VIEW_STACK_DEPTH {
+            self.entries.remove(0);
+        }
+        self.entries.push(transition);
+    }
+
+    /// Remove and return the most recent transition
+    pub fn pop(&mut self) -> Option<ViewTransition> {
+        self.entries.pop()
+    }
+
+    /// Get the most recent transition without removing it
+    pub fn last(&self) -> Option<&ViewTransition> {
+        self.entries.last()
+    }
+
+    /// Number of recorded transitions
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there is nothing to step back through
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 /// Main application state
 ///
 /// This struct holds all mutable state for the TUI application and serves
@@ -56,7 +208,7 @@ pub enum InputMode {
 /// to user input events and used to render the interface.
 #[derive(Debug)]
 pub struct AppState {
-    /// All discovered skills (immutable after initialization)
+    /// All discovered skills (set at initialization or when loading finishes)
     skills: Vec<Skill>,
     /// Indices into `skills` vec after filtering (updated on search/view change)
     filtered_skills: Vec<usize>,
@@ -64,41 +216,116 @@ pub struct AppState {
     selected_index: usize,
     /// Current search query text
     search_query: String,
+    /// Cursor position within the search query (in characters, 0..=query length)
+    search_cursor: usize,
+    /// Past search queries for this session (ordered, most recent first)
+    search_history: Vec<String>,
+    /// Index into `search_history` of the recalled query (None when not recalling)
+    search_history_index: Option<usize>,
     /// Current view mode filter
     view_mode: ViewMode,
+    /// Category filter applied on top of the view mode (None shows all categories)
+    category_filter: Option<String>,
+    /// Flag indicating the category picker overlay is visible
+    category_picker_visible: bool,
+    /// Selected row in the category picker (0 is "all categories")
+    category_picker_index: usize,
+    /// Tags a skill must all carry to be listed (sorted, empty shows all skills)
+    tag_filter: Vec<String>,
+    /// Flag indicating the tag picker overlay is visible
+    tag_picker_visible: bool,
+    /// Selected row in the tag picker (0 is "clear tags")
+    tag_picker_index: usize,
     /// Current input mode (Normal or Insert)
     input_mode: InputMode,
+    /// Transitions Esc steps back through before quitting
+    view_stack: ViewStack,
     /// Skill IDs marked as favorites
     favorites: HashSet<String>,
+    /// Skill IDs the user has disabled (persisted between sessions)
+    disabled: HashSet<String>,
     /// Recently executed skill IDs (ordered, most recent first)
     recent: Vec<String>,
+    /// Execution counts by skill ID (persisted between sessions)
+    usage: HashMap<String, u64>,
     /// User configuration
     config: Config,
     /// Resolved theme (cached from config at startup for efficient access)
     resolved_theme: crate::ui::theme::ThemeConfig,
+    /// Number of skills per source, highest precedence first (computed once;
+    /// reloads only replace manifests, never sources)
+    source_counts: Vec<(SkillSource, usize)>,
     /// UI translations (cached from config language at startup)
     translations: Translations,
+    /// Flag indicating skill discovery is still running in the background
+    loading: bool,
+    /// Event loop iterations since loading started (drives the spinner)
+    loading_tick: usize,
     /// Flag indicating the application should exit
     should_quit: bool,
     /// Scroll offset for the skill list (for auto-scrolling)
     scroll_offset: usize,
+    /// Number of skill items visible in the list area (updated from terminal size)
+    list_viewport_height: usize,
+    /// Whether the detail pane is shown beside the skill list
+    detail_pane_visible: bool,
+    /// Detail pane scroll offset, tagged with the ID of the skill it applies to
+    detail_scroll: Option<(String, u16)>,
     /// Flag indicating an inline skill is currently executing
     executing_inline: bool,
-    /// Status message for inline execution (e.g., "Executing skill-name...")
+    /// Status message for inline execution (e.g., "Running skill-name...")
     inline_execution_status: Option<String>,
+    /// Inline skill running in the background, if any
+    inline_run: Option<InlineRun>,
     /// Active skill output for display in output panel
     active_output: Option<crate::skills::output::SkillOutput>,
+    /// Display name of the skill that produced the active output, if any
+    output_skill_name: Option<String>,
+    /// Lines the output panel shows for the active output and stream view
+    output_lines: Vec<Line<'static>>,
+    /// Number of wrapped rows each output line takes at the viewport width
+    output_line_rows: Vec<usize>,
+    /// Most recent inline output per skill ID this session (truncated for preview)
+    last_outputs: HashMap<String, crate::skills::output::SkillOutput>,
     /// Flag indicating the output panel is visible
     output_panel_visible: bool,
-    /// Scroll offset for the output panel (current line position)
+    /// Scroll offset for the output panel, in wrapped rows
     output_scroll_offset: usize,
+    /// Number of output lines visible in the output panel (updated from terminal size)
+    output_viewport_height: usize,
+    /// Number of columns output lines wrap at in the output panel (updated from terminal size)
+    output_viewport_width: usize,
+    /// Which output streams the output panel shows
+    output_stream_view: OutputStreamView,
+    /// Whether the output panel search query is being typed
+    output_search_typing: bool,
+    /// Query for searching within the output panel
+    output_search_query: String,
+    /// Position of the current match within `output_search_matches()`
+    output_search_index: Option<usize>,
+    /// Input mode to restore once the output search query is submitted or cancelled
+    mode_before_output_search: InputMode,
+    /// Transient status message shown as a toast (cleared on the next key press)
+    status_message: Option<String>,
+    /// Whether the status message reports a failure (drawn in red)
+    status_is_error: bool,
+    /// Confirmation prompt awaiting a y/n answer, if any
+    pending_confirmation: Option<Confirmation>,
+    /// Inline output to print once the terminal is restored (`quit_after_run`)
+    exit_output: Option<crate::skills::output::SkillOutput>,
+    /// Extra arguments being typed in the launch-arguments prompt, if open
+    args_input: Option<String>,
+    /// Extra arguments to append to the next run's manifest args
+    pending_args: Option<String>,
 }
 
 impl AppState {
     /// Create a new AppState with the given skills and configuration
     ///
     /// Initializes the state with all skills visible (no filter applied),
-    /// selection at index 0, and view mode from config.
+    /// selection on the config's `default_skill` if it is listed (index 0
+    /// otherwise), and view mode from config. Starts in Insert mode
+    /// when `start_in_search` is enabled, otherwise in Normal mode.
     ///
     /// # Arguments
     ///
@@ -109,36 +336,190 @@ impl AppState {
     ///
     /// A new AppState ready for use in the event loop
     pub fn new(skills: Vec<Skill>, config: Config) -> Self {
-        let filtered_skills: Vec<usize> = (0..skills.len()).collect();
+        let filtered_skills: Vec<usize> = (0..skills.len())
+            .filter(|&idx| !skills[idx].manifest.hidden)
+            .collect();
         let view_mode = config.default_view_mode.clone();
-        let resolved_theme = config.theme.clone().unwrap_or_default();
+        let detail_pane_visible = config.show_detail_pane;
+        let resolved_theme = resolve_theme(&config);
+        let input_mode = if config.start_in_search {
+            InputMode::Insert
+        } else {
+            InputMode::Normal
+        };
 
         // Load translations based on configured language
         let language = Language::from_code(&config.language);
         let translations = Translations::load(language);
+        let source_counts = count_skills_by_source(&skills);
 
-        Self {
+        let mut state = Self {
             skills,
             filtered_skills,
             selected_index: 0,
             search_query: String::new(),
+            search_cursor: 0,
+            search_history: Vec::new(),
+            search_history_index: None,
             view_mode,
-            input_mode: InputMode::Normal,
+            category_filter: None,
+            category_picker_visible: false,
+            category_picker_index: 0,
+            tag_filter: Vec::new(),
+            tag_picker_visible: false,
+            tag_picker_index: 0,
+            input_mode,
             favorites: HashSet::new(),
+            disabled: HashSet::new(),
             recent: Vec::new(),
+            usage: HashMap::new(),
             config,
             resolved_theme,
             translations,
+            source_counts,
+            loading: false,
+            loading_tick: 0,
             should_quit: false,
             scroll_offset: 0,
+            list_viewport_height: DEFAULT_LIST_VIEWPORT_HEIGHT,
+            detail_pane_visible,
+            detail_scroll: None,
             executing_inline: false,
             inline_execution_status: None,
+            inline_run: None,
             active_output: None,
+            output_skill_name: None,
+            output_lines: Vec::new(),
+            output_line_rows: Vec::new(),
+            last_outputs: HashMap::new(),
             output_panel_visible: false,
             output_scroll_offset: 0,
+            output_viewport_height: DEFAULT_OUTPUT_VIEWPORT_HEIGHT,
+            output_viewport_width: DEFAULT_OUTPUT_VIEWPORT_WIDTH,
+            output_stream_view: OutputStreamView::Combined,
+            output_search_typing: false,
+            output_search_query: String::new(),
+            output_search_index: None,
+            mode_before_output_search: InputMode::Normal,
+            status_message: None,
+            status_is_error: false,
+            pending_confirmation: None,
+            exit_output: None,
+            args_input: None,
+            pending_args: None,
+            view_stack: ViewStack::default(),
+        };
+
+        if let Some(id) = state.config.default_skill.clone() {
+            state.select_skill_by_id(&id);
+        }
+        if !Language::is_recognized(&state.config.language) {
+            state.set_status_error(format!(
+                "{} {}",
+                state.translations.status_unknown_language, state.config.language
+            ));
+        }
+        state
+    }
+
+    /// Create an AppState that is waiting for skill discovery to finish
+    ///
+    /// The state starts with no skills and `is_loading()` returns `true`
+    /// until `finish_loading` hands over the discovered skills.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - User configuration
+    ///
+    /// # Returns
+    ///
+    /// A new AppState in the loading state
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::AppState;
+    /// use pane::config::Config;
+    ///
+    /// let mut state = AppState::new_loading(Config::default());
+    /// assert!(state.is_loading());
+    ///
+    /// state.finish_loading(vec![]);
+    /// assert!(!state.is_loading());
+    /// ```
+    pub fn new_loading(config: Config) -> Self {
+        let mut state = Self::new(Vec::new(), config);
+        state.loading = true;
+        state
+    }
+
+    /// Whether skill discovery is still running
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Whether any skill was discovered, counting hidden and disabled ones
+    pub fn has_skills(&self) -> bool {
+        !self.skills.is_empty()
+    }
+
+    /// Event loop iterations since loading started, for animating the spinner
+    pub fn loading_tick(&self) -> usize {
+        self.loading_tick
+    }
+
+    /// Advance the loading spinner by one frame (no-op once loaded)
+    pub fn advance_loading_tick(&mut self) {
+        if self.loading {
+            self.loading_tick = self.loading_tick.wrapping_add(1);
+        }
+    }
+
+    /// Swap in the skills found by background discovery and leave the loading state
+    ///
+    /// Filters are applied to the new skills and the config's `default_skill`
+    /// is selected if it is listed, as `new` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `skills` - Vector of discovered skills
+    pub fn finish_loading(&mut self, skills: Vec<Skill>) {
+        self.source_counts = count_skills_by_source(&skills);
+        self.skills = skills;
+        self.loading = false;
+        self.apply_view_filter();
+
+        if let Some(id) = self.config.default_skill.clone() {
+            self.select_skill_by_id(&id);
         }
     }
 
+    /// Select the listed skill with the given ID
+    ///
+    /// The scroll offset is adjusted to keep it visible. The selection is
+    /// unchanged when no skill in the filtered list has that ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - ID of the skill to select
+    ///
+    /// # Returns
+    ///
+    /// `true` if the skill was found and selected
+    pub fn select_skill_by_id(&mut self, skill_id: &str) -> bool {
+        let Some(position) = self
+            .filtered_skills
+            .iter()
+            .position(|&idx| self.skills[idx].manifest.id == skill_id)
+        else {
+            return false;
+        };
+
+        self.selected_index = position;
+        self.update_scroll_offset(self.list_viewport_height);
+        true
+    }
+
     /// Get the currently selected skill, if any
     ///
     /// Returns None if the filtered list is empty.
@@ -152,41 +533,87 @@ impl AppState {
             .and_then(|&idx| self.skills.get(idx))
     }
 
+    /// Get the skill Enter should run
+    ///
+    /// With `enter_runs_top_match` enabled and a search query active, this is
+    /// the highest-scored match (search results are kept in relevance order)
+    /// wherever the selection has moved; otherwise it is the selected skill.
+    pub fn skill_to_run(&self) -> Option<&Skill> {
+        if self.config.enter_runs_top_match && !self.search_query.is_empty() {
+            self.filtered_skills
+                .first()
+                .and_then(|&idx| self.skills.get(idx))
+        } else {
+            self.selected_skill()
+        }
+    }
+
     /// Move selection up in the filtered list
     ///
-    /// Wraps to the bottom if already at the top.
-    /// Updates scroll offset to keep selection visible.
+    /// Wraps to the bottom if already at the top, unless `wrap_navigation`
+    /// is disabled in the config, in which case the selection stays put.
+    /// Updates scroll offset to keep selection visible within the list viewport.
     pub fn move_selection_up(&mut self) {
         if self.filtered_skills.is_empty() {
             return;
         }
 
         if self.selected_index == 0 {
-            self.selected_index = self.filtered_skills.len() - 1;
+            if self.config.wrap_navigation {
+                self.selected_index = self.filtered_skills.len() - 1;
+            }
         } else {
             self.selected_index -= 1;
         }
 
-        // Update scroll offset if selection moved above visible area
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        }
+        self.update_scroll_offset(self.list_viewport_height);
     }
 
     /// Move selection down in the filtered list
     ///
-    /// Wraps to the top if already at the bottom.
-    /// Updates scroll offset to keep selection visible.
+    /// Wraps to the top if already at the bottom, unless `wrap_navigation`
+    /// is disabled in the config, in which case the selection stays put.
+    /// Updates scroll offset to keep selection visible within the list viewport.
     pub fn move_selection_down(&mut self) {
         if self.filtered_skills.is_empty() {
             return;
         }
 
-        self.selected_index = (self.selected_index + 1) % self.filtered_skills.len();
+        let last = self.filtered_skills.len() - 1;
+        if self.selected_index < last {
+            self.selected_index += 1;
+        } else if self.config.wrap_navigation {
+            self.selected_index = 0;
+        }
 
-        // Reset scroll when wrapping to top
-        if self.selected_index == 0 {
-            self.scroll_offset = 0;
+        self.update_scroll_offset(self.list_viewport_height);
+    }
+
+    /// Jump to the next skill whose name starts with `ch`
+    ///
+    /// Scans forward from the current selection, wrapping to the top, so
+    /// repeated presses cycle through every matching skill. Matching ignores
+    /// case. The selection is unchanged when no skill matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - First letter to look for
+    pub fn jump_to_prefix(&mut self, ch: char) {
+        let len = self.filtered_skills.len();
+        let starts_with = |skill: &Skill| {
+            skill
+                .display_name()
+                .chars()
+                .next()
+                .is_some_and(|first| first.to_lowercase().eq(ch.to_lowercase()))
+        };
+
+        let next = (1..=len)
+            .map(|step| (self.selected_index + step) % len)
+            .find(|&idx| starts_with(&self.skills[self.filtered_skills[idx]]));
+        if let Some(idx) = next {
+            self.selected_index = idx;
+            self.update_scroll_offset(self.list_viewport_height);
         }
     }
 
@@ -206,15 +633,26 @@ impl AppState {
     /// - Search filtering is applied AFTER view mode filtering
     /// - Results are ranked by match score (best matches first)
     /// - Selection and scroll offset reset to 0
+    /// - Search cursor moves to the end of the new query
     pub fn set_search_query(&mut self, query: String) {
+        self.search_history_index = None;
+        self.search_cursor = query.chars().count();
         self.search_query = query;
         self.apply_view_filter();
     }
 
+    /// Clear the search query and show every skill in the current view
+    ///
+    /// The input mode is left unchanged so the user can retype right away.
+    pub fn clear_search(&mut self) {
+        self.set_search_query(String::new());
+    }
+
     /// Append a character to the search query
     ///
     /// Adds a character to the end of the current search query and re-filters
-    /// the skill list within the current view mode. Selection resets to the first result.
+    /// the skill list within the current view mode. The search cursor moves to the
+    /// end of the query. Selection resets to the first result.
     ///
     /// # Arguments
     ///
@@ -232,15 +670,46 @@ impl AppState {
     /// assert_eq!(state.search_query(), "clau");
     /// ```
     pub fn append_to_search(&mut self, ch: char) {
+        self.search_history_index = None;
         self.search_query.push(ch);
+        self.search_cursor = self.search_query.chars().count();
+        self.apply_view_filter();
+    }
+
+    /// Insert a character into the search query at the cursor
+    ///
+    /// Inserts the character before the search cursor, advances the cursor past it,
+    /// and re-filters the skill list within the current view mode. Selection resets
+    /// to the first result.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - Character to insert
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.set_search_query("cade".to_string());
+    /// state.move_search_cursor_to_start();
+    /// state.move_search_cursor_right();
+    /// state.insert_into_search('l');
+    /// assert_eq!(state.search_query(), "clade");
+    /// ```
+    pub fn insert_into_search(&mut self, ch: char) {
+        self.search_history_index = None;
+        let byte_index = self.search_cursor_byte_index();
+        self.search_query.insert(byte_index, ch);
+        self.search_cursor += 1;
         self.apply_view_filter();
     }
 
-    /// Remove the last character from the search query
+    /// Remove the character before the cursor from the search query
     ///
-    /// Removes the last character from the current search query (backspace behavior)
-    /// and re-filters the skill list within the current view mode. If the query is
-    /// already empty, this is a no-op. Selection resets to the first result.
+    /// Removes the character before the search cursor (backspace behavior) and
+    /// re-filters the skill list within the current view mode. If the cursor is at
+    /// the start of the query, this is a no-op. Selection resets to the first result.
     ///
     /// # Example
     ///
@@ -252,104 +721,499 @@ impl AppState {
     /// assert_eq!(state.search_query(), "cla");
     /// ```
     pub fn remove_from_search(&mut self) {
-        if !self.search_query.is_empty() {
-            self.search_query.pop();
+        if self.search_cursor > 0 {
+            self.search_history_index = None;
+            self.search_cursor -= 1;
+            let byte_index = self.search_cursor_byte_index();
+            self.search_query.remove(byte_index);
             self.apply_view_filter();
         }
     }
 
-    /// Get the current search query
-    pub fn search_query(&self) -> &str {
-        &self.search_query
+    /// Delete the character under the cursor from the search query
+    ///
+    /// Removes the character at the search cursor (Delete key behavior) and
+    /// re-filters the skill list. If the cursor is at the end of the query, this
+    /// is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.set_search_query("clau".to_string());
+    /// state.move_search_cursor_to_start();
+    /// state.delete_from_search(); // "clau" -> "lau"
+    /// assert_eq!(state.search_query(), "lau");
+    /// ```
+    pub fn delete_from_search(&mut self) {
+        if self.search_cursor < self.search_query.chars().count() {
+            self.search_history_index = None;
+            let byte_index = self.search_cursor_byte_index();
+            self.search_query.remove(byte_index);
+            self.apply_view_filter();
+        }
     }
 
-    /// Get the current view mode
-    pub fn view_mode(&self) -> &ViewMode {
-        &self.view_mode
+    /// Move the search cursor one character left, stopping at the start
+    pub fn move_search_cursor_left(&mut self) {
+        self.search_cursor = self.search_cursor.saturating_sub(1);
     }
 
-    /// Check if the application should quit
-    pub fn should_quit(&self) -> bool {
-        self.should_quit
+    /// Move the search cursor one character right, stopping at the end
+    pub fn move_search_cursor_right(&mut self) {
+        let len = self.search_query.chars().count();
+        self.search_cursor = (self.search_cursor + 1).min(len);
     }
 
-    /// Set the quit flag
-    pub fn quit(&mut self) {
-        self.should_quit = true;
+    /// Move the search cursor to the start of the query
+    pub fn move_search_cursor_to_start(&mut self) {
+        self.search_cursor = 0;
     }
 
-    /// Check if an inline skill is currently executing
-    pub fn is_executing_inline(&self) -> bool {
-        self.executing_inline
+    /// Move the search cursor to the end of the query
+    pub fn move_search_cursor_to_end(&mut self) {
+        self.search_cursor = self.search_query.chars().count();
     }
 
-    /// Get the current inline execution status message
-    pub fn inline_execution_status(&self) -> Option<&str> {
-        self.inline_execution_status.as_deref()
+    /// Get the current search query
+    pub fn search_query(&self) -> &str {
+        &self.search_query
     }
 
-    /// Start inline skill execution
-    ///
-    /// Sets the executing_inline flag to true and stores a status message
-    /// indicating which skill is executing. This is used to display an
-    /// "Executing..." indicator in the TUI.
-    ///
-    /// # Arguments
+    /// Get the search cursor position (in characters)
+    pub fn search_cursor(&self) -> usize {
+        self.search_cursor
+    }
+
+    /// Remember the current search query in the session search history
     ///
-    /// * `skill_name` - Name of the skill being executed
+    /// Called when a search is "committed" (executing a skill or leaving Insert
+    /// mode). Empty queries are ignored; a query already in the history is moved
+    /// to the front. The history is capped at the most recent 50 queries.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// # use pane::{AppState, Config};
-    /// # let mut state = AppState::new(vec![], Config::default());
-    /// state.start_inline_execution("my-skill".to_string());
-    /// assert!(state.is_executing_inline());
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// state.set_search_query("docker".to_string());
+    /// state.push_search_history();
+    /// assert_eq!(state.search_history(), ["docker"]);
     /// ```
-    pub fn start_inline_execution(&mut self, skill_name: String) {
-        self.executing_inline = true;
-        self.inline_execution_status = Some(format!("Executing {}...", skill_name));
+    pub fn push_search_history(&mut self) {
+        self.search_history_index = None;
+
+        let query = self.search_query.trim();
+        if query.is_empty() {
+            return;
+        }
+
+        let query = query.to_string();
+        self.search_history.retain(|past| past != &query);
+        self.search_history.insert(0, query);
+        self.search_history.truncate(MAX_SEARCH_HISTORY);
     }
 
-    /// Finish inline skill execution
+    /// Get the session search history (most recent first)
+    pub fn search_history(&self) -> &[String] {
+        &self.search_history
+    }
+
+    /// Check whether Up should recall an older query instead of moving the selection
     ///
-    /// Clears the executing_inline flag and status message. The output is stored
-    /// for later display (Story 3.2).
+    /// Recall only applies in Insert mode while the query is empty or is itself a
+    /// recalled entry, so Up/Down keep navigating the list once the user types.
+    pub fn can_recall_previous_search(&self) -> bool {
+        self.is_insert_mode()
+            && !self.search_history.is_empty()
+            && (self.search_query.is_empty() || self.search_history_index.is_some())
+    }
+
+    /// Check whether the search box currently shows a recalled query
     ///
-    /// # Arguments
+    /// While recalling, Down steps back towards newer queries instead of
+    /// moving the selection.
+    pub fn is_recalling_search(&self) -> bool {
+        self.is_insert_mode() && self.search_history_index.is_some()
+    }
+
+    /// Replace the search query with the next older history entry
     ///
-    /// * `_output` - The captured skill output (stored in Story 3.2)
+    /// Stops at the oldest entry. Does nothing if the history is empty.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// # use pane::{AppState, Config};
-    /// # use pane::skills::output::SkillOutput;
-    /// # use std::time::Duration;
-    /// # let mut state = AppState::new(vec![], Config::default());
-    /// # let output = SkillOutput {
-    /// #     stdout: "test".to_string(),
-    /// #     stderr: String::new(),
-    /// #     exit_code: Some(0),
-    /// #     truncated: false,
-    /// #     execution_time: Duration::from_secs(1),
-    /// # };
-    /// state.start_inline_execution("my-skill".to_string());
-    /// state.finish_inline_execution(output);
-    /// assert!(!state.is_executing_inline());
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// for query in ["git", "docker"] {
+    ///     state.set_search_query(query.to_string());
+    ///     state.push_search_history();
+    /// }
+    /// state.set_search_query(String::new());
+    ///
+    /// state.recall_previous_search();
+    /// assert_eq!(state.search_query(), "docker");
+    /// state.recall_previous_search();
+    /// assert_eq!(state.search_query(), "git");
     /// ```
-    pub fn finish_inline_execution(&mut self, output: crate::skills::output::SkillOutput) {
-        self.executing_inline = false;
-        self.inline_execution_status = None;
-        // Show output panel automatically after execution completes
-        self.show_output_panel(output);
+    pub fn recall_previous_search(&mut self) {
+        if self.search_history.is_empty() {
+            return;
+        }
+
+        let index = match self.search_history_index {
+            Some(index) => (index + 1).min(self.search_history.len() - 1),
+            None => 0,
+        };
+        self.set_search_query(self.search_history[index].clone());
+        self.search_history_index = Some(index);
     }
 
-    /// Show the output panel with the given skill output
+    /// Replace the search query with the next newer history entry
     ///
-    /// Sets the output panel visible, stores the output, and resets scroll position.
+    /// Stepping past the most recent entry clears the query and ends recall.
+    /// Does nothing when no query is being recalled.
+    pub fn recall_next_search(&mut self) {
+        match self.search_history_index {
+            Some(0) => self.set_search_query(String::new()),
+            Some(index) => {
+                self.set_search_query(self.search_history[index - 1].clone());
+                self.search_history_index = Some(index - 1);
+            }
+            None => {}
+        }
+    }
+
+    /// Convert the character-based search cursor into a byte index into the query
+    fn search_cursor_byte_index(&self) -> usize {
+        self.search_query
+            .char_indices()
+            .nth(self.search_cursor)
+            .map(|(idx, _)| idx)
+            .unwrap_or(self.search_query.len())
+    }
+
+    /// Get the current view mode
+    pub fn view_mode(&self) -> &ViewMode {
+        &self.view_mode
+    }
+
+    /// Check if the application should quit
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Set the quit flag
+    pub fn quit(&mut self) {
+        self.should_quit = true;
+    }
+
+    /// Step back to the previous view state, as Esc does outside overlays
     ///
-    /// # Arguments
+    /// Pop order:
+    /// 1. An open output panel closes.
+    /// 2. Otherwise the most recent transition still in effect is undone:
+    ///    entering search clears the query (staying in Normal mode), and a
+    ///    view switch restores the previous view. Transitions already undone
+    ///    another way (e.g. the query was cleared with Ctrl-u) are skipped.
+    /// 3. With no transitions left, a remaining search query is cleared.
+    ///
+    /// Pickers, confirmation prompts, output search, and Insert mode handle
+    /// Esc themselves before this is reached.
+    ///
+    /// # Returns
+    ///
+    /// `false` when there was nothing to step back from, meaning Esc should quit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::{AppState, ViewMode};
+    /// use pane::config::Config;
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// state.cycle_view_mode();
+    /// assert!(state.go_back());
+    /// assert_eq!(state.view_mode(), &ViewMode::All);
+    /// assert!(!state.go_back());
+    /// ```
+    pub fn go_back(&mut self) -> bool {
+        if self.output_panel_visible {
+            if self.view_stack.last() == Some(&ViewTransition::OpenedOutputPanel) {
+                self.view_stack.pop();
+            }
+            self.hide_output_panel();
+            return true;
+        }
+
+        while let Some(transition) = self.view_stack.pop() {
+            match transition {
+                ViewTransition::EnteredSearch if !self.search_query.is_empty() => {
+                    self.enter_normal_mode();
+                    self.clear_search();
+                    return true;
+                }
+                ViewTransition::SwitchedView(mode) if mode != self.view_mode => {
+                    self.view_mode = mode;
+                    self.apply_view_filter();
+                    return true;
+                }
+                // Already undone some other way
+                _ => {}
+            }
+        }
+
+        if !self.search_query.is_empty() {
+            self.clear_search();
+            return true;
+        }
+        false
+    }
+
+    /// Quit, asking for confirmation first if an inline skill is executing
+    ///
+    /// While a skill is running, quitting would orphan its process, so a
+    /// `Confirmation::QuitWhileExecuting` prompt is raised instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::{AppState, Confirmation};
+    /// use pane::config::Config;
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// state.start_inline_execution("build".to_string());
+    /// state.request_quit();
+    /// assert_eq!(state.pending_confirmation(), Some(Confirmation::QuitWhileExecuting));
+    /// assert!(!state.should_quit());
+    /// ```
+    pub fn request_quit(&mut self) {
+        if self.executing_inline {
+            self.pending_confirmation = Some(Confirmation::QuitWhileExecuting);
+        } else {
+            self.quit();
+        }
+    }
+
+    /// Quit, keeping a skill's output to print after the launcher exits
+    ///
+    /// Used with `quit_after_run`, so an inline skill's output ends up in the
+    /// terminal instead of the output panel.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - The finished run's captured output
+    pub fn quit_with_output(&mut self, output: crate::skills::output::SkillOutput) {
+        self.exit_output = Some(output);
+        self.quit();
+    }
+
+    /// Take the output kept by `quit_with_output`, if any
+    pub fn take_exit_output(&mut self) -> Option<crate::skills::output::SkillOutput> {
+        self.exit_output.take()
+    }
+
+    /// Open the prompt for extra arguments to run the selected skill with
+    ///
+    /// Switches to Insert mode so every key is typed into the arguments. Does
+    /// nothing when there is no skill to run.
+    pub fn start_args_input(&mut self) {
+        if self.skill_to_run().is_none() {
+            return;
+        }
+        self.args_input = Some(String::new());
+        self.input_mode = InputMode::Insert;
+    }
+
+    /// Check if the launch-arguments prompt is open
+    pub fn is_args_input_active(&self) -> bool {
+        self.args_input.is_some()
+    }
+
+    /// Get the arguments typed so far in the launch-arguments prompt
+    pub fn args_input(&self) -> Option<&str> {
+        self.args_input.as_deref()
+    }
+
+    /// Append a character to the launch arguments being typed
+    pub fn push_args_input_char(&mut self, c: char) {
+        if let Some(input) = &mut self.args_input {
+            input.push(c);
+        }
+    }
+
+    /// Remove the last character from the launch arguments being typed
+    pub fn pop_args_input_char(&mut self) {
+        if let Some(input) = &mut self.args_input {
+            input.pop();
+        }
+    }
+
+    /// Close the launch-arguments prompt, keeping the arguments for the next run
+    ///
+    /// Blank input leaves nothing pending. The caller runs the skill, which
+    /// consumes the arguments with `take_pending_args`.
+    pub fn submit_args_input(&mut self) {
+        self.pending_args = self
+            .args_input
+            .take()
+            .filter(|input| !input.trim().is_empty());
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Close the launch-arguments prompt without running anything
+    pub fn cancel_args_input(&mut self) {
+        self.args_input = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Take the extra arguments for the run about to start, if any
+    ///
+    /// Pending arguments apply to a single execution, so they are cleared here.
+    pub fn take_pending_args(&mut self) -> Option<String> {
+        self.pending_args.take()
+    }
+
+    /// Get the confirmation prompt awaiting an answer, if any
+    pub fn pending_confirmation(&self) -> Option<Confirmation> {
+        self.pending_confirmation
+    }
+
+    /// Accept the pending confirmation and perform its action
+    ///
+    /// For `QuitWhileExecuting`, the running inline skill is killed and the
+    /// application quits.
+    pub fn confirm_pending(&mut self) {
+        match self.pending_confirmation.take() {
+            Some(Confirmation::QuitWhileExecuting) => {
+                if let Some(run) = self.inline_run.take() {
+                    run.kill();
+                }
+                self.executing_inline = false;
+                self.inline_execution_status = None;
+                self.quit();
+            }
+            None => {}
+        }
+    }
+
+    /// Dismiss the pending confirmation without performing its action
+    pub fn cancel_pending(&mut self) {
+        self.pending_confirmation = None;
+    }
+
+    /// Check if an inline skill is currently executing
+    pub fn is_executing_inline(&self) -> bool {
+        self.executing_inline
+    }
+
+    /// Get the current inline execution status message
+    pub fn inline_execution_status(&self) -> Option<&str> {
+        self.inline_execution_status.as_deref()
+    }
+
+    /// Start inline skill execution
+    ///
+    /// Sets the executing_inline flag to true and stores a status message
+    /// indicating which skill is executing. This is used to display a
+    /// "Running..." indicator in the status toast.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_name` - Name of the skill being executed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.start_inline_execution("my-skill".to_string());
+    /// assert!(state.is_executing_inline());
+    /// ```
+    pub fn start_inline_execution(&mut self, skill_name: String) {
+        self.executing_inline = true;
+        self.inline_execution_status = Some(format!(
+            "{} {}...",
+            self.translations.status_skill_running, skill_name
+        ));
+    }
+
+    /// Start tracking an inline skill running in the background
+    ///
+    /// Marks the skill as executing (see `start_inline_execution`) until
+    /// `take_finished_inline_run` returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `run` - The run returned by `runner::spawn_inline`
+    pub fn start_inline_run(&mut self, run: InlineRun) {
+        self.start_inline_execution(run.skill_name().to_string());
+        self.inline_run = Some(run);
+    }
+
+    /// Take the background inline run and its result once it has finished
+    ///
+    /// Clears the executing flag when a result is returned; the caller
+    /// decides how to show the output.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no run is in progress or it has not finished yet
+    pub fn take_finished_inline_run(
+        &mut self,
+    ) -> Option<(InlineRun, Result<crate::skills::output::SkillOutput>)> {
+        let result = self.inline_run.as_ref()?.try_output()?;
+        let run = self.inline_run.take()?;
+        self.executing_inline = false;
+        self.inline_execution_status = None;
+        Some((run, result))
+    }
+
+    /// Finish inline skill execution
+    ///
+    /// Clears the executing_inline flag and status message. The output is stored
+    /// for later display (Story 3.2).
+    ///
+    /// # Arguments
+    ///
+    /// * `_output` - The captured skill output (stored in Story 3.2)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # use pane::skills::output::SkillOutput;
+    /// # use pane::skills::manifest::OutputFormat;
+    /// # use std::time::Duration;
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// # let output = SkillOutput {
+    /// #     stdout: "test".to_string(),
+    /// #     stderr: String::new(),
+    /// #     exit_code: Some(0),
+    /// #     truncated: false,
+    /// #     execution_time: Duration::from_secs(1),
+    /// #     format: OutputFormat::Text,
+    /// #     attempts: 1,
+    /// # };
+    /// state.start_inline_execution("my-skill".to_string());
+    /// state.finish_inline_execution(output);
+    /// assert!(!state.is_executing_inline());
+    /// ```
+    pub fn finish_inline_execution(&mut self, output: crate::skills::output::SkillOutput) {
+        self.executing_inline = false;
+        self.inline_execution_status = None;
+        // Show output panel automatically after execution completes
+        self.show_output_panel(output);
+    }
+
+    /// Show the output panel with the given skill output
+    ///
+    /// Sets the output panel visible, stores the output, and resets scroll position
+    /// and the stream view (so both streams are shown).
+    ///
+    /// # Arguments
     ///
     /// * `output` - The skill output to display
     ///
@@ -358,6 +1222,7 @@ impl AppState {
     /// ```no_run
     /// # use pane::{AppState, Config};
     /// # use pane::skills::output::SkillOutput;
+    /// # use pane::skills::manifest::OutputFormat;
     /// # use std::time::Duration;
     /// # let mut state = AppState::new(vec![], Config::default());
     /// # let output = SkillOutput {
@@ -366,14 +1231,74 @@ impl AppState {
     /// #     exit_code: Some(0),
     /// #     truncated: false,
     /// #     execution_time: Duration::from_secs(1),
+    /// #     format: OutputFormat::Text,
+    /// #     attempts: 1,
     /// # };
     /// state.show_output_panel(output);
     /// assert!(state.is_output_panel_visible());
     /// ```
     pub fn show_output_panel(&mut self, output: crate::skills::output::SkillOutput) {
+        self.view_stack.push(ViewTransition::OpenedOutputPanel);
         self.active_output = Some(output);
+        self.output_skill_name = None;
         self.output_panel_visible = true;
         self.output_scroll_offset = 0;
+        self.output_stream_view = OutputStreamView::Combined;
+        self.refresh_output_lines();
+        self.output_search_query.clear();
+        self.output_search_index = None;
+    }
+
+    /// Show the output panel for a skill's run, labelled with the skill's name
+    ///
+    /// The name is captured here rather than read from the selection later,
+    /// since the skill that ran is not always the selected one.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_name` - Display name of the skill that produced the output
+    /// * `output` - The skill output to display
+    pub fn show_skill_output(
+        &mut self,
+        skill_name: String,
+        output: crate::skills::output::SkillOutput,
+    ) {
+        self.show_output_panel(output);
+        self.output_skill_name = Some(skill_name);
+    }
+
+    /// Remember a skill's output for the last-output preview
+    ///
+    /// Only the most recent output per skill is kept, and stdout/stderr are
+    /// each cut to 16KB (marking the copy as truncated) to bound memory use.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - ID of the skill that produced the output
+    /// * `output` - The output to remember
+    pub fn record_last_output(
+        &mut self,
+        skill_id: String,
+        output: &crate::skills::output::SkillOutput,
+    ) {
+        let mut preview = output.clone();
+        let stdout_cut = truncate_at_char_boundary(&mut preview.stdout, MAX_PREVIEW_OUTPUT_BYTES);
+        let stderr_cut = truncate_at_char_boundary(&mut preview.stderr, MAX_PREVIEW_OUTPUT_BYTES);
+        preview.truncated |= stdout_cut || stderr_cut;
+        self.last_outputs.insert(skill_id, preview);
+    }
+
+    /// Get the most recent output recorded for a skill this session
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - ID of the skill
+    ///
+    /// # Returns
+    ///
+    /// The (possibly truncated) last output, or None if the skill has not run inline
+    pub fn last_output(&self, skill_id: &str) -> Option<&crate::skills::output::SkillOutput> {
+        self.last_outputs.get(skill_id)
     }
 
     /// Hide the output panel
@@ -385,6 +1310,7 @@ impl AppState {
     /// ```no_run
     /// # use pane::{AppState, Config};
     /// # use pane::skills::output::SkillOutput;
+    /// # use pane::skills::manifest::OutputFormat;
     /// # use std::time::Duration;
     /// # let mut state = AppState::new(vec![], Config::default());
     /// # let output = SkillOutput {
@@ -393,17 +1319,25 @@ impl AppState {
     /// #     exit_code: Some(0),
     /// #     truncated: false,
     /// #     execution_time: Duration::from_secs(1),
+    /// #     format: OutputFormat::Text,
+    /// #     attempts: 1,
     /// # };
     /// state.show_output_panel(output);
     /// state.hide_output_panel();
     /// assert!(!state.is_output_panel_visible());
     /// ```
     pub fn hide_output_panel(&mut self) {
+        if self.output_search_typing {
+            self.cancel_output_search();
+        }
         self.output_panel_visible = false;
         self.active_output = None;
+        self.output_skill_name = None;
+        self.output_lines.clear();
+        self.output_line_rows.clear();
     }
 
-    /// Scroll the output panel up by one line
+    /// Scroll the output panel up by one wrapped row
     ///
     /// Decrements the scroll offset with boundary check at 0.
     ///
@@ -412,6 +1346,7 @@ impl AppState {
     /// ```no_run
     /// # use pane::{AppState, Config};
     /// # use pane::skills::output::SkillOutput;
+    /// # use pane::skills::manifest::OutputFormat;
     /// # use std::time::Duration;
     /// # let mut state = AppState::new(vec![], Config::default());
     /// # let output = SkillOutput {
@@ -420,25 +1355,29 @@ impl AppState {
     /// #     exit_code: Some(0),
     /// #     truncated: false,
     /// #     execution_time: Duration::from_secs(1),
+    /// #     format: OutputFormat::Text,
+    /// #     attempts: 1,
     /// # };
     /// state.show_output_panel(output);
-    /// state.scroll_output_down(10); // Scroll down first
+    /// state.scroll_output_down(); // Scroll down first
     /// state.scroll_output_up();
     /// ```
     pub fn scroll_output_up(&mut self) {
         self.output_scroll_offset = self.output_scroll_offset.saturating_sub(1);
     }
 
-    /// Scroll the output panel down by one line
+    /// Scroll the output panel down by one wrapped row
     ///
     /// Increments the scroll offset if not at maximum. The maximum is calculated
-    /// automatically from the active output content (total lines of stdout + stderr).
+    /// automatically from the active output content (total lines of stdout + stderr)
+    /// and the output viewport height.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use pane::{AppState, Config};
     /// # use pane::skills::output::SkillOutput;
+    /// # use pane::skills::manifest::OutputFormat;
     /// # use std::time::Duration;
     /// # let mut state = AppState::new(vec![], Config::default());
     /// # let output = SkillOutput {
@@ -447,105 +1386,390 @@ impl AppState {
     /// #     exit_code: Some(0),
     /// #     truncated: false,
     /// #     execution_time: Duration::from_secs(1),
+    /// #     format: OutputFormat::Text,
+    /// #     attempts: 1,
     /// # };
     /// state.show_output_panel(output);
     /// state.scroll_output_down();
     /// ```
     pub fn scroll_output_down(&mut self) {
-        // Calculate max_offset from active output content
-        let max_offset = if let Some(output) = &self.active_output {
-            let stdout_lines = if output.stdout.is_empty() {
-                0
-            } else {
-                output.stdout.lines().count()
-            };
-            let stderr_lines = if output.stderr.is_empty() {
-                0
-            } else {
-                // +1 for separator line if stderr present
-                output.stderr.lines().count() + 1
-            };
-            let total_lines = stdout_lines + stderr_lines;
-            // Assume typical visible height of 20 lines (will be clamped by renderer anyway)
-            total_lines.saturating_sub(20)
-        } else {
-            0
-        };
-
-        if self.output_scroll_offset < max_offset {
+        if self.output_scroll_offset < self.max_output_scroll_offset() {
             self.output_scroll_offset += 1;
         }
     }
 
-    /// Check if output panel is visible
+    /// Scroll the output panel to the first line
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// true if the output panel is currently visible, false otherwise
-    pub fn is_output_panel_visible(&self) -> bool {
-        self.output_panel_visible
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.scroll_output_to_top();
+    /// assert_eq!(state.output_scroll_offset(), 0);
+    /// ```
+    pub fn scroll_output_to_top(&mut self) {
+        self.output_scroll_offset = 0;
     }
 
-    /// Get the active output for display
+    /// Scroll the output panel so the last line is visible
     ///
-    /// # Returns
+    /// Uses the current output viewport height, so the last page of output
+    /// fills the panel rather than leaving only the final line on screen.
     ///
-    /// Reference to the active SkillOutput, or None if no output is stored
-    pub fn active_output(&self) -> Option<&crate::skills::output::SkillOutput> {
-        self.active_output.as_ref()
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.set_output_viewport_height(10);
+    /// state.scroll_output_to_bottom();
+    /// ```
+    pub fn scroll_output_to_bottom(&mut self) {
+        self.output_scroll_offset = self.max_output_scroll_offset();
     }
 
-    /// Get the current output panel scroll offset
-    ///
-    /// # Returns
+    /// Scroll the output panel down by half a viewport
     ///
-    /// The current scroll offset (line number)
-    pub fn output_scroll_offset(&self) -> usize {
-        self.output_scroll_offset
+    /// Stops at the maximum scroll offset for the active output.
+    pub fn scroll_output_half_page_down(&mut self) {
+        let step = half_page(self.output_viewport_height);
+        self.output_scroll_offset =
+            (self.output_scroll_offset + step).min(self.max_output_scroll_offset());
     }
 
-    /// Get the filtered skills for rendering
+    /// Scroll the output panel up by half a viewport
     ///
-    /// Returns an iterator over the filtered skills in display order.
-    pub fn filtered_skills(&self) -> impl Iterator<Item = &Skill> {
-        self.filtered_skills
-            .iter()
-            .filter_map(move |&idx| self.skills.get(idx))
+    /// Stops at the first row.
+    pub fn scroll_output_half_page_up(&mut self) {
+        let step = half_page(self.output_viewport_height);
+        self.output_scroll_offset = self.output_scroll_offset.saturating_sub(step);
     }
 
-    /// Get the number of filtered skills
-    pub fn filtered_count(&self) -> usize {
-        self.filtered_skills.len()
+    /// Set the number of rows visible in the output panel
+    ///
+    /// Called from the event loop with the height derived from the current
+    /// terminal size, so scroll bounds match what the renderer draws.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Number of content rows visible in the output panel
+    pub fn set_output_viewport_height(&mut self, height: usize) {
+        self.output_viewport_height = height;
     }
 
-    /// Get the selected index in the filtered list
-    pub fn selected_index(&self) -> usize {
-        self.selected_index
+    /// Get the number of rows visible in the output panel
+    pub fn output_viewport_height(&self) -> usize {
+        self.output_viewport_height
     }
 
-    /// Get the scroll offset for the skill list
-    pub fn scroll_offset(&self) -> usize {
-        self.scroll_offset
+    /// Set the number of columns output lines wrap at in the output panel
+    ///
+    /// Called from the event loop alongside `set_output_viewport_height`, so
+    /// scroll bounds account for long lines taking several rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Number of columns in the output panel content area
+    pub fn set_output_viewport_width(&mut self, width: usize) {
+        if width != self.output_viewport_width {
+            self.output_viewport_width = width;
+            self.refresh_output_line_rows();
+        }
     }
 
-    /// Get a reference to the user configuration
-    pub fn config(&self) -> &Config {
-        &self.config
+    /// Number of lines the output panel shows for the current stream view
+    ///
+    /// Includes the separator before stderr in the combined view; 0 when no
+    /// output is active.
+    pub fn output_line_count(&self) -> usize {
+        self.output_lines.len()
     }
 
-    /// Get the theme configuration
-    ///
-    /// Returns the resolved theme (user-configured or default).
-    /// This method is efficient and does not clone the theme on each access.
-    ///
-    /// # Returns
+    /// Lines the output panel shows for the current stream view, before wrapping
     ///
-    /// Reference to the ThemeConfig to use for rendering
+    /// Built by `output_panel::output_lines`, so JSON stdout is pretty-printed
+    /// and scroll bounds and search matches refer to the rows that are drawn.
+    pub fn output_lines(&self) -> &[Line<'static>] {
+        &self.output_lines
+    }
+
+    /// Calculate the maximum output scroll offset for the active output
     ///
-    /// # Examples
+    /// Counts the rows the lines shown for the current stream view take once
+    /// wrapped at the viewport width, so the bottom of a long wrapped line is
+    /// reachable, and leaves the last page filling the viewport height.
+    pub fn max_output_scroll_offset(&self) -> usize {
+        let total_rows: usize = self.output_line_rows.iter().sum();
+        total_rows.saturating_sub(self.output_viewport_height)
+    }
+
+    /// Index of the output line shown in the panel's first row
     ///
-    /// ```
-    /// use pane::state::AppState;
+    /// The scroll offset is clamped to the current bounds first; 0 when no
+    /// output is active.
+    pub fn first_visible_output_line(&self) -> usize {
+        let offset = self
+            .output_scroll_offset
+            .min(self.max_output_scroll_offset());
+        let mut rows = 0;
+        for (line, &line_rows) in self.output_line_rows.iter().enumerate() {
+            rows += line_rows;
+            if rows > offset {
+                return line;
+            }
+        }
+        0
+    }
+
+    /// Row offset at which an output line starts
+    fn output_line_start_row(&self, line: usize) -> usize {
+        self.output_line_rows.iter().take(line).sum()
+    }
+
+    /// Rebuild the output panel lines for the active output and stream view
+    fn refresh_output_lines(&mut self) {
+        self.output_lines = match &self.active_output {
+            Some(output) => {
+                output_panel::output_lines(output, self.output_stream_view, &self.resolved_theme)
+            }
+            None => Vec::new(),
+        };
+        self.refresh_output_line_rows();
+    }
+
+    /// Recount the wrapped rows of each output line at the viewport width
+    fn refresh_output_line_rows(&mut self) {
+        let width = self.output_viewport_width;
+        self.output_line_rows = self
+            .output_lines
+            .iter()
+            .map(|line| wrapped_row_count(&line_text(line), width))
+            .collect();
+    }
+
+    /// Show the next output stream view (combined, stdout only, stderr only)
+    ///
+    /// The scroll offset is clamped to the new view's length and the current
+    /// search match is reset, since line numbers change between views.
+    pub fn cycle_output_stream_view(&mut self) {
+        self.output_stream_view = self.output_stream_view.next();
+        self.refresh_output_lines();
+        self.output_scroll_offset = self
+            .output_scroll_offset
+            .min(self.max_output_scroll_offset());
+        self.output_search_index = None;
+    }
+
+    /// Get which output streams the output panel shows
+    pub fn output_stream_view(&self) -> OutputStreamView {
+        self.output_stream_view
+    }
+
+    /// Start typing a search query in the output panel
+    ///
+    /// Switches to Insert mode so every key is typed into the query; the
+    /// previous mode is restored when the search is submitted or cancelled.
+    pub fn start_output_search(&mut self) {
+        if !self.output_search_typing {
+            self.mode_before_output_search = self.input_mode.clone();
+        }
+        self.output_search_typing = true;
+        self.output_search_query.clear();
+        self.output_search_index = None;
+        self.input_mode = InputMode::Insert;
+    }
+
+    /// Check if an output panel search query is being typed
+    pub fn is_output_search_active(&self) -> bool {
+        self.output_search_typing
+    }
+
+    /// Get the output panel search query
+    pub fn output_search_query(&self) -> &str {
+        &self.output_search_query
+    }
+
+    /// Append a character to the output panel search query
+    pub fn push_output_search_char(&mut self, c: char) {
+        self.output_search_query.push(c);
+    }
+
+    /// Remove the last character from the output panel search query
+    pub fn pop_output_search_char(&mut self) {
+        self.output_search_query.pop();
+    }
+
+    /// Finish typing the output search query and jump to the next match
+    ///
+    /// Jumps to the first match at or below the current scroll position,
+    /// wrapping to the first match in the output.
+    pub fn submit_output_search(&mut self) {
+        self.output_search_typing = false;
+        self.input_mode = self.mode_before_output_search.clone();
+
+        let matches = self.output_search_matches();
+        let index = matches
+            .iter()
+            .position(|&line| line >= self.first_visible_output_line())
+            .or((!matches.is_empty()).then_some(0));
+        self.output_search_index = index;
+        if let Some(index) = index {
+            self.scroll_output_to_line(matches[index]);
+        }
+    }
+
+    /// Stop typing the output search query and clear it
+    pub fn cancel_output_search(&mut self) {
+        self.output_search_typing = false;
+        self.input_mode = self.mode_before_output_search.clone();
+        self.output_search_query.clear();
+        self.output_search_index = None;
+    }
+
+    /// Jump to the next output search match, wrapping to the first
+    pub fn next_output_match(&mut self) {
+        self.step_output_match(true);
+    }
+
+    /// Jump to the previous output search match, wrapping to the last
+    pub fn prev_output_match(&mut self) {
+        self.step_output_match(false);
+    }
+
+    /// Move the current output match forward or backward with wrap-around
+    fn step_output_match(&mut self, forward: bool) {
+        let matches = self.output_search_matches();
+        if matches.is_empty() {
+            return;
+        }
+
+        let len = matches.len();
+        let index = match (self.output_search_index, forward) {
+            (Some(index), true) => (index + 1) % len,
+            (Some(index), false) => (index + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        self.output_search_index = Some(index);
+        self.scroll_output_to_line(matches[index]);
+    }
+
+    /// Get the output line of the current search match, if any
+    pub fn current_output_match(&self) -> Option<usize> {
+        let index = self.output_search_index?;
+        self.output_search_matches().get(index).copied()
+    }
+
+    /// Find output lines containing the search query (ASCII case-insensitive)
+    ///
+    /// Lines are numbered like the output panel for the current stream view,
+    /// after output formatting: stdout lines first, then the stderr separator,
+    /// then stderr lines.
+    ///
+    /// # Returns
+    ///
+    /// Matching line numbers in ascending order; empty without a query
+    pub fn output_search_matches(&self) -> Vec<usize> {
+        if self.output_search_query.is_empty() {
+            return Vec::new();
+        }
+
+        let query = self.output_search_query.to_ascii_lowercase();
+        self.output_lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line_text(line).to_ascii_lowercase().contains(&query))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Scroll the output panel so `line` starts at the top, within scroll bounds
+    fn scroll_output_to_line(&mut self, line: usize) {
+        self.output_scroll_offset = self
+            .output_line_start_row(line)
+            .min(self.max_output_scroll_offset());
+    }
+
+    /// Check if output panel is visible
+    ///
+    /// # Returns
+    ///
+    /// true if the output panel is currently visible, false otherwise
+    pub fn is_output_panel_visible(&self) -> bool {
+        self.output_panel_visible
+    }
+
+    /// Get the active output for display
+    ///
+    /// # Returns
+    ///
+    /// Reference to the active SkillOutput, or None if no output is stored
+    pub fn active_output(&self) -> Option<&crate::skills::output::SkillOutput> {
+        self.active_output.as_ref()
+    }
+
+    /// Get the display name of the skill that produced the active output
+    ///
+    /// # Returns
+    ///
+    /// The name passed to `show_skill_output`, or `None` for outputs shown
+    /// without one (such as error panels)
+    pub fn output_skill_name(&self) -> Option<&str> {
+        self.output_skill_name.as_deref()
+    }
+
+    /// Get the current output panel scroll offset
+    ///
+    /// # Returns
+    ///
+    /// The current scroll offset (line number)
+    pub fn output_scroll_offset(&self) -> usize {
+        self.output_scroll_offset
+    }
+
+    /// Get the filtered skills for rendering
+    ///
+    /// Returns an iterator over the filtered skills in display order.
+    pub fn filtered_skills(&self) -> impl Iterator<Item = &Skill> {
+        self.filtered_skills
+            .iter()
+            .filter_map(move |&idx| self.skills.get(idx))
+    }
+
+    /// Get the number of filtered skills
+    pub fn filtered_count(&self) -> usize {
+        self.filtered_skills.len()
+    }
+
+    /// Get the selected index in the filtered list
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    /// Get the scroll offset for the skill list
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Get a reference to the user configuration
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Get the theme configuration
+    ///
+    /// Returns the resolved theme (user-configured or default).
+    /// This method is efficient and does not clone the theme on each access.
+    ///
+    /// # Returns
+    ///
+    /// Reference to the ThemeConfig to use for rendering
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::AppState;
     /// use pane::config::Config;
     ///
     /// let config = Config::default();
@@ -556,6 +1780,19 @@ impl AppState {
         &self.resolved_theme
     }
 
+    /// Get the number of loaded skills from each source
+    ///
+    /// Counts every skill, including hidden and disabled ones. Computed once
+    /// when the state is created.
+    ///
+    /// # Returns
+    ///
+    /// `(source, count)` pairs for sources with at least one skill, highest
+    /// precedence first (e.g. project before user before system)
+    pub fn skills_by_source_counts(&self) -> &[(SkillSource, usize)] {
+        &self.source_counts
+    }
+
     /// Get the UI translations
     ///
     /// Returns the translations loaded based on the configured language.
@@ -580,6 +1817,90 @@ impl AppState {
         &self.translations
     }
 
+    /// Apply a newly loaded configuration
+    ///
+    /// Replaces the configuration and re-resolves the theme and translations
+    /// from it. The skill list, filters, and selection are left untouched so
+    /// the user keeps their place.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The reloaded configuration
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::AppState;
+    /// use pane::config::Config;
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// let config = Config {
+    ///     language: "ko".to_string(),
+    ///     ..Config::default()
+    /// };
+    /// state.reload_config(config);
+    /// assert_eq!(state.translations().app_title, "페인");
+    /// ```
+    pub fn reload_config(&mut self, config: Config) {
+        self.resolved_theme = resolve_theme(&config);
+        self.translations = Translations::load(Language::from_code(&config.language));
+        self.config = config;
+        // Output lines carry theme colors
+        self.refresh_output_lines();
+    }
+
+    /// Show a transient status message (toast) to the user
+    ///
+    /// Replaces any message currently shown. The event loop clears it on the
+    /// next key press.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::AppState;
+    /// use pane::config::Config;
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// state.set_status_message("Config reloaded".to_string());
+    /// assert_eq!(state.status_message(), Some("Config reloaded"));
+    /// ```
+    pub fn set_status_message(&mut self, message: String) {
+        self.status_message = Some(message);
+        self.status_is_error = false;
+    }
+
+    /// Set a transient status message that reports a failure
+    ///
+    /// Works like `set_status_message`, but the toast is drawn in red.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to display
+    pub fn set_status_error(&mut self, message: String) {
+        self.status_message = Some(message);
+        self.status_is_error = true;
+    }
+
+    /// Get the current status message, if any
+    pub fn status_message(&self) -> Option<&str> {
+        self.status_message.as_deref()
+    }
+
+    /// Whether the current status message reports a failure
+    pub fn status_is_error(&self) -> bool {
+        self.status_is_error
+    }
+
+    /// Clear the current status message
+    pub fn clear_status_message(&mut self) {
+        self.status_message = None;
+        self.status_is_error = false;
+    }
+
     /// Move selection down by one page
     ///
     /// Advances the selection by `page_size` items, stopping at the end
@@ -615,6 +1936,115 @@ impl AppState {
         self.update_scroll_offset(page_size);
     }
 
+    /// Move selection down by half a viewport
+    ///
+    /// Advances the selection by half the list viewport height (at least one item),
+    /// stopping at the end of the filtered list. Updates scroll offset to keep
+    /// selection visible.
+    pub fn move_selection_half_page_down(&mut self) {
+        if self.filtered_skills.is_empty() {
+            return;
+        }
+
+        let max_index = self.filtered_skills.len() - 1;
+        let step = half_page(self.list_viewport_height);
+        self.selected_index = (self.selected_index + step).min(max_index);
+        self.update_scroll_offset(self.list_viewport_height);
+    }
+
+    /// Move selection up by half a viewport
+    ///
+    /// Moves the selection back by half the list viewport height (at least one item),
+    /// stopping at the start of the filtered list. Updates scroll offset to keep
+    /// selection visible.
+    pub fn move_selection_half_page_up(&mut self) {
+        if self.filtered_skills.is_empty() {
+            return;
+        }
+
+        let step = half_page(self.list_viewport_height);
+        self.selected_index = self.selected_index.saturating_sub(step);
+        self.update_scroll_offset(self.list_viewport_height);
+    }
+
+    /// Set the number of skill items visible in the list area
+    ///
+    /// Called from the event loop with the height derived from the current
+    /// terminal size, so scroll bounds match what the renderer draws.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Number of skill items visible in the list area
+    pub fn set_list_viewport_height(&mut self, height: usize) {
+        self.list_viewport_height = height;
+    }
+
+    /// Get the number of skill items visible in the list area
+    pub fn list_viewport_height(&self) -> usize {
+        self.list_viewport_height
+    }
+
+    /// Show or hide the detail pane, starting from the `show_detail_pane` setting
+    ///
+    /// The event loop recomputes the list viewport on the next draw, since
+    /// the list gains or loses height in the stacked layout.
+    pub fn toggle_detail_pane(&mut self) {
+        self.detail_pane_visible = !self.detail_pane_visible;
+    }
+
+    /// Check if the detail pane is shown beside the skill list
+    pub fn is_detail_pane_visible(&self) -> bool {
+        self.detail_pane_visible
+    }
+
+    /// Scroll the detail pane down by one row without moving the selection
+    ///
+    /// The renderer stops the content at its last row, so extra presses past
+    /// the end leave the pane unchanged.
+    pub fn scroll_detail_down(&mut self) {
+        if let Some(id) = self.selected_skill().map(|skill| skill.manifest.id.clone()) {
+            let offset = self.detail_scroll_offset().saturating_add(1);
+            self.detail_scroll = Some((id, offset));
+        }
+    }
+
+    /// Scroll the detail pane up by one row without moving the selection
+    pub fn scroll_detail_up(&mut self) {
+        if let Some(id) = self.selected_skill().map(|skill| skill.manifest.id.clone()) {
+            let offset = self.detail_scroll_offset().saturating_sub(1);
+            self.detail_scroll = Some((id, offset));
+        }
+    }
+
+    /// Get the detail pane scroll offset for the selected skill
+    ///
+    /// Selecting a different skill starts its details from the top again.
+    pub fn detail_scroll_offset(&self) -> u16 {
+        match (&self.detail_scroll, self.selected_skill()) {
+            (Some((id, offset)), Some(skill)) if *id == skill.manifest.id => *offset,
+            _ => 0,
+        }
+    }
+
+    /// Apply new viewport heights after a terminal resize
+    ///
+    /// Updates both cached heights and re-clamps the list and output scroll
+    /// offsets, so bounds are correct before the next key press.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_height` - Number of skill items visible in the list area
+    /// * `output_height` - Number of content rows visible in the output panel
+    pub fn resize_viewports(&mut self, list_height: usize, output_height: usize) {
+        self.list_viewport_height = list_height;
+        self.output_viewport_height = output_height;
+
+        self.update_scroll_offset(list_height);
+        self.output_scroll_offset = self
+            .output_scroll_offset
+            .min(self.max_output_scroll_offset());
+    }
+
     /// Update scroll offset to keep selected item visible
     ///
     /// Adjusts the scroll offset so the selected item is always within
@@ -640,7 +2070,8 @@ impl AppState {
 
     /// Cycle to the next view mode
     ///
-    /// Transitions through the view modes in order: All → Favorites → Recent → All.
+    /// Transitions through the view modes in order: All → Favorites → Recent →
+    /// Disabled → All. The Disabled view is skipped while no skill is disabled.
     /// After cycling, caller should call `apply_view_filter()` to update the displayed skills.
     ///
     /// # Example
@@ -653,48 +2084,340 @@ impl AppState {
     /// state.cycle_view_mode(); // Recent → All
     /// ```
     pub fn cycle_view_mode(&mut self) {
+        self.view_stack
+            .push(ViewTransition::SwitchedView(self.view_mode.clone()));
         self.view_mode = match self.view_mode {
             ViewMode::All => ViewMode::Favorites,
             ViewMode::Favorites => ViewMode::Recent,
-            ViewMode::Recent => ViewMode::All,
+            ViewMode::Recent if !self.disabled.is_empty() => ViewMode::Disabled,
+            ViewMode::Recent | ViewMode::Disabled => ViewMode::All,
         };
     }
 
-    /// Get the current input mode
+    /// Get the categories available across all discovered skills
     ///
-    /// # Returns
+    /// Categories are sorted alphabetically, with skills lacking a category
+    /// grouped under `UNCATEGORIZED_LABEL`, which is listed last.
     ///
-    /// Reference to the current input mode (Normal or Insert)
+    /// # Returns
     ///
-    /// # Examples
+    /// Unique category labels in display order
+    pub fn categories(&self) -> Vec<String> {
+        let mut has_uncategorized = false;
+        let mut named: BTreeSet<&str> = BTreeSet::new();
+        for skill in &self.skills {
+            match skill.manifest.category.as_deref() {
+                Some(category) if category != UNCATEGORIZED_LABEL => {
+                    named.insert(category);
+                }
+                _ => has_uncategorized = true,
+            }
+        }
+
+        let mut categories: Vec<String> = named.into_iter().map(String::from).collect();
+        if has_uncategorized {
+            categories.push(UNCATEGORIZED_LABEL.to_string());
+        }
+        categories
+    }
+
+    /// Get the active category filter
     ///
-    /// ```
-    /// use pane::state::{AppState, InputMode};
-    /// use pane::config::Config;
+    /// # Returns
     ///
-    /// let state = AppState::new(vec![], Config::default());
-    /// assert_eq!(state.input_mode(), &InputMode::Normal);
-    /// ```
-    pub fn input_mode(&self) -> &InputMode {
-        &self.input_mode
+    /// The selected category label, or None if all categories are shown
+    pub fn category_filter(&self) -> Option<&str> {
+        self.category_filter.as_deref()
     }
 
-    /// Enter Insert mode for search text input
+    /// Set the category filter and re-filter skills
     ///
-    /// Switches the input mode from Normal to Insert, enabling all character
-    /// keys to be captured as search input (including j, k, etc.).
+    /// # Arguments
     ///
-    /// # Examples
+    /// * `category` - Category label to show, or None to show all categories
     ///
-    /// ```
-    /// use pane::state::{AppState, InputMode};
-    /// use pane::config::Config;
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.set_category_filter(Some("git".to_string()));
+    /// assert_eq!(state.category_filter(), Some("git"));
+    /// ```
+    pub fn set_category_filter(&mut self, category: Option<String>) {
+        self.category_filter = category;
+        self.apply_view_filter();
+    }
+
+    /// Open the category picker overlay
+    ///
+    /// Pre-selects the row for the active category filter.
+    pub fn open_category_picker(&mut self) {
+        self.category_picker_index = match &self.category_filter {
+            Some(active) => self
+                .categories()
+                .iter()
+                .position(|category| category == active)
+                .map_or(0, |position| position + 1),
+            None => 0,
+        };
+        self.category_picker_visible = true;
+    }
+
+    /// Close the category picker overlay without changing the filter
+    pub fn close_category_picker(&mut self) {
+        self.category_picker_visible = false;
+    }
+
+    /// Check if the category picker overlay is visible
+    pub fn is_category_picker_visible(&self) -> bool {
+        self.category_picker_visible
+    }
+
+    /// Get the selected row in the category picker
+    ///
+    /// Row 0 is "all categories"; row N is `categories()[N - 1]`.
+    pub fn category_picker_index(&self) -> usize {
+        self.category_picker_index
+    }
+
+    /// Move the category picker selection up, wrapping to the bottom
+    pub fn move_category_picker_up(&mut self) {
+        let row_count = self.categories().len() + 1;
+        self.category_picker_index = (self.category_picker_index + row_count - 1) % row_count;
+    }
+
+    /// Move the category picker selection down, wrapping to the top
+    pub fn move_category_picker_down(&mut self) {
+        let row_count = self.categories().len() + 1;
+        self.category_picker_index = (self.category_picker_index + 1) % row_count;
+    }
+
+    /// Apply the category selected in the picker and close it
+    ///
+    /// Selecting row 0 clears the category filter.
+    pub fn select_category_from_picker(&mut self) {
+        let category = self
+            .category_picker_index
+            .checked_sub(1)
+            .and_then(|idx| self.categories().get(idx).cloned());
+        self.category_picker_visible = false;
+        self.set_category_filter(category);
+    }
+
+    /// Collect every unique tag across discovered skills with its skill count
+    ///
+    /// # Returns
+    ///
+    /// `(tag, count)` pairs sorted alphabetically by tag
+    pub fn tags(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for skill in &self.skills {
+            // Count each tag once per skill even if the manifest repeats it
+            let unique: BTreeSet<&str> = skill.manifest.tags.iter().map(String::as_str).collect();
+            for tag in unique {
+                *counts.entry(tag).or_insert(0) += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect()
+    }
+
+    /// Get the IDs of skills marked as favorites
+    pub fn favorites(&self) -> &HashSet<String> {
+        &self.favorites
+    }
+
+    /// Replace the favorite skill IDs with ones loaded from disk
+    ///
+    /// Filters are re-applied so the Favorites view and `favorites_first`
+    /// ordering take effect immediately; the selected skill stays selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `favorites` - IDs of the favorite skills
+    pub fn set_favorites(&mut self, favorites: HashSet<String>) {
+        self.favorites = favorites;
+
+        let selected_id = self.selected_skill().map(|skill| skill.manifest.id.clone());
+        self.apply_view_filter();
+        if let Some(id) = selected_id {
+            self.select_skill_by_id(&id);
+        }
+    }
+
+    /// Mark the selected skill as a favorite, or unmark it if it already is one
+    ///
+    /// The filtered list is rebuilt, since the skill may enter or leave the
+    /// Favorites view or move with `favorites_first`. The selection follows
+    /// the skill while it is still listed, and otherwise stays at the same
+    /// row, clamped to the new list length.
+    ///
+    /// # Returns
+    ///
+    /// The toggled skill's display name and whether it is now a favorite, or
+    /// `None` if no skill is selected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::config::Config;
+    /// use pane::state::AppState;
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// assert_eq!(state.toggle_selected_skill_favorite(), None);
+    /// ```
+    pub fn toggle_selected_skill_favorite(&mut self) -> Option<(String, bool)> {
+        let skill = self.selected_skill()?;
+        let id = skill.manifest.id.clone();
+        let name = skill.display_name().to_string();
+
+        let now_favorite = if self.favorites.remove(&id) {
+            false
+        } else {
+            self.favorites.insert(id.clone());
+            true
+        };
+
+        let row = self.selected_index;
+        self.apply_view_filter();
+        if !self.select_skill_by_id(&id) {
+            self.selected_index = row.min(self.filtered_skills.len().saturating_sub(1));
+            self.update_scroll_offset(self.list_viewport_height);
+        }
+        Some((name, now_favorite))
+    }
+
+    /// Get the active tag filter
+    ///
+    /// # Returns
+    ///
+    /// The selected tags (sorted); skills must carry all of them. Empty shows all skills.
+    pub fn tag_filter(&self) -> &[String] {
+        &self.tag_filter
+    }
+
+    /// Add a tag to the filter, or remove it if already selected, and re-filter skills
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to toggle; matched in lowercase, like manifest tags
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pane::{AppState, Config};
+    /// # let mut state = AppState::new(vec![], Config::default());
+    /// state.toggle_tag_filter("docker");
+    /// assert_eq!(state.tag_filter(), ["docker"]);
+    /// state.toggle_tag_filter("docker");
+    /// assert!(state.tag_filter().is_empty());
+    /// ```
+    pub fn toggle_tag_filter(&mut self, tag: &str) {
+        let tag = tag.trim().to_lowercase();
+        match self.tag_filter.binary_search(&tag) {
+            Ok(position) => {
+                self.tag_filter.remove(position);
+            }
+            Err(position) => self.tag_filter.insert(position, tag),
+        }
+        self.apply_view_filter();
+    }
+
+    /// Clear the tag filter and re-filter skills
+    pub fn clear_tag_filter(&mut self) {
+        self.tag_filter.clear();
+        self.apply_view_filter();
+    }
+
+    /// Open the tag picker overlay with the first row selected
+    pub fn open_tag_picker(&mut self) {
+        self.tag_picker_index = 0;
+        self.tag_picker_visible = true;
+    }
+
+    /// Close the tag picker overlay, keeping the selected tags
+    pub fn close_tag_picker(&mut self) {
+        self.tag_picker_visible = false;
+    }
+
+    /// Check if the tag picker overlay is visible
+    pub fn is_tag_picker_visible(&self) -> bool {
+        self.tag_picker_visible
+    }
+
+    /// Get the selected row in the tag picker
+    ///
+    /// Row 0 is "clear tags"; row N is `tags()[N - 1]`.
+    pub fn tag_picker_index(&self) -> usize {
+        self.tag_picker_index
+    }
+
+    /// Move the tag picker selection up, wrapping to the bottom
+    pub fn move_tag_picker_up(&mut self) {
+        let row_count = self.tags().len() + 1;
+        self.tag_picker_index = (self.tag_picker_index + row_count - 1) % row_count;
+    }
+
+    /// Move the tag picker selection down, wrapping to the top
+    pub fn move_tag_picker_down(&mut self) {
+        let row_count = self.tags().len() + 1;
+        self.tag_picker_index = (self.tag_picker_index + 1) % row_count;
+    }
+
+    /// Toggle the tag on the picker's selected row, keeping the picker open
+    ///
+    /// Selecting row 0 clears all selected tags.
+    pub fn toggle_tag_from_picker(&mut self) {
+        match self.tag_picker_index.checked_sub(1) {
+            Some(idx) => {
+                if let Some((tag, _)) = self.tags().get(idx) {
+                    self.toggle_tag_filter(tag);
+                }
+            }
+            None => self.clear_tag_filter(),
+        }
+    }
+
+    /// Get the current input mode
+    ///
+    /// # Returns
+    ///
+    /// Reference to the current input mode (Normal or Insert)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::{AppState, InputMode};
+    /// use pane::config::Config;
+    ///
+    /// let state = AppState::new(vec![], Config::default());
+    /// assert_eq!(state.input_mode(), &InputMode::Normal);
+    /// ```
+    pub fn input_mode(&self) -> &InputMode {
+        &self.input_mode
+    }
+
+    /// Enter Insert mode for search text input
+    ///
+    /// Switches the input mode from Normal to Insert, enabling all character
+    /// keys to be captured as search input (including j, k, etc.).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::state::{AppState, InputMode};
+    /// use pane::config::Config;
     ///
     /// let mut state = AppState::new(vec![], Config::default());
     /// state.enter_insert_mode();
     /// assert_eq!(state.input_mode(), &InputMode::Insert);
     /// ```
     pub fn enter_insert_mode(&mut self) {
+        self.view_stack.push(ViewTransition::EnteredSearch);
         self.input_mode = InputMode::Insert;
     }
 
@@ -788,6 +2511,202 @@ impl AppState {
         }
     }
 
+    /// Count one execution of a skill
+    ///
+    /// Called alongside `add_to_recent` whenever a skill is run.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - ID of the skill that was executed
+    pub fn record_run(&mut self, skill_id: &str) {
+        *self.usage.entry(skill_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Get how many times a skill has been executed
+    pub fn usage_count(&self, skill_id: &str) -> u64 {
+        self.usage.get(skill_id).copied().unwrap_or(0)
+    }
+
+    /// Get all execution counts by skill ID, for persisting
+    pub fn usage(&self) -> &HashMap<String, u64> {
+        &self.usage
+    }
+
+    /// Check whether the user has disabled a skill
+    pub fn is_skill_disabled(&self, skill_id: &str) -> bool {
+        self.disabled.contains(skill_id)
+    }
+
+    /// Get the IDs of all disabled skills, for persisting
+    pub fn disabled_skills(&self) -> &HashSet<String> {
+        &self.disabled
+    }
+
+    /// Replace the disabled skill IDs with ones loaded from disk
+    ///
+    /// Filters are re-applied so disabled skills leave the list immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `disabled` - IDs of the disabled skills
+    pub fn set_disabled_skills(&mut self, disabled: HashSet<String>) {
+        self.disabled = disabled;
+
+        let selected_id = self.selected_skill().map(|skill| skill.manifest.id.clone());
+        self.apply_view_filter();
+        if let Some(id) = selected_id {
+            self.select_skill_by_id(&id);
+        }
+    }
+
+    /// Disable the selected skill, or re-enable it if it is already disabled
+    ///
+    /// The skill moves between the Disabled view and the other views, so the
+    /// filtered list is rebuilt; the selection stays at the same row, clamped
+    /// to the new list length.
+    ///
+    /// # Returns
+    ///
+    /// The toggled skill's display name and whether it is now disabled, or
+    /// `None` if no skill is selected
+    pub fn toggle_selected_skill_disabled(&mut self) -> Option<(String, bool)> {
+        let skill = self.selected_skill()?;
+        let id = skill.manifest.id.clone();
+        let name = skill.display_name().to_string();
+
+        let now_disabled = if self.disabled.remove(&id) {
+            false
+        } else {
+            self.disabled.insert(id);
+            true
+        };
+
+        let row = self.selected_index;
+        self.apply_view_filter();
+        self.selected_index = row.min(self.filtered_skills.len().saturating_sub(1));
+        self.update_scroll_offset(self.list_viewport_height);
+        Some((name, now_disabled))
+    }
+
+    /// Replace the execution counts with ones loaded from disk
+    ///
+    /// Counts for IDs that match no discovered skill are kept, so they are
+    /// saved back unchanged; a skill source disabled for one session keeps
+    /// its counts. Filters are re-applied so a usage sort takes effect immediately; the
+    /// selected skill stays selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `usage` - Execution counts by skill ID
+    pub fn set_usage(&mut self, usage: HashMap<String, u64>) {
+        self.usage = usage;
+
+        let selected_id = self.selected_skill().map(|skill| skill.manifest.id.clone());
+        self.apply_view_filter();
+        if let Some(id) = selected_id {
+            self.select_skill_by_id(&id);
+        }
+    }
+
+    /// Snapshot the view to remember for the next session
+    ///
+    /// # Returns
+    ///
+    /// The current view mode, search query, selected skill ID, favorites,
+    /// and recent skills
+    pub fn session(&self) -> Session {
+        let mut favorites: Vec<String> = self.favorites.iter().cloned().collect();
+        favorites.sort();
+        Session {
+            view_mode: self.view_mode.clone(),
+            search_query: self.search_query.clone(),
+            selected_skill: self.selected_skill().map(|skill| skill.manifest.id.clone()),
+            favorites,
+            recent: self.recent.clone(),
+        }
+    }
+
+    /// Restore the view saved by a previous session
+    ///
+    /// Restores favorites and recent skills first, so a saved Favorites or
+    /// Recent view is rebuilt with its contents, then applies the view mode
+    /// and search query and re-selects the saved skill. When that skill is no
+    /// longer listed, the selection stays on the first result. Saved favorites
+    /// are added to those already loaded, so they never drop a favorite.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The view loaded from the session file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pane::config::Config;
+    /// use pane::session::Session;
+    /// use pane::state::{AppState, ViewMode};
+    ///
+    /// let mut state = AppState::new(vec![], Config::default());
+    /// state.restore_session(Session {
+    ///     view_mode: ViewMode::Recent,
+    ///     search_query: "git".to_string(),
+    ///     selected_skill: Some("git-status".to_string()),
+    ///     ..Session::default()
+    /// });
+    /// assert_eq!(state.view_mode(), &ViewMode::Recent);
+    /// assert_eq!(state.search_query(), "git");
+    /// assert_eq!(state.selected_index(), 0);
+    /// ```
+    pub fn restore_session(&mut self, session: Session) {
+        self.favorites.extend(session.favorites);
+        self.recent = session.recent;
+        self.recent.truncate(self.config.max_recent_skills);
+        self.view_mode = session.view_mode;
+        self.set_search_query(session.search_query);
+        if let Some(id) = session.selected_skill {
+            self.select_skill_by_id(&id);
+        }
+    }
+
+    /// Replace the manifest of the skill loaded from `manifest_path`
+    ///
+    /// Used after the manifest was edited on disk. Filters are re-applied and
+    /// the edited skill stays selected if it still matches them.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_path` - Path of the manifest file that was reloaded
+    /// * `manifest` - The freshly parsed manifest
+    ///
+    /// # Returns
+    ///
+    /// `true` if a skill with that manifest path was found and updated
+    pub fn replace_skill_manifest(
+        &mut self,
+        manifest_path: &Path,
+        manifest: SkillManifest,
+    ) -> bool {
+        let Some(skill_idx) = self
+            .skills
+            .iter()
+            .position(|skill| skill.manifest_path == manifest_path)
+        else {
+            return false;
+        };
+        self.skills[skill_idx].manifest = manifest;
+        self.skills[skill_idx].parse_estimated_time(self.config.parse_estimated_time);
+
+        self.apply_view_filter();
+        if let Some(position) = self
+            .filtered_skills
+            .iter()
+            .position(|&idx| idx == skill_idx)
+        {
+            self.selected_index = position;
+            self.update_scroll_offset(self.list_viewport_height);
+        }
+        true
+    }
+
     /// Apply view mode and search filters to update the filtered skills list
     ///
     /// Filters skills based on the current view mode, then applies the search query filter
@@ -799,7 +2718,13 @@ impl AppState {
     ///    - `ViewMode::All` - Show all skills
     ///    - `ViewMode::Favorites` - Show only favorited skills
     ///    - `ViewMode::Recent` - Show only recently executed skills (up to `max_recent_skills`)
-    /// 2. **Search Filter**: Apply fuzzy search query to view-filtered results
+    ///    - `ViewMode::Disabled` - Show only disabled skills, which every other
+    ///      view leaves out
+    /// 2. **Category Filter**: Keep only skills in the selected category, if any
+    /// 3. **Tag Filter**: Keep only skills carrying every selected tag, if any
+    /// 4. **Search Filter**: Apply fuzzy search query to the remaining results
+    /// 5. **Usage Sort**: With `sort_by_usage` and no query, most-run skills come first;
+    ///    otherwise with `sort_by_estimated_time`, the quickest skills come first
     ///
     /// # Example
     ///
@@ -812,7 +2737,10 @@ impl AppState {
     pub fn apply_view_filter(&mut self) {
         // Step 1: Filter by view mode
         let view_filtered: Vec<usize> = match self.view_mode {
-            ViewMode::All => (0..self.skills.len()).collect(),
+            // Hidden skills are only reachable via Favorites/Recent
+            ViewMode::All => (0..self.skills.len())
+                .filter(|&idx| !self.skills[idx].manifest.hidden)
+                .collect(),
             ViewMode::Favorites => self
                 .skills
                 .iter()
@@ -830,52 +2758,148 @@ impl AppState {
                     .map(|(idx, _)| idx)
                     .collect()
             }
+            ViewMode::Disabled => self
+                .skills
+                .iter()
+                .enumerate()
+                .filter(|(_, skill)| self.disabled.contains(&skill.manifest.id))
+                .map(|(idx, _)| idx)
+                .collect(),
+        };
+
+        // Disabled skills only appear in the Disabled view
+        let view_filtered: Vec<usize> = if self.view_mode == ViewMode::Disabled {
+            view_filtered
+        } else {
+            view_filtered
+                .into_iter()
+                .filter(|&idx| !self.disabled.contains(&self.skills[idx].manifest.id))
+                .collect()
+        };
+
+        // Step 2: Narrow to the selected category
+        let view_filtered: Vec<usize> = match &self.category_filter {
+            Some(category) => view_filtered
+                .into_iter()
+                .filter(|&idx| self.skills[idx].category_label() == category)
+                .collect(),
+            None => view_filtered,
         };
 
-        // Step 2: Apply search query filter on view-filtered results
+        // Step 3: Keep only skills carrying every selected tag
+        let view_filtered: Vec<usize> = view_filtered
+            .into_iter()
+            .filter(|&idx| {
+                let tags = &self.skills[idx].manifest.tags;
+                self.tag_filter.iter().all(|tag| tags.contains(tag))
+            })
+            .collect();
+
+        // Step 4: Apply search query filter on view-filtered results
         if self.search_query.is_empty() {
             self.filtered_skills = view_filtered;
         } else {
-            // Use existing search filtering on view-filtered subset
-            let search_filtered = filter_skills(&self.search_query, &self.skills);
+            // Use existing search filtering on view-filtered subset, expanding
+            // configured synonyms and dropping matches below the minimum score
+            let search_filtered = filter_skills_with_synonyms(
+                &self.search_query,
+                &self.skills,
+                self.config.search.min_score,
+                &self.config.search.synonyms,
+            );
             self.filtered_skills = search_filtered
                 .into_iter()
                 .filter(|idx| view_filtered.contains(idx))
                 .collect();
         }
 
-        // Step 3: Reset selection and scroll offset to avoid out-of-bounds
+        // Step 5: Order by execution count or estimated time when sorting is
+        // enabled; search results keep their relevance order
+        if self.config.sort_by_usage && self.search_query.is_empty() {
+            let usage = &self.usage;
+            let skills = &self.skills;
+            self.filtered_skills.sort_by_key(|&idx| {
+                std::cmp::Reverse(usage.get(&skills[idx].manifest.id).copied().unwrap_or(0))
+            });
+        } else if self.config.sort_by_estimated_time && self.search_query.is_empty() {
+            // Unparsed estimates sort last
+            let skills = &self.skills;
+            self.filtered_skills.sort_by_key(|&idx| {
+                let estimate = skills[idx].estimated_duration;
+                (estimate.is_none(), estimate)
+            });
+        }
+
+        // Step 6: Pin favorites to the top of the All view; the stable sort
+        // keeps the order from step 5 within favorites and the rest
+        if self.config.favorites_first
+            && self.view_mode == ViewMode::All
+            && self.search_query.is_empty()
+        {
+            let favorites = &self.favorites;
+            let skills = &self.skills;
+            self.filtered_skills
+                .sort_by_key(|&idx| !favorites.contains(&skills[idx].manifest.id));
+        }
+
+        // Step 7: Reset selection and scroll offset to avoid out-of-bounds
         self.selected_index = 0;
         self.scroll_offset = 0;
     }
 }
 
+/// Tally skills per source, highest precedence first, skipping empty sources
+fn count_skills_by_source(skills: &[Skill]) -> Vec<(SkillSource, usize)> {
+    let mut counts: BTreeMap<SkillSource, usize> = BTreeMap::new();
+    for skill in skills {
+        *counts.entry(skill.source.clone()).or_default() += 1;
+    }
+    counts.into_iter().rev().collect()
+}
+
+/// Pick the theme to render with
+///
+/// `no_color` (which `load_config` also sets from `NO_COLOR`) forces the
+/// monochrome theme over any configured one.
+fn resolve_theme(config: &Config) -> crate::ui::theme::ThemeConfig {
+    if config.no_color {
+        crate::ui::theme::ThemeConfig::monochrome()
+    } else {
+        config.theme.clone().unwrap_or_default()
+    }
+}
+
+/// Half of a viewport height, moving at least one row
+fn half_page(viewport_height: usize) -> usize {
+    (viewport_height / 2).max(1)
+}
+
+/// Truncate a string to at most `max_bytes`, backing off to a char boundary
+///
+/// Returns true if anything was removed.
+fn truncate_at_char_boundary(text: &mut String, max_bytes: usize) -> bool {
+    if text.len() <= max_bytes {
+        return false;
+    }
+
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::skills::{SkillManifest, SkillSource};
-    use std::path::PathBuf;
+    use crate::skills::{OutputFormat, SkillManifest, SkillSource};
+    use crate::test_support::test_skill;
 
     fn create_test_skill(id: &str, name: &str) -> Skill {
-        Skill {
-            manifest: SkillManifest {
-                id: id.to_string(),
-                name: name.to_string(),
-                description: "Test skill".to_string(),
-                version: "1.0.0".to_string(),
-                exec: "test".to_string(),
-                args: vec![],
-                tags: vec![],
-                estimated_time: None,
-                ui: crate::skills::manifest::UiConfig {
-                    mode: crate::skills::manifest::UiMode::Tui,
-                    fullscreen: true,
-                },
-                context: crate::skills::manifest::ContextConfig::default(),
-            },
-            source: SkillSource::Project,
-            manifest_path: PathBuf::from("test.yaml"),
-        }
+        let mut skill = test_skill(id);
+        skill.manifest.name = name.to_string();
+        skill
     }
 
     fn create_test_config() -> Config {
@@ -883,735 +2907,2999 @@ mod tests {
     }
 
     #[test]
-    fn test_app_state_new_initializes_correctly() {
+    fn test_app_state_new_initializes_correctly() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let config = create_test_config();
+
+        // Act
+        let state = AppState::new(skills, config);
+
+        // Assert
+        assert_eq!(state.filtered_count(), 2);
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.search_query(), "");
+        assert_eq!(state.view_mode(), &ViewMode::All);
+        assert!(!state.should_quit());
+    }
+
+    #[test]
+    fn test_app_state_new_preselects_default_skill() {
+        // Arrange
+        let skills: Vec<Skill> = (0..30)
+            .map(|i| create_test_skill(&format!("skill{}", i), &format!("Skill {}", i)))
+            .collect();
+        let config = Config {
+            default_skill: Some("skill25".to_string()),
+            ..create_test_config()
+        };
+
+        // Act
+        let state = AppState::new(skills, config);
+
+        // Assert
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "skill25");
+        assert_eq!(state.selected_index(), 25);
+        assert!(state.scroll_offset() <= 25);
+        assert!(25 < state.scroll_offset() + DEFAULT_LIST_VIEWPORT_HEIGHT);
+    }
+
+    #[test]
+    fn test_app_state_new_unknown_default_skill_selects_first() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let config = Config {
+            default_skill: Some("missing".to_string()),
+            ..create_test_config()
+        };
+
+        // Act
+        let state = AppState::new(skills, config);
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_app_state_new_without_default_skill_selects_first() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+
+        // Act
+        let state = AppState::new(skills, create_test_config());
+
+        // Assert
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "skill1");
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_set_usage_keeps_default_skill_selected() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let config = Config {
+            default_skill: Some("skill2".to_string()),
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+
+        // Act
+        state.set_usage(HashMap::from([("skill1".to_string(), 3)]));
+
+        // Assert
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "skill2");
+    }
+
+    #[test]
+    fn test_move_selection_down_increments_index() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.move_selection_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_down_wraps_at_end() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
+
+        // Act
+        state.move_selection_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_move_selection_up_decrements_index() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 2;
+
+        // Act
+        state.move_selection_up();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_up_wraps_at_start() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.move_selection_up();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_down_stops_at_end_without_wrap() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let config = Config {
+            wrap_navigation: false,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+        state.selected_index = 1;
+
+        // Act
+        state.move_selection_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_up_stops_at_start_without_wrap() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let config = Config {
+            wrap_navigation: false,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+
+        // Act
+        state.move_selection_up();
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+
+        // Act - moving away from the ends still works
+        state.move_selection_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_set_search_query_resets_selection() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
+
+        // Act
+        state.set_search_query("test".to_string());
+
+        // Assert
+        assert_eq!(state.search_query(), "test");
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_set_search_query_applies_configured_min_score() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut config = create_test_config();
+        config.search.min_score = u32::MAX;
+        let mut state = AppState::new(skills, config);
+
+        // Act
+        state.set_search_query("skill".to_string());
+
+        // Assert
+        assert_eq!(state.filtered_count(), 0);
+    }
+
+    #[test]
+    fn test_set_search_query_expands_configured_synonyms() {
+        // Arrange
+        let mut skills = vec![
+            create_test_skill("pods", "List Pods"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        skills[0].manifest.tags = vec!["kubernetes".to_string()];
+        let mut config = create_test_config();
+        config
+            .search
+            .synonyms
+            .insert("k8s".to_string(), "kubernetes".to_string());
+        let mut state = AppState::new(skills, config);
+
+        // Act
+        state.set_search_query("k8s".to_string());
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![0]);
+    }
+
+    #[test]
+    fn test_selected_skill_returns_correct_skill() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
+
+        // Act
+        let selected = state.selected_skill();
+
+        // Assert
+        assert!(selected.is_some());
+        assert_eq!(selected.unwrap().manifest.id, "skill2");
+    }
+
+    #[test]
+    fn test_selected_skill_returns_none_when_empty() {
+        // Arrange
+        let state = AppState::new(vec![], create_test_config());
+
+        // Act
+        let selected = state.selected_skill();
+
+        // Assert
+        assert!(selected.is_none());
+    }
+
+    #[test]
+    fn test_move_selection_page_down_advances_by_page_size() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("skill4", "Skill 4"),
+            create_test_skill("skill5", "Skill 5"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.move_selection_page_down(3);
+
+        // Assert
+        assert_eq!(state.selected_index(), 3);
+    }
+
+    #[test]
+    fn test_move_selection_page_down_stops_at_end() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.move_selection_page_down(10);
+
+        // Assert
+        assert_eq!(state.selected_index(), 2); // Last index
+    }
+
+    #[test]
+    fn test_move_selection_page_up_goes_back_by_page_size() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("skill4", "Skill 4"),
+            create_test_skill("skill5", "Skill 5"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 4;
+
+        // Act
+        state.move_selection_page_up(3);
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_page_up_stops_at_start() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
+
+        // Act
+        state.move_selection_page_up(10);
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_scroll_offset_updates_when_selection_moves() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("skill4", "Skill 4"),
+            create_test_skill("skill5", "Skill 5"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act - move beyond visible area (assuming page size of 2)
+        state.move_selection_page_down(2);
+
+        // Assert - scroll offset should be non-zero
+        assert_eq!(state.selected_index(), 2);
+        assert_eq!(state.scroll_offset(), 1); // Adjusted to keep selection visible
+    }
+
+    fn create_numbered_skills(count: usize) -> Vec<Skill> {
+        (1..=count)
+            .map(|i| create_test_skill(&format!("skill{}", i), &format!("Skill {}", i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_move_selection_down_past_viewport_advances_scroll_by_one() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(10), create_test_config());
+        state.set_list_viewport_height(3);
+
+        // Act & Assert - scroll stays put until the selection leaves the viewport
+        state.move_selection_down();
+        state.move_selection_down();
+        assert_eq!(state.selected_index(), 2);
+        assert_eq!(state.scroll_offset(), 0);
+
+        // Each further step scrolls by exactly one row
+        for expected_offset in 1..=5 {
+            state.move_selection_down();
+            assert_eq!(state.scroll_offset(), expected_offset);
+        }
+        assert_eq!(state.selected_index(), 7);
+    }
+
+    #[test]
+    fn test_move_selection_down_wrap_resets_scroll() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(5), create_test_config());
+        state.set_list_viewport_height(3);
+        for _ in 0..4 {
+            state.move_selection_down();
+        }
+        assert_eq!(state.scroll_offset(), 2);
+
+        // Act
+        state.move_selection_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_move_selection_up_wrap_scrolls_to_last_page() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(10), create_test_config());
+        state.set_list_viewport_height(3);
+
+        // Act
+        state.move_selection_up();
+
+        // Assert - last item is visible at the bottom of the viewport
+        assert_eq!(state.selected_index(), 9);
+        assert_eq!(state.scroll_offset(), 7);
+    }
+
+    #[test]
+    fn test_move_selection_up_above_viewport_scrolls_up() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(10), create_test_config());
+        state.set_list_viewport_height(3);
+        state.move_selection_up(); // wrap to 9, offset 7
+
+        // Act
+        for _ in 0..3 {
+            state.move_selection_up();
+        }
+
+        // Assert
+        assert_eq!(state.selected_index(), 6);
+        assert_eq!(state.scroll_offset(), 6);
+    }
+
+    #[test]
+    fn test_move_selection_half_page_down_moves_half_viewport() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(30), create_test_config());
+        state.set_list_viewport_height(10);
+
+        // Act
+        state.move_selection_half_page_down();
+        state.move_selection_half_page_down();
+        state.move_selection_half_page_down();
+
+        // Assert - 3 x 5 items, scrolled to keep selection on the last visible row
+        assert_eq!(state.selected_index(), 15);
+        assert_eq!(state.scroll_offset(), 6);
+    }
+
+    #[test]
+    fn test_move_selection_half_page_odd_viewport_rounds_down() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(30), create_test_config());
+        state.set_list_viewport_height(7);
+
+        // Act
+        state.move_selection_half_page_down();
+
+        // Assert - half of 7 is 3
+        assert_eq!(state.selected_index(), 3);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_move_selection_half_page_down_stops_at_end() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(4), create_test_config());
+        state.set_list_viewport_height(10);
+
+        // Act
+        state.move_selection_half_page_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 3);
+    }
+
+    #[test]
+    fn test_move_selection_half_page_up_stops_at_start() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(30), create_test_config());
+        state.set_list_viewport_height(10);
+        state.move_selection_half_page_down();
+        state.move_selection_half_page_down();
+        state.move_selection_half_page_down();
+
+        // Act
+        state.move_selection_half_page_up();
+        let after_one = state.selected_index();
+        for _ in 0..5 {
+            state.move_selection_half_page_up();
+        }
+
+        // Assert
+        assert_eq!(after_one, 10);
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_move_selection_half_page_tiny_viewport_moves_one() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(5), create_test_config());
+        state.set_list_viewport_height(1);
+
+        // Act
+        state.move_selection_half_page_down();
+
+        // Assert
+        assert_eq!(state.selected_index(), 1);
+    }
+
+    #[test]
+    fn test_resize_viewports_reclamps_scroll_offsets() {
+        // Arrange
+        let skills: Vec<Skill> = (0..20)
+            .map(|i| create_test_skill(&format!("skill{}", i), &format!("Skill {}", i)))
+            .collect();
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_list_viewport_height(10);
+        for _ in 0..12 {
+            state.move_selection_down();
+        }
+        state.show_output_panel(crate::skills::output::SkillOutput {
+            stdout: (0..30).map(|i| format!("line {}\n", i)).collect(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        });
+        state.set_output_viewport_height(5);
+        state.scroll_output_to_bottom();
+
+        // Act
+        state.resize_viewports(4, 20);
+
+        // Assert
+        assert_eq!(state.list_viewport_height(), 4);
+        assert_eq!(state.output_viewport_height(), 20);
+        assert!(state.selected_index() < state.scroll_offset() + 4);
+        assert!(state.selected_index() >= state.scroll_offset());
+        assert_eq!(state.output_scroll_offset(), 10);
+    }
+
+    #[test]
+    fn test_append_to_search_updates_query_and_filters() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips"),
+            create_test_skill("docker-build", "Docker Build"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.append_to_search('c');
+        state.append_to_search('l');
+        state.append_to_search('a');
+        state.append_to_search('u');
+
+        // Assert
+        assert_eq!(state.search_query(), "clau");
+        // Filtered list should contain claude-tips (fuzzy matches)
+        assert!(state.filtered_count() > 0);
+    }
+
+    #[test]
+    fn test_remove_from_search_handles_backspace() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips"),
+            create_test_skill("docker-build", "Docker Build"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_search_query("clau".to_string());
+
+        // Act
+        state.remove_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "cla");
+    }
+
+    #[test]
+    fn test_remove_from_search_handles_empty_query() {
+        // Arrange
+        let skills = vec![create_test_skill("skill1", "Skill 1")];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.remove_from_search();
+
+        // Assert - should be no-op
+        assert_eq!(state.search_query(), "");
+    }
+
+    #[test]
+    fn test_insert_into_search_inserts_at_cursor() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips"),
+            create_test_skill("docker-build", "Docker Build"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_search_query("cade".to_string());
+        state.move_search_cursor_to_start();
+        state.move_search_cursor_right();
+
+        // Act
+        state.insert_into_search('l');
+
+        // Assert
+        assert_eq!(state.search_query(), "clade");
+        assert_eq!(state.search_cursor(), 2);
+    }
+
+    #[test]
+    fn test_insert_into_search_refilters() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips"),
+            create_test_skill("docker-build", "Docker Build"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_search_query("dcker".to_string());
+        state.move_search_cursor_to_start();
+        state.move_search_cursor_right();
+
+        // Act
+        state.insert_into_search('o');
+
+        // Assert
+        assert_eq!(state.search_query(), "docker");
+        assert_eq!(state.filtered_count(), 1);
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "docker-build");
+    }
+
+    #[test]
+    fn test_remove_from_search_deletes_before_cursor() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("clxau".to_string());
+        state.move_search_cursor_left();
+        state.move_search_cursor_left();
+
+        // Act
+        state.remove_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "clau");
+        assert_eq!(state.search_cursor(), 2);
+    }
+
+    #[test]
+    fn test_remove_from_search_at_start_is_noop() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("clau".to_string());
+        state.move_search_cursor_to_start();
+
+        // Act
+        state.remove_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "clau");
+        assert_eq!(state.search_cursor(), 0);
+    }
+
+    #[test]
+    fn test_delete_from_search_deletes_forward() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("claxu".to_string());
+        state.move_search_cursor_to_start();
+        for _ in 0..3 {
+            state.move_search_cursor_right();
+        }
+
+        // Act
+        state.delete_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "clau");
+        assert_eq!(state.search_cursor(), 3);
+    }
+
+    #[test]
+    fn test_delete_from_search_at_end_is_noop() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("clau".to_string());
+
+        // Act
+        state.delete_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "clau");
+    }
+
+    #[test]
+    fn test_search_cursor_clamped_at_bounds() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("ab".to_string());
+
+        // Act & Assert - right stops at the end
+        state.move_search_cursor_right();
+        assert_eq!(state.search_cursor(), 2);
+
+        // Left stops at the start
+        for _ in 0..5 {
+            state.move_search_cursor_left();
+        }
+        assert_eq!(state.search_cursor(), 0);
+
+        // Home/End jump to bounds
+        state.move_search_cursor_to_end();
+        assert_eq!(state.search_cursor(), 2);
+        state.move_search_cursor_to_start();
+        assert_eq!(state.search_cursor(), 0);
+    }
+
+    #[test]
+    fn test_search_cursor_handles_multibyte_characters() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.set_search_query("검색".to_string());
+        state.move_search_cursor_left();
+
+        // Act
+        state.insert_into_search('x');
+        state.delete_from_search();
+
+        // Assert
+        assert_eq!(state.search_query(), "검x");
+        assert_eq!(state.search_cursor(), 2);
+    }
+
+    #[test]
+    fn test_update_search_query_resets_selected_index() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
+
+        // Act
+        state.set_search_query("test".to_string());
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_update_search_query_empty_shows_all_skills() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_search_query("test".to_string()); // Filter
+
+        // Act
+        state.set_search_query("".to_string()); // Clear filter
+
+        // Assert
+        assert_eq!(state.filtered_count(), 3); // All skills visible
+    }
+
+    #[test]
+    fn test_go_back_undoes_transitions_in_reverse_order() {
+        // Arrange - switch view, search, then open the output panel
+        let skills = vec![
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.cycle_view_mode();
+        state.apply_view_filter();
+        state.enter_insert_mode();
+        state.set_search_query("alpha".to_string());
+        state.enter_normal_mode();
+        state.show_output_panel(create_numbered_output(3));
+
+        // Act & Assert - panel closes first, search and view are kept
+        assert!(state.go_back());
+        assert!(!state.output_panel_visible);
+        assert_eq!(state.search_query, "alpha");
+        assert_eq!(state.view_mode, ViewMode::Favorites);
+
+        // Act & Assert - then the search is cleared
+        assert!(state.go_back());
+        assert_eq!(state.search_query, "");
+        assert_eq!(state.input_mode, InputMode::Normal);
+        assert_eq!(state.view_mode, ViewMode::Favorites);
+
+        // Act & Assert - then the view switch is undone
+        assert!(state.go_back());
+        assert_eq!(state.view_mode, ViewMode::All);
+
+        // Act & Assert - nothing is left, so Esc would quit
+        assert!(!state.go_back());
+    }
+
+    #[test]
+    fn test_go_back_skips_transitions_already_undone() {
+        // Arrange - search entered, then cleared and left without Esc popping it
+        let mut state = AppState::new(vec![create_test_skill("a", "Alpha")], create_test_config());
+        state.enter_insert_mode();
+        state.set_search_query("alpha".to_string());
+        state.clear_search();
+        state.enter_normal_mode();
+
+        // Act
+        let stepped_back = state.go_back();
+
+        // Assert
+        assert!(!stepped_back);
+        assert!(state.view_stack.is_empty());
+    }
+
+    #[test]
+    fn test_go_back_clears_search_without_recorded_transition() {
+        // Arrange
+        let mut state = AppState::new(vec![create_test_skill("a", "Alpha")], create_test_config());
+        state.set_search_query("alpha".to_string());
+
+        // Act & Assert
+        assert!(state.go_back());
+        assert_eq!(state.search_query, "");
+        assert!(!state.go_back());
+    }
+
+    #[test]
+    fn test_view_stack_collapses_repeats_and_caps_depth() {
+        // Arrange
+        let mut stack = ViewStack::default();
+
+        // Act
+        stack.push(ViewTransition::EnteredSearch);
+        stack.push(ViewTransition::EnteredSearch);
+        let after_repeat = stack.len();
+        for _ in 0..MAX_VIEW_STACK_DEPTH + 5 {
+            stack.push(ViewTransition::SwitchedView(ViewMode::All));
+        }
+
+        // Assert
+        assert_eq!(after_repeat, 1);
+        assert_eq!(stack.len(), MAX_VIEW_STACK_DEPTH);
+        assert_eq!(
+            stack.last(),
+            Some(&ViewTransition::SwitchedView(ViewMode::All))
+        );
+    }
+
+    #[test]
+    fn test_favorites_first_pins_favorites_in_all_view() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
+            create_test_skill("c", "Gamma"),
+            create_test_skill("d", "Delta"),
+        ];
+        let config = Config {
+            favorites_first: true,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+        state.favorites.insert("c".to_string());
+        state.favorites.insert("b".to_string());
+
+        // Act
+        state.apply_view_filter();
+
+        // Assert - favorites first, each group in its original order
+        assert_eq!(state.filtered_skills, vec![1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn test_favorites_first_off_keeps_order() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.favorites.insert("b".to_string());
+
+        // Act
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_cycle_view_mode_all_to_favorites() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        assert_eq!(state.view_mode(), &ViewMode::All);
+
+        // Act
+        state.cycle_view_mode();
+
+        // Assert
+        assert_eq!(state.view_mode(), &ViewMode::Favorites);
+    }
+
+    #[test]
+    fn test_cycle_view_mode_favorites_to_recent() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.cycle_view_mode(); // All -> Favorites
+
+        // Act
+        state.cycle_view_mode();
+
+        // Assert
+        assert_eq!(state.view_mode(), &ViewMode::Recent);
+    }
+
+    #[test]
+    fn test_cycle_view_mode_recent_to_all() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.cycle_view_mode(); // All -> Favorites
+        state.cycle_view_mode(); // Favorites -> Recent
+
+        // Act
+        state.cycle_view_mode();
+
+        // Assert
+        assert_eq!(state.view_mode(), &ViewMode::All);
+    }
+
+    #[test]
+    fn test_apply_view_filter_all_shows_all_skills() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_count(), 3);
+        assert_eq!(state.view_mode(), &ViewMode::All);
+    }
+
+    #[test]
+    fn test_apply_view_filter_favorites_shows_only_favorited() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.favorites.insert("skill1".to_string());
+        state.favorites.insert("skill3".to_string());
+
+        // Act - Switch to Favorites mode and apply filter
+        state.cycle_view_mode(); // All -> Favorites
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_count(), 2);
+        assert_eq!(state.view_mode(), &ViewMode::Favorites);
+    }
+
+    #[test]
+    fn test_apply_view_filter_recent_shows_only_recent_skills() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.recent.push("skill2".to_string());
+        state.recent.push("skill1".to_string());
+
+        // Act - Switch to Recent mode and apply filter
+        state.cycle_view_mode(); // All -> Favorites
+        state.cycle_view_mode(); // Favorites -> Recent
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_count(), 2);
+        assert_eq!(state.view_mode(), &ViewMode::Recent);
+    }
+
+    #[test]
+    fn test_apply_view_filter_recent_respects_max_recent_limit() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("skill4", "Skill 4"),
+            create_test_skill("skill5", "Skill 5"),
+        ];
+        let mut config = create_test_config();
+        config.max_recent_skills = 3; // Limit to 3 recent skills
+        let mut state = AppState::new(skills, config);
+
+        // Add 5 recent skills (more than max_recent_skills limit)
+        state.recent.push("skill5".to_string());
+        state.recent.push("skill4".to_string());
+        state.recent.push("skill3".to_string());
+        state.recent.push("skill2".to_string());
+        state.recent.push("skill1".to_string());
+
+        // Act - Switch to Recent mode and apply filter
+        state.cycle_view_mode(); // All -> Favorites
+        state.cycle_view_mode(); // Favorites -> Recent
+        state.apply_view_filter();
+
+        // Assert - Only first 3 recent skills should be shown
+        assert_eq!(state.filtered_count(), 3);
+        assert_eq!(state.view_mode(), &ViewMode::Recent);
+    }
+
+    #[test]
+    fn test_view_and_search_filters_combine_correctly() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("claude-tips", "Claude Tips"),
+            create_test_skill("docker-build", "Docker Build"),
+            create_test_skill("git-helper", "Git Helper"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Mark only claude-tips and git-helper as favorites
+        state.favorites.insert("claude-tips".to_string());
+        state.favorites.insert("git-helper".to_string());
+
+        // Switch to Favorites mode
+        state.cycle_view_mode(); // All -> Favorites
+
+        // Act - Apply search query within Favorites view
+        state.set_search_query("git".to_string());
+        state.apply_view_filter();
+
+        // Assert - Should only show git-helper (in favorites AND matches search)
+        assert_eq!(state.filtered_count(), 1);
+        let filtered_skills: Vec<&Skill> = state.filtered_skills().collect();
+        assert_eq!(filtered_skills[0].manifest.id, "git-helper");
+    }
+
+    #[test]
+    fn test_app_state_theme_returns_custom_theme() {
+        // Arrange
+        let mut config = create_test_config();
+        let custom_theme = crate::ui::theme::ThemeConfig {
+            primary: ratatui::style::Color::Red,
+            ..crate::ui::theme::ThemeConfig::default()
+        };
+        config.theme = Some(custom_theme.clone());
+        let state = AppState::new(vec![], config);
+
+        // Act
+        let theme = state.theme();
+
+        // Assert
+        assert_eq!(theme.primary, ratatui::style::Color::Red);
+    }
+
+    #[test]
+    fn test_app_state_theme_returns_default_when_none() {
+        // Arrange
+        let config = create_test_config();
+        let state = AppState::new(vec![], config);
+
+        // Act
+        let theme = state.theme();
+
+        // Assert
+        assert_eq!(theme.primary, ratatui::style::Color::Cyan);
+        assert_eq!(*theme, crate::ui::theme::ThemeConfig::default());
+    }
+
+    #[test]
+    fn test_app_state_no_color_overrides_configured_theme() {
+        // Arrange
+        let mut config = create_test_config();
+        config.theme = Some(crate::ui::theme::ThemeConfig {
+            primary: ratatui::style::Color::Red,
+            ..crate::ui::theme::ThemeConfig::default()
+        });
+        config.no_color = true;
+
+        // Act
+        let state = AppState::new(vec![], config);
+
+        // Assert
+        let theme = state.theme();
+        assert_eq!(*theme, crate::ui::theme::ThemeConfig::monochrome());
+        assert_eq!(theme.primary, ratatui::style::Color::Reset);
+        let selected = theme.selected_style();
+        assert_eq!(selected.bg, None);
+        assert!(selected
+            .add_modifier
+            .contains(ratatui::style::Modifier::REVERSED | ratatui::style::Modifier::BOLD));
+    }
+
+    // Output panel state management tests
+    #[test]
+    fn test_show_output_panel_sets_visibility() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "test output".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+
+        // Act
+        state.show_output_panel(output);
+
+        // Assert
+        assert!(state.is_output_panel_visible());
+        assert!(state.active_output().is_some());
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_hide_output_panel_clears_state() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "test output".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.show_output_panel(output);
+
+        // Act
+        state.hide_output_panel();
+
+        // Assert
+        assert!(!state.is_output_panel_visible());
+        assert!(state.active_output().is_none());
+    }
+
+    #[test]
+    fn test_scroll_output_up_decrements_offset() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "line1\nline2\nline3".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.show_output_panel(output);
+        state.scroll_output_down(); // Scroll down first
+
+        // Act
+        state.scroll_output_up();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_scroll_output_up_stops_at_zero() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "line1\nline2\nline3".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.show_output_panel(output);
+
+        // Act
+        state.scroll_output_up();
+        state.scroll_output_up();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_scroll_output_down_increments_offset() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        // Create output with 25 lines (more than visible height of 20)
+        let mut lines = Vec::new();
+        for i in 1..=25 {
+            lines.push(format!("line{}", i));
+        }
+        let output = crate::skills::output::SkillOutput {
+            stdout: lines.join("\n"),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.show_output_panel(output);
+
+        // Act
+        state.scroll_output_down();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 1);
+    }
+
+    #[test]
+    fn test_scroll_output_down_stops_at_max() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "line1\nline2\nline3".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.show_output_panel(output);
+
+        // Act - scroll down 10 times (but output only has 3 lines, so max_offset = 0)
+        for _ in 0..10 {
+            state.scroll_output_down();
+        }
+
+        // Assert - should stop at 0 (3 lines - 20 visible height assumption = 0, clamped)
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    fn create_numbered_output(line_count: usize) -> crate::skills::output::SkillOutput {
+        let lines: Vec<String> = (1..=line_count).map(|i| format!("line{}", i)).collect();
+        crate::skills::output::SkillOutput {
+            stdout: lines.join("\n"),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn test_scroll_output_to_bottom_uses_viewport_height() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(50));
+        state.set_output_viewport_height(10);
+
+        // Act
+        state.scroll_output_to_bottom();
+
+        // Assert - last page fills the viewport exactly
+        assert_eq!(state.output_scroll_offset(), 40);
+    }
+
+    #[test]
+    fn test_scroll_output_to_bottom_counts_stderr_separator() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let mut output = create_numbered_output(10);
+        output.stderr = "err1\nerr2".to_string();
+        state.show_output_panel(output);
+        state.set_output_viewport_height(5);
+
+        // Act
+        state.scroll_output_to_bottom();
+
+        // Assert - 10 stdout + 1 separator + 2 stderr - 5 visible
+        assert_eq!(state.output_scroll_offset(), 8);
+    }
+
+    #[test]
+    fn test_scroll_output_to_bottom_short_output_stays_at_zero() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(3));
+        state.set_output_viewport_height(10);
+
+        // Act
+        state.scroll_output_to_bottom();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_scroll_output_to_top_resets_offset() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(50));
+        state.set_output_viewport_height(10);
+        state.scroll_output_to_bottom();
+
+        // Act
+        state.scroll_output_to_top();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_scroll_output_down_stops_at_viewport_max() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(12));
+        state.set_output_viewport_height(10);
+
+        // Act
+        for _ in 0..5 {
+            state.scroll_output_down();
+        }
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 2);
+    }
+
+    #[test]
+    fn test_scroll_output_to_bottom_accounts_for_wrapped_lines() {
+        // Arrange - the last line wraps onto 3 rows at 10 columns
+        let mut output = create_numbered_output(10);
+        output.stdout.push_str("\nthe quick brown fox jumps over");
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(output);
+        state.set_output_viewport_height(5);
+        state.set_output_viewport_width(10);
+
+        // Act
+        state.scroll_output_to_bottom();
+
+        // Assert - line9, line10 and the 3 wrapped rows fill the 5-row viewport
+        assert_eq!(state.output_scroll_offset(), 8);
+    }
+
+    #[test]
+    fn test_scroll_output_reaches_end_of_single_tall_line() {
+        // Arrange - one 100-character word wraps onto 10 rows at 10 columns
+        let mut output = create_numbered_output(0);
+        output.stdout = "x".repeat(100);
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(output);
+        state.set_output_viewport_height(4);
+        state.set_output_viewport_width(10);
+
+        // Act
+        state.scroll_output_down();
+        let after_one = state.output_scroll_offset();
+        state.scroll_output_to_bottom();
+
+        // Assert
+        assert_eq!(after_one, 1);
+        assert_eq!(state.output_scroll_offset(), 6);
+        assert_eq!(state.first_visible_output_line(), 0);
+    }
+
+    #[test]
+    fn test_output_search_scrolls_to_match_row_after_wrapped_lines() {
+        // Arrange - the first line wraps onto 3 rows at 10 columns
+        let mut output = create_numbered_output(0);
+        output.stdout = "the quick brown fox jumps over\nplain\nneedle\n1\n2\n3\n4".to_string();
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(output);
+        state.set_output_viewport_height(3);
+        state.set_output_viewport_width(10);
+        state.start_output_search();
+        for c in "needle".chars() {
+            state.push_output_search_char(c);
+        }
+
+        // Act
+        state.submit_output_search();
+
+        // Assert - "needle" is line 2, which starts at row 4
+        assert_eq!(state.current_output_match(), Some(2));
+        assert_eq!(state.output_scroll_offset(), 4);
+        assert_eq!(state.first_visible_output_line(), 2);
+    }
+
+    #[test]
+    fn test_scroll_output_half_page_down_moves_half_viewport() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(100));
+        state.set_output_viewport_height(20);
+
+        // Act
+        state.scroll_output_half_page_down();
+
+        // Assert
+        assert_eq!(state.output_scroll_offset(), 10);
+    }
+
+    #[test]
+    fn test_scroll_output_half_page_down_clamps_to_max() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(15));
+        state.set_output_viewport_height(9);
+
+        // Act
+        state.scroll_output_half_page_down();
+        state.scroll_output_half_page_down();
+
+        // Assert - 4 + 4 would be 8, but max offset is 15 - 9 = 6
+        assert_eq!(state.output_scroll_offset(), 6);
+    }
+
+    #[test]
+    fn test_scroll_output_half_page_up_stops_at_zero() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.show_output_panel(create_numbered_output(100));
+        state.set_output_viewport_height(20);
+        state.scroll_output_half_page_down();
+        state.scroll_output_half_page_down();
+
+        // Act
+        state.scroll_output_half_page_up();
+        let after_one = state.output_scroll_offset();
+        state.scroll_output_half_page_up();
+        state.scroll_output_half_page_up();
+
+        // Assert
+        assert_eq!(after_one, 10);
+        assert_eq!(state.output_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_finish_inline_execution_shows_output_panel() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        let output = crate::skills::output::SkillOutput {
+            stdout: "test output".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(100),
+            format: OutputFormat::Text,
+            attempts: 1,
+        };
+        state.start_inline_execution("test-skill".to_string());
+
+        // Act
+        state.finish_inline_execution(output);
+
+        // Assert
+        assert!(!state.is_executing_inline());
+        assert!(state.is_output_panel_visible());
+        assert!(state.active_output().is_some());
+    }
+
+    // InputMode tests
+    #[test]
+    fn test_input_mode_defaults_to_normal() {
+        // Arrange & Act
+        let state = AppState::new(vec![], create_test_config());
+
+        // Assert
+        assert_eq!(state.input_mode(), &InputMode::Normal);
+        assert!(state.is_normal_mode());
+        assert!(!state.is_insert_mode());
+    }
+
+    #[test]
+    fn test_start_in_search_starts_in_insert_mode() {
+        // Arrange
+        let config = Config {
+            start_in_search: true,
+            ..Config::default()
+        };
+
+        // Act
+        let state = AppState::new(vec![], config);
+
+        // Assert
+        assert!(state.is_insert_mode());
+    }
+
+    #[test]
+    fn test_enter_insert_mode_changes_state() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+
+        // Act
+        state.enter_insert_mode();
+
+        // Assert
+        assert_eq!(state.input_mode(), &InputMode::Insert);
+        assert!(state.is_insert_mode());
+        assert!(!state.is_normal_mode());
+    }
+
+    #[test]
+    fn test_enter_normal_mode_changes_state() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+        state.enter_insert_mode();
+
+        // Act
+        state.enter_normal_mode();
+
+        // Assert
+        assert_eq!(state.input_mode(), &InputMode::Normal);
+        assert!(state.is_normal_mode());
+        assert!(!state.is_insert_mode());
+    }
+
+    #[test]
+    fn test_is_insert_mode_returns_correct_state() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+
+        // Assert initial state
+        assert!(!state.is_insert_mode());
+
+        // Act
+        state.enter_insert_mode();
+
+        // Assert after mode change
+        assert!(state.is_insert_mode());
+    }
+
+    #[test]
+    fn test_is_normal_mode_returns_correct_state() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+
+        // Assert initial state
+        assert!(state.is_normal_mode());
+
+        // Act
+        state.enter_insert_mode();
+
+        // Assert after mode change
+        assert!(!state.is_normal_mode());
+    }
+
+    fn create_categorized_skill(id: &str, name: &str, category: Option<&str>) -> Skill {
+        let mut skill = create_test_skill(id, name);
+        skill.manifest.category = category.map(String::from);
+        skill
+    }
+
+    fn create_categorized_skills() -> Vec<Skill> {
+        vec![
+            create_categorized_skill("git-status", "Git Status", Some("git")),
+            create_categorized_skill("docker-build", "Docker Build", Some("docker")),
+            create_categorized_skill("misc", "Misc Tool", None),
+            create_categorized_skill("git-log", "Git Log", Some("git")),
+        ]
+    }
+
+    #[test]
+    fn test_categories_sorted_with_uncategorized_last() {
+        // Arrange
+        let state = AppState::new(create_categorized_skills(), create_test_config());
+
+        // Act
+        let categories = state.categories();
+
+        // Assert
+        assert_eq!(categories, vec!["docker", "git", "Uncategorized"]);
+    }
+
+    #[test]
+    fn test_set_category_filter_narrows_filtered_skills() {
+        // Arrange
+        let mut state = AppState::new(create_categorized_skills(), create_test_config());
+
+        // Act
+        state.set_category_filter(Some("git".to_string()));
+
+        // Assert
+        assert_eq!(state.category_filter(), Some("git"));
+        assert_eq!(state.filtered_skills, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_set_category_filter_uncategorized_matches_skills_without_category() {
+        // Arrange
+        let mut state = AppState::new(create_categorized_skills(), create_test_config());
+
+        // Act
+        state.set_category_filter(Some(UNCATEGORIZED_LABEL.to_string()));
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![2]);
+    }
+
+    #[test]
+    fn test_search_applies_within_category_filter() {
+        // Arrange
+        let mut state = AppState::new(create_categorized_skills(), create_test_config());
+        state.set_category_filter(Some("git".to_string()));
+
+        // Act
+        state.set_search_query("log".to_string());
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![3]);
+    }
+
+    #[test]
+    fn test_select_category_from_picker_applies_and_clears_filter() {
+        // Arrange
+        let mut state = AppState::new(create_categorized_skills(), create_test_config());
+        state.open_category_picker();
+
+        // Act - row 0 is "all categories", row 2 is "git"
+        state.move_category_picker_down();
+        state.move_category_picker_down();
+        state.select_category_from_picker();
+
+        // Assert
+        assert!(!state.is_category_picker_visible());
+        assert_eq!(state.category_filter(), Some("git"));
+
+        // Act - reopening preselects the active category, row 0 clears it
+        state.open_category_picker();
+        assert_eq!(state.category_picker_index(), 2);
+        state.move_category_picker_up();
+        state.move_category_picker_up();
+        state.select_category_from_picker();
+
+        // Assert
+        assert_eq!(state.category_filter(), None);
+        assert_eq!(state.filtered_skills.len(), 4);
+    }
+
+    #[test]
+    fn test_move_category_picker_wraps_around() {
+        // Arrange
+        let mut state = AppState::new(create_categorized_skills(), create_test_config());
+        state.open_category_picker();
+
+        // Act
+        state.move_category_picker_up();
+
+        // Assert - 3 categories plus the "all" row
+        assert_eq!(state.category_picker_index(), 3);
+
+        // Act
+        state.move_category_picker_down();
+
+        // Assert
+        assert_eq!(state.category_picker_index(), 0);
+    }
+
+    #[test]
+    fn test_new_warns_on_unknown_language() {
+        // Arrange
+        let mut config = create_test_config();
+        config.language = "xx".to_string();
+
+        // Act
+        let state = AppState::new(vec![], config);
+
+        // Assert
+        assert_eq!(
+            state.status_message(),
+            Some("Unknown language code, using English: xx")
+        );
+        assert_eq!(state.translations().app_title, "Pane");
+    }
+
+    #[test]
+    fn test_new_accepts_language_aliases_without_warning() {
+        // Arrange
+        let mut config = create_test_config();
+        config.language = "Korean".to_string();
+
+        // Act
+        let state = AppState::new(vec![], config);
+
+        // Assert
+        assert_eq!(state.status_message(), None);
+    }
+
+    #[test]
+    fn test_finish_loading_replaces_skills_and_leaves_loading_state() {
+        // Arrange
+        let mut state = AppState::new_loading(create_test_config());
+        assert!(state.is_loading());
+        assert_eq!(state.filtered_count(), 0);
+
+        // Act
+        state.finish_loading(vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ]);
+
+        // Assert
+        assert!(!state.is_loading());
+        assert_eq!(state.skills.len(), 2);
+        assert_eq!(state.filtered_count(), 2);
+        assert_eq!(
+            state.skills_by_source_counts(),
+            &[(SkillSource::Project, 2)]
+        );
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "skill1");
+    }
+
+    #[test]
+    fn test_finish_loading_selects_default_skill() {
+        // Arrange
+        let mut config = create_test_config();
+        config.default_skill = Some("skill2".to_string());
+        let mut state = AppState::new_loading(config);
+
+        // Act
+        state.finish_loading(vec![
+            create_test_skill("skill1", "Skill 1"),
+            create_test_skill("skill2", "Skill 2"),
+        ]);
+
+        // Assert
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "skill2");
+    }
+
+    #[test]
+    fn test_advance_loading_tick_stops_once_loaded() {
+        // Arrange
+        let mut state = AppState::new_loading(create_test_config());
+
+        // Act
+        state.advance_loading_tick();
+        state.advance_loading_tick();
+        state.finish_loading(vec![]);
+        state.advance_loading_tick();
+
+        // Assert
+        assert_eq!(state.loading_tick(), 2);
+    }
+
+    #[test]
+    fn test_session_round_trips_view_mode_query_and_selection() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("git-status", "Git Status"),
+            create_test_skill("git-log", "Git Log"),
+            create_test_skill("deploy", "Deploy"),
+        ];
+        let mut state = AppState::new(skills.clone(), create_test_config());
+        state.set_search_query("git".to_string());
+        state.select_skill_by_id("git-log");
+        let session = state.session();
+
+        // Act
+        let mut restored = AppState::new(skills, create_test_config());
+        restored.restore_session(session);
+
+        // Assert
+        assert_eq!(restored.view_mode(), &ViewMode::All);
+        assert_eq!(restored.search_query(), "git");
+        assert_eq!(restored.search_cursor(), 3);
+        assert_eq!(restored.selected_skill().unwrap().manifest.id, "git-log");
+    }
+
+    #[test]
+    fn test_session_round_trips_favorites_and_recent_views() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("git-status", "Git Status"),
+            create_test_skill("git-log", "Git Log"),
+            create_test_skill("deploy", "Deploy"),
+        ];
+        let mut state = AppState::new(skills.clone(), create_test_config());
+        state.favorites.insert("deploy".to_string());
+        state.favorites.insert("git-log".to_string());
+        state.add_to_recent("git-status".to_string());
+        state.cycle_view_mode();
+        state.select_skill_by_id("git-log");
+        let session = state.session();
+
+        // Act
+        let mut restored = AppState::new(skills, create_test_config());
+        restored.restore_session(session);
+
+        // Assert
+        assert_eq!(restored.view_mode(), &ViewMode::Favorites);
+        assert_eq!(restored.filtered_count(), 2);
+        assert_eq!(restored.selected_skill().unwrap().manifest.id, "git-log");
+        assert_eq!(restored.recent, vec!["git-status".to_string()]);
+    }
+
+    #[test]
+    fn test_restore_session_applies_view_mode_filter() {
+        // Arrange
+        let mut state = AppState::new(
+            vec![
+                create_test_skill("skill1", "Skill 1"),
+                create_test_skill("skill2", "Skill 2"),
+            ],
+            create_test_config(),
+        );
+
+        // Act
+        state.restore_session(Session {
+            view_mode: ViewMode::Disabled,
+            ..Session::default()
+        });
+
+        // Assert
+        assert_eq!(state.view_mode(), &ViewMode::Disabled);
+        assert_eq!(state.filtered_count(), 0);
+    }
+
+    #[test]
+    fn test_restore_session_with_stale_selection_falls_back_to_first() {
+        // Arrange
+        let mut state = AppState::new(
+            vec![
+                create_test_skill("skill1", "Skill 1"),
+                create_test_skill("skill2", "Skill 2"),
+            ],
+            create_test_config(),
+        );
+        state.select_skill_by_id("skill2");
+
+        // Act
+        state.restore_session(Session {
+            selected_skill: Some("removed-skill".to_string()),
+            ..Session::default()
+        });
+
+        // Assert
+        assert_eq!(state.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_skills_by_source_counts_tallies_mixed_sources() {
+        // Arrange
+        let sources = [
+            SkillSource::System,
+            SkillSource::Project,
+            SkillSource::User,
+            SkillSource::Project,
+            SkillSource::System,
+            SkillSource::System,
+        ];
+        let skills: Vec<Skill> = sources
+            .iter()
+            .enumerate()
+            .map(|(i, source)| {
+                let mut skill = create_test_skill(&format!("skill{}", i), "Skill");
+                skill.source = source.clone();
+                skill
+            })
+            .collect();
+
+        // Act
+        let state = AppState::new(skills, create_test_config());
+
+        // Assert
+        assert_eq!(
+            state.skills_by_source_counts(),
+            &[
+                (SkillSource::Project, 2),
+                (SkillSource::User, 1),
+                (SkillSource::System, 3),
+            ]
+        );
+        assert!(AppState::new(vec![], create_test_config())
+            .skills_by_source_counts()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_status_message_set_and_clear() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+
+        // Act
+        state.set_status_message("Duplicate skill IDs ignored: a".to_string());
+
+        // Assert
+        assert_eq!(
+            state.status_message(),
+            Some("Duplicate skill IDs ignored: a")
+        );
+
+        // Act
+        state.clear_status_message();
+
+        // Assert
+        assert_eq!(state.status_message(), None);
+    }
+
+    #[test]
+    fn test_reload_config_updates_theme_and_language() {
+        // Arrange
+        let mut state = AppState::new(create_numbered_skills(5), create_test_config());
+        state.move_selection_down();
+        let theme = crate::ui::theme::ThemeConfig {
+            primary: ratatui::style::Color::Magenta,
+            ..Default::default()
+        };
+        let config = Config {
+            language: "ko".to_string(),
+            theme: Some(theme),
+            ..Config::default()
+        };
+
+        // Act
+        state.reload_config(config);
+
+        // Assert
+        assert_eq!(state.config().language, "ko");
+        assert_eq!(state.theme().primary, ratatui::style::Color::Magenta);
+        assert_eq!(state.translations().app_title, "페인");
+        assert_eq!(state.selected_index(), 1);
+        assert_eq!(state.filtered_count(), 5);
+    }
+
+    fn create_hidden_skill(id: &str, name: &str) -> Skill {
+        let mut skill = create_test_skill(id, name);
+        skill.manifest.hidden = true;
+        skill
+    }
+
+    #[test]
+    fn test_hidden_skill_absent_from_all_view_and_search() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("visible", "Visible Skill"),
+            create_hidden_skill("helper", "Helper Skill"),
+        ];
+
+        // Act
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![0]);
+
+        // Act
+        state.set_search_query("helper".to_string());
+
+        // Assert
+        assert!(state.filtered_skills.is_empty());
+    }
+
+    #[test]
+    fn test_hidden_skill_present_in_favorites_and_recent() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("visible", "Visible Skill"),
+            create_hidden_skill("helper", "Helper Skill"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.favorites.insert("helper".to_string());
+        state.add_to_recent("helper".to_string());
+
+        // Act
+        state.view_mode = ViewMode::Favorites;
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![1]);
+
+        // Act
+        state.view_mode = ViewMode::Recent;
+        state.apply_view_filter();
+
+        // Assert
+        assert_eq!(state.filtered_skills, vec![1]);
+    }
+
+    #[test]
+    fn test_toggle_selected_skill_disabled_moves_it_to_disabled_view() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("first", "First"),
+            create_test_skill("second", "Second"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        let toggled = state.toggle_selected_skill_disabled();
+
+        // Assert - the skill leaves the All view and the next one is selected
+        assert_eq!(toggled, Some(("First".to_string(), true)));
+        assert!(state.is_skill_disabled("first"));
+        assert_eq!(state.filtered_skills, vec![1]);
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "second");
+
+        // Act - re-enable from the Disabled view
+        state.view_mode = ViewMode::Disabled;
+        state.apply_view_filter();
+        assert_eq!(state.filtered_skills, vec![0]);
+        let toggled = state.toggle_selected_skill_disabled();
+
+        // Assert
+        assert_eq!(toggled, Some(("First".to_string(), false)));
+        assert!(!state.is_skill_disabled("first"));
+        assert!(state.filtered_skills.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_selected_skill_favorite_adds_and_removes_it() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("first", "First"),
+            create_test_skill("second", "Second"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+
+        // Act
+        let toggled = state.toggle_selected_skill_favorite();
+
+        // Assert - the skill joins the Favorites view and stays selected
+        assert_eq!(toggled, Some(("First".to_string(), true)));
+        assert!(state.favorites().contains("first"));
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "first");
+
+        // Act - unmark it from the Favorites view
+        state.view_mode = ViewMode::Favorites;
+        state.apply_view_filter();
+        assert_eq!(state.filtered_skills, vec![0]);
+        let toggled = state.toggle_selected_skill_favorite();
+
+        // Assert
+        assert_eq!(toggled, Some(("First".to_string(), false)));
+        assert!(state.favorites().is_empty());
+        assert!(state.filtered_skills.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_skill_excluded_from_all_favorites_and_recent() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("kept", "Kept"),
+            create_test_skill("off", "Off"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.favorites.insert("kept".to_string());
+        state.favorites.insert("off".to_string());
+        state.add_to_recent("kept".to_string());
+        state.add_to_recent("off".to_string());
+
+        // Act
+        state.set_disabled_skills(["off".to_string()].into_iter().collect());
+
+        // Assert
+        for view_mode in [ViewMode::All, ViewMode::Favorites, ViewMode::Recent] {
+            state.view_mode = view_mode;
+            state.apply_view_filter();
+            assert_eq!(state.filtered_skills, vec![0]);
+        }
+        state.view_mode = ViewMode::Disabled;
+        state.apply_view_filter();
+        assert_eq!(state.filtered_skills, vec![1]);
+    }
+
+    #[test]
+    fn test_cycle_view_mode_includes_disabled_only_when_skills_are_disabled() {
+        // Arrange
+        let mut state = AppState::new(vec![create_test_skill("a", "A")], create_test_config());
+        state.view_mode = ViewMode::Recent;
+
+        // Act & Assert - nothing disabled, so Recent wraps to All
+        state.cycle_view_mode();
+        assert_eq!(state.view_mode(), &ViewMode::All);
+
+        // Act & Assert - with a disabled skill, Recent → Disabled → All
+        state.set_disabled_skills(["a".to_string()].into_iter().collect());
+        state.view_mode = ViewMode::Recent;
+        state.cycle_view_mode();
+        assert_eq!(state.view_mode(), &ViewMode::Disabled);
+        state.cycle_view_mode();
+        assert_eq!(state.view_mode(), &ViewMode::All);
+    }
+
+    #[test]
+    fn test_request_quit_when_idle_quits_immediately() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
+
+        // Act
+        state.request_quit();
+
+        // Assert
+        assert!(state.should_quit());
+        assert_eq!(state.pending_confirmation(), None);
+    }
+
+    #[test]
+    fn test_request_quit_while_executing_then_confirm_quits() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let config = create_test_config();
+        let mut state = AppState::new(vec![], create_test_config());
+        state.start_inline_execution("build".to_string());
 
         // Act
-        let state = AppState::new(skills, config);
+        state.request_quit();
 
         // Assert
-        assert_eq!(state.filtered_count(), 2);
-        assert_eq!(state.selected_index(), 0);
-        assert_eq!(state.search_query(), "");
-        assert_eq!(state.view_mode(), &ViewMode::All);
         assert!(!state.should_quit());
+        assert_eq!(
+            state.pending_confirmation(),
+            Some(Confirmation::QuitWhileExecuting)
+        );
+
+        // Act
+        state.confirm_pending();
+
+        // Assert
+        assert!(state.should_quit());
+        assert!(!state.is_executing_inline());
+        assert_eq!(state.pending_confirmation(), None);
     }
 
     #[test]
-    fn test_move_selection_down_increments_index() {
+    fn test_request_quit_while_executing_then_cancel_keeps_running() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(vec![], create_test_config());
+        state.start_inline_execution("build".to_string());
+        state.request_quit();
 
         // Act
-        state.move_selection_down();
+        state.cancel_pending();
 
         // Assert
-        assert_eq!(state.selected_index(), 1);
+        assert!(!state.should_quit());
+        assert!(state.is_executing_inline());
+        assert_eq!(state.pending_confirmation(), None);
     }
 
     #[test]
-    fn test_move_selection_down_wraps_at_end() {
+    fn test_push_search_history_orders_most_recent_first() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 1;
+        let mut state = AppState::new(vec![], Config::default());
 
         // Act
-        state.move_selection_down();
+        for query in ["git", "docker", "", "  ", "git"] {
+            state.set_search_query(query.to_string());
+            state.push_search_history();
+        }
 
         // Assert
-        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.search_history(), ["git", "docker"]);
     }
 
     #[test]
-    fn test_move_selection_up_decrements_index() {
+    fn test_push_search_history_caps_length() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 2;
+        let mut state = AppState::new(vec![], Config::default());
 
         // Act
-        state.move_selection_up();
+        for i in 0..MAX_SEARCH_HISTORY + 5 {
+            state.set_search_query(format!("query{}", i));
+            state.push_search_history();
+        }
 
         // Assert
-        assert_eq!(state.selected_index(), 1);
+        assert_eq!(state.search_history().len(), MAX_SEARCH_HISTORY);
+        assert_eq!(
+            state.search_history()[0],
+            format!("query{}", MAX_SEARCH_HISTORY + 4)
+        );
     }
 
     #[test]
-    fn test_move_selection_up_wraps_at_start() {
+    fn test_recall_search_cycles_older_then_newer() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(vec![], Config::default());
+        for query in ["one", "two", "three"] {
+            state.set_search_query(query.to_string());
+            state.push_search_history();
+        }
+        state.set_search_query(String::new());
+        state.enter_insert_mode();
+
+        // Act & Assert - Up walks back in time and stops at the oldest entry
+        assert!(state.can_recall_previous_search());
+        state.recall_previous_search();
+        assert_eq!(state.search_query(), "three");
+        state.recall_previous_search();
+        assert_eq!(state.search_query(), "two");
+        state.recall_previous_search();
+        state.recall_previous_search();
+        assert_eq!(state.search_query(), "one");
+        assert_eq!(state.search_cursor(), 3);
+
+        // Act & Assert - Down walks forward and clears past the newest entry
+        state.recall_next_search();
+        assert_eq!(state.search_query(), "two");
+        state.recall_next_search();
+        assert_eq!(state.search_query(), "three");
+        state.recall_next_search();
+        assert_eq!(state.search_query(), "");
+        assert!(!state.is_recalling_search());
+    }
+
+    #[test]
+    fn test_recall_search_ends_when_query_is_edited() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        state.set_search_query("docker".to_string());
+        state.push_search_history();
+        state.set_search_query(String::new());
+        state.enter_insert_mode();
+        state.recall_previous_search();
 
         // Act
-        state.move_selection_up();
+        state.insert_into_search('x');
 
         // Assert
-        assert_eq!(state.selected_index(), 1);
+        assert!(!state.is_recalling_search());
+        assert!(!state.can_recall_previous_search());
+        assert_eq!(state.search_query(), "dockerx");
     }
 
     #[test]
-    fn test_set_search_query_resets_selection() {
+    fn test_recall_search_only_in_insert_mode() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 1;
+        let mut state = AppState::new(vec![], Config::default());
+        state.set_search_query("docker".to_string());
+        state.push_search_history();
+        state.set_search_query(String::new());
+
+        // Act & Assert
+        assert!(!state.can_recall_previous_search());
+        state.enter_insert_mode();
+        assert!(state.can_recall_previous_search());
+    }
+
+    fn create_test_output(stdout: &str) -> crate::skills::output::SkillOutput {
+        crate::skills::output::SkillOutput {
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(5),
+            format: OutputFormat::Text,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn test_last_output_is_recorded_per_skill() {
+        // Arrange
+        let mut state = AppState::new(vec![], create_test_config());
 
         // Act
-        state.set_search_query("test".to_string());
+        state.record_last_output("skill1".to_string(), &create_test_output("first"));
+        state.record_last_output("skill2".to_string(), &create_test_output("second"));
+        state.record_last_output("skill1".to_string(), &create_test_output("third"));
 
         // Assert
-        assert_eq!(state.search_query(), "test");
-        assert_eq!(state.selected_index(), 0);
+        assert_eq!(state.last_output("skill1").unwrap().stdout, "third");
+        assert_eq!(state.last_output("skill2").unwrap().stdout, "second");
+        assert!(state.last_output("skill3").is_none());
+        assert_eq!(state.last_outputs.len(), 2);
     }
 
     #[test]
-    fn test_selected_skill_returns_correct_skill() {
+    fn test_record_last_output_truncates_large_output() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 1;
+        let mut state = AppState::new(vec![], create_test_config());
+        let large = "가".repeat(MAX_PREVIEW_OUTPUT_BYTES); // 3 bytes per char
 
         // Act
-        let selected = state.selected_skill();
+        state.record_last_output("skill1".to_string(), &create_test_output(&large));
 
         // Assert
-        assert!(selected.is_some());
-        assert_eq!(selected.unwrap().manifest.id, "skill2");
+        let preview = state.last_output("skill1").unwrap();
+        assert!(preview.stdout.len() <= MAX_PREVIEW_OUTPUT_BYTES);
+        assert!(preview.stdout.chars().all(|c| c == '가'));
+        assert!(preview.truncated);
+    }
+
+    fn create_tagged_skills() -> Vec<Skill> {
+        let tagged = |id: &str, tags: &[&str]| {
+            let mut skill = create_test_skill(id, id);
+            skill.manifest.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            skill
+        };
+        vec![
+            tagged("docker-build", &["docker", "build"]),
+            tagged("docker-run", &["docker"]),
+            tagged("cargo-build", &["rust", "build"]),
+            tagged("notes", &[]),
+        ]
     }
 
     #[test]
-    fn test_selected_skill_returns_none_when_empty() {
+    fn test_tags_lists_unique_tags_with_counts() {
         // Arrange
-        let state = AppState::new(vec![], create_test_config());
+        let state = AppState::new(create_tagged_skills(), create_test_config());
 
         // Act
-        let selected = state.selected_skill();
+        let tags = state.tags();
 
         // Assert
-        assert!(selected.is_none());
+        assert_eq!(
+            tags,
+            vec![
+                ("build".to_string(), 2),
+                ("docker".to_string(), 2),
+                ("rust".to_string(), 1),
+            ]
+        );
     }
 
     #[test]
-    fn test_move_selection_page_down_advances_by_page_size() {
+    fn test_toggle_tag_filter_narrows_skills() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-            create_test_skill("skill4", "Skill 4"),
-            create_test_skill("skill5", "Skill 5"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(create_tagged_skills(), create_test_config());
 
         // Act
-        state.move_selection_page_down(3);
+        state.toggle_tag_filter("docker");
 
         // Assert
-        assert_eq!(state.selected_index(), 3);
+        assert_eq!(state.filtered_skills, vec![0, 1]);
     }
 
     #[test]
-    fn test_move_selection_page_down_stops_at_end() {
-        // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-        ];
+    fn test_tag_filter_and_search_use_normalized_tags() {
+        // Arrange - tags as a manifest would have them after loading
+        let mut tagged = create_test_skill("build-image", "Build Image");
+        tagged.manifest.tags = vec!["Docker".to_string(), "DOCKER".to_string()];
+        tagged.manifest.normalize_tags();
+        let skills = vec![tagged, create_test_skill("other", "Other")];
         let mut state = AppState::new(skills, create_test_config());
 
         // Act
-        state.move_selection_page_down(10);
+        state.toggle_tag_filter("Docker");
+        let tag_filtered = state.filtered_skills.clone();
+        state.clear_tag_filter();
+        state.set_search_query("DOCKER".to_string());
 
         // Assert
-        assert_eq!(state.selected_index(), 2); // Last index
+        assert_eq!(tag_filtered, vec![0]);
+        assert_eq!(state.filtered_skills, vec![0]);
     }
 
     #[test]
-    fn test_move_selection_page_up_goes_back_by_page_size() {
+    fn test_multiple_tags_are_combined_with_and() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-            create_test_skill("skill4", "Skill 4"),
-            create_test_skill("skill5", "Skill 5"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 4;
+        let mut state = AppState::new(create_tagged_skills(), create_test_config());
 
         // Act
-        state.move_selection_page_up(3);
+        state.toggle_tag_filter("docker");
+        state.toggle_tag_filter("build");
 
         // Assert
-        assert_eq!(state.selected_index(), 1);
+        assert_eq!(state.tag_filter(), ["build", "docker"]);
+        assert_eq!(state.filtered_skills, vec![0]);
     }
 
     #[test]
-    fn test_move_selection_page_up_stops_at_start() {
+    fn test_clear_tag_filter_restores_full_list() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 1;
+        let mut state = AppState::new(create_tagged_skills(), create_test_config());
+        state.toggle_tag_filter("docker");
+        state.toggle_tag_filter("build");
 
         // Act
-        state.move_selection_page_up(10);
+        state.clear_tag_filter();
 
         // Assert
-        assert_eq!(state.selected_index(), 0);
+        assert!(state.tag_filter().is_empty());
+        assert_eq!(state.filtered_skills, vec![0, 1, 2, 3]);
     }
 
     #[test]
-    fn test_scroll_offset_updates_when_selection_moves() {
+    fn test_search_composes_with_tag_filter() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-            create_test_skill("skill4", "Skill 4"),
-            create_test_skill("skill5", "Skill 5"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(create_tagged_skills(), create_test_config());
+        state.toggle_tag_filter("build");
 
-        // Act - move beyond visible area (assuming page size of 2)
-        state.move_selection_page_down(2);
+        // Act
+        state.set_search_query("cargo".to_string());
 
-        // Assert - scroll offset should be non-zero
-        assert_eq!(state.selected_index(), 2);
-        assert_eq!(state.scroll_offset(), 1); // Adjusted to keep selection visible
+        // Assert
+        assert_eq!(state.filtered_skills, vec![2]);
     }
 
     #[test]
-    fn test_append_to_search_updates_query_and_filters() {
+    fn test_toggle_tag_from_picker_toggles_and_clears() {
         // Arrange
-        let skills = vec![
-            create_test_skill("claude-tips", "Claude Tips"),
-            create_test_skill("docker-build", "Docker Build"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(create_tagged_skills(), create_test_config());
+        state.open_tag_picker();
 
-        // Act
-        state.append_to_search('c');
-        state.append_to_search('l');
-        state.append_to_search('a');
-        state.append_to_search('u');
+        // Act - row 0 is "clear tags", row 2 is "docker"
+        state.move_tag_picker_down();
+        state.move_tag_picker_down();
+        state.toggle_tag_from_picker();
+
+        // Assert - picker stays open for further toggling
+        assert!(state.is_tag_picker_visible());
+        assert_eq!(state.tag_filter(), ["docker"]);
+
+        // Act - wrap around to row 0 and clear
+        state.move_tag_picker_up();
+        state.move_tag_picker_up();
+        state.toggle_tag_from_picker();
 
         // Assert
-        assert_eq!(state.search_query(), "clau");
-        // Filtered list should contain claude-tips (fuzzy matches)
-        assert!(state.filtered_count() > 0);
+        assert_eq!(state.tag_picker_index(), 0);
+        assert!(state.tag_filter().is_empty());
+        assert_eq!(state.filtered_skills.len(), 4);
     }
 
     #[test]
-    fn test_remove_from_search_handles_backspace() {
+    fn test_replace_skill_manifest_reloads_edited_file() {
         // Arrange
-        let skills = vec![
-            create_test_skill("claude-tips", "Claude Tips"),
-            create_test_skill("docker-build", "Docker Build"),
-        ];
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("pane-skill.yaml");
+        let write_manifest = |name: &str| {
+            std::fs::write(
+                &manifest_path,
+                format!(
+                    "id: edited\nname: {}\ndescription: d\nversion: 1.0.0\nexec: ls\nui:\n  mode: tui\n",
+                    name
+                ),
+            )
+            .unwrap();
+        };
+        write_manifest("Before");
+        let mut edited = create_test_skill("edited", "Before");
+        edited.manifest_path = manifest_path.clone();
+        let skills = vec![create_test_skill("other", "Other"), edited];
         let mut state = AppState::new(skills, create_test_config());
-        state.set_search_query("clau".to_string());
+        state.selected_index = 1;
+        write_manifest("After");
 
         // Act
-        state.remove_from_search();
+        let manifest = SkillManifest::from_yaml_file(manifest_path.clone()).unwrap();
+        let replaced = state.replace_skill_manifest(&manifest_path, manifest);
 
         // Assert
-        assert_eq!(state.search_query(), "cla");
+        assert!(replaced);
+        assert_eq!(state.skills[1].manifest.name, "After");
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "edited");
     }
 
     #[test]
-    fn test_remove_from_search_handles_empty_query() {
+    fn test_replace_skill_manifest_unknown_path_is_ignored() {
         // Arrange
-        let skills = vec![create_test_skill("skill1", "Skill 1")];
-        let mut state = AppState::new(skills, create_test_config());
+        let mut state = AppState::new(vec![create_test_skill("a", "A")], create_test_config());
+        let manifest = create_test_skill("b", "B").manifest;
 
         // Act
-        state.remove_from_search();
+        let replaced = state.replace_skill_manifest(Path::new("missing.yaml"), manifest);
 
-        // Assert - should be no-op
-        assert_eq!(state.search_query(), "");
+        // Assert
+        assert!(!replaced);
+        assert_eq!(state.skills[0].manifest.id, "a");
     }
 
     #[test]
-    fn test_update_search_query_resets_selected_index() {
+    fn test_record_run_increments_usage_count() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.selected_index = 1;
+        let mut state = AppState::new(vec![create_test_skill("a", "A")], create_test_config());
 
         // Act
-        state.set_search_query("test".to_string());
+        state.record_run("a");
+        state.record_run("a");
 
         // Assert
-        assert_eq!(state.selected_index(), 0);
-        assert_eq!(state.scroll_offset(), 0);
+        assert_eq!(state.usage_count("a"), 2);
+        assert_eq!(state.usage_count("never-run"), 0);
     }
 
     #[test]
-    fn test_update_search_query_empty_shows_all_skills() {
+    fn test_set_usage_keeps_counts_for_undiscovered_ids() {
         // Arrange
-        let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-        state.set_search_query("test".to_string()); // Filter
+        let mut state = AppState::new(vec![create_test_skill("a", "A")], create_test_config());
+        let usage: HashMap<String, u64> = [("a".to_string(), 4), ("removed".to_string(), 9)]
+            .into_iter()
+            .collect();
 
         // Act
-        state.set_search_query("".to_string()); // Clear filter
+        state.set_usage(usage);
 
         // Assert
-        assert_eq!(state.filtered_count(), 3); // All skills visible
+        assert_eq!(state.usage().len(), 2);
+        assert_eq!(state.usage_count("a"), 4);
+        assert_eq!(state.usage_count("removed"), 9);
     }
 
     #[test]
-    fn test_cycle_view_mode_all_to_favorites() {
+    fn test_toggle_detail_pane_starts_from_config() {
         // Arrange
-        let mut state = AppState::new(vec![], create_test_config());
-        assert_eq!(state.view_mode(), &ViewMode::All);
+        let config = Config {
+            show_detail_pane: false,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(vec![], config);
+        let initially_visible = state.is_detail_pane_visible();
 
         // Act
-        state.cycle_view_mode();
+        state.toggle_detail_pane();
 
         // Assert
-        assert_eq!(state.view_mode(), &ViewMode::Favorites);
+        assert!(!initially_visible);
+        assert!(state.is_detail_pane_visible());
     }
 
     #[test]
-    fn test_cycle_view_mode_favorites_to_recent() {
+    fn test_skill_to_run_is_selection_by_default() {
         // Arrange
-        let mut state = AppState::new(vec![], create_test_config());
-        state.cycle_view_mode(); // All -> Favorites
+        let skills = vec![
+            create_test_skill("git-status", "Git Status"),
+            create_test_skill("git-log", "Git Log"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.set_search_query("git".to_string());
+        state.move_selection_down();
 
         // Act
-        state.cycle_view_mode();
+        let skill = state.skill_to_run().unwrap();
 
         // Assert
-        assert_eq!(state.view_mode(), &ViewMode::Recent);
+        assert_eq!(
+            skill.manifest.id,
+            state.selected_skill().unwrap().manifest.id
+        );
     }
 
     #[test]
-    fn test_cycle_view_mode_recent_to_all() {
+    fn test_skill_to_run_is_top_match_when_enabled() {
         // Arrange
-        let mut state = AppState::new(vec![], create_test_config());
-        state.cycle_view_mode(); // All -> Favorites
-        state.cycle_view_mode(); // Favorites -> Recent
+        let skills = vec![
+            create_test_skill("git-status", "Git Status"),
+            create_test_skill("git-log", "Git Log"),
+        ];
+        let config = Config {
+            enter_runs_top_match: true,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+        state.set_search_query("git".to_string());
+        let top = state.selected_skill().unwrap().manifest.id.clone();
+        state.move_selection_down();
 
         // Act
-        state.cycle_view_mode();
+        let skill = state.skill_to_run().unwrap();
 
         // Assert
-        assert_eq!(state.view_mode(), &ViewMode::All);
+        assert_eq!(skill.manifest.id, top);
+        assert_ne!(state.selected_skill().unwrap().manifest.id, top);
     }
 
     #[test]
-    fn test_apply_view_filter_all_shows_all_skills() {
+    fn test_skill_to_run_is_selection_without_query_when_enabled() {
         // Arrange
         let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
         ];
-        let mut state = AppState::new(skills, create_test_config());
+        let config = Config {
+            enter_runs_top_match: true,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
+        state.move_selection_down();
 
         // Act
-        state.apply_view_filter();
+        let skill = state.skill_to_run().unwrap();
 
         // Assert
-        assert_eq!(state.filtered_count(), 3);
-        assert_eq!(state.view_mode(), &ViewMode::All);
+        assert_eq!(
+            skill.manifest.id,
+            state.selected_skill().unwrap().manifest.id
+        );
+        assert_eq!(state.selected_index, 1);
     }
 
     #[test]
-    fn test_apply_view_filter_favorites_shows_only_favorited() {
+    fn test_scroll_detail_keeps_list_selection() {
         // Arrange
         let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
         ];
         let mut state = AppState::new(skills, create_test_config());
-        state.favorites.insert("skill1".to_string());
-        state.favorites.insert("skill3".to_string());
+        state.selected_index = 1;
 
-        // Act - Switch to Favorites mode and apply filter
-        state.cycle_view_mode(); // All -> Favorites
-        state.apply_view_filter();
+        // Act
+        state.scroll_detail_down();
+        state.scroll_detail_down();
+        state.scroll_detail_up();
 
         // Assert
-        assert_eq!(state.filtered_count(), 2);
-        assert_eq!(state.view_mode(), &ViewMode::Favorites);
+        assert_eq!(state.detail_scroll_offset(), 1);
+        assert_eq!(state.selected_index, 1);
     }
 
     #[test]
-    fn test_apply_view_filter_recent_shows_only_recent_skills() {
+    fn test_detail_scroll_resets_when_selection_changes() {
         // Arrange
         let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
+            create_test_skill("a", "Alpha"),
+            create_test_skill("b", "Beta"),
         ];
         let mut state = AppState::new(skills, create_test_config());
-        state.recent.push("skill2".to_string());
-        state.recent.push("skill1".to_string());
+        state.scroll_detail_down();
 
-        // Act - Switch to Recent mode and apply filter
-        state.cycle_view_mode(); // All -> Favorites
-        state.cycle_view_mode(); // Favorites -> Recent
-        state.apply_view_filter();
+        // Act
+        state.move_selection_down();
+        let after_move = state.detail_scroll_offset();
+        state.scroll_detail_up();
 
         // Assert
-        assert_eq!(state.filtered_count(), 2);
-        assert_eq!(state.view_mode(), &ViewMode::Recent);
+        assert_eq!(after_move, 0);
+        assert_eq!(state.detail_scroll_offset(), 0);
     }
 
     #[test]
-    fn test_apply_view_filter_recent_respects_max_recent_limit() {
+    fn test_sort_by_usage_orders_most_run_first() {
         // Arrange
         let skills = vec![
-            create_test_skill("skill1", "Skill 1"),
-            create_test_skill("skill2", "Skill 2"),
-            create_test_skill("skill3", "Skill 3"),
-            create_test_skill("skill4", "Skill 4"),
-            create_test_skill("skill5", "Skill 5"),
+            create_test_skill("rare", "Rare"),
+            create_test_skill("frequent", "Frequent"),
+            create_test_skill("never", "Never"),
         ];
-        let mut config = create_test_config();
-        config.max_recent_skills = 3; // Limit to 3 recent skills
+        let config = Config {
+            sort_by_usage: true,
+            ..create_test_config()
+        };
         let mut state = AppState::new(skills, config);
+        state.record_run("rare");
+        for _ in 0..3 {
+            state.record_run("frequent");
+        }
 
-        // Add 5 recent skills (more than max_recent_skills limit)
-        state.recent.push("skill5".to_string());
-        state.recent.push("skill4".to_string());
-        state.recent.push("skill3".to_string());
-        state.recent.push("skill2".to_string());
-        state.recent.push("skill1".to_string());
-
-        // Act - Switch to Recent mode and apply filter
-        state.cycle_view_mode(); // All -> Favorites
-        state.cycle_view_mode(); // Favorites -> Recent
+        // Act
         state.apply_view_filter();
 
-        // Assert - Only first 3 recent skills should be shown
-        assert_eq!(state.filtered_count(), 3);
-        assert_eq!(state.view_mode(), &ViewMode::Recent);
+        // Assert
+        let ids: Vec<&str> = state
+            .filtered_skills()
+            .map(|skill| skill.manifest.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["frequent", "rare", "never"]);
     }
 
     #[test]
-    fn test_view_and_search_filters_combine_correctly() {
+    fn test_sort_by_estimated_time_orders_quickest_first_unparsed_last() {
         // Arrange
-        let skills = vec![
-            create_test_skill("claude-tips", "Claude Tips"),
-            create_test_skill("docker-build", "Docker Build"),
-            create_test_skill("git-helper", "Git Helper"),
-        ];
-        let mut state = AppState::new(skills, create_test_config());
-
-        // Mark only claude-tips and git-helper as favorites
-        state.favorites.insert("claude-tips".to_string());
-        state.favorites.insert("git-helper".to_string());
-
-        // Switch to Favorites mode
-        state.cycle_view_mode(); // All -> Favorites
+        let skills: Vec<Skill> = [
+            ("vague", "a while"),
+            ("slow", "2h"),
+            ("quick", "30s"),
+            ("medium", "1-3 min"),
+        ]
+        .into_iter()
+        .map(|(id, time)| {
+            let mut skill = create_test_skill(id, id);
+            skill.manifest.estimated_time = Some(time.to_string());
+            skill.parse_estimated_time(true);
+            skill
+        })
+        .collect();
+        let config = Config {
+            parse_estimated_time: true,
+            sort_by_estimated_time: true,
+            ..create_test_config()
+        };
+        let mut state = AppState::new(skills, config);
 
-        // Act - Apply search query within Favorites view
-        state.set_search_query("git".to_string());
+        // Act
         state.apply_view_filter();
 
-        // Assert - Should only show git-helper (in favorites AND matches search)
-        assert_eq!(state.filtered_count(), 1);
-        let filtered_skills: Vec<&Skill> = state.filtered_skills().collect();
-        assert_eq!(filtered_skills[0].manifest.id, "git-helper");
+        // Assert
+        let ids: Vec<&str> = state
+            .filtered_skills()
+            .map(|skill| skill.manifest.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["quick", "medium", "slow", "vague"]);
     }
 
     #[test]
-    fn test_app_state_theme_returns_custom_theme() {
+    fn test_jump_to_prefix_cycles_matches_and_wraps() {
         // Arrange
-        let mut config = create_test_config();
-        let custom_theme = crate::ui::theme::ThemeConfig {
-            primary: ratatui::style::Color::Red,
-            ..crate::ui::theme::ThemeConfig::default()
-        };
-        config.theme = Some(custom_theme.clone());
-        let state = AppState::new(vec![], config);
+        let skills = vec![
+            create_test_skill("docker", "Docker"),
+            create_test_skill("git", "Git Status"),
+            create_test_skill("deploy", "deploy"),
+            create_test_skill("tips", "Tips"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
 
-        // Act
-        let theme = state.theme();
+        // Act & Assert - skips the current selection and ignores case
+        state.jump_to_prefix('d');
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "deploy");
 
-        // Assert
-        assert_eq!(theme.primary, ratatui::style::Color::Red);
+        // Act & Assert - wraps back to the first match
+        state.jump_to_prefix('D');
+        assert_eq!(state.selected_skill().unwrap().manifest.id, "docker");
     }
 
     #[test]
-    fn test_app_state_theme_returns_default_when_none() {
+    fn test_jump_to_prefix_without_match_keeps_selection() {
         // Arrange
-        let config = create_test_config();
-        let state = AppState::new(vec![], config);
+        let skills = vec![
+            create_test_skill("docker", "Docker"),
+            create_test_skill("git", "Git Status"),
+        ];
+        let mut state = AppState::new(skills, create_test_config());
+        state.selected_index = 1;
 
         // Act
-        let theme = state.theme();
+        state.jump_to_prefix('z');
 
         // Assert
-        assert_eq!(theme.primary, ratatui::style::Color::Cyan);
-        assert_eq!(*theme, crate::ui::theme::ThemeConfig::default());
+        assert_eq!(state.selected_index, 1);
     }
 
-    // Output panel state management tests
     #[test]
-    fn test_show_output_panel_sets_visibility() {
+    fn test_jump_to_prefix_empty_list_is_noop() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "test output".to_string(),
-            stderr: String::new(),
-            exit_code: Some(0),
-            truncated: false,
-            execution_time: std::time::Duration::from_millis(100),
-        };
 
         // Act
-        state.show_output_panel(output);
+        state.jump_to_prefix('a');
 
         // Assert
-        assert!(state.is_output_panel_visible());
-        assert!(state.active_output().is_some());
-        assert_eq!(state.output_scroll_offset(), 0);
+        assert_eq!(state.selected_index, 0);
     }
 
-    #[test]
-    fn test_hide_output_panel_clears_state() {
-        // Arrange
-        let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "test output".to_string(),
-            stderr: String::new(),
-            exit_code: Some(0),
+    /// Show output with stdout lines `out-0..out-N` and the given stderr
+    fn show_search_output(state: &mut AppState, stdout_lines: usize, stderr: &str) {
+        let stdout: Vec<String> = (0..stdout_lines).map(|i| format!("out-{}", i)).collect();
+        state.show_output_panel(crate::skills::output::SkillOutput {
+            stdout: stdout.join("\n"),
+            stderr: stderr.to_string(),
+            exit_code: Some(1),
             truncated: false,
             execution_time: std::time::Duration::from_millis(100),
-        };
-        state.show_output_panel(output);
-
-        // Act
-        state.hide_output_panel();
-
-        // Assert
-        assert!(!state.is_output_panel_visible());
-        assert!(state.active_output().is_none());
+            format: OutputFormat::Text,
+            attempts: 1,
+        });
     }
 
     #[test]
-    fn test_scroll_output_up_decrements_offset() {
+    fn test_output_search_matches_span_stdout_and_stderr() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "line1\nline2\nline3".to_string(),
-            stderr: String::new(),
-            exit_code: Some(0),
-            truncated: false,
-            execution_time: std::time::Duration::from_millis(100),
-        };
-        state.show_output_panel(output);
-        state.scroll_output_down(); // Scroll down first
+        show_search_output(&mut state, 3, "warning: OUT-of-date\nfatal");
+        state.start_output_search();
+        for c in "out-".chars() {
+            state.push_output_search_char(c);
+        }
 
         // Act
-        state.scroll_output_up();
+        let matches = state.output_search_matches();
 
-        // Assert
-        assert_eq!(state.output_scroll_offset(), 0);
+        // Assert - stderr starts after the 3 stdout lines and the separator
+        assert_eq!(matches, vec![0, 1, 2, 4]);
     }
 
     #[test]
-    fn test_scroll_output_up_stops_at_zero() {
+    fn test_output_search_submit_starts_at_scroll_position() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "line1\nline2\nline3".to_string(),
-            stderr: String::new(),
-            exit_code: Some(0),
-            truncated: false,
-            execution_time: std::time::Duration::from_millis(100),
-        };
-        state.show_output_panel(output);
+        state.set_output_viewport_height(2);
+        show_search_output(&mut state, 12, "");
+        state.scroll_output_half_page_down();
+        state.scroll_output_half_page_down();
+        state.start_output_search();
+        state.push_output_search_char('1');
 
-        // Act
-        state.scroll_output_up();
-        state.scroll_output_up();
+        // Act - matches are out-1, out-10, out-11; scrolled to line 2
+        state.submit_output_search();
 
         // Assert
-        assert_eq!(state.output_scroll_offset(), 0);
+        assert_eq!(state.current_output_match(), Some(10));
+        assert_eq!(state.output_scroll_offset(), 10);
     }
 
     #[test]
-    fn test_scroll_output_down_increments_offset() {
+    fn test_output_search_n_and_shift_n_cycle() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        // Create output with 25 lines (more than visible height of 20)
-        let mut lines = Vec::new();
-        for i in 1..=25 {
-            lines.push(format!("line{}", i));
-        }
-        let output = crate::skills::output::SkillOutput {
-            stdout: lines.join("\n"),
-            stderr: String::new(),
-            exit_code: Some(0),
-            truncated: false,
-            execution_time: std::time::Duration::from_millis(100),
-        };
-        state.show_output_panel(output);
-
-        // Act
-        state.scroll_output_down();
-
-        // Assert
+        state.set_output_viewport_height(2);
+        show_search_output(&mut state, 12, "");
+        state.start_output_search();
+        state.push_output_search_char('1');
+        // Matches "1": out-1, out-10, out-11
+
+        // Act & Assert - Enter jumps to the first match
+        state.submit_output_search();
+        assert_eq!(state.current_output_match(), Some(1));
         assert_eq!(state.output_scroll_offset(), 1);
+
+        // Act & Assert - n moves forward and wraps
+        state.next_output_match();
+        assert_eq!(state.current_output_match(), Some(10));
+        state.next_output_match();
+        assert_eq!(state.current_output_match(), Some(11));
+        assert_eq!(state.output_scroll_offset(), 10);
+        state.next_output_match();
+        assert_eq!(state.current_output_match(), Some(1));
+
+        // Act & Assert - N moves backward and wraps
+        state.prev_output_match();
+        assert_eq!(state.current_output_match(), Some(11));
     }
 
     #[test]
-    fn test_scroll_output_down_stops_at_max() {
+    fn test_output_search_restores_input_mode() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "line1\nline2\nline3".to_string(),
-            stderr: String::new(),
-            exit_code: Some(0),
-            truncated: false,
-            execution_time: std::time::Duration::from_millis(100),
-        };
-        state.show_output_panel(output);
+        show_search_output(&mut state, 3, "");
 
-        // Act - scroll down 10 times (but output only has 3 lines, so max_offset = 0)
-        for _ in 0..10 {
-            state.scroll_output_down();
-        }
+        // Act
+        state.start_output_search();
+        let typing_mode = state.input_mode().clone();
+        state.cancel_output_search();
 
-        // Assert - should stop at 0 (3 lines - 20 visible height assumption = 0, clamped)
-        assert_eq!(state.output_scroll_offset(), 0);
+        // Assert
+        assert_eq!(typing_mode, InputMode::Insert);
+        assert_eq!(*state.input_mode(), InputMode::Normal);
+        assert!(!state.is_output_search_active());
+        assert!(state.output_search_query().is_empty());
     }
 
     #[test]
-    fn test_finish_inline_execution_shows_output_panel() {
+    fn test_output_lines_follow_pretty_printed_json() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-        let output = crate::skills::output::SkillOutput {
-            stdout: "test output".to_string(),
+        state.set_output_viewport_height(2);
+        state.show_output_panel(crate::skills::output::SkillOutput {
+            stdout: r#"{"name":"pane","tags":["tui","cli"]}"#.to_string(),
             stderr: String::new(),
             exit_code: Some(0),
             truncated: false,
             execution_time: std::time::Duration::from_millis(100),
-        };
-        state.start_inline_execution("test-skill".to_string());
+            format: OutputFormat::Json,
+            attempts: 1,
+        });
+        state.start_output_search();
+        for c in "cli".chars() {
+            state.push_output_search_char(c);
+        }
 
         // Act
-        state.finish_inline_execution(output);
-
-        // Assert
-        assert!(!state.is_executing_inline());
-        assert!(state.is_output_panel_visible());
-        assert!(state.active_output().is_some());
-    }
-
-    // InputMode tests
-    #[test]
-    fn test_input_mode_defaults_to_normal() {
-        // Arrange & Act
-        let state = AppState::new(vec![], create_test_config());
-
-        // Assert
-        assert_eq!(state.input_mode(), &InputMode::Normal);
-        assert!(state.is_normal_mode());
-        assert!(!state.is_insert_mode());
+        let line_count = state.output_line_count();
+        let max_offset = state.max_output_scroll_offset();
+        let matches = state.output_search_matches();
+
+        // Assert - compact JSON is drawn as 7 pretty-printed lines
+        assert_eq!(line_count, 7);
+        assert_eq!(max_offset, 5);
+        assert_eq!(matches, vec![4]);
     }
 
     #[test]
-    fn test_enter_insert_mode_changes_state() {
+    fn test_cycle_output_stream_view_selects_stream_lines() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
+        show_search_output(&mut state, 2, "err-a\nerr-b\nerr-c");
+        let lines = |state: &AppState| -> Vec<String> {
+            state.output_lines().iter().map(line_text).collect()
+        };
 
         // Act
-        state.enter_insert_mode();
+        let combined = lines(&state);
+        state.cycle_output_stream_view();
+        let stdout_only = lines(&state);
+        state.cycle_output_stream_view();
+        let stderr_only = lines(&state);
+        state.cycle_output_stream_view();
 
         // Assert
-        assert_eq!(state.input_mode(), &InputMode::Insert);
-        assert!(state.is_insert_mode());
-        assert!(!state.is_normal_mode());
+        assert_eq!(combined.len(), 6);
+        assert_eq!(combined[2], "─── Error Output ───");
+        assert_eq!(stdout_only, vec!["out-0", "out-1"]);
+        assert_eq!(stderr_only, vec!["err-a", "err-b", "err-c"]);
+        assert_eq!(state.output_stream_view(), OutputStreamView::Combined);
     }
 
     #[test]
-    fn test_enter_normal_mode_changes_state() {
-        // Arrange
+    fn test_cycle_output_stream_view_clamps_scroll_per_stream() {
+        // Arrange - 20 stdout lines, 3 stderr lines, 5 visible
         let mut state = AppState::new(vec![], create_test_config());
-        state.enter_insert_mode();
-
-        // Act
-        state.enter_normal_mode();
+        state.set_output_viewport_height(5);
+        show_search_output(&mut state, 20, "e1\ne2\ne3");
+        state.scroll_output_to_bottom();
+        assert_eq!(state.output_scroll_offset(), 19);
+
+        // Act & Assert - stdout only: 20 lines
+        state.cycle_output_stream_view();
+        assert_eq!(state.output_scroll_offset(), 15);
+        state.scroll_output_down();
+        assert_eq!(state.output_scroll_offset(), 15);
 
-        // Assert
-        assert_eq!(state.input_mode(), &InputMode::Normal);
-        assert!(state.is_normal_mode());
-        assert!(!state.is_insert_mode());
+        // Act & Assert - stderr only: 3 lines fit in the viewport
+        state.cycle_output_stream_view();
+        assert_eq!(state.output_scroll_offset(), 0);
+        state.scroll_output_to_bottom();
+        assert_eq!(state.output_scroll_offset(), 0);
     }
 
     #[test]
-    fn test_is_insert_mode_returns_correct_state() {
+    fn test_output_search_matches_follow_stream_view() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-
-        // Assert initial state
-        assert!(!state.is_insert_mode());
+        show_search_output(&mut state, 3, "out-err");
+        state.start_output_search();
+        for c in "out".chars() {
+            state.push_output_search_char(c);
+        }
+        state.submit_output_search();
 
         // Act
-        state.enter_insert_mode();
+        state.cycle_output_stream_view();
+        state.cycle_output_stream_view();
 
-        // Assert after mode change
-        assert!(state.is_insert_mode());
+        // Assert - only the stderr line remains, numbered from zero
+        assert_eq!(state.output_search_matches(), vec![0]);
+        assert_eq!(state.current_output_match(), None);
     }
 
     #[test]
-    fn test_is_normal_mode_returns_correct_state() {
+    fn test_show_output_panel_resets_stream_view() {
         // Arrange
         let mut state = AppState::new(vec![], create_test_config());
-
-        // Assert initial state
-        assert!(state.is_normal_mode());
+        show_search_output(&mut state, 1, "err");
+        state.cycle_output_stream_view();
 
         // Act
-        state.enter_insert_mode();
+        show_search_output(&mut state, 1, "err");
 
-        // Assert after mode change
-        assert!(!state.is_normal_mode());
+        // Assert
+        assert_eq!(state.output_stream_view(), OutputStreamView::Combined);
     }
 }
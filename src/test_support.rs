@@ -0,0 +1,52 @@
+//! Fixtures shared by the unit tests
+//!
+//! Tests build skills from these instead of full struct literals, so adding
+//! a manifest field only touches `test_manifest`.
+
+use std::path::PathBuf;
+
+use crate::skills::manifest::{ContextConfig, OutputFormat, SkillManifest, UiConfig, UiMode};
+use crate::skills::{Skill, SkillSource};
+
+/// Manifest for a TUI skill running `test`, named after its ID
+pub(crate) fn test_manifest(id: &str) -> SkillManifest {
+    SkillManifest {
+        id: id.to_string(),
+        name: id.to_string(),
+        description: "Test skill".to_string(),
+        version: "1.0.0".to_string(),
+        exec: "test".to_string(),
+        args: vec![],
+        tags: vec![],
+        estimated_time: None,
+        ui: UiConfig {
+            mode: UiMode::Tui,
+            fullscreen: true,
+            capture: false,
+        },
+        context: ContextConfig::default(),
+        category: None,
+        hidden: false,
+        output_format: OutputFormat::Text,
+        long_description: None,
+        schema_version: 1,
+        exec_windows: None,
+        exec_macos: None,
+        exec_linux: None,
+        post_run: None,
+        accent: None,
+        retries: 0,
+        pty: false,
+        hint: None,
+    }
+}
+
+/// Project skill wrapping `test_manifest(id)`, loaded from `test.yaml`
+pub(crate) fn test_skill(id: &str) -> Skill {
+    Skill {
+        manifest: test_manifest(id),
+        source: SkillSource::Project,
+        manifest_path: PathBuf::from("test.yaml"),
+        estimated_duration: None,
+    }
+}
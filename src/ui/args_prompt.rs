@@ -0,0 +1,135 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::state::AppState;
+use crate::ui::output_panel::centered_rect;
+
+/// Prefix drawn before the typed arguments
+const PROMPT_PREFIX: &str = "> ";
+
+/// Render the launch-arguments prompt as a modal overlay
+///
+/// Shows the arguments typed so far for the skill Enter would run, with the
+/// terminal cursor after them. Enter runs the skill with the arguments
+/// appended to its manifest args; Esc closes the prompt.
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui Frame to render into
+/// * `state` - Application state containing the arguments being typed
+///
+/// # Returns
+///
+/// Returns early if the prompt is not open.
+pub fn render_args_prompt(frame: &mut Frame, state: &AppState) {
+    let Some(input) = state.args_input() else {
+        return;
+    };
+
+    let theme = state.theme();
+    let translations = state.translations();
+    let title = match state.skill_to_run() {
+        Some(skill) => format!(
+            "{}: {}",
+            translations.args_prompt_title,
+            skill.display_name()
+        ),
+        None => translations.args_prompt_title.to_string(),
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(PROMPT_PREFIX, Style::default().fg(theme.primary)),
+            Span::raw(input),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            translations.args_prompt_hint,
+            Style::default()
+                .fg(theme.text_dim)
+                .add_modifier(Modifier::ITALIC),
+        )),
+    ];
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(theme.border_style)
+        .border_style(Style::default().fg(theme.primary));
+
+    let area = centered_rect(60, 20, frame.size());
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    // Keep the cursor after the typed text, within the box
+    let offset = (PROMPT_PREFIX.width() + input.width()).min(usize::from(inner.width));
+    frame.set_cursor(inner.x + offset as u16, inner.y);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::skills::Skill;
+    use crate::test_support::test_skill;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn create_test_skill(id: &str, name: &str) -> Skill {
+        let mut skill = test_skill(id);
+        skill.manifest.name = name.to_string();
+        skill
+    }
+
+    fn render_to_string(state: &AppState) -> String {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_args_prompt(frame, state))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_args_prompt_shows_typed_arguments() {
+        // Arrange
+        let skill = create_test_skill("deploy", "Deploy");
+        let mut state = AppState::new(vec![skill], Config::default());
+        state.start_args_input();
+        for c in "--dry-run".chars() {
+            state.push_args_input_char(c);
+        }
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("Run with extra arguments: Deploy"));
+        assert!(rendered.contains("> --dry-run"));
+        assert!(rendered.contains("Enter to run"));
+    }
+
+    #[test]
+    fn test_render_args_prompt_when_closed_renders_nothing() {
+        // Arrange
+        let state = AppState::new(vec![], Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.trim().is_empty());
+    }
+}
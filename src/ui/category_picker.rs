@@ -0,0 +1,130 @@
+use ratatui::{
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::state::AppState;
+use crate::ui::output_panel::centered_rect;
+
+/// Render the category picker as a modal overlay
+///
+/// Lists "all categories" followed by every category derived from the
+/// discovered skills, highlighting the picker's current row. The active
+/// category filter is marked so users can see what is currently applied.
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui Frame to render into
+/// * `state` - Application state containing the categories and picker selection
+///
+/// # Returns
+///
+/// Returns early if the category picker is not visible.
+pub fn render_category_picker(frame: &mut Frame, state: &AppState) {
+    if !state.is_category_picker_visible() {
+        return;
+    }
+
+    let theme = state.theme();
+    let translations = state.translations();
+    let active = state.category_filter();
+
+    // "All categories" row first, then each category
+    let mut rows = vec![(
+        translations.category_all_label.to_string(),
+        active.is_none(),
+    )];
+    rows.extend(state.categories().into_iter().map(|category| {
+        let is_active = active == Some(category.as_str());
+        (category, is_active)
+    }));
+
+    let items: Vec<ListItem> = rows
+        .into_iter()
+        .map(|(label, is_active)| {
+            let marker = if is_active { "● " } else { "  " };
+            ListItem::new(format!("{}{}", marker, label))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(translations.category_picker_title)
+                .borders(Borders::ALL)
+                .border_type(theme.border_style)
+                .border_style(Style::default().fg(theme.primary)),
+        )
+        .highlight_style(theme.selected_style());
+
+    let mut list_state = ListState::default().with_selected(Some(state.category_picker_index()));
+
+    let area = centered_rect(50, 60, frame.size());
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::skills::Skill;
+    use crate::test_support::test_skill;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn create_test_skill(id: &str, category: Option<&str>) -> Skill {
+        let mut skill = test_skill(id);
+        skill.manifest.category = category.map(String::from);
+        skill
+    }
+
+    fn render_to_string(state: &AppState) -> String {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_category_picker(frame, state))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_category_picker_lists_categories() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", Some("git")),
+            create_test_skill("skill2", None),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        state.open_category_picker();
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("Categories"));
+        assert!(rendered.contains("All categories"));
+        assert!(rendered.contains("git"));
+        assert!(rendered.contains("Uncategorized"));
+    }
+
+    #[test]
+    fn test_render_category_picker_hidden_renders_nothing() {
+        // Arrange
+        let skills = vec![create_test_skill("skill1", Some("git"))];
+        let state = AppState::new(skills, Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(!rendered.contains("Categories"));
+    }
+}
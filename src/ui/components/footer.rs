@@ -1,12 +1,16 @@
 use ratatui::{
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, Paragraph,
+    },
     Frame,
 };
 
 use crate::i18n::Translations;
+use crate::skills::SkillSource;
 use crate::state::{InputMode, ViewMode};
 use crate::ui::theme::ThemeConfig;
 
@@ -22,6 +26,10 @@ use crate::ui::theme::ThemeConfig;
 /// * `frame` - The ratatui frame to render into
 /// * `view_mode` - The current view mode (All/Favorites/Recent)
 /// * `input_mode` - The current input mode (Normal/Insert)
+/// * `category` - The active category filter, if any
+/// * `tags` - The active tag filter (empty if none)
+/// * `source_counts` - Number of skills per source, shown dimmed on the bottom border
+/// * `translations` - UI translations for hint and label text
 /// * `theme` - Theme configuration for styling
 ///
 /// # Mode-Specific Display
@@ -37,22 +45,28 @@ use crate::ui::theme::ThemeConfig;
 /// use pane::state::{ViewMode, InputMode};
 /// use pane::ui::components::footer::render_footer;
 /// use pane::ui::theme::ThemeConfig;
+/// use pane::i18n::{Language, Translations};
 ///
 /// let backend = TestBackend::new(80, 3);
 /// let mut terminal = Terminal::new(backend).unwrap();
 /// let view_mode = ViewMode::All;
 /// let input_mode = InputMode::Normal;
+/// let translations = Translations::load(Language::En);
 /// let theme = ThemeConfig::default();
 ///
 /// terminal.draw(|frame| {
-///     render_footer(frame.size(), frame, &view_mode, &input_mode, &theme);
+///     render_footer(frame.size(), frame, &view_mode, &input_mode, None, &[], &[], &translations, &theme);
 /// }).unwrap();
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn render_footer(
     area: Rect,
     frame: &mut Frame,
     view_mode: &ViewMode,
     input_mode: &InputMode,
+    category: Option<&str>,
+    tags: &[String],
+    source_counts: &[(SkillSource, usize)],
     translations: &Translations,
     theme: &ThemeConfig,
 ) {
@@ -60,6 +74,7 @@ pub fn render_footer(
         ViewMode::All => translations.footer_view_all,
         ViewMode::Favorites => translations.footer_view_favorites,
         ViewMode::Recent => translations.footer_view_recent,
+        ViewMode::Disabled => translations.footer_view_disabled,
     };
 
     // Build mode-specific footer content
@@ -97,18 +112,68 @@ pub fn render_footer(
         Style::default().fg(theme.primary),
     ));
 
+    // Active category filter indicator
+    if let Some(category) = category {
+        footer_spans.push(Span::raw(" | "));
+        footer_spans.push(Span::styled(
+            format!("{} {}", translations.footer_category_label, category),
+            Style::default().fg(theme.secondary),
+        ));
+    }
+
+    // Active tag filter indicator
+    if !tags.is_empty() {
+        footer_spans.push(Span::raw(" | "));
+        footer_spans.push(Span::styled(
+            format!("{} {}", translations.footer_tags_label, tags.join(", ")),
+            Style::default().fg(theme.secondary),
+        ));
+    }
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(theme.border_style)
+        .border_style(theme.border_style());
+
+    // Skill counts per source (informational, right side of the bottom border)
+    if let Some(counts) = source_counts_text(source_counts) {
+        block = block.title(
+            Title::from(Span::styled(
+                format!(" {} ", counts),
+                Style::default().fg(theme.text_dim),
+            ))
+            .alignment(Alignment::Right)
+            .position(Position::Bottom),
+        );
+    }
+
     let footer = Paragraph::new(Line::from(footer_spans))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(theme.border_style)
-                .border_style(theme.border_style()),
-        )
+        .block(block)
         .style(Style::default());
 
     frame.render_widget(footer, area);
 }
 
+/// Format per-source skill counts, e.g. "12 project • 3 user • 30 system"
+///
+/// # Arguments
+///
+/// * `source_counts` - Number of skills per source, in display order
+///
+/// # Returns
+///
+/// The counts joined with bullets, or `None` if there are no skills
+fn source_counts_text(source_counts: &[(SkillSource, usize)]) -> Option<String> {
+    if source_counts.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = source_counts
+        .iter()
+        .map(|(source, count)| format!("{} {}", count, source.to_string().to_lowercase()))
+        .collect();
+    Some(parts.join(" • "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,6 +199,9 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
@@ -159,6 +227,9 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
@@ -184,6 +255,9 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
@@ -209,6 +283,9 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
@@ -234,6 +311,9 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
@@ -259,10 +339,123 @@ mod tests {
                     frame,
                     &view_mode,
                     &input_mode,
+                    None,
+                    &[],
+                    &[],
                     &translations,
                     &theme,
                 );
             })
             .unwrap();
     }
+
+    #[test]
+    fn test_render_footer_shows_active_category() {
+        // Arrange
+        let backend = TestBackend::new(80, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let view_mode = ViewMode::All;
+        let input_mode = InputMode::Normal;
+        let translations = Translations::load(Language::En);
+        let theme = ThemeConfig::default();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_footer(
+                    frame.size(),
+                    frame,
+                    &view_mode,
+                    &input_mode,
+                    Some("git"),
+                    &[],
+                    &[],
+                    &translations,
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Category: git"));
+    }
+
+    #[test]
+    fn test_render_footer_shows_active_tags() {
+        // Arrange
+        let backend = TestBackend::new(120, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let view_mode = ViewMode::All;
+        let input_mode = InputMode::Normal;
+        let translations = Translations::load(Language::En);
+        let theme = ThemeConfig::default();
+        let tags = vec!["build".to_string(), "docker".to_string()];
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_footer(
+                    frame.size(),
+                    frame,
+                    &view_mode,
+                    &input_mode,
+                    None,
+                    &tags,
+                    &[],
+                    &translations,
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Tags: build, docker"));
+    }
+
+    #[test]
+    fn test_render_footer_shows_source_counts_on_bottom_border() {
+        // Arrange
+        let backend = TestBackend::new(120, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let translations = Translations::load(Language::En);
+        let theme = ThemeConfig::default();
+        let counts = [(SkillSource::Project, 12), (SkillSource::User, 3)];
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_footer(
+                    frame.size(),
+                    frame,
+                    &ViewMode::All,
+                    &InputMode::Normal,
+                    None,
+                    &[],
+                    &counts,
+                    &translations,
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let bottom_row: String = (0..120).map(|x| buffer.get(x, 2).symbol()).collect();
+        assert!(bottom_row.contains("12 project • 3 user"));
+        assert_eq!(buffer.get(115, 2).fg, theme.text_dim);
+    }
 }
@@ -4,5 +4,8 @@
 /// terminal user interface.
 pub mod detail_pane;
 pub mod footer;
+pub mod output_preview;
+pub mod scrollbar;
 pub mod search_bar;
 pub mod skill_list;
+pub mod status_toast;
@@ -0,0 +1,161 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::skills::output::SkillOutput;
+use crate::ui::theme::ThemeConfig;
+
+/// Render a compact preview of a skill's most recent output
+///
+/// Shows a one-line status summary (exit code and execution time) followed by
+/// as many leading stdout lines as fit, dimmed. Stderr is shown in red when
+/// there is no stdout.
+///
+/// # Arguments
+///
+/// * `area` - The rectangular area to render into
+/// * `frame` - The ratatui frame to render into
+/// * `output` - The last output recorded for the selected skill
+/// * `title` - Translated block title
+/// * `theme` - Theme configuration for styling
+pub fn render_output_preview(
+    area: Rect,
+    frame: &mut Frame,
+    output: &SkillOutput,
+    title: &str,
+    theme: &ThemeConfig,
+) {
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(theme.border_style)
+        .border_style(theme.border_style());
+    // Rows left for output after the borders and the status line
+    let body_rows = block.inner(area).height.saturating_sub(1) as usize;
+
+    let paragraph = Paragraph::new(preview_lines(output, body_rows, theme)).block(block);
+    frame.render_widget(paragraph, area);
+}
+
+/// Build the status line plus up to `max_rows` output lines
+fn preview_lines(output: &SkillOutput, max_rows: usize, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let (status, status_color) = match output.exit_code {
+        Some(0) => ("✓".to_string(), theme.success),
+        Some(code) => (format!("✗ {}", code), theme.error),
+        None => ("✗".to_string(), theme.error),
+    };
+    let mut summary = vec![
+        Span::styled(status, Style::default().fg(status_color)),
+        Span::styled(
+            format!("  {}ms", output.execution_time.as_millis()),
+            Style::default().fg(theme.text_dim),
+        ),
+    ];
+    if output.truncated {
+        summary.push(Span::styled(
+            "  (truncated)",
+            Style::default().fg(theme.warning),
+        ));
+    }
+
+    let (body, body_style) = if output.stdout.trim().is_empty() {
+        (&output.stderr, Style::default().fg(theme.error))
+    } else {
+        (&output.stdout, Style::default().fg(theme.text_dim))
+    };
+
+    let mut lines = vec![Line::from(summary)];
+    lines.extend(
+        body.lines()
+            .take(max_rows)
+            .map(|line| Line::from(Span::styled(line.to_string(), body_style))),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::manifest::OutputFormat;
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Color;
+    use ratatui::Terminal;
+    use std::time::Duration;
+
+    fn create_output(stdout: &str, stderr: &str, exit_code: Option<i32>) -> SkillOutput {
+        SkillOutput {
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+            exit_code,
+            truncated: false,
+            execution_time: Duration::from_millis(42),
+            format: OutputFormat::Text,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn test_preview_lines_limits_rows() {
+        // Arrange
+        let output = create_output("one\ntwo\nthree\nfour", "", Some(0));
+
+        // Act
+        let lines = preview_lines(&output, 2, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].to_string(), "✓  42ms");
+        assert_eq!(lines[1].to_string(), "one");
+        assert_eq!(lines[2].to_string(), "two");
+    }
+
+    #[test]
+    fn test_preview_lines_shows_stderr_when_stdout_empty() {
+        // Arrange
+        let output = create_output("", "boom", Some(2));
+
+        // Act
+        let lines = preview_lines(&output, 5, &ThemeConfig::default());
+
+        // Assert
+        assert!(lines[0].to_string().starts_with("✗ 2"));
+        assert_eq!(lines[1].to_string(), "boom");
+        assert_eq!(lines[1].spans[0].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_render_output_preview_draws_title_and_output() {
+        // Arrange
+        let backend = TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let output = create_output("hello preview", "", Some(0));
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_output_preview(
+                    frame.size(),
+                    frame,
+                    &output,
+                    "Last output",
+                    &ThemeConfig::default(),
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Last output"));
+        assert!(rendered.contains("hello preview"));
+    }
+}
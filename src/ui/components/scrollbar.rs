@@ -0,0 +1,66 @@
+use ratatui::{
+    layout::Rect,
+    symbols,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+use crate::ui::theme::ThemeConfig;
+
+/// Render a vertical scrollbar along the right edge of an area
+///
+/// Nothing is drawn when `max_offset` is 0, i.e. the content fits. The thumb
+/// size follows the share of the content visible in the viewport, and it
+/// reaches the bottom of the track at `max_offset`.
+///
+/// # Arguments
+///
+/// * `area` - The area whose rightmost column holds the scrollbar
+/// * `frame` - The ratatui frame to render into
+/// * `offset` - Current scroll offset
+/// * `max_offset` - Largest scroll offset for the content
+/// * `viewport` - Number of items or lines visible at once
+/// * `theme` - Theme configuration for the track and thumb colors
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::backend::TestBackend;
+/// use ratatui::Terminal;
+/// use pane::ui::components::scrollbar::render_scrollbar;
+/// use pane::ui::theme::ThemeConfig;
+///
+/// let backend = TestBackend::new(20, 10);
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// let theme = ThemeConfig::default();
+///
+/// terminal.draw(|frame| {
+///     render_scrollbar(frame.size(), frame, 5, 40, 10, &theme);
+/// }).unwrap();
+/// ```
+pub fn render_scrollbar(
+    area: Rect,
+    frame: &mut Frame,
+    offset: usize,
+    max_offset: usize,
+    viewport: usize,
+    theme: &ThemeConfig,
+) {
+    if max_offset == 0 || area.width == 0 {
+        return;
+    }
+
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .symbols(symbols::scrollbar::VERTICAL)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_style(theme.scrollbar_track_style())
+        .thumb_style(theme.scrollbar_thumb_style());
+
+    // One position per scroll offset, so the last offset puts the thumb at the bottom
+    let mut state = ScrollbarState::new(max_offset + 1)
+        .position(offset.min(max_offset))
+        .viewport_content_length(viewport);
+
+    frame.render_stateful_widget(scrollbar, area, &mut state);
+}
@@ -8,10 +8,13 @@ use ratatui::{
 
 use crate::ui::theme::ThemeConfig;
 
+/// Label shown before the query text
+const SEARCH_LABEL: &str = "Search: ";
+
 /// Render a search bar for filtering skills
 ///
-/// Displays a bordered input area showing the current search query with a cursor
-/// indicator when focused. The search bar allows users to type queries that filter
+/// Displays a bordered input area showing the current search query with a visible
+/// terminal cursor when focused (Insert mode). The search bar allows users to type queries that filter
 /// the skill list in real-time using fuzzy matching.
 ///
 /// # Arguments
@@ -19,21 +22,23 @@ use crate::ui::theme::ThemeConfig;
 /// * `area` - The rectangular area to render the search bar into
 /// * `frame` - The ratatui frame to render into
 /// * `query` - The current search query string
+/// * `cursor` - Cursor position within the query (in characters)
 /// * `is_focused` - Whether the search bar is currently focused (shows cursor)
+/// * `placeholder` - Text shown when the query is empty
 /// * `theme` - Theme configuration for styling
 ///
 /// # Layout
 ///
 /// ```text
 /// ┌─ Search ───────────────────────────────────────┐
-/// │ Search: query_                                 │
+/// │ Search: query█                                 │
 /// └────────────────────────────────────────────────┘
 /// ```
 ///
 /// - Border: Thin border with "Search" title, styled with theme colors
 /// - Label: "Search: " prefix before query text
-/// - Cursor: `_` character at end of query when focused
-/// - Placeholder: "Type to search..." shown when query is empty and focused
+/// - Cursor: Terminal cursor placed at the cursor position when focused, hidden otherwise
+/// - Placeholder: "Type to search..." shown dimmed when query is empty
 /// - Height: 3 lines (border top + content + border bottom)
 ///
 /// # Example
@@ -46,35 +51,33 @@ use crate::ui::theme::ThemeConfig;
 ///
 /// fn render(frame: &mut Frame, query: &str, area: Rect) {
 ///     let theme = ThemeConfig::default();
-///     render_search_bar(area, frame, query, true, &theme);
+///     render_search_bar(area, frame, query, query.chars().count(), true, "Type to search...", &theme);
 /// }
 /// ```
 pub fn render_search_bar(
     area: Rect,
     frame: &mut Frame,
     query: &str,
+    cursor: usize,
     is_focused: bool,
     placeholder: &str,
     theme: &ThemeConfig,
 ) {
-    // Format the search text with cursor when focused
-    let search_text = if query.is_empty() && is_focused {
-        format!("Search: {}_", placeholder)
+    // Query text, or a dimmed placeholder when empty
+    let query_span = if query.is_empty() {
+        Span::styled(placeholder.to_string(), Style::default().fg(theme.text_dim))
     } else if is_focused {
-        format!("Search: {}_", query)
+        Span::styled(query.to_string(), Style::default().fg(theme.text))
     } else {
-        format!("Search: {}", query)
+        Span::styled(query.to_string(), Style::default().fg(theme.text_dim))
     };
 
-    // Create text line with appropriate styling
-    let line = Line::from(vec![Span::styled(
-        search_text,
-        if is_focused {
-            Style::default().fg(theme.text)
-        } else {
-            Style::default().fg(theme.text_dim)
-        },
-    )]);
+    let label_style = if is_focused {
+        Style::default().fg(theme.text)
+    } else {
+        Style::default().fg(theme.text_dim)
+    };
+    let line = Line::from(vec![Span::styled(SEARCH_LABEL, label_style), query_span]);
 
     // Create paragraph widget with border
     let paragraph = Paragraph::new(line).block(
@@ -91,6 +94,37 @@ pub fn render_search_bar(
 
     // Render the search bar
     frame.render_widget(paragraph, area);
+
+    // Show the terminal cursor only while typing (Insert mode)
+    if is_focused {
+        let (x, y) = cursor_position(area, query, cursor);
+        frame.set_cursor(x, y);
+    }
+}
+
+/// Calculate the terminal cursor position within the query
+///
+/// Accounts for the border, the "Search: " label, and the display width of
+/// the query text before the cursor (wide characters occupy two cells). The position is clamped to
+/// stay inside the search bar's inner area.
+///
+/// # Arguments
+///
+/// * `area` - The search bar area (including borders)
+/// * `query` - The current search query string
+/// * `cursor` - Cursor position within the query (in characters)
+///
+/// # Returns
+///
+/// The `(x, y)` terminal cell where the cursor should be drawn
+pub fn cursor_position(area: Rect, query: &str, cursor: usize) -> (u16, u16) {
+    let before_cursor: String = query.chars().take(cursor).collect();
+    let text_width = Span::raw(SEARCH_LABEL).width() + Span::raw(before_cursor).width();
+    let inner_right = area.x + area.width.saturating_sub(2);
+    let x = (area.x + 1)
+        .saturating_add(text_width as u16)
+        .min(inner_right);
+    (x, area.y + 1)
 }
 
 #[cfg(test)]
@@ -110,7 +144,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 60, 3);
-                render_search_bar(area, frame, "", true, "Type to search...", &theme);
+                render_search_bar(area, frame, "", 0, true, "Type to search...", &theme);
             })
             .unwrap();
 
@@ -129,7 +163,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 60, 3);
-                render_search_bar(area, frame, "clau", true, "Type to search...", &theme);
+                render_search_bar(area, frame, "clau", 4, true, "Type to search...", &theme);
             })
             .unwrap();
 
@@ -147,7 +181,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 60, 3);
-                render_search_bar(area, frame, "test", false, "Type to search...", &theme);
+                render_search_bar(area, frame, "test", 4, false, "Type to search...", &theme);
             })
             .unwrap();
 
@@ -165,13 +199,107 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 60, 3);
-                render_search_bar(area, frame, "", false, "Type to search...", &theme);
+                render_search_bar(area, frame, "", 0, false, "Type to search...", &theme);
             })
             .unwrap();
 
         // Assert - should render without panicking
     }
 
+    #[test]
+    fn test_render_search_bar_focused_places_cursor_after_query() {
+        // Arrange
+        let backend = TestBackend::new(60, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let theme = ThemeConfig::default();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 60, 3);
+                render_search_bar(area, frame, "clau", 4, true, "Type to search...", &theme);
+            })
+            .unwrap();
+
+        // Assert - border (1) + "Search: " (8) + "clau" (4)
+        assert_eq!(terminal.get_cursor().unwrap(), (13, 1));
+    }
+
+    #[test]
+    fn test_render_search_bar_unfocused_hides_cursor() {
+        // Arrange
+        let backend = TestBackend::new(60, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let theme = ThemeConfig::default();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 60, 3);
+                render_search_bar(area, frame, "clau", 4, false, "Type to search...", &theme);
+            })
+            .unwrap();
+
+        // Assert - cursor was never moved into the search bar
+        assert_eq!(terminal.get_cursor().unwrap(), (0, 0));
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(!rendered.contains('_'));
+    }
+
+    #[test]
+    fn test_cursor_position_empty_query_sits_after_label() {
+        // Arrange
+        let area = Rect::new(0, 3, 60, 3);
+
+        // Act
+        let position = cursor_position(area, "", 0);
+
+        // Assert
+        assert_eq!(position, (9, 4));
+    }
+
+    #[test]
+    fn test_cursor_position_counts_wide_characters() {
+        // Arrange
+        let area = Rect::new(0, 0, 60, 3);
+
+        // Act
+        let position = cursor_position(area, "검색", 2);
+
+        // Assert - each Hangul syllable is two cells wide
+        assert_eq!(position, (13, 1));
+    }
+
+    #[test]
+    fn test_cursor_position_mid_query() {
+        // Arrange
+        let area = Rect::new(0, 0, 60, 3);
+
+        // Act
+        let position = cursor_position(area, "clau", 1);
+
+        // Assert - border (1) + "Search: " (8) + "c" (1)
+        assert_eq!(position, (10, 1));
+    }
+
+    #[test]
+    fn test_cursor_position_clamped_to_inner_area() {
+        // Arrange
+        let area = Rect::new(0, 0, 20, 3);
+
+        // Act
+        let position = cursor_position(area, "a very long query that overflows", 32);
+
+        // Assert
+        assert_eq!(position, (18, 1));
+    }
+
     #[test]
     fn test_render_search_bar_long_query() {
         // Arrange
@@ -184,7 +312,15 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 60, 3);
-                render_search_bar(area, frame, long_query, true, "Type to search...", &theme);
+                render_search_bar(
+                    area,
+                    frame,
+                    long_query,
+                    long_query.chars().count(),
+                    true,
+                    "Type to search...",
+                    &theme,
+                );
             })
             .unwrap();
 
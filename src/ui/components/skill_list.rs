@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
@@ -5,10 +7,31 @@ use ratatui::{
     widgets::{List, ListItem},
     Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use crate::search::match_indices;
 use crate::skills::Skill;
+use crate::ui::components::scrollbar::render_scrollbar;
 use crate::ui::theme::ThemeConfig;
 
+/// Number of terminal rows used by each skill item (name line + description line)
+pub const ITEM_HEIGHT: u16 = 2;
+
+/// Marker that prefixes each unselected skill name
+const DEFAULT_MARKER: &str = "●";
+
+/// Marker that prefixes favorite skills in place of `DEFAULT_MARKER`
+const FAVORITE_MARKER: &str = "★";
+
+/// Maximum display width of the description line (including indentation)
+const MAX_DESCRIPTION_WIDTH: usize = 80;
+
+/// Extra items built on each side of the visible window
+const RENDER_BUFFER_ITEMS: usize = 2;
+
+/// Narrowest name the list keeps before dropping tags and estimated time
+const MIN_NAME_WIDTH: usize = 12;
+
 /// Render a scrollable list of skills
 ///
 /// Displays all provided skills in a vertical list with the specified item
@@ -21,19 +44,32 @@ use crate::ui::theme::ThemeConfig;
 /// * `skills` - Slice of skill references to display
 /// * `selected` - Index of the currently selected skill (for highlighting)
 /// * `scroll_offset` - Scroll offset to control which items are visible
+/// * `query` - Current search query; characters of each name it matches are highlighted
+/// * `favorites` - IDs of favorite skills, marked with a ★
 /// * `theme` - Theme configuration for styling
 ///
 /// # Layout
 ///
 /// Each skill item is formatted with two lines:
 /// - Line 1: Name (bold) + Tags [tag1] [tag2] + Estimated time (⏱ X min)
-/// - Line 2: Description (indented, truncated if >80 chars)
+/// - Line 2: Description (indented, truncated if >80 columns)
+///
+/// Both lines are truncated to the list width so rows never wrap or overflow.
+/// When a long name is truncated, the visible window shifts so the first
+/// character matched by the query stays in view.
 ///
-/// The selected skill is highlighted with theme colors.
+/// The selected skill is highlighted with theme colors, plus the theme's
+/// selection marker when one is configured. Other favorite skills are marked
+/// with a ★ instead of the usual dot. A skill's `accent` color, if
+/// set and the theme allows accents, tints its marker.
+///
+/// When there are more skills than fit, the rightmost column holds a
+/// scrollbar showing the scroll position.
 ///
 /// # Example
 ///
 /// ```no_run
+/// use std::collections::HashSet;
 /// use ratatui::Frame;
 /// use ratatui::layout::Rect;
 /// # use pane::skills::Skill;
@@ -42,80 +78,363 @@ use crate::ui::theme::ThemeConfig;
 ///
 /// fn render(frame: &mut Frame, skills: Vec<&Skill>, selected_index: usize, scroll_offset: usize, area: Rect) {
 ///     let theme = ThemeConfig::default();
-///     render_skill_list(area, frame, &skills, selected_index, scroll_offset, &theme);
+///     let favorites = HashSet::new();
+///     render_skill_list(area, frame, &skills, selected_index, scroll_offset, "", &favorites, &theme);
 /// }
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn render_skill_list(
     area: Rect,
     frame: &mut Frame,
     skills: &[&Skill],
     selected: usize,
     scroll_offset: usize,
+    query: &str,
+    favorites: &HashSet<String>,
     theme: &ThemeConfig,
 ) {
-    // Format each skill into a ListItem
-    let items: Vec<ListItem> = skills
-        .iter()
-        .map(|skill| format_skill_item(skill, theme))
-        .collect();
+    // Leave the rightmost column for a scrollbar when not every skill fits
+    let full_items = usize::from(area.height / ITEM_HEIGHT);
+    let max_offset = skills.len().saturating_sub(full_items);
+    let list_area = if max_offset > 0 {
+        Rect {
+            width: area.width.saturating_sub(1),
+            ..area
+        }
+    } else {
+        area
+    };
+
+    // Format only the skills that can be on screen into ListItems
+    let width = list_area.width as usize;
+    let viewport = usize::from(area.height).div_ceil(usize::from(ITEM_HEIGHT));
+    let (start, items) =
+        build_visible_items(skills, selected, scroll_offset, viewport, |idx, skill| {
+            let favorite = favorites.contains(&skill.manifest.id);
+            format_skill_item(&skill, theme, width, query, idx == selected, favorite)
+        });
 
     // Create the list widget with theme-based highlighting
     let list = List::new(items).highlight_style(theme.selected_style());
 
-    // Create list state with selection and scroll offset
+    // Create list state with selection and scroll offset, relative to the window
     let mut list_state = ratatui::widgets::ListState::default()
-        .with_selected(Some(selected))
-        .with_offset(scroll_offset);
+        .with_selected(Some(selected.saturating_sub(start)))
+        .with_offset(scroll_offset.saturating_sub(start));
 
     // Render the list as a stateful widget with selection
-    frame.render_stateful_widget(list, area, &mut list_state);
+    frame.render_stateful_widget(list, list_area, &mut list_state);
+
+    let offset = list_offset(selected, scroll_offset, full_items);
+    render_scrollbar(area, frame, offset, max_offset, full_items, theme);
+}
+
+/// Offset the list uses once it scrolls to keep the selection in view
+fn list_offset(selected: usize, scroll_offset: usize, viewport: usize) -> usize {
+    scroll_offset
+        .min(selected)
+        .max((selected + 1).saturating_sub(viewport))
+}
+
+/// Build list items for the skills that can be visible, plus a small buffer
+///
+/// The window covers `viewport` items from `scroll_offset`, moved to the
+/// selected skill if it lies outside (the list scrolls to show it), then
+/// padded by `RENDER_BUFFER_ITEMS` on each side. Rendering cost stays
+/// proportional to the viewport however many skills are filtered.
+///
+/// # Arguments
+///
+/// * `skills` - All skills in the list
+/// * `selected` - Index of the selected skill
+/// * `scroll_offset` - Index of the first visible skill
+/// * `viewport` - Number of items visible at once
+/// * `build` - Builds the item for a skill, given its index in `skills`
+///
+/// # Returns
+///
+/// The index of the first built item and the items, in order
+fn build_visible_items<'a>(
+    skills: &[&'a Skill],
+    selected: usize,
+    scroll_offset: usize,
+    viewport: usize,
+    mut build: impl FnMut(usize, &'a Skill) -> ListItem<'static>,
+) -> (usize, Vec<ListItem<'static>>) {
+    let first = list_offset(selected, scroll_offset, viewport);
+    let start = first.saturating_sub(RENDER_BUFFER_ITEMS).min(skills.len());
+    let end = (first + viewport + RENDER_BUFFER_ITEMS).min(skills.len());
+
+    let items = skills[start..end]
+        .iter()
+        .enumerate()
+        .map(|(offset, skill)| build(start + offset, skill))
+        .collect();
+    (start, items)
+}
+
+/// Truncate text to a display width, ending with an ellipsis when cut
+///
+/// Widths are measured in terminal columns, so double-width characters
+/// (e.g. CJK) count as two. Text that already fits is returned unchanged.
+///
+/// # Arguments
+///
+/// * `text` - The text to truncate
+/// * `max_width` - Maximum display width in columns (including the ellipsis)
+///
+/// # Returns
+///
+/// Text whose display width is at most `max_width`
+///
+/// # Examples
+///
+/// ```
+/// use pane::ui::components::skill_list::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("Docker Build", 8), "Docker …");
+/// assert_eq!(truncate_to_width("한국어 스킬", 6), "한국…");
+/// assert_eq!(truncate_to_width("Git", 8), "Git");
+/// ```
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Reserve one column for the ellipsis
+    let budget = max_width - 1;
+    let mut truncated = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > budget {
+            break;
+        }
+        used += ch_width;
+        truncated.push(ch);
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// Truncate text to a display width while keeping the first matched character visible
+///
+/// Behaves like `truncate_to_width` unless the earliest match would be cut
+/// off, in which case the head of the text is replaced by an ellipsis and the
+/// visible window starts as late as needed to show that match (with as much
+/// preceding text as still fits).
+///
+/// # Arguments
+///
+/// * `text` - The text to truncate
+/// * `max_width` - Maximum display width in columns (including ellipses)
+/// * `matches` - Sorted character indices into `text` that should stay visible
+///
+/// # Returns
+///
+/// The truncated text and the character indices of the matches that remain
+/// visible, relative to the returned text
+///
+/// # Examples
+///
+/// ```
+/// use pane::ui::components::skill_list::truncate_around_matches;
+///
+/// let (text, matches) = truncate_around_matches("Run Integration Tests", 10, &[16]);
+/// assert_eq!(text, "…ion Tests");
+/// assert_eq!(matches, vec![5]);
+/// ```
+pub fn truncate_around_matches(
+    text: &str,
+    max_width: usize,
+    matches: &[usize],
+) -> (String, Vec<usize>) {
+    let chars: Vec<char> = text.chars().collect();
+    let char_width = |ch: &char| ch.width().unwrap_or(0);
+    let head_width: usize = matches
+        .first()
+        .map(|&first| chars.iter().take(first + 1).map(char_width).sum())
+        .unwrap_or(0);
+
+    // Plain tail truncation already shows the first match (or there is none)
+    if text.width() <= max_width || head_width < max_width || max_width < 2 {
+        let truncated = truncate_to_width(text, max_width);
+        let visible = if truncated == text {
+            chars.len()
+        } else {
+            // Drop the trailing ellipsis
+            truncated.chars().count().saturating_sub(1)
+        };
+        let kept = matches
+            .iter()
+            .copied()
+            .filter(|&idx| idx < visible)
+            .collect();
+        return (truncated, kept);
+    }
+
+    // Start the window at the first match, leaving a column for the leading ellipsis
+    let budget = max_width - 1;
+    let mut start = matches[0];
+    let tail_width: usize = chars[start..].iter().map(char_width).sum();
+
+    let window: String = if tail_width <= budget {
+        // The rest of the text fits: pull in preceding characters to fill the row
+        let mut used = tail_width;
+        while start > 0 && used + char_width(&chars[start - 1]) <= budget {
+            start -= 1;
+            used += char_width(&chars[start]);
+        }
+        chars[start..].iter().collect()
+    } else {
+        truncate_to_width(&chars[start..].iter().collect::<String>(), budget)
+    };
+
+    // Only matches inside the window (excluding a trailing ellipsis) stay visible
+    let window_chars = window.chars().count();
+    let visible = if tail_width > budget {
+        window_chars - 1
+    } else {
+        window_chars
+    };
+    let kept = matches
+        .iter()
+        .filter(|&&idx| idx >= start && idx - start < visible)
+        .map(|&idx| idx - start + 1)
+        .collect();
+
+    (format!("…{}", window), kept)
+}
+
+/// Split a name into spans, styling the matched characters
+///
+/// # Arguments
+///
+/// * `name` - The (already truncated) name to render
+/// * `matches` - Sorted character indices into `name` to highlight
+/// * `base` - Style for unmatched characters
+/// * `highlight` - Style for matched characters
+///
+/// # Returns
+///
+/// Spans that together render `name`, one per run of matched/unmatched characters
+fn highlighted_name_spans(
+    name: &str,
+    matches: &[usize],
+    base: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (idx, ch) in name.chars().enumerate() {
+        let matched = matches.binary_search(&idx).is_ok();
+        if matched != run_matched && !run.is_empty() {
+            let style = if run_matched { highlight } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = matched;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        let style = if run_matched { highlight } else { base };
+        spans.push(Span::styled(run, style));
+    }
+
+    spans
 }
 
 /// Format a single skill into a ListItem with metadata
 ///
 /// Creates a two-line list item with the skill's name, tags, estimated time,
-/// and description. Handles missing optional fields gracefully.
+/// and description. Handles missing optional fields gracefully. The name is
+/// truncated to leave room for tags and estimated time; when the row is too
+/// narrow for both, the metadata is dropped in favor of the name.
 ///
 /// # Arguments
 ///
 /// * `skill` - Reference to the skill to format
 /// * `theme` - Theme configuration for styling
+/// * `width` - Available row width in columns
+/// * `query` - Current search query, used to highlight matched name characters
+/// * `selected` - Whether this is the selected row (uses the theme's selection marker)
 ///
 /// # Returns
 ///
 /// A `ListItem` ready for rendering in a `List` widget
-fn format_skill_item(skill: &&Skill, theme: &ThemeConfig) -> ListItem<'static> {
-    // Line 1: Name (bold) + Tags + Estimated time
-    let mut line1_spans = vec![
-        Span::styled("● ", Style::default().fg(theme.primary)),
-        Span::styled(
-            skill.manifest.name.clone(),
-            Style::default().add_modifier(Modifier::BOLD),
-        ),
-        Span::raw("  "),
-    ];
+fn format_skill_item(
+    skill: &&Skill,
+    theme: &ThemeConfig,
+    width: usize,
+    query: &str,
+    selected: bool,
+    favorite: bool,
+) -> ListItem<'static> {
+    let marker = match &theme.selected_marker {
+        Some(marker) if selected => format!("{} ", marker),
+        _ if favorite => format!("{} ", FAVORITE_MARKER),
+        _ => format!("{} ", DEFAULT_MARKER),
+    };
+
+    // Line 1 metadata: Tags + Estimated time
+    let mut metadata_spans = Vec::new();
 
     // Add tags if present with chip-style formatting
     if !skill.manifest.tags.is_empty() {
         let tags_text = format!("[{}]", skill.manifest.tags.join("] ["));
-        line1_spans.push(Span::styled(tags_text, theme.tag_style()));
-        line1_spans.push(Span::raw("  "));
+        metadata_spans.push(Span::raw("  "));
+        metadata_spans.push(Span::styled(tags_text, theme.tag_style()));
     }
 
     // Add estimated time if present with icon prefix
-    if let Some(ref time) = skill.manifest.estimated_time {
-        line1_spans.push(Span::styled(format!("⏱ {}", time), theme.time_style()));
+    if let Some(time) = skill.estimated_time_label() {
+        metadata_spans.push(Span::raw("  "));
+        metadata_spans.push(Span::styled(format!("⏱ {}", time), theme.time_style()));
     }
 
-    let line1 = Line::from(line1_spans);
+    let metadata_width: usize = metadata_spans.iter().map(|span| span.width()).sum();
+    let name_width = skill.display_name().width();
+    let available = width.saturating_sub(marker.width());
 
-    // Line 2: Description (indented, truncated if too long)
-    let description = if skill.manifest.description.len() > 80 {
-        format!("  {}...", &skill.manifest.description[..77])
+    // Keep metadata only if the name still gets a reasonable share of the row
+    let keep_metadata = name_width + metadata_width <= available
+        || available.saturating_sub(metadata_width) >= MIN_NAME_WIDTH;
+    let name_budget = if keep_metadata {
+        available.saturating_sub(metadata_width)
     } else {
-        format!("  {}", skill.manifest.description)
+        available
     };
 
+    // Line 1: Name (bold, matched characters highlighted) + Tags + Estimated time
+    let name_style = Style::default().add_modifier(Modifier::BOLD);
+    let matches = match_indices(query, skill.display_name());
+    let (name, visible_matches) =
+        truncate_around_matches(skill.display_name(), name_budget, &matches);
+    let marker_color = theme.accent_color(skill.manifest.accent);
+    let mut line1_spans = vec![Span::styled(marker, Style::default().fg(marker_color))];
+    line1_spans.extend(highlighted_name_spans(
+        &name,
+        &visible_matches,
+        name_style,
+        name_style.fg(theme.secondary),
+    ));
+    if keep_metadata {
+        line1_spans.extend(metadata_spans);
+    }
+
+    let line1 = Line::from(line1_spans);
+
+    // Line 2: Description (indented, truncated if too long)
+    let description_width = width.min(MAX_DESCRIPTION_WIDTH).saturating_sub(2);
+    let description = format!(
+        "  {}",
+        truncate_to_width(&skill.manifest.description, description_width)
+    );
+
     let line2 = Line::from(vec![Span::styled(
         description,
         Style::default().fg(theme.text_dim),
@@ -128,11 +447,8 @@ fn format_skill_item(skill: &&Skill, theme: &ThemeConfig) -> ListItem<'static> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::skills::{
-        manifest::{ContextConfig, SkillManifest, UiConfig, UiMode},
-        SkillSource,
-    };
-    use std::path::PathBuf;
+    use crate::test_support::test_skill;
+    use ratatui::style::Color;
 
     fn create_test_skill(
         name: &str,
@@ -140,25 +456,12 @@ mod tests {
         tags: Vec<String>,
         estimated_time: Option<String>,
     ) -> Skill {
-        Skill {
-            manifest: SkillManifest {
-                id: "test-skill".to_string(),
-                name: name.to_string(),
-                description: description.to_string(),
-                version: "1.0.0".to_string(),
-                exec: "test".to_string(),
-                args: vec![],
-                tags,
-                estimated_time,
-                ui: UiConfig {
-                    mode: UiMode::Tui,
-                    fullscreen: true,
-                },
-                context: ContextConfig::default(),
-            },
-            source: SkillSource::Project,
-            manifest_path: PathBuf::from("test.yaml"),
-        }
+        let mut skill = test_skill("test-skill");
+        skill.manifest.name = name.to_string();
+        skill.manifest.description = description.to_string();
+        skill.manifest.tags = tags;
+        skill.manifest.estimated_time = estimated_time;
+        skill
     }
 
     #[test]
@@ -173,7 +476,7 @@ mod tests {
         let theme = ThemeConfig::default();
 
         // Act
-        let item = format_skill_item(&&skill, &theme);
+        let item = format_skill_item(&&skill, &theme, 120, "", false, false);
 
         // Assert
         // Item should have 2 lines
@@ -192,7 +495,7 @@ mod tests {
         let theme = ThemeConfig::default();
 
         // Act
-        let item = format_skill_item(&&skill, &theme);
+        let item = format_skill_item(&&skill, &theme, 120, "", false, false);
 
         // Assert
         assert_eq!(item.height(), 2);
@@ -210,7 +513,7 @@ mod tests {
         let theme = ThemeConfig::default();
 
         // Act
-        let item = format_skill_item(&&skill, &theme);
+        let item = format_skill_item(&&skill, &theme, 120, "", false, false);
 
         // Assert
         assert_eq!(item.height(), 2);
@@ -224,7 +527,7 @@ mod tests {
         let theme = ThemeConfig::default();
 
         // Act
-        let item = format_skill_item(&&skill, &theme);
+        let item = format_skill_item(&&skill, &theme, 120, "", false, false);
 
         // Assert
         assert_eq!(item.height(), 2);
@@ -238,9 +541,378 @@ mod tests {
         let theme = ThemeConfig::default();
 
         // Act
-        let item = format_skill_item(&&skill, &theme);
+        let item = format_skill_item(&&skill, &theme, 120, "", false, false);
 
         // Assert
         assert_eq!(item.height(), 2);
     }
+
+    #[test]
+    fn test_truncate_to_width_ascii_adds_ellipsis() {
+        // Arrange
+        let text = "Docker Build and Push";
+
+        // Act
+        let truncated = truncate_to_width(text, 10);
+
+        // Assert
+        assert_eq!(truncated, "Docker Bu…");
+        assert_eq!(truncated.width(), 10);
+    }
+
+    #[test]
+    fn test_truncate_to_width_counts_cjk_as_double_width() {
+        // Arrange
+        let text = "깃 상태 확인하기";
+
+        // Act
+        let truncated = truncate_to_width(text, 7);
+
+        // Assert
+        // "깃 상" is 5 columns; adding "태" (2) would exceed the 6 columns before the ellipsis
+        assert_eq!(truncated, "깃 상…");
+        assert!(truncated.width() <= 7);
+    }
+
+    #[test]
+    fn test_truncate_to_width_short_text_unchanged() {
+        // Arrange & Act & Assert
+        assert_eq!(truncate_to_width("Git", 10), "Git");
+        assert_eq!(truncate_to_width("0123456789", 10), "0123456789");
+        assert_eq!(truncate_to_width("", 0), "");
+        assert_eq!(truncate_to_width("Git", 0), "");
+    }
+
+    #[test]
+    fn test_format_skill_item_fits_narrow_width() {
+        // Arrange
+        let skill = create_test_skill(
+            "A Skill With An Extremely Long Name That Overflows",
+            "A description that is also far too long for a narrow list column",
+            vec!["tag1".to_string(), "tag2".to_string()],
+            Some("1-3 min".to_string()),
+        );
+        let theme = ThemeConfig::default();
+
+        // Act
+        let item = format_skill_item(&&skill, &theme, 24, "", false, false);
+        let backend = ratatui::backend::TestBackend::new(24, 2);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(List::new(vec![item]), frame.size());
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..24).map(|x| buffer.get(x, y).symbol()).collect() };
+        assert!(row(0).starts_with("● A Skill With An Extre…"));
+        assert!(row(1).trim_end().ends_with('…'));
+    }
+
+    #[test]
+    fn test_render_skill_list_stars_favorites() {
+        // Arrange
+        let plain = create_test_skill("Plain", "Not a favorite", vec![], None);
+        let mut favorite = create_test_skill("Starred", "A favorite", vec![], None);
+        favorite.manifest.id = "starred".to_string();
+        let favorites: HashSet<String> = HashSet::from(["starred".to_string()]);
+        let theme = ThemeConfig::default();
+        let backend = ratatui::backend::TestBackend::new(30, 4);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_skill_list(
+                    frame.size(),
+                    frame,
+                    &[&favorite, &plain],
+                    1,
+                    0,
+                    "",
+                    &favorites,
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.get(0, 0).symbol(), "★");
+        assert_eq!(buffer.get(0, 2).symbol(), "●");
+    }
+
+    #[test]
+    fn test_display_name_falls_back_to_id() {
+        // Arrange
+        let skill = create_test_skill("   ", "Description", vec![], None);
+
+        // Act & Assert
+        assert_eq!(skill.display_name(), "test-skill");
+    }
+
+    #[test]
+    fn test_render_skill_list_selected_row_uses_marker_and_bold() {
+        // Arrange
+        let first = create_test_skill("First", "One", vec![], None);
+        let second = create_test_skill("Second", "Two", vec![], None);
+        let theme = ThemeConfig {
+            selected_marker: Some("▶".to_string()),
+            selected_bold: true,
+            ..ThemeConfig::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(30, 4);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_skill_list(
+                    frame.size(),
+                    frame,
+                    &[&first, &second],
+                    1,
+                    0,
+                    "",
+                    &HashSet::new(),
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..30).map(|x| buffer.get(x, y).symbol()).collect() };
+        assert!(row(0).starts_with("● First"));
+        assert!(row(2).starts_with("▶ Second"));
+        assert!(buffer.get(0, 2).modifier.contains(Modifier::BOLD));
+        assert!(!buffer.get(0, 0).modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_truncate_around_matches_keeps_late_match_visible() {
+        // Arrange
+        let text = "Deploy Application To Production Cluster";
+        let matches = match_indices("cluster", text);
+
+        // Act
+        let (truncated, visible) = truncate_around_matches(text, 16, &matches);
+
+        // Assert
+        assert_eq!(truncated, "…duction Cluster");
+        assert_eq!(truncated.width(), 16);
+        let highlighted: String = truncated
+            .chars()
+            .enumerate()
+            .filter(|(idx, _)| visible.contains(idx))
+            .map(|(_, ch)| ch)
+            .collect();
+        assert_eq!(highlighted, "Cluster");
+    }
+
+    #[test]
+    fn test_truncate_around_matches_trims_both_ends_for_middle_match() {
+        // Arrange
+        let text = "Run The Very Long Integration Test Suite Now";
+
+        // Act
+        let (truncated, visible) = truncate_around_matches(text, 12, &[18, 19]);
+
+        // Assert
+        assert_eq!(truncated, "…Integratio…");
+        assert_eq!(visible, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_truncate_around_matches_early_match_truncates_tail() {
+        // Arrange & Act
+        let (truncated, visible) =
+            truncate_around_matches("Docker Build and Push", 10, &[0, 7, 17]);
+
+        // Assert
+        assert_eq!(truncated, "Docker Bu…");
+        assert_eq!(visible, vec![0, 7]);
+        assert_eq!(
+            truncate_around_matches("Git", 0, &[0]),
+            (String::new(), vec![])
+        );
+    }
+
+    #[test]
+    fn test_format_skill_item_shows_match_near_end_of_long_name() {
+        // Arrange
+        let skill = create_test_skill(
+            "A Skill With An Extremely Long Name Ending In Kubernetes",
+            "Description",
+            vec![],
+            None,
+        );
+        let theme = ThemeConfig::default();
+
+        // Act
+        let item = format_skill_item(&&skill, &theme, 24, "kubernetes", false, false);
+        let backend = ratatui::backend::TestBackend::new(24, 2);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(List::new(vec![item]), frame.size());
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..24).map(|x| buffer.get(x, 0).symbol()).collect();
+        assert_eq!(row, "● … Ending In Kubernetes");
+        assert_eq!(buffer.get(14, 0).fg, theme.secondary);
+    }
+
+    #[test]
+    fn test_render_skill_list_accent_tints_marker() {
+        // Arrange
+        let mut accented = create_test_skill("Drop Database", "Destructive", vec![], None);
+        accented.manifest.accent = Some(Color::Red);
+        let plain = create_test_skill("Status", "Safe", vec![], None);
+        let theme = ThemeConfig::default();
+        let backend = ratatui::backend::TestBackend::new(30, 4);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_skill_list(
+                    frame.size(),
+                    frame,
+                    &[&plain, &accented],
+                    0,
+                    0,
+                    "",
+                    &HashSet::new(),
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.get(0, 0).fg, theme.primary);
+        assert_eq!(buffer.get(0, 2).fg, Color::Red);
+    }
+
+    #[test]
+    fn test_build_visible_items_only_builds_visible_window() {
+        // Arrange
+        let skill = create_test_skill("Skill", "Description", vec![], None);
+        let skills: Vec<&Skill> = vec![&skill; 5000];
+        let mut built = Vec::new();
+
+        // Act
+        let (start, items) = build_visible_items(&skills, 2003, 2000, 10, |idx, _| {
+            built.push(idx);
+            ListItem::new("skill")
+        });
+
+        // Assert - 10 visible items plus 2 on each side
+        assert_eq!(start, 1998);
+        assert_eq!(items.len(), 14);
+        assert_eq!(built, (1998..2012).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_build_visible_items_reaches_selection_outside_window() {
+        // Arrange
+        let skill = create_test_skill("Skill", "Description", vec![], None);
+        let skills: Vec<&Skill> = vec![&skill; 100];
+
+        // Act
+        let (start, items) = build_visible_items(&skills, 50, 0, 10, |_, _| ListItem::new("skill"));
+
+        // Assert - the list scrolls to show 41..=50, plus 2 on each side
+        assert_eq!(start, 39);
+        assert_eq!(items.len(), 14);
+    }
+
+    #[test]
+    fn test_render_skill_list_scrolled_shows_same_rows() {
+        // Arrange
+        let skills: Vec<Skill> = (0..50)
+            .map(|i| create_test_skill(&format!("Skill {}", i), "Description", vec![], None))
+            .collect();
+        let refs: Vec<&Skill> = skills.iter().collect();
+        let theme = ThemeConfig::default();
+        let backend = ratatui::backend::TestBackend::new(30, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_skill_list(
+                    frame.size(),
+                    frame,
+                    &refs,
+                    21,
+                    20,
+                    "",
+                    &HashSet::new(),
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..30).map(|x| buffer.get(x, y).symbol()).collect() };
+        assert!(row(0).starts_with("● Skill 20"));
+        assert!(row(2).starts_with("● Skill 21"));
+        assert!(row(4).starts_with("● Skill 22"));
+        assert_eq!(buffer.get(2, 2).bg, theme.selected_style().bg.unwrap());
+    }
+
+    /// Symbols in the rightmost column after rendering `count` skills into 30x6
+    fn last_column(count: usize) -> String {
+        let skills: Vec<Skill> = (0..count)
+            .map(|i| create_test_skill(&format!("Skill {}", i), "Description", vec![], None))
+            .collect();
+        let refs: Vec<&Skill> = skills.iter().collect();
+        let theme = ThemeConfig::default();
+        let backend = ratatui::backend::TestBackend::new(30, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_skill_list(
+                    frame.size(),
+                    frame,
+                    &refs,
+                    0,
+                    0,
+                    "",
+                    &HashSet::new(),
+                    &theme,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..6).map(|y| buffer.get(29, y).symbol()).collect()
+    }
+
+    #[test]
+    fn test_render_skill_list_draws_scrollbar_when_skills_overflow() {
+        // Act
+        let column = last_column(20);
+
+        // Assert - thumb at the top of a full-height track
+        assert!(column.starts_with('█'));
+        assert!(column.ends_with('│'));
+    }
+
+    #[test]
+    fn test_render_skill_list_omits_scrollbar_when_skills_fit() {
+        // Act
+        let column = last_column(3);
+
+        // Assert
+        assert_eq!(column.trim(), "");
+    }
 }
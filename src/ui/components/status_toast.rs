@@ -0,0 +1,142 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Span,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::ui::theme::ThemeConfig;
+
+/// Render a transient status message as a toast
+///
+/// Draws the message in a small bordered box anchored to the right edge of
+/// the given area (typically the footer), clearing whatever was underneath.
+/// Messages wider than the area are truncated by the terminal. Error
+/// messages are drawn in red instead of yellow.
+///
+/// # Arguments
+///
+/// * `area` - The area to anchor the toast in
+/// * `frame` - The ratatui frame to render into
+/// * `message` - The status message to display
+/// * `is_error` - Whether the message reports a failure
+/// * `theme` - Theme configuration for styling
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::backend::TestBackend;
+/// use ratatui::Terminal;
+/// use pane::ui::components::status_toast::render_status_toast;
+/// use pane::ui::theme::ThemeConfig;
+///
+/// let backend = TestBackend::new(80, 3);
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// let theme = ThemeConfig::default();
+///
+/// terminal.draw(|frame| {
+///     render_status_toast(frame.size(), frame, "Config reloaded", false, &theme);
+/// }).unwrap();
+/// ```
+pub fn render_status_toast(
+    area: Rect,
+    frame: &mut Frame,
+    message: &str,
+    is_error: bool,
+    theme: &ThemeConfig,
+) {
+    let color = if is_error { theme.error } else { theme.warning };
+
+    // Message width plus borders and one column of padding on each side
+    let width = (Span::raw(message).width() as u16)
+        .saturating_add(4)
+        .min(area.width);
+    let toast_area = Rect {
+        x: area.x + area.width - width,
+        y: area.y,
+        width,
+        height: area.height.min(3),
+    };
+
+    let toast = Paragraph::new(format!(" {} ", message))
+        .style(Style::default().fg(color))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(theme.border_style)
+                .border_style(Style::default().fg(color)),
+        );
+
+    frame.render_widget(Clear, toast_area);
+    frame.render_widget(toast, toast_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Color;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_render_status_toast_displays_message_at_right_edge() {
+        // Arrange
+        let backend = TestBackend::new(40, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let theme = ThemeConfig::default();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_status_toast(frame.size(), frame, "Saved", false, &theme);
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let middle_row: String = (0..40).map(|x| buffer.get(x, 1).symbol()).collect();
+        assert!(middle_row.ends_with(" Saved │"));
+    }
+
+    #[test]
+    fn test_render_status_toast_truncates_to_area_width() {
+        // Arrange
+        let backend = TestBackend::new(10, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let theme = ThemeConfig::default();
+
+        // Act & Assert - rendering a long message should not panic
+        terminal
+            .draw(|frame| {
+                render_status_toast(
+                    frame.size(),
+                    frame,
+                    "A very long status message",
+                    false,
+                    &theme,
+                );
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_render_status_toast_error_is_red() {
+        // Arrange
+        let backend = TestBackend::new(40, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let theme = ThemeConfig::default();
+
+        // Act
+        terminal
+            .draw(|frame| {
+                render_status_toast(frame.size(), frame, "Failed", true, &theme);
+            })
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.get(39, 1).fg, Color::Red);
+        assert_eq!(buffer.get(37, 1).fg, Color::Red);
+    }
+}
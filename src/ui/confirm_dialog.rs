@@ -0,0 +1,98 @@
+use ratatui::{
+    layout::Alignment,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::state::{AppState, Confirmation};
+use crate::ui::output_panel::centered_rect;
+
+/// Render the pending confirmation prompt as a modal overlay
+///
+/// Shows the question for the active `Confirmation` in a small centered box.
+/// The user answers with `y` (confirm) or `n`/Esc (cancel).
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui Frame to render into
+/// * `state` - Application state containing the pending confirmation
+///
+/// # Returns
+///
+/// Returns early if no confirmation is pending.
+pub fn render_confirm_dialog(frame: &mut Frame, state: &AppState) {
+    let Some(confirmation) = state.pending_confirmation() else {
+        return;
+    };
+
+    let translations = state.translations();
+    let theme = state.theme();
+    let message = match confirmation {
+        Confirmation::QuitWhileExecuting => translations.confirm_quit_while_executing,
+    };
+
+    let dialog = Paragraph::new(message)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title(translations.confirm_title)
+                .borders(Borders::ALL)
+                .border_type(theme.border_style)
+                .border_style(Style::default().fg(theme.warning)),
+        );
+
+    let area = centered_rect(60, 20, frame.size());
+    frame.render_widget(Clear, area);
+    frame.render_widget(dialog, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn render_to_string(state: &AppState) -> String {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_confirm_dialog(frame, state))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_confirm_dialog_shows_quit_prompt() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        state.start_inline_execution("build".to_string());
+        state.request_quit();
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("A skill is running. Quit and kill it? (y/n)"));
+    }
+
+    #[test]
+    fn test_render_confirm_dialog_without_pending_renders_nothing() {
+        // Arrange
+        let state = AppState::new(vec![], Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.trim().is_empty());
+    }
+}
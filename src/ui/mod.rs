@@ -1,7 +1,13 @@
 /// UI module for TUI rendering
+pub mod args_prompt;
+pub mod category_picker;
 pub mod components;
+pub mod confirm_dialog;
+pub mod output_format;
 pub mod output_panel;
 pub mod renderer;
+pub mod tag_picker;
+pub mod text;
 pub mod theme;
 
 pub use renderer::render;
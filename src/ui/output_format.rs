@@ -0,0 +1,131 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::ui::theme::ThemeConfig;
+
+/// Render basic markdown into styled lines
+///
+/// Supports the subset that reads well in a terminal:
+/// - `#` to `######` headings (bold, in the theme's primary color)
+/// - `-`, `*`, and `+` list items (rendered with a bullet)
+/// - `**bold**` inline spans
+///
+/// Everything else is shown as plain text.
+///
+/// # Arguments
+///
+/// * `text` - The markdown source
+/// * `theme` - Theme supplying the heading color
+///
+/// # Returns
+///
+/// One styled line per source line
+pub fn render_markdown(text: &str, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    text.lines()
+        .map(|line| markdown_line(line, theme))
+        .collect()
+}
+
+/// Render a single markdown source line
+fn markdown_line(line: &str, theme: &ThemeConfig) -> Line<'static> {
+    let trimmed = line.trim_start();
+
+    // Headings: one to six '#' followed by a space
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+        return Line::from(Span::styled(
+            trimmed[level..].trim().to_string(),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    // Unordered list items keep their indentation
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = trimmed.strip_prefix(marker) {
+            let indent = &line[..line.len() - trimmed.len()];
+            let mut spans = vec![Span::raw(format!("{}• ", indent))];
+            spans.extend(inline_spans(item));
+            return Line::from(spans);
+        }
+    }
+
+    Line::from(inline_spans(line))
+}
+
+/// Split text into plain and `**bold**` spans
+fn inline_spans(text: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("**") {
+        let Some(len) = rest[start + 2..].find("**") else {
+            break;
+        };
+
+        if start > 0 {
+            spans.push(Span::raw(rest[..start].to_string()));
+        }
+        spans.push(Span::styled(
+            rest[start + 2..start + 2 + len].to_string(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        rest = &rest[start + 2 + len + 2..];
+    }
+
+    if !rest.is_empty() || spans.is_empty() {
+        spans.push(Span::raw(rest.to_string()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_styles_headings() {
+        // Arrange
+        let text = "# Title\n### Section\n#not a heading";
+
+        // Act
+        let lines = render_markdown(text, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].spans[0].content, "Title");
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Cyan));
+        assert!(lines[0].spans[0]
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD));
+        assert_eq!(lines[1].spans[0].content, "Section");
+        assert_eq!(lines[2].spans[0].content, "#not a heading");
+        assert_eq!(lines[2].spans[0].style, Style::default());
+    }
+
+    #[test]
+    fn test_render_markdown_renders_lists_and_bold() {
+        // Arrange
+        let text = "- first **item**\n  * nested";
+
+        // Act
+        let lines = render_markdown(text, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines[0].spans[0].content, "• ");
+        assert_eq!(lines[0].spans[1].content, "first ");
+        assert_eq!(lines[0].spans[2].content, "item");
+        assert!(lines[0].spans[2]
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD));
+        assert_eq!(lines[1].spans[0].content, "  • ");
+        assert_eq!(lines[1].spans[1].content, "nested");
+    }
+}
@@ -1,22 +1,43 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, Paragraph,
+    },
     Frame,
 };
 
-use crate::state::AppState;
+use crate::i18n::Translations;
+use crate::json;
+use crate::skills::manifest::OutputFormat;
+use crate::skills::output::{OutputStream, SkillOutput};
+use crate::state::{AppState, OutputStreamView};
+use crate::ui::components::scrollbar::render_scrollbar;
+use crate::ui::output_format::render_markdown;
+use crate::ui::text::wrap_line;
+use crate::ui::theme::ThemeConfig;
+
+/// Width of the output panel as a percentage of the terminal width
+const OUTPUT_PANEL_WIDTH_PERCENT: u16 = 80;
 
 /// Render the output panel as a modal overlay
 ///
 /// Displays the output from an inline skill execution in a centered modal panel.
 /// The panel shows:
-/// - Title bar with skill name
-/// - Status header (execution status, exit code, execution time)
+/// - Title bar
+/// - Summary line (skill name, exit code, execution time, stream sizes,
+///   truncation) followed by the execution status
 /// - Scrollable stdout output
 /// - Stderr output (if present, in red)
-/// - Footer with key hints
+/// - Footer with key hints, or the `/` search prompt while typing a query
+/// - Stats in the bottom border: current line, total lines, size, and
+///   whether the output was truncated
+///
+/// Lines matching the output search query have the matches highlighted.
+/// Tab cycles between both streams, stdout only, and stderr only; the title
+/// names the stream when only one is shown.
 ///
 /// # Arguments
 ///
@@ -37,151 +58,355 @@ pub fn render_output_panel(frame: &mut Frame, state: &AppState) {
         return;
     };
 
-    // Create centered modal area (80% width, 80% height)
-    let area = centered_rect(80, 80, frame.size());
+    // Create centered modal area (80% width, configured share of the height)
+    let area = panel_area(frame.size(), state.config().output_panel_percent());
 
-    // Create main panel block with title
-    let title = " Output ";
-    let panel_block = Block::default()
+    // Create main panel block with title naming a single-stream view
+    let title = match state.output_stream_view() {
+        OutputStreamView::Combined => " Output ",
+        OutputStreamView::Stdout => " Output (stdout) ",
+        OutputStreamView::Stderr => " Output (stderr) ",
+    };
+    let mut panel_block = Block::default()
         .borders(Borders::ALL)
         .title(title)
-        .border_style(Style::default().fg(Color::Cyan));
-
-    // Calculate inner area for content
-    let inner_area = panel_block.inner(area);
+        .border_style(Style::default().fg(state.theme().primary));
+    if let Some(stats) = output_stats(state) {
+        panel_block = panel_block.title(
+            Title::from(format!(" {} ", stats_text(&stats, state.translations())))
+                .position(Position::Bottom)
+                .alignment(Alignment::Right),
+        );
+    }
 
     // Render the panel border
     frame.render_widget(panel_block, area);
 
-    // Split inner area into sections
+    let [header_area, content_area, footer_area] = panel_sections(area);
+
+    // Render status header, named after the skill that produced the output
+    let skill_name = state.output_skill_name();
+    render_status_header(
+        frame,
+        header_area,
+        output,
+        skill_name,
+        state.translations(),
+        state.theme(),
+    );
+
+    // Render scrollable output content
+    render_output_content(frame, content_area, state);
+
+    // Render footer with key hints (or the search prompt while typing)
+    render_footer(frame, footer_area, state);
+}
+
+/// Calculate the number of output lines visible in the panel
+///
+/// Mirrors the layout used by `render_output_panel` so that scroll bounds
+/// computed in `AppState` match what is actually drawn on screen.
+///
+/// # Arguments
+///
+/// * `frame_area` - The full terminal area the panel is centered in
+/// * `height_percent` - Panel height as a percentage of the terminal, from
+///   `Config::output_panel_percent`
+///
+/// # Returns
+///
+/// Number of content lines visible at once (excluding scroll indicators)
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use pane::ui::output_panel::content_viewport_height;
+///
+/// let height = content_viewport_height(Rect::new(0, 0, 100, 50), 80);
+/// assert!(height > 0);
+/// ```
+pub fn content_viewport_height(frame_area: Rect, height_percent: u16) -> usize {
+    let [_, content_area, _] = panel_sections(panel_area(frame_area, height_percent));
+    // -2 for potential scroll indicators
+    content_area.height.saturating_sub(2) as usize
+}
+
+/// Calculate the output panel's content width for a terminal area
+///
+/// Output lines wrap at this width, so the app passes it to
+/// `AppState::set_output_viewport_width` to keep scroll bounds exact.
+///
+/// # Arguments
+///
+/// * `frame_area` - The full terminal area the panel is centered in
+/// * `height_percent` - Panel height as a percentage of the terminal, from
+///   `Config::output_panel_percent`
+///
+/// # Returns
+///
+/// Number of columns available to each output line
+pub fn content_viewport_width(frame_area: Rect, height_percent: u16) -> usize {
+    let [_, content_area, _] = panel_sections(panel_area(frame_area, height_percent));
+    usize::from(content_area.width)
+}
+
+/// Centered area the output panel covers within the terminal
+fn panel_area(frame_area: Rect, height_percent: u16) -> Rect {
+    centered_rect(OUTPUT_PANEL_WIDTH_PERCENT, height_percent, frame_area)
+}
+
+/// Split the panel area into status header, content, and footer sections
+fn panel_sections(area: Rect) -> [Rect; 3] {
+    // Inner area excludes the panel border
+    let inner_area = Block::default().borders(Borders::ALL).inner(area);
+
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Status header
+            Constraint::Length(2), // Status header
             Constraint::Min(1),    // Output content
             Constraint::Length(1), // Footer
         ])
         .split(inner_area);
 
-    let header_area = sections[0];
-    let content_area = sections[1];
-    let footer_area = sections[2];
-
-    // Render status header
-    render_status_header(frame, header_area, output);
-
-    // Render scrollable output content
-    render_output_content(frame, content_area, state, output);
-
-    // Render footer with key hints
-    render_footer(frame, footer_area);
+    [sections[0], sections[1], sections[2]]
 }
 
 /// Render the status header section
 ///
-/// Displays execution status, exit code, and execution time.
+/// Displays the one-line run summary, then the execution status and attempt
+/// count (after retries).
 fn render_status_header(
     frame: &mut Frame,
     area: Rect,
     output: &crate::skills::output::SkillOutput,
+    skill_name: Option<&str>,
+    translations: &Translations,
+    theme: &ThemeConfig,
 ) {
-    let mut lines = Vec::new();
+    let mut lines = vec![summary_line(output, skill_name, translations, theme)];
 
-    // Status line
+    // Status line; truncation is already flagged in the summary
     let (status_text, status_color) = match output.exit_code {
-        Some(0) => ("Completed ✓", Color::Green),
-        Some(_) => ("Failed ✗", Color::Red),
-        None => ("Error", Color::Red),
+        Some(0) => (translations.output_panel_status_completed, theme.success),
+        Some(_) => (translations.output_panel_status_failed, theme.error),
+        None => (translations.output_panel_status_error, theme.error),
     };
 
-    let status_line = Line::from(vec![
-        Span::raw("Status: "),
+    let mut status_spans = vec![
+        Span::raw(format!("{} ", translations.output_panel_status_label)),
         Span::styled(status_text, Style::default().fg(status_color)),
-        Span::raw("    Exit Code: "),
-        Span::raw(match output.exit_code {
-            Some(code) => code.to_string(),
-            None => "N/A".to_string(),
-        }),
-    ]);
-    lines.push(status_line);
+    ];
+    if output.attempts > 1 {
+        status_spans.push(Span::raw(format!(
+            "    {} {}",
+            translations.output_panel_attempts_label, output.attempts
+        )));
+    }
+    lines.push(Line::from(status_spans));
 
-    // Execution time line
-    let time_ms = output.execution_time.as_millis();
-    let time_line = Line::from(vec![
-        Span::raw("Execution Time: "),
-        Span::raw(format!("{}ms", time_ms)),
-    ]);
-    lines.push(time_line);
+    let header = Paragraph::new(lines);
+    frame.render_widget(header, area);
+}
+
+/// Build the glanceable summary of a run
+///
+/// Reads e.g. "Deploy · Exit Code: 0 · Execution Time: 120ms · stdout 1.2 KB
+/// · stderr 0 B", with the exit code green on success and red otherwise, and
+/// a yellow truncation flag when output was cut off.
+///
+/// # Arguments
+///
+/// * `output` - The run to summarize
+/// * `skill_name` - Name of the skill that produced it, if known
+/// * `translations` - Labels for the exit code, time and truncation flag
+/// * `theme` - Theme supplying the status colors
+///
+/// # Returns
+///
+/// The summary as a single line
+fn summary_line(
+    output: &SkillOutput,
+    skill_name: Option<&str>,
+    translations: &Translations,
+    theme: &ThemeConfig,
+) -> Line<'static> {
+    const SEPARATOR: &str = " · ";
+    let mut spans = Vec::new();
+
+    if let Some(name) = skill_name {
+        spans.push(Span::styled(
+            name.to_string(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(SEPARATOR));
+    }
+
+    let (code_text, code_color) = match output.exit_code {
+        Some(0) => ("0".to_string(), theme.success),
+        Some(code) => (code.to_string(), theme.error),
+        None => ("N/A".to_string(), theme.error),
+    };
+    spans.push(Span::raw(format!(
+        "{} ",
+        translations.output_panel_exit_code_label
+    )));
+    spans.push(Span::styled(code_text, Style::default().fg(code_color)));
+
+    spans.push(Span::raw(format!(
+        "{}{} {}ms{}{} {}{}{} {}",
+        SEPARATOR,
+        translations.output_panel_execution_time_label,
+        output.execution_time.as_millis(),
+        SEPARATOR,
+        OutputStream::Stdout.name(),
+        format_byte_count(output.stdout.len()),
+        SEPARATOR,
+        OutputStream::Stderr.name(),
+        format_byte_count(output.stderr.len()),
+    )));
 
-    // Truncation warning if applicable
     if output.truncated {
-        let warning_line = Line::from(vec![Span::styled(
-            "⚠️  Output truncated (exceeded 10MB limit)",
-            Style::default().fg(Color::Yellow),
-        )]);
-        lines.push(warning_line);
+        spans.push(Span::raw(SEPARATOR));
+        spans.push(Span::styled(
+            translations.output_summary_truncated,
+            Style::default().fg(theme.warning),
+        ));
     }
 
-    let header = Paragraph::new(lines);
-    frame.render_widget(header, area);
+    Line::from(spans)
+}
+
+/// Scroll position and size of the active output, for the panel's stats line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputStats {
+    /// 1-based number of the first visible line (0 when nothing is shown)
+    pub line: usize,
+    /// Number of lines shown for the current stream view
+    pub total_lines: usize,
+    /// Size in bytes of the streams shown
+    pub bytes: usize,
+    /// Whether the output was cut off at the size limit
+    pub truncated: bool,
+}
+
+/// Compute the stats line figures for the active output
+///
+/// The scroll offset is clamped against the viewport height from the last
+/// render, matching what `render_output_content` draws.
+///
+/// # Arguments
+///
+/// * `state` - Application state holding the active output and scroll position
+///
+/// # Returns
+///
+/// The stats, or `None` if no output is active
+pub fn output_stats(state: &AppState) -> Option<OutputStats> {
+    let output = state.active_output()?;
+    let view = state.output_stream_view();
+    let total_lines = state.output_line_count();
+    let line = if total_lines == 0 {
+        0
+    } else {
+        state.first_visible_output_line() + 1
+    };
+
+    let mut bytes = 0;
+    if view.shows_stdout() {
+        bytes += output.stdout.len();
+    }
+    if view.shows_stderr() {
+        bytes += output.stderr.len();
+    }
+
+    Some(OutputStats {
+        line,
+        total_lines,
+        bytes,
+        truncated: output.truncated,
+    })
+}
+
+/// Format output stats, e.g. "line 42/310, 12.3 KB, truncated"
+fn stats_text(stats: &OutputStats, translations: &Translations) -> String {
+    let mut text = format!(
+        "{} {}/{}, {}",
+        translations.output_stats_line_label,
+        stats.line,
+        stats.total_lines,
+        format_byte_count(stats.bytes)
+    );
+    if stats.truncated {
+        text.push_str(", ");
+        text.push_str(translations.output_summary_truncated);
+    }
+    text
+}
+
+/// Format a byte count compactly, e.g. "512 B", "1.2 KB" or "3.4 MB"
+fn format_byte_count(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let value = bytes as f64;
+    if value < KB {
+        format!("{} B", bytes)
+    } else if value < KB * KB {
+        format!("{:.1} KB", value / KB)
+    } else {
+        format!("{:.1} MB", value / (KB * KB))
+    }
 }
 
 /// Render the scrollable output content
 ///
-/// Displays stdout and stderr with scrolling support.
-fn render_output_content(
-    frame: &mut Frame,
-    area: Rect,
-    state: &AppState,
-    output: &crate::skills::output::SkillOutput,
-) {
+/// Displays stdout and/or stderr, depending on the stream view, with
+/// scrolling support.
+fn render_output_content(frame: &mut Frame, area: Rect, state: &AppState) {
     let scroll_offset = state.output_scroll_offset();
 
-    // Handle empty output
-    if output.stdout.is_empty() && output.stderr.is_empty() {
+    // Handle empty output (or an empty stream in a single-stream view)
+    if state.output_lines().is_empty() {
         let empty_msg = Paragraph::new("No output")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(state.theme().text_dim))
             .alignment(ratatui::layout::Alignment::Center);
         frame.render_widget(empty_msg, area);
         return;
     }
 
-    // Collect all output lines
-    let mut all_lines: Vec<Line> = Vec::new();
-
-    // Add stdout lines
-    if !output.stdout.is_empty() {
-        for line in output.stdout.lines() {
-            all_lines.push(Line::from(line));
-        }
-    }
-
-    // Add stderr section if present
-    if !output.stderr.is_empty() {
-        // Add separator
-        all_lines.push(Line::from(Span::styled(
-            "─── Error Output ───",
-            Style::default().fg(Color::Red),
-        )));
+    let mut all_lines = state.output_lines().to_vec();
 
-        // Add stderr lines in red
-        for line in output.stderr.lines() {
-            all_lines.push(Line::from(Span::styled(
-                line,
-                Style::default().fg(Color::Red),
-            )));
+    // Highlight output search matches, with the current match emphasized
+    let query = state.output_search_query();
+    if !query.is_empty() && !state.is_output_search_active() {
+        let theme = state.theme();
+        let current = state.current_output_match();
+        for (idx, line) in all_lines.iter_mut().enumerate() {
+            let style = if current == Some(idx) {
+                theme
+                    .selected_style()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().bg(theme.highlight)
+            };
+            *line = highlight_matches(std::mem::take(line), query, style);
         }
     }
 
-    // Calculate visible window
-    let total_lines = all_lines.len();
+    // Calculate visible window in wrapped rows
+    let width = usize::from(area.width);
+    let rows: Vec<Line> = all_lines
+        .iter()
+        .flat_map(|line| wrap_line(line, width))
+        .collect();
     let visible_height = area.height.saturating_sub(2) as usize; // -2 for potential scroll indicators
-    let max_offset = total_lines.saturating_sub(visible_height);
+    let max_offset = rows.len().saturating_sub(visible_height);
     let clamped_offset = scroll_offset.min(max_offset);
 
     // Add scroll indicators if needed
     let has_more_above = clamped_offset > 0;
-    let has_more_below = clamped_offset + visible_height < total_lines;
+    let has_more_below = clamped_offset < max_offset;
 
     let mut display_lines = Vec::new();
 
@@ -190,43 +415,217 @@ fn render_output_content(
         display_lines.push(Line::from(Span::styled(
             "▲ More above",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(state.theme().text_dim)
                 .add_modifier(Modifier::DIM),
         )));
     }
 
-    // Visible content lines
-    let visible_lines = all_lines
-        .iter()
-        .skip(clamped_offset)
-        .take(visible_height)
-        .cloned();
-    display_lines.extend(visible_lines);
+    // Visible content rows
+    let visible_rows = rows.into_iter().skip(clamped_offset).take(visible_height);
+    display_lines.extend(visible_rows);
 
     // Bottom scroll indicator
     if has_more_below {
         display_lines.push(Line::from(Span::styled(
             "▼ More below",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(state.theme().text_dim)
                 .add_modifier(Modifier::DIM),
         )));
     }
 
-    let content = Paragraph::new(display_lines).wrap(Wrap { trim: false });
+    let content = Paragraph::new(display_lines);
     frame.render_widget(content, area);
+
+    // Scrollbar over the panel's right border, alongside the content
+    let scrollbar_area = Rect {
+        x: area.right(),
+        width: 1,
+        ..area
+    };
+    render_scrollbar(
+        scrollbar_area,
+        frame,
+        clamped_offset,
+        max_offset,
+        visible_height,
+        state.theme(),
+    );
 }
 
-/// Render the footer with key hints
-fn render_footer(frame: &mut Frame, area: Rect) {
+/// Build the lines the output panel shows for a stream view, before wrapping
+///
+/// Stdout is formatted according to the skill's output format hint; in the
+/// combined view stderr follows under a separator line. `AppState` keeps
+/// these lines so scroll bounds, search matches and stats count the same rows
+/// the panel draws.
+///
+/// # Arguments
+///
+/// * `output` - The output to display
+/// * `view` - Which streams to include
+/// * `theme` - Theme supplying the stderr, warning and heading colors
+///
+/// # Returns
+///
+/// One entry per logical line; empty when the streams shown are empty
+///
+/// # Examples
+///
+/// ```
+/// use pane::skills::output::SkillOutput;
+/// use pane::state::OutputStreamView;
+/// use pane::ui::output_panel::output_lines;
+/// use pane::ui::theme::ThemeConfig;
+///
+/// let output = SkillOutput::error("boom");
+/// let theme = ThemeConfig::default();
+/// assert_eq!(output_lines(&output, OutputStreamView::Combined, &theme).len(), 2);
+/// assert_eq!(output_lines(&output, OutputStreamView::Stdout, &theme).len(), 0);
+/// ```
+pub fn output_lines(
+    output: &SkillOutput,
+    view: OutputStreamView,
+    theme: &ThemeConfig,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    if view.shows_stdout() && !output.stdout.is_empty() {
+        lines.extend(stdout_lines(output, theme));
+    }
+
+    if view.shows_stderr() && !output.stderr.is_empty() {
+        // Add separator when stderr follows stdout
+        if view == OutputStreamView::Combined {
+            lines.push(Line::from(Span::styled(
+                "─── Error Output ───",
+                Style::default().fg(theme.error),
+            )));
+        }
+
+        // Add stderr lines in the theme's error color
+        lines.extend(output.stderr.lines().map(|line| {
+            Line::from(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.error),
+            ))
+        }));
+    }
+
+    lines
+}
+
+/// Build the display lines for stdout based on the output format hint
+///
+/// JSON is pretty-printed when it parses; otherwise a warning is shown above
+/// the raw output. Markdown gets basic heading, list, and bold styling.
+fn stdout_lines(output: &SkillOutput, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let raw_lines = || {
+        output
+            .stdout
+            .lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    match output.format {
+        OutputFormat::Text => raw_lines(),
+        OutputFormat::Json => match json::pretty_print(&output.stdout) {
+            Ok(pretty) => pretty
+                .lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect(),
+            Err(e) => {
+                let mut lines = vec![Line::from(Span::styled(
+                    format!("⚠ Invalid JSON: {}", e),
+                    Style::default().fg(theme.warning),
+                ))];
+                lines.extend(raw_lines());
+                lines
+            }
+        },
+        OutputFormat::Markdown => render_markdown(&output.stdout, theme),
+    }
+}
+
+/// Highlight every ASCII case-insensitive occurrence of `query` in a line
+///
+/// Spans are split around matches so the rest of each span keeps its style;
+/// matches get `style` patched on top.
+///
+/// # Arguments
+///
+/// * `line` - The line to highlight
+/// * `query` - Text to look for (must not be empty)
+/// * `style` - Style patched onto each match
+///
+/// # Returns
+///
+/// The line with matches split into separately styled spans
+fn highlight_matches<'a>(line: Line<'a>, query: &str, style: Style) -> Line<'a> {
+    let query = query.to_ascii_lowercase();
+    let mut spans = Vec::new();
+
+    for span in line.spans {
+        let content = span.content.into_owned();
+        // ASCII lowercasing keeps byte offsets aligned with `content`
+        let lower = content.to_ascii_lowercase();
+        let mut start = 0;
+        while let Some(found) = lower[start..].find(&query) {
+            let match_start = start + found;
+            let match_end = match_start + query.len();
+            if match_start > start {
+                spans.push(Span::styled(
+                    content[start..match_start].to_string(),
+                    span.style,
+                ));
+            }
+            spans.push(Span::styled(
+                content[match_start..match_end].to_string(),
+                span.style.patch(style),
+            ));
+            start = match_end;
+        }
+        if start < content.len() {
+            spans.push(Span::styled(content[start..].to_string(), span.style));
+        }
+    }
+
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// Render the footer with key hints, or the search prompt while typing
+fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
+    let theme = state.theme();
+    let key = Style::default().fg(theme.primary);
+    if state.is_output_search_active() {
+        let prompt = Line::from(vec![
+            Span::styled("/", key),
+            Span::raw(state.output_search_query().to_string()),
+            Span::styled("█", Style::default().fg(theme.border)),
+        ]);
+        frame.render_widget(Paragraph::new(prompt), area);
+        return;
+    }
+
     let hints = Line::from(vec![
-        Span::styled("↑/↓ or j/k", Style::default().fg(Color::Cyan)),
+        Span::styled("↑/↓ or j/k", key),
         Span::raw(": scroll | "),
-        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::styled("g/G", key),
+        Span::raw(": top/bottom | "),
+        Span::styled("/ n/N", key),
+        Span::raw(": search | "),
+        Span::styled("Tab", key),
+        Span::raw(": stdout/stderr | "),
+        Span::styled("Esc", key),
         Span::raw(": close"),
     ]);
 
-    let footer = Paragraph::new(hints).style(Style::default().fg(Color::Gray));
+    let footer = Paragraph::new(hints).style(Style::default().fg(theme.border));
     frame.render_widget(footer, area);
 }
 
@@ -241,7 +640,7 @@ fn render_footer(frame: &mut Frame, area: Rect) {
 /// # Returns
 ///
 /// A centered Rect with the specified percentage dimensions
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -263,6 +662,8 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 
 #[cfg(test)]
 mod tests {
+    use ratatui::style::Color;
+
     use super::*;
 
     #[test]
@@ -285,6 +686,117 @@ mod tests {
         assert_eq!(centered.y, 10); // (100 - 80) / 2
     }
 
+    #[test]
+    fn test_content_viewport_height_matches_panel_layout() {
+        // Arrange
+        let frame_area = Rect::new(0, 0, 100, 50);
+
+        // Act
+        let height = content_viewport_height(frame_area, 80);
+
+        // Assert
+        // 40 rows panel - 2 border - 2 header - 1 footer - 2 scroll indicators
+        assert_eq!(height, 33);
+    }
+
+    #[test]
+    fn test_content_viewport_width_matches_panel_layout() {
+        // Arrange
+        let frame_area = Rect::new(0, 0, 100, 50);
+
+        // Act
+        let width = content_viewport_width(frame_area, 80);
+
+        // Assert
+        // 80 columns panel - 2 border
+        assert_eq!(width, 78);
+    }
+
+    #[test]
+    fn test_content_viewport_height_follows_configured_ratio() {
+        // Arrange
+        let frame_area = Rect::new(0, 0, 100, 50);
+        let config = crate::config::Config {
+            output_panel_ratio: 0.5,
+            ..crate::config::Config::default()
+        };
+
+        // Act
+        let height = content_viewport_height(frame_area, config.output_panel_percent());
+
+        // Assert
+        // 25 rows panel - 2 border - 2 header - 1 footer - 2 scroll indicators
+        assert_eq!(height, 18);
+        assert_eq!(panel_area(frame_area, 50).height, 25);
+    }
+
+    fn create_output(stdout: &str, format: OutputFormat) -> SkillOutput {
+        SkillOutput {
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(1),
+            format,
+            attempts: 1,
+        }
+    }
+
+    #[test]
+    fn test_stdout_lines_pretty_prints_json() {
+        // Arrange
+        let output = create_output(r#"{"ok":true}"#, OutputFormat::Json);
+
+        // Act
+        let lines = stdout_lines(&output, &ThemeConfig::default());
+
+        // Assert
+        let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(text, vec!["{", "  \"ok\": true", "}"]);
+    }
+
+    #[test]
+    fn test_stdout_lines_invalid_json_falls_back_to_raw_with_note() {
+        // Arrange
+        let output = create_output("not json", OutputFormat::Json);
+
+        // Act
+        let lines = stdout_lines(&output, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].to_string().starts_with("⚠ Invalid JSON:"));
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Yellow));
+        assert_eq!(lines[1].to_string(), "not json");
+    }
+
+    #[test]
+    fn test_stdout_lines_text_is_unchanged() {
+        // Arrange
+        let output = create_output("# not a heading\n{\"a\":1}", OutputFormat::Text);
+
+        // Act
+        let lines = stdout_lines(&output, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines[0].to_string(), "# not a heading");
+        assert_eq!(lines[1].to_string(), "{\"a\":1}");
+    }
+
+    #[test]
+    fn test_stdout_lines_markdown_styles_heading() {
+        // Arrange
+        let output = create_output("# Report\nbody", OutputFormat::Markdown);
+
+        // Act
+        let lines = stdout_lines(&output, &ThemeConfig::default());
+
+        // Assert
+        assert_eq!(lines[0].to_string(), "Report");
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(lines[1].to_string(), "body");
+    }
+
     #[test]
     fn test_centered_rect_handles_small_percentages() {
         // Arrange
@@ -304,4 +816,301 @@ mod tests {
         assert_eq!(centered.x, 25); // (100 - 50) / 2
         assert_eq!(centered.y, 25); // (100 - 50) / 2
     }
+
+    #[test]
+    fn test_highlight_matches_splits_spans_around_matches() {
+        // Arrange
+        let line = Line::from(vec![
+            Span::raw("Error: "),
+            Span::styled("disk error", Style::default().fg(Color::Red)),
+        ]);
+        let style = Style::default().bg(Color::Yellow);
+
+        // Act
+        let highlighted = highlight_matches(line, "ERROR", style);
+
+        // Assert
+        let parts: Vec<(&str, Style)> = highlighted
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("Error", style),
+                (": ", Style::default()),
+                ("disk ", Style::default().fg(Color::Red)),
+                ("error", Style::default().fg(Color::Red).bg(Color::Yellow)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_output_panel_shows_only_selected_stream() {
+        // Arrange
+        let mut state = AppState::new(vec![], crate::config::Config::default());
+        state.show_output_panel(SkillOutput {
+            stdout: "regular output".to_string(),
+            stderr: "something failed".to_string(),
+            exit_code: Some(1),
+            truncated: false,
+            execution_time: std::time::Duration::from_millis(5),
+            format: OutputFormat::Text,
+            attempts: 1,
+        });
+        let render = |state: &AppState| -> String {
+            let mut terminal =
+                ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 30)).unwrap();
+            terminal
+                .draw(|frame| render_output_panel(frame, state))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect()
+        };
+
+        // Act
+        let combined = render(&state);
+        state.cycle_output_stream_view();
+        let stdout_only = render(&state);
+        state.cycle_output_stream_view();
+        let stderr_only = render(&state);
+
+        // Assert
+        assert!(combined.contains("regular output"));
+        assert!(combined.contains("Error Output"));
+        assert!(combined.contains("something failed"));
+        assert!(stdout_only.contains("Output (stdout)"));
+        assert!(stdout_only.contains("regular output"));
+        assert!(!stdout_only.contains("something failed"));
+        assert!(stderr_only.contains("Output (stderr)"));
+        assert!(stderr_only.contains("something failed"));
+        assert!(!stderr_only.contains("regular output"));
+        assert!(!stderr_only.contains("Error Output"));
+    }
+
+    #[test]
+    fn test_render_output_panel_shows_attempts_only_after_retries() {
+        // Arrange
+        let mut state = AppState::new(vec![], crate::config::Config::default());
+        let render = |state: &AppState| -> String {
+            let mut terminal =
+                ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 30)).unwrap();
+            terminal
+                .draw(|frame| render_output_panel(frame, state))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect()
+        };
+
+        // Act
+        state.show_output_panel(create_output("ok", OutputFormat::Text));
+        let single = render(&state);
+        state.show_output_panel(SkillOutput {
+            attempts: 3,
+            ..create_output("ok", OutputFormat::Text)
+        });
+        let retried = render(&state);
+
+        // Assert
+        assert!(!single.contains("Attempts:"));
+        assert!(retried.contains("Attempts: 3"));
+    }
+
+    #[test]
+    fn test_render_output_panel_shows_summary_line() {
+        // Arrange - the skill that ran is named, not the selected one
+        let mut state = AppState::new(
+            vec![crate::test_support::test_skill("other")],
+            crate::config::Config::default(),
+        );
+        state.show_skill_output(
+            "Deploy".to_string(),
+            SkillOutput {
+                stderr: "e".repeat(2048),
+                exit_code: Some(2),
+                execution_time: std::time::Duration::from_millis(1234),
+                truncated: true,
+                ..create_output("hello", OutputFormat::Text)
+            },
+        );
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(120, 30)).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| render_output_panel(frame, &state))
+            .unwrap();
+
+        // Assert - the summary is the first line inside the panel
+        let buffer = terminal.backend().buffer();
+        let area = centered_rect(80, 80, buffer.area);
+        let row = |y: u16| -> String {
+            (area.x + 1..area.right() - 1)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect()
+        };
+        assert!(row(area.y + 1).starts_with(
+            "Deploy · Exit Code: 2 · Execution Time: 1234ms · stdout 5 B · stderr 2.0 KB · truncated"
+        ));
+        let code_x = area.x + 1 + "Deploy · Exit Code: ".chars().count() as u16;
+        assert_eq!(buffer.get(code_x, area.y + 1).fg, Color::Red);
+        // Truncation is flagged once, in the summary
+        assert!(!row(area.y + 3).contains("truncated"));
+    }
+
+    #[test]
+    fn test_render_output_panel_error_panel_has_no_skill_name() {
+        // Arrange
+        let mut state = AppState::new(
+            vec![crate::test_support::test_skill("deploy")],
+            crate::config::Config::default(),
+        );
+        state.show_output_panel(SkillOutput::error("Failed to execute skill"));
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(120, 30)).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| render_output_panel(frame, &state))
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let area = centered_rect(80, 80, buffer.area);
+        let summary: String = (area.x + 1..area.right() - 1)
+            .map(|x| buffer.get(x, area.y + 1).symbol())
+            .collect();
+        assert!(summary.starts_with("Exit Code: N/A"));
+    }
+
+    #[test]
+    fn test_output_stats_reflects_scroll_offset_and_view() {
+        // Arrange
+        let mut state = AppState::new(vec![], crate::config::Config::default());
+        state.set_output_viewport_height(10);
+        let stdout: String = (1..=100).map(|n| format!("line {}\n", n)).collect();
+        state.show_output_panel(SkillOutput {
+            stderr: "oops\n".to_string(),
+            truncated: true,
+            ..create_output(&stdout, OutputFormat::Text)
+        });
+        for _ in 0..41 {
+            state.scroll_output_down();
+        }
+
+        // Act
+        let combined = output_stats(&state).unwrap();
+        state.cycle_output_stream_view();
+        state.cycle_output_stream_view();
+        let stderr_only = output_stats(&state).unwrap();
+
+        // Assert
+        // 100 stdout lines, the stderr separator, and one stderr line
+        assert_eq!(
+            combined,
+            OutputStats {
+                line: 42,
+                total_lines: 102,
+                bytes: stdout.len() + 5,
+                truncated: true,
+            }
+        );
+        // The offset is clamped to the single stderr line
+        assert_eq!(stderr_only.line, 1);
+        assert_eq!(stderr_only.total_lines, 1);
+        assert_eq!(stderr_only.bytes, 5);
+    }
+
+    #[test]
+    fn test_output_stats_none_without_output() {
+        // Arrange
+        let state = AppState::new(vec![], crate::config::Config::default());
+
+        // Act & Assert
+        assert_eq!(output_stats(&state), None);
+    }
+
+    #[test]
+    fn test_render_output_panel_shows_stats_in_bottom_border() {
+        // Arrange
+        let mut state = AppState::new(vec![], crate::config::Config::default());
+        state.set_output_viewport_height(10);
+        state.show_output_panel(SkillOutput {
+            truncated: true,
+            ..create_output(&"x\n".repeat(310), OutputFormat::Text)
+        });
+        for _ in 0..41 {
+            state.scroll_output_down();
+        }
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(120, 30)).unwrap();
+
+        // Act
+        terminal
+            .draw(|frame| render_output_panel(frame, &state))
+            .unwrap();
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let area = centered_rect(80, 80, buffer.area);
+        let bottom: String = (area.x..area.right())
+            .map(|x| buffer.get(x, area.bottom() - 1).symbol())
+            .collect();
+        assert!(bottom.contains(" line 42/310, 620 B, truncated "));
+    }
+
+    /// Symbols in the panel's right border column, top to bottom
+    fn right_border_column(stdout: &str) -> String {
+        let mut state = AppState::new(vec![], crate::config::Config::default());
+        state.show_output_panel(create_output(stdout, OutputFormat::Text));
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_output_panel(frame, &state))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let area = centered_rect(80, 80, buffer.area);
+        (area.y..area.bottom())
+            .map(|y| buffer.get(area.right() - 1, y).symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_output_panel_draws_scrollbar_when_output_overflows() {
+        // Act
+        let column = right_border_column(&"line\n".repeat(100));
+
+        // Assert
+        assert!(column.contains('█'));
+    }
+
+    #[test]
+    fn test_render_output_panel_omits_scrollbar_when_output_fits() {
+        // Act
+        let column = right_border_column("one\ntwo\n");
+
+        // Assert
+        assert!(!column.contains('█'));
+    }
+
+    #[test]
+    fn test_format_byte_count_picks_unit() {
+        // Arrange & Act & Assert
+        assert_eq!(format_byte_count(0), "0 B");
+        assert_eq!(format_byte_count(1023), "1023 B");
+        assert_eq!(format_byte_count(1536), "1.5 KB");
+        assert_eq!(format_byte_count(3 * 1024 * 1024), "3.0 MB");
+    }
 }
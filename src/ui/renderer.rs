@@ -1,18 +1,30 @@
+use std::rc::Rc;
+
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
-    style::Style,
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::Title, Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
 use crate::{
-    state::AppState,
+    i18n::Translations,
+    state::{AppState, InputMode, ViewMode},
     ui::{
+        args_prompt::render_args_prompt,
+        category_picker::render_category_picker,
         components::{
-            detail_pane::render_detail_pane, footer::render_footer, search_bar::render_search_bar,
-            skill_list::render_skill_list,
+            detail_pane::render_detail_pane,
+            footer::render_footer,
+            output_preview::render_output_preview,
+            search_bar::render_search_bar,
+            skill_list::{render_skill_list, ITEM_HEIGHT},
+            status_toast::render_status_toast,
         },
+        confirm_dialog::render_confirm_dialog,
         output_panel::render_output_panel,
+        tag_picker::render_tag_picker,
     },
 };
 
@@ -23,66 +35,112 @@ use crate::{
 /// - Wide terminals (≥80 cols): Side-by-side list and detail pane
 /// - Narrow terminals (<80 cols): Stacked list and detail pane
 ///
+/// While skills are still being discovered, the list and detail pane are
+/// replaced by a loading message. When no skills were discovered at all, they
+/// are replaced by first-run guidance listing the configured skill paths.
+///
 /// # Arguments
 ///
 /// * `frame` - The ratatui frame to render into
 /// * `state` - The current application state
 pub fn render(frame: &mut Frame, state: &AppState) {
-    let terminal_width = frame.size().width;
     let theme = state.theme();
 
     // Main vertical layout: header, search, content area, footer
-    let main_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Length(3), // Search bar
-            Constraint::Min(0),    // Content area (list + detail)
-            Constraint::Length(3), // Footer
-        ])
-        .split(frame.size());
+    let main_chunks = split_main(frame.size());
 
-    // Render header with theme styling and translated title
+    // Render header with theme styling, translated title, and input mode indicator
+    let mode_color = match state.input_mode() {
+        InputMode::Normal => theme.primary,
+        InputMode::Insert => theme.secondary,
+    };
+    let mode_indicator = Title::from(Span::styled(
+        mode_indicator(state.input_mode(), state.translations()),
+        Style::default().fg(mode_color).add_modifier(Modifier::BOLD),
+    ))
+    .alignment(Alignment::Right);
     let header = Block::default()
         .title(state.translations().app_title)
+        .title(mode_indicator)
         .borders(Borders::ALL)
         .border_type(theme.border_style)
         .border_style(theme.border_style())
         .style(theme.header_style());
     frame.render_widget(header, main_chunks[0]);
 
-    // Render search bar (always focused for now) with translated placeholder
+    // Render search bar (focused with a visible cursor in Insert mode) with translated placeholder
     render_search_bar(
         main_chunks[1],
         frame,
         state.search_query(),
-        true,
+        state.search_cursor(),
+        state.is_insert_mode(),
         state.translations().search_placeholder,
         theme,
     );
 
-    // Responsive layout for content area (list + detail pane)
-    let (list_area, detail_area) = if terminal_width >= 80 {
-        // Wide terminal: side-by-side layout
-        let horizontal_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(50), // Skill list (left)
-                Constraint::Percentage(50), // Detail pane (right)
-            ])
-            .split(main_chunks[2]);
-        (horizontal_chunks[0], horizontal_chunks[1])
+    // Discovery is still running in the background; nothing to list yet
+    if state.is_loading() {
+        render_loading(main_chunks[2], frame, state);
+    } else if is_first_run(state) {
+        // First-run empty state: nothing was discovered, so explain where to put skills
+        render_first_run_guidance(main_chunks[2], frame, state);
     } else {
-        // Narrow terminal: stacked layout
-        let vertical_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(60), // Skill list (top)
-                Constraint::Percentage(40), // Detail pane (bottom)
-            ])
-            .split(main_chunks[2]);
-        (vertical_chunks[0], vertical_chunks[1])
-    };
+        render_content(main_chunks[2], frame, state);
+    }
+
+    // Render footer with view mode, input mode, and translations
+    render_footer(
+        main_chunks[3],
+        frame,
+        state.view_mode(),
+        state.input_mode(),
+        state.category_filter(),
+        state.tag_filter(),
+        state.skills_by_source_counts(),
+        state.translations(),
+        theme,
+    );
+
+    // Render status toast over the footer, if any, falling back to the
+    // progress of a running inline skill
+    if let Some(message) = state
+        .status_message()
+        .or_else(|| state.inline_execution_status())
+    {
+        render_status_toast(
+            main_chunks[3],
+            frame,
+            message,
+            state.status_is_error(),
+            theme,
+        );
+    }
+
+    // Render category and tag picker overlays if visible
+    render_category_picker(frame, state);
+    render_tag_picker(frame, state);
+
+    // Render output panel overlay if visible
+    render_output_panel(frame, state);
+
+    // Render launch-arguments prompt if open
+    render_args_prompt(frame, state);
+
+    // Render confirmation prompt if pending (highest z-order)
+    render_confirm_dialog(frame, state);
+}
+
+/// Render the skill list and detail pane into the content area
+fn render_content(content_area: Rect, frame: &mut Frame, state: &AppState) {
+    let theme = state.theme();
+
+    // Responsive layout for content area (list + detail pane, or list only)
+    let (list_area, detail_area) = split_content(
+        content_area,
+        frame.size().width,
+        state.is_detail_pane_visible(),
+    );
 
     // Render skill list
     let skills: Vec<_> = state.filtered_skills().collect();
@@ -92,15 +150,59 @@ pub fn render(frame: &mut Frame, state: &AppState) {
         &skills,
         state.selected_index(),
         state.scroll_offset(),
+        state.search_query(),
+        state.favorites(),
         theme,
     );
 
+    let Some(detail_area) = detail_area else {
+        return;
+    };
+
     // Render detail pane (with empty state handling)
     if let Some(selected_skill) = state.selected_skill() {
-        render_detail_pane(detail_area, frame, selected_skill, theme);
+        let usage_count = state
+            .config()
+            .show_usage_count
+            .then(|| state.usage_count(&selected_skill.manifest.id));
+        // Split off a preview of the skill's last output from this session, if any
+        match state.last_output(&selected_skill.manifest.id) {
+            Some(output) => {
+                let detail_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Percentage(60), // Details
+                        Constraint::Percentage(40), // Last output preview
+                    ])
+                    .split(detail_area);
+                render_detail_pane(
+                    detail_chunks[0],
+                    frame,
+                    selected_skill,
+                    theme,
+                    usage_count,
+                    state.detail_scroll_offset(),
+                );
+                render_output_preview(
+                    detail_chunks[1],
+                    frame,
+                    output,
+                    state.translations().output_preview_title,
+                    theme,
+                );
+            }
+            None => render_detail_pane(
+                detail_area,
+                frame,
+                selected_skill,
+                theme,
+                usage_count,
+                state.detail_scroll_offset(),
+            ),
+        }
     } else {
         // Empty state: no skill selected - use translated message
-        let empty_message = Paragraph::new(state.translations().empty_skills_message)
+        let empty_message = Paragraph::new(empty_message(state))
             .block(
                 Block::default()
                     .title(state.translations().detail_pane_title)
@@ -111,17 +213,495 @@ pub fn render(frame: &mut Frame, state: &AppState) {
             .style(Style::default().fg(theme.text_dim));
         frame.render_widget(empty_message, detail_area);
     }
+}
 
-    // Render footer with view mode, input mode, and translations
-    render_footer(
-        main_chunks[3],
-        frame,
-        state.view_mode(),
-        state.input_mode(),
-        state.translations(),
-        theme,
+/// Message for an empty skill list, specific to the view mode when possible
+///
+/// An empty Favorites, Recent, or Disabled view gets its own message unless
+/// a search query, category, or tag filter could be what emptied it; every
+/// other case falls back to the generic `empty_skills_message`.
+fn empty_message(state: &AppState) -> &'static str {
+    let translations = state.translations();
+    let filtered = !state.search_query().is_empty()
+        || state.category_filter().is_some()
+        || !state.tag_filter().is_empty();
+    if filtered {
+        return translations.empty_skills_message;
+    }
+
+    match state.view_mode() {
+        ViewMode::All => translations.empty_skills_message,
+        ViewMode::Favorites => translations.empty_favorites_message,
+        ViewMode::Recent => translations.empty_recent_message,
+        ViewMode::Disabled => translations.empty_disabled_message,
+    }
+}
+
+/// Header label for the current input mode, e.g. "[NORMAL]" or "[INSERT]"
+///
+/// Reuses the footer's mode labels without their "--" decoration.
+fn mode_indicator(input_mode: &InputMode, translations: &Translations) -> String {
+    let label = match input_mode {
+        InputMode::Normal => translations.footer_normal_mode,
+        InputMode::Insert => translations.footer_insert_mode,
+    };
+    format!(
+        "[{}]",
+        label.trim_matches(|c: char| c == '-' || c.is_whitespace())
+    )
+}
+
+/// Spinner frames cycled through while skills are being discovered
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Render the loading message shown while skills are discovered in the background
+fn render_loading(area: Rect, frame: &mut Frame, state: &AppState) {
+    let theme = state.theme();
+    let spinner = SPINNER_FRAMES[state.loading_tick() % SPINNER_FRAMES.len()];
+
+    let loading = Paragraph::new(Line::from(vec![
+        Span::styled(spinner, Style::default().fg(theme.primary)),
+        Span::raw(" "),
+        Span::styled(
+            state.translations().loading_skills_message,
+            Style::default().fg(theme.text_dim),
+        ),
+    ]))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(theme.border_style)
+            .border_style(theme.border_style()),
     );
+    frame.render_widget(loading, area);
+}
 
-    // Render output panel overlay if visible (highest z-order)
-    render_output_panel(frame, state);
+/// Whether the first-run guidance should replace the list and detail pane
+///
+/// True only when no skills were discovered at all; skills that exist but
+/// are hidden or disabled get the normal empty-list message instead. While
+/// a search is typed the list stays, so the query remains visible.
+fn is_first_run(state: &AppState) -> bool {
+    !state.has_skills() && state.search_query().is_empty()
+}
+
+/// Render guidance explaining where skills are searched for and how to add one
+///
+/// Lists the configured `skill_paths` so a fresh install shows exactly which
+/// directories Pane scanned, and names the configured manifest files.
+fn render_first_run_guidance(area: Rect, frame: &mut Frame, state: &AppState) {
+    let theme = state.theme();
+    let translations = state.translations();
+
+    let mut lines = vec![
+        Line::styled(
+            translations.first_run_no_skills,
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+        Line::from(translations.first_run_search_paths_label),
+    ];
+    lines.extend(
+        state
+            .config()
+            .skill_paths
+            .iter()
+            .map(|path| Line::from(format!("  • {}", path.display()))),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        translations.first_run_add_skill_message(&state.config().manifest_filenames),
+        Style::default().fg(theme.text_dim),
+    ));
+
+    let guidance = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(translations.first_run_title)
+                .borders(Borders::ALL)
+                .border_type(theme.border_style)
+                .border_style(theme.border_style()),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(guidance, area);
+}
+
+/// Calculate the number of skill items visible in the list area
+///
+/// Mirrors the layout used by `render` so that scroll bounds computed in
+/// `AppState` match what is actually drawn on screen.
+///
+/// # Arguments
+///
+/// * `frame_area` - The full terminal area
+/// * `show_detail_pane` - Whether the detail pane shares the content area
+///
+/// # Returns
+///
+/// Number of skill items that fit in the list area at once
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use pane::ui::renderer::list_viewport_height;
+///
+/// let height = list_viewport_height(Rect::new(0, 0, 100, 30), true);
+/// assert_eq!(height, 10);
+/// ```
+pub fn list_viewport_height(frame_area: Rect, show_detail_pane: bool) -> usize {
+    let main_chunks = split_main(frame_area);
+    let (list_area, _) = split_content(main_chunks[2], frame_area.width, show_detail_pane);
+    (list_area.height / ITEM_HEIGHT) as usize
+}
+
+/// Split the frame into header, search bar, content area, and footer
+fn split_main(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Search bar
+            Constraint::Min(0),    // Content area (list + detail)
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+}
+
+/// Split the content area into list and detail pane areas
+///
+/// Responsive layout based on terminal width: side-by-side when wide,
+/// stacked when narrow. With the detail pane hidden, the list takes the
+/// whole content area and no detail area is returned.
+fn split_content(
+    content_area: Rect,
+    terminal_width: u16,
+    show_detail_pane: bool,
+) -> (Rect, Option<Rect>) {
+    if !show_detail_pane {
+        (content_area, None)
+    } else if terminal_width >= 80 {
+        // Wide terminal: side-by-side layout
+        let horizontal_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(50), // Skill list (left)
+                Constraint::Percentage(50), // Detail pane (right)
+            ])
+            .split(content_area);
+        (horizontal_chunks[0], Some(horizontal_chunks[1]))
+    } else {
+        // Narrow terminal: stacked layout
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(60), // Skill list (top)
+                Constraint::Percentage(40), // Detail pane (bottom)
+            ])
+            .split(content_area);
+        (vertical_chunks[0], Some(vertical_chunks[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::session::Session;
+    use crate::skills::{Skill, SkillManifest, SkillSource};
+    use ratatui::{backend::TestBackend, Terminal};
+    use rstest::rstest;
+    use std::path::PathBuf;
+
+    fn render_to_string(state: &AppState) -> String {
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|frame| render(frame, state)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_shows_first_run_guidance_when_no_skills_discovered() {
+        // Arrange
+        let config = Config {
+            skill_paths: vec![PathBuf::from("/opt/team-skills")],
+            ..Config::default()
+        };
+        let state = AppState::new(vec![], config);
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("Welcome to Pane"));
+        assert!(rendered.contains("No skills were found."));
+        assert!(rendered.contains("/opt/team-skills"));
+        assert!(!rendered.contains("No skills available"));
+    }
+
+    #[test]
+    fn test_render_first_run_hint_names_configured_manifest_file() {
+        // Arrange
+        let config = Config {
+            manifest_filenames: vec!["skill.yaml".to_string()],
+            ..Config::default()
+        };
+        let state = AppState::new(vec![], config);
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("skill.yaml"));
+        assert!(!rendered.contains("pane-skill.yaml"));
+    }
+
+    #[test]
+    fn test_render_skips_first_run_guidance_when_all_skills_hidden() {
+        // Arrange
+        let mut skill = state_in_view(ViewMode::All, "")
+            .selected_skill()
+            .cloned()
+            .unwrap();
+        skill.manifest.hidden = true;
+        let state = AppState::new(vec![skill], Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(!rendered.contains("Welcome to Pane"));
+        assert!(rendered.contains("No skills available"));
+    }
+
+    #[test]
+    fn test_render_skips_first_run_guidance_while_searching() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+        state.set_search_query("deploy".to_string());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(!rendered.contains("Welcome to Pane"));
+        assert!(rendered.contains("No skills available"));
+    }
+
+    /// State with one discovered skill, switched to `view_mode` and `query`
+    fn state_in_view(view_mode: ViewMode, query: &str) -> AppState {
+        let skill = Skill {
+            manifest: SkillManifest::from_yaml_str(
+                "id: demo\nname: Demo\ndescription: Demo skill\nversion: 1.0.0\n\
+                 exec: echo\nui:\n  mode: inline\n",
+            )
+            .unwrap(),
+            source: SkillSource::User,
+            manifest_path: PathBuf::from("test.yaml"),
+            estimated_duration: None,
+        };
+        let mut state = AppState::new(vec![skill], Config::default());
+        state.restore_session(Session {
+            view_mode,
+            search_query: query.to_string(),
+            ..Session::default()
+        });
+        state
+    }
+
+    #[rstest]
+    #[case(ViewMode::Favorites, "No favorites yet — press f to add")]
+    #[case(ViewMode::Recent, "No recently used skills")]
+    #[case(ViewMode::Disabled, "No disabled skills")]
+    fn test_render_empty_view_shows_view_specific_message(
+        #[case] view_mode: ViewMode,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        let state = state_in_view(view_mode, "");
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains(expected));
+        assert!(!rendered.contains("No skills available"));
+    }
+
+    #[test]
+    fn test_render_empty_view_while_searching_shows_generic_message() {
+        // Arrange
+        let state = state_in_view(ViewMode::Favorites, "nothing-matches");
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("No skills available"));
+        assert!(!rendered.contains("No favorites yet"));
+    }
+
+    #[test]
+    fn test_render_shows_loading_message_instead_of_first_run_guidance() {
+        // Arrange
+        let state = AppState::new_loading(Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("Discovering skills…"));
+        assert!(!rendered.contains("Welcome to Pane"));
+        assert!(!rendered.contains("No skills available"));
+    }
+
+    #[test]
+    fn test_render_header_shows_input_mode_indicator() {
+        // Arrange
+        let mut state = AppState::new(vec![], Config::default());
+
+        // Act
+        let normal = render_to_string(&state);
+        state.enter_insert_mode();
+        let insert = render_to_string(&state);
+
+        // Assert
+        assert!(normal.contains("[NORMAL]"));
+        assert!(!normal.contains("[INSERT]"));
+        assert!(insert.contains("[INSERT]"));
+        assert!(!insert.contains("[NORMAL]"));
+    }
+
+    #[test]
+    fn test_list_viewport_height_wide_terminal_uses_full_content_height() {
+        // Arrange - 40 rows: 9 rows of chrome leaves 31 rows of content
+        let frame_area = Rect::new(0, 0, 100, 40);
+
+        // Act
+        let height = list_viewport_height(frame_area, true);
+
+        // Assert - two rows per skill item
+        assert_eq!(height, 15);
+    }
+
+    #[test]
+    fn test_list_viewport_height_narrow_terminal_uses_stacked_height() {
+        // Arrange - 40 rows: 31 rows of content, 60% for the list
+        let frame_area = Rect::new(0, 0, 60, 40);
+
+        // Act
+        let height = list_viewport_height(frame_area, true);
+
+        // Assert
+        assert_eq!(height, 9);
+    }
+
+    #[test]
+    fn test_list_viewport_height_without_detail_pane_uses_whole_content_area() {
+        // Arrange - 40 rows: 31 rows of content, all for the list
+        let frame_area = Rect::new(0, 0, 60, 40);
+
+        // Act
+        let height = list_viewport_height(frame_area, false);
+
+        // Assert
+        assert_eq!(height, 15);
+    }
+
+    #[test]
+    fn test_render_list_only_layout_gives_list_full_width() {
+        // Arrange - a name too long for half of a 120-column terminal
+        let long_name = "Deploy The Staging Environment And Run Every Smoke Test Suite";
+        let skill = Skill {
+            manifest: SkillManifest::from_yaml_str(&format!(
+                "id: demo\nname: {}\ndescription: Demo skill\nversion: 1.0.0\n\
+                 exec: echo\nui:\n  mode: inline\n",
+                long_name
+            ))
+            .unwrap(),
+            source: SkillSource::User,
+            manifest_path: PathBuf::from("test.yaml"),
+            estimated_duration: None,
+        };
+        let mut state = AppState::new(vec![skill], Config::default());
+        // The list ends where the detail pane's left border starts on the
+        // content area's top row (row 6), or at the frame edge without one
+        let list_width = |state: &AppState| {
+            let backend = TestBackend::new(120, 30);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal.draw(|frame| render(frame, state)).unwrap();
+            let buffer = terminal.backend().buffer();
+            let width = (0..120)
+                .find(|&x| matches!(buffer.get(x, 6).symbol(), "┌" | "╭" | "╔" | "┏"))
+                .unwrap_or(120);
+            let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+            (width, rendered.contains(long_name))
+        };
+
+        // Act
+        let (split_width, split_shows_name) = list_width(&state);
+        state.toggle_detail_pane();
+        let (list_only_width, list_only_shows_name) = list_width(&state);
+
+        // Assert
+        assert_eq!(split_width, 60);
+        assert!(!split_shows_name);
+        assert_eq!(list_only_width, 120);
+        assert!(list_only_shows_name);
+    }
+
+    #[test]
+    fn test_render_no_color_uses_no_colors() {
+        // Arrange - an accented, tagged skill with a failed, truncated run
+        let mut skill = crate::test_support::test_skill("deploy");
+        skill.manifest.accent = Some(ratatui::style::Color::Red);
+        skill.manifest.tags = vec!["ops".to_string()];
+        let output = crate::skills::output::SkillOutput {
+            stdout: "# Report\n- item".to_string(),
+            stderr: "disk full".to_string(),
+            exit_code: Some(1),
+            truncated: true,
+            execution_time: std::time::Duration::from_millis(5),
+            format: crate::skills::manifest::OutputFormat::Markdown,
+            attempts: 2,
+        };
+        let config = Config {
+            no_color: true,
+            ..Config::default()
+        };
+        let mut state = AppState::new(vec![skill], config);
+        state.record_last_output("deploy".to_string(), &output);
+        state.set_status_message("Config reloaded".to_string());
+        let colors = |state: &AppState| {
+            let backend = TestBackend::new(120, 30);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal.draw(|frame| render(frame, state)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .flat_map(|cell| [cell.fg, cell.bg])
+                .filter(|color| *color != ratatui::style::Color::Reset)
+                .collect::<Vec<_>>()
+        };
+
+        // Act
+        let list_colors = colors(&state);
+        state.show_skill_output("Deploy".to_string(), output);
+        let panel_colors = colors(&state);
+
+        // Assert
+        assert!(list_colors.is_empty(), "colors used: {:?}", list_colors);
+        assert!(panel_colors.is_empty(), "colors used: {:?}", panel_colors);
+    }
 }
@@ -0,0 +1,126 @@
+use ratatui::{
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::state::AppState;
+use crate::ui::output_panel::centered_rect;
+
+/// Render the tag picker as a modal overlay
+///
+/// Lists "clear tags" followed by every tag found across the discovered
+/// skills with the number of skills carrying it. Selected tags are checked;
+/// the list is narrowed to skills that carry all of them.
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui Frame to render into
+/// * `state` - Application state containing the tags and picker selection
+///
+/// # Returns
+///
+/// Returns early if the tag picker is not visible.
+pub fn render_tag_picker(frame: &mut Frame, state: &AppState) {
+    if !state.is_tag_picker_visible() {
+        return;
+    }
+
+    let theme = state.theme();
+    let translations = state.translations();
+    let selected = state.tag_filter();
+
+    // "Clear tags" row first, then each tag with its skill count
+    let mut items = vec![ListItem::new(format!(
+        "    {}",
+        translations.tag_clear_label
+    ))];
+    items.extend(state.tags().into_iter().map(|(tag, count)| {
+        let checkbox = if selected.contains(&tag) {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        ListItem::new(format!("{} {} ({})", checkbox, tag, count))
+    }));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(translations.tag_picker_title)
+                .borders(Borders::ALL)
+                .border_type(theme.border_style)
+                .border_style(Style::default().fg(theme.primary)),
+        )
+        .highlight_style(theme.selected_style());
+
+    let mut list_state = ListState::default().with_selected(Some(state.tag_picker_index()));
+
+    let area = centered_rect(50, 60, frame.size());
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::skills::Skill;
+    use crate::test_support::test_skill;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn create_test_skill(id: &str, tags: &[&str]) -> Skill {
+        let mut skill = test_skill(id);
+        skill.manifest.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        skill
+    }
+
+    fn render_to_string(state: &AppState) -> String {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_tag_picker(frame, state))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_tag_picker_lists_tags_with_counts_and_selection() {
+        // Arrange
+        let skills = vec![
+            create_test_skill("skill1", &["docker", "build"]),
+            create_test_skill("skill2", &["docker"]),
+        ];
+        let mut state = AppState::new(skills, Config::default());
+        state.toggle_tag_filter("docker");
+        state.open_tag_picker();
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(rendered.contains("Clear tags"));
+        assert!(rendered.contains("[ ] build (1)"));
+        assert!(rendered.contains("[x] docker (2)"));
+    }
+
+    #[test]
+    fn test_render_tag_picker_hidden_renders_nothing() {
+        // Arrange
+        let skills = vec![create_test_skill("skill1", &["docker"])];
+        let state = AppState::new(skills, Config::default());
+
+        // Act
+        let rendered = render_to_string(&state);
+
+        // Assert
+        assert!(!rendered.contains("Clear tags"));
+    }
+}
@@ -0,0 +1,305 @@
+//! Width-aware text wrapping shared by the panels
+//!
+//! Wrapping here rather than with ratatui's `Wrap` lets the panels know
+//! exactly how many rows their content takes, so scroll bounds match what is
+//! drawn. Widths are measured in terminal columns, so CJK and other wide
+//! characters count as two.
+
+use std::ops::Range;
+
+use ratatui::text::{Line, Span};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Wrap text to a column width, one entry per terminal row
+///
+/// Each line of `text` is wrapped on its own, so line breaks are kept and an
+/// empty line stays an empty row. Lines break at whitespace, which is dropped
+/// at the break; words wider than `width` are split between characters.
+/// Leading whitespace on a line (indentation) is kept.
+///
+/// # Arguments
+///
+/// * `text` - The text to wrap, possibly spanning several lines
+/// * `width` - Columns available per row (treated as 1 if 0)
+///
+/// # Returns
+///
+/// The wrapped rows, none wider than `width` unless a single character is
+///
+/// # Examples
+///
+/// ```
+/// use pane::ui::text::wrap_lines;
+///
+/// assert_eq!(wrap_lines("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+/// assert_eq!(wrap_lines("한국어", 4), vec!["한국", "어"]);
+/// ```
+pub fn wrap_lines(text: &str, width: usize) -> Vec<String> {
+    text.lines()
+        .flat_map(|line| {
+            wrap_ranges(line, width)
+                .into_iter()
+                .map(move |range| line[range].to_string())
+        })
+        .collect()
+}
+
+/// Wrap a styled line to a column width, keeping each span's style
+///
+/// Breaks fall exactly where `wrap_lines` puts them for the line's text.
+///
+/// # Arguments
+///
+/// * `line` - The line to wrap (must not contain line breaks)
+/// * `width` - Columns available per row (treated as 1 if 0)
+///
+/// # Returns
+///
+/// One line per terminal row, with the original line style and alignment
+pub fn wrap_line(line: &Line<'_>, width: usize) -> Vec<Line<'static>> {
+    let text = line_text(line);
+
+    wrap_ranges(&text, width)
+        .into_iter()
+        .map(|range| {
+            let mut spans = Vec::new();
+            let mut span_start = 0;
+            for span in &line.spans {
+                let span_end = span_start + span.content.len();
+                let start = range.start.max(span_start);
+                let end = range.end.min(span_end);
+                if start < end {
+                    spans.push(Span::styled(
+                        span.content[start - span_start..end - span_start].to_string(),
+                        span.style,
+                    ));
+                }
+                span_start = span_end;
+            }
+            Line {
+                spans,
+                style: line.style,
+                alignment: line.alignment,
+            }
+        })
+        .collect()
+}
+
+/// Plain text of a styled line, with its spans joined
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::{Line, Span};
+/// use pane::ui::text::line_text;
+///
+/// let line = Line::from(vec![Span::raw("error: "), Span::raw("disk full")]);
+/// assert_eq!(line_text(&line), "error: disk full");
+/// ```
+pub fn line_text(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+/// Number of rows a line takes once wrapped to a column width
+///
+/// Counts the rows `wrap_lines` would produce without building them.
+///
+/// # Arguments
+///
+/// * `line` - The line to measure (must not contain line breaks)
+/// * `width` - Columns available per row (treated as 1 if 0)
+///
+/// # Examples
+///
+/// ```
+/// use pane::ui::text::wrapped_row_count;
+///
+/// assert_eq!(wrapped_row_count("the quick brown fox", 10), 2);
+/// assert_eq!(wrapped_row_count("", 10), 1);
+/// ```
+pub fn wrapped_row_count(line: &str, width: usize) -> usize {
+    wrap_ranges(line, width).len()
+}
+
+/// Byte ranges of `line` that make up each wrapped row
+///
+/// Always returns at least one (possibly empty) range.
+fn wrap_ranges(line: &str, width: usize) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    // Current row as a byte range plus its width in columns
+    let mut row: Option<(Range<usize>, usize)> = None;
+    // Whitespace between the current row and the next word
+    let mut gap = 0;
+
+    for (index, segment) in segments(line).into_iter().enumerate() {
+        let segment_width = line[segment.clone()].width();
+        let is_space = line[segment.clone()].starts_with(char::is_whitespace);
+
+        // Whitespace after the indentation is only a break opportunity
+        if is_space && index > 0 {
+            gap = segment_width;
+            continue;
+        }
+
+        match row.as_mut() {
+            Some((range, row_width)) if *row_width + gap + segment_width <= width => {
+                range.end = segment.end;
+                *row_width += gap + segment_width;
+            }
+            _ => {
+                if let Some((range, _)) = row.take() {
+                    rows.push(range);
+                }
+                row = Some(split_long_word(line, segment, width, &mut rows));
+            }
+        }
+        gap = 0;
+    }
+
+    if let Some((range, _)) = row {
+        rows.push(range);
+    }
+    if rows.is_empty() {
+        rows.push(0..0);
+    }
+    rows
+}
+
+/// Start a new row with a word, first emitting full rows if it is too wide
+///
+/// # Returns
+///
+/// The range and width of the word's last piece, which becomes the current row
+fn split_long_word(
+    line: &str,
+    word: Range<usize>,
+    width: usize,
+    rows: &mut Vec<Range<usize>>,
+) -> (Range<usize>, usize) {
+    let mut start = word.start;
+    let mut row_width = 0;
+    for (offset, c) in line[word.clone()].char_indices() {
+        let position = word.start + offset;
+        let char_width = c.width().unwrap_or(0);
+        // A character wider than the row still gets a row of its own
+        if row_width + char_width > width && position > start {
+            rows.push(start..position);
+            start = position;
+            row_width = 0;
+        }
+        row_width += char_width;
+    }
+    (start..word.end, row_width)
+}
+
+/// Split a line into alternating runs of whitespace and non-whitespace
+fn segments(line: &str) -> Vec<Range<usize>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (index, c) in line.char_indices() {
+        let is_space = c.is_whitespace();
+        if in_space.is_some_and(|previous| previous != is_space) {
+            segments.push(start..index);
+            start = index;
+        }
+        in_space = Some(is_space);
+    }
+    if start < line.len() {
+        segments.push(start..line.len());
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn test_wrap_lines_breaks_ascii_at_spaces() {
+        // Act
+        let rows = wrap_lines("the quick brown fox jumps", 10);
+
+        // Assert
+        assert_eq!(rows, vec!["the quick", "brown fox", "jumps"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_counts_cjk_as_two_columns() {
+        // Act
+        let unspaced = wrap_lines("漢字漢字漢字", 5);
+        let spaced = wrap_lines("스킬을 찾는 중", 8);
+
+        // Assert
+        assert_eq!(unspaced, vec!["漢字", "漢字", "漢字"]);
+        assert_eq!(spaced, vec!["스킬을", "찾는 중"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_splits_words_longer_than_width() {
+        // Act
+        let rows = wrap_lines("see abcdefghijkl now", 5);
+
+        // Assert
+        assert_eq!(rows, vec!["see", "abcde", "fghij", "kl", "now"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_keeps_line_breaks_and_indentation() {
+        // Act
+        let rows = wrap_lines("first\n\n  indented text here", 12);
+
+        // Assert
+        assert_eq!(rows, vec!["first", "", "  indented", "text here"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_gives_wide_character_its_own_row_when_too_narrow() {
+        // Act
+        let rows = wrap_lines("漢字", 1);
+
+        // Assert
+        assert_eq!(rows, vec!["漢", "字"]);
+    }
+
+    #[test]
+    fn test_wrapped_row_count_matches_wrap_lines() {
+        // Arrange
+        let lines = [
+            "the quick brown fox jumps",
+            "漢字漢字漢字",
+            "see abcdefghijkl now",
+        ];
+
+        // Act & Assert
+        for line in lines {
+            assert_eq!(wrapped_row_count(line, 5), wrap_lines(line, 5).len());
+        }
+    }
+
+    #[test]
+    fn test_wrap_line_keeps_span_styles_across_rows() {
+        // Arrange
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![
+            Span::raw("error: "),
+            Span::styled("disk full now", red),
+        ]);
+
+        // Act
+        let rows = wrap_line(&line, 11);
+
+        // Assert
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].spans[0].content, "error: ");
+        assert_eq!(rows[0].spans[1].content, "disk");
+        assert_eq!(rows[0].spans[1].style, red);
+        assert_eq!(rows[1].spans[0].content, "full now");
+        assert_eq!(rows[1].spans[0].style, red);
+    }
+}
@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// // Access theme colors
 /// let primary_color = theme.primary;
-/// let header = theme.header_style;
+/// let header = theme.header_style();
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThemeConfig {
@@ -60,6 +60,72 @@ pub struct ThemeConfig {
     /// Border type for all blocks
     #[serde(with = "border_type_serde")]
     pub border_style: BorderType,
+
+    /// Marker drawn in place of "●" on the selected row (e.g. "▶"), so the
+    /// selection shows even without background colors (default: none)
+    #[serde(default)]
+    pub selected_marker: Option<String>,
+
+    /// Render the selected row in bold (default: false)
+    #[serde(default)]
+    pub selected_bold: bool,
+
+    /// Foreground color of the selected row (default: unchanged)
+    #[serde(default, with = "option_color_serde")]
+    pub selected_fg: Option<Color>,
+
+    /// Show the selected row in reverse video instead of the `highlight`
+    /// background (default: false)
+    #[serde(default)]
+    pub selected_reverse: bool,
+
+    /// Scrollbar track color, shown beside long lists and output (default: DarkGray)
+    #[serde(default = "default_scrollbar_track", with = "color_serde")]
+    pub scrollbar_track: Color,
+
+    /// Scrollbar thumb color, marking the visible part (default: Gray)
+    #[serde(default = "default_scrollbar_thumb", with = "color_serde")]
+    pub scrollbar_thumb: Color,
+
+    /// Color for a successful run, e.g. exit code 0 (default: Green)
+    #[serde(default = "default_success", with = "color_serde")]
+    pub success: Color,
+
+    /// Color for failures and stderr output (default: Red)
+    #[serde(default = "default_error", with = "color_serde")]
+    pub error: Color,
+
+    /// Color for warnings such as truncated output and status toasts (default: Yellow)
+    #[serde(default = "default_warning", with = "color_serde")]
+    pub warning: Color,
+
+    /// Tint skills with the `accent` color from their manifest (default: true)
+    #[serde(default = "default_skill_accents")]
+    pub skill_accents: bool,
+}
+
+fn default_scrollbar_track() -> Color {
+    Color::DarkGray
+}
+
+fn default_scrollbar_thumb() -> Color {
+    Color::Gray
+}
+
+fn default_success() -> Color {
+    Color::Green
+}
+
+fn default_error() -> Color {
+    Color::Red
+}
+
+fn default_warning() -> Color {
+    Color::Yellow
+}
+
+fn default_skill_accents() -> bool {
+    true
 }
 
 impl Default for ThemeConfig {
@@ -84,11 +150,62 @@ impl Default for ThemeConfig {
             tag_bg: Color::Blue,
             tag_fg: Color::White,
             border_style: BorderType::Rounded,
+            selected_marker: None,
+            selected_bold: false,
+            selected_fg: None,
+            selected_reverse: false,
+            scrollbar_track: default_scrollbar_track(),
+            scrollbar_thumb: default_scrollbar_thumb(),
+            success: default_success(),
+            error: default_error(),
+            warning: default_warning(),
+            skill_accents: default_skill_accents(),
         }
     }
 }
 
 impl ThemeConfig {
+    /// Returns a theme that uses no colors, only bold and reverse video
+    ///
+    /// Every color is the terminal's default (`Color::Reset`), and the
+    /// selected row is shown bold and reversed, and manifest `accent` colors
+    /// are ignored. Used when `no_color` is set in the config or the
+    /// `NO_COLOR` environment variable is present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Modifier};
+    /// use pane::ui::theme::ThemeConfig;
+    ///
+    /// let theme = ThemeConfig::monochrome();
+    /// assert_eq!(theme.primary, Color::Reset);
+    /// assert!(theme.selected_style().add_modifier.contains(Modifier::REVERSED));
+    /// ```
+    pub fn monochrome() -> Self {
+        Self {
+            primary: Color::Reset,
+            secondary: Color::Reset,
+            highlight: Color::Reset,
+            border: Color::Reset,
+            text: Color::Reset,
+            text_dim: Color::Reset,
+            tag_bg: Color::Reset,
+            tag_fg: Color::Reset,
+            border_style: BorderType::Rounded,
+            selected_marker: None,
+            selected_bold: true,
+            selected_fg: None,
+            selected_reverse: true,
+            scrollbar_track: Color::Reset,
+            scrollbar_thumb: Color::Reset,
+            success: Color::Reset,
+            error: Color::Reset,
+            warning: Color::Reset,
+            skill_accents: false,
+        }
+    }
+
     /// Returns the header style based on theme configuration
     ///
     /// # Example
@@ -116,7 +233,18 @@ impl ThemeConfig {
     /// let selected_style = theme.selected_style();
     /// ```
     pub fn selected_style(&self) -> Style {
-        Style::default().bg(self.highlight)
+        let mut style = if self.selected_reverse {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().bg(self.highlight)
+        };
+        if let Some(fg) = self.selected_fg {
+            style = style.fg(fg);
+        }
+        if self.selected_bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        style
     }
 
     /// Returns the tag chip style based on theme configuration
@@ -160,6 +288,60 @@ impl ThemeConfig {
     pub fn border_style(&self) -> Style {
         Style::default().fg(self.border)
     }
+
+    /// Returns the scrollbar track style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pane::ui::theme::ThemeConfig;
+    ///
+    /// let theme = ThemeConfig::default();
+    /// let track = theme.scrollbar_track_style();
+    /// ```
+    pub fn scrollbar_track_style(&self) -> Style {
+        Style::default().fg(self.scrollbar_track)
+    }
+
+    /// Returns the scrollbar thumb style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pane::ui::theme::ThemeConfig;
+    ///
+    /// let theme = ThemeConfig::default();
+    /// let thumb = theme.scrollbar_thumb_style();
+    /// ```
+    pub fn scrollbar_thumb_style(&self) -> Style {
+        Style::default().fg(self.scrollbar_thumb)
+    }
+
+    /// Returns the color a skill is tinted with
+    ///
+    /// # Arguments
+    ///
+    /// * `accent` - The `accent` color from the skill's manifest, if any
+    ///
+    /// # Returns
+    ///
+    /// The accent when set and `skill_accents` is enabled, otherwise `primary`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use pane::ui::theme::ThemeConfig;
+    ///
+    /// assert_eq!(ThemeConfig::default().accent_color(Some(Color::Red)), Color::Red);
+    /// assert_eq!(ThemeConfig::monochrome().accent_color(Some(Color::Red)), Color::Reset);
+    /// ```
+    pub fn accent_color(&self, accent: Option<Color>) -> Color {
+        match accent {
+            Some(accent) if self.skill_accents => accent,
+            _ => self.primary,
+        }
+    }
 }
 
 // Custom serde implementations for ratatui types
@@ -219,6 +401,32 @@ mod color_serde {
     }
 }
 
+/// Serde helpers for optional colors, shared with skill manifests
+pub(crate) mod option_color_serde {
+    use ratatui::style::Color;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(color: &Option<Color>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match color {
+            Some(color) => super::color_serde::serialize(color, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super::color_serde")] Color);
+
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(color)| color))
+    }
+}
+
 mod border_type_serde {
     use ratatui::widgets::BorderType;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -270,6 +478,16 @@ mod tests {
         assert_eq!(theme.tag_bg, Color::Blue);
         assert_eq!(theme.tag_fg, Color::White);
         assert_eq!(theme.border_style, BorderType::Rounded);
+        assert_eq!(theme.selected_marker, None);
+        assert!(!theme.selected_bold);
+        assert_eq!(theme.selected_fg, None);
+        assert!(!theme.selected_reverse);
+        assert_eq!(theme.scrollbar_track, Color::DarkGray);
+        assert_eq!(theme.scrollbar_thumb, Color::Gray);
+        assert_eq!(theme.success, Color::Green);
+        assert_eq!(theme.error, Color::Red);
+        assert_eq!(theme.warning, Color::Yellow);
+        assert!(theme.skill_accents);
     }
 
     #[test]
@@ -310,6 +528,10 @@ mod tests {
         let theme = theme.unwrap();
         assert_eq!(theme.primary, Color::Cyan);
         assert_eq!(theme.border_style, BorderType::Rounded);
+        assert_eq!(theme.scrollbar_track, Color::DarkGray);
+        assert_eq!(theme.scrollbar_thumb, Color::Gray);
+        assert_eq!(theme.error, Color::Red);
+        assert!(theme.skill_accents);
     }
 
     #[test]
@@ -373,4 +595,70 @@ mod tests {
         // Assert
         assert_eq!(style.fg, Some(Color::Gray));
     }
+
+    #[test]
+    fn test_theme_config_selection_fields_deserialize() {
+        // Arrange
+        let toml_str = r#"
+            primary = "Cyan"
+            secondary = "Blue"
+            highlight = "DarkGray"
+            border = "Gray"
+            text = "White"
+            text_dim = "DarkGray"
+            tag_bg = "Blue"
+            tag_fg = "White"
+            border_style = "Rounded"
+            selected_marker = "▶"
+            selected_bold = true
+            selected_fg = "Yellow"
+            scrollbar_track = "Black"
+            scrollbar_thumb = "LightCyan"
+        "#;
+
+        // Act
+        let theme: ThemeConfig = toml::from_str(toml_str).unwrap();
+
+        // Assert
+        assert_eq!(theme.selected_marker.as_deref(), Some("▶"));
+        assert_eq!(theme.scrollbar_track_style().fg, Some(Color::Black));
+        assert_eq!(theme.scrollbar_thumb_style().fg, Some(Color::LightCyan));
+        assert_eq!(
+            theme.selected_style(),
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn test_monochrome_theme_has_no_colors() {
+        // Arrange & Act
+        let theme = ThemeConfig::monochrome();
+
+        // Assert
+        for color in [
+            theme.primary,
+            theme.secondary,
+            theme.highlight,
+            theme.border,
+            theme.text,
+            theme.text_dim,
+            theme.tag_bg,
+            theme.tag_fg,
+            theme.scrollbar_track,
+            theme.scrollbar_thumb,
+            theme.success,
+            theme.error,
+            theme.warning,
+            theme.accent_color(Some(Color::Red)),
+        ] {
+            assert_eq!(color, Color::Reset);
+        }
+        assert_eq!(
+            theme.selected_style(),
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        );
+    }
 }
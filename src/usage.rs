@@ -0,0 +1,118 @@
+//! Persistence for per-skill execution counts
+//!
+//! Counts are stored as a YAML map of skill ID to run count so the
+//! sort-by-usage order and the detail pane badge survive restarts. A missing
+//! or unreadable usage file is treated as "never run" rather than an error.
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// File name of the usage file inside the Pane state directory
+const USAGE_FILE: &str = "usage.yaml";
+
+/// Returns the default location of the usage file
+///
+/// Uses `$XDG_STATE_HOME/pane/usage.yaml`, falling back to
+/// `~/.local/state/pane/usage.yaml`.
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set
+pub fn default_usage_path() -> Option<PathBuf> {
+    Some(state_dir()?.join(USAGE_FILE))
+}
+
+/// Returns the Pane state directory, `$XDG_STATE_HOME/pane` or `~/.local/state/pane`
+///
+/// # Returns
+///
+/// `None` if neither `XDG_STATE_HOME` nor `HOME` is set
+pub(crate) fn state_dir() -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))?;
+    Some(state_home.join("pane"))
+}
+
+/// Load execution counts from `path`
+///
+/// # Arguments
+///
+/// * `path` - Location of the usage file
+///
+/// # Returns
+///
+/// The stored counts by skill ID, or an empty map if the file is missing or corrupt
+pub fn load_usage(path: &Path) -> HashMap<String, u64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_yaml::from_str::<HashMap<String, u64>>(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Save execution counts to `path`, creating parent directories
+///
+/// IDs are written sorted so the file is stable between saves.
+///
+/// # Arguments
+///
+/// * `path` - Location of the usage file
+/// * `usage` - Counts by skill ID to persist
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file cannot be written
+pub fn save_usage(path: &Path, usage: &HashMap<String, u64>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory {:?}", parent))?;
+    }
+
+    let sorted: BTreeMap<&String, &u64> = usage.iter().collect();
+    let contents = serde_yaml::to_string(&sorted).context("Failed to serialize usage counts")?;
+
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write usage counts to {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_round_trip() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(USAGE_FILE);
+        let usage: HashMap<String, u64> = [("deploy".to_string(), 3), ("tips".to_string(), 1)]
+            .into_iter()
+            .collect();
+
+        // Act
+        save_usage(&path, &usage).unwrap();
+        let loaded = load_usage(&path);
+
+        // Assert
+        assert_eq!(loaded, usage);
+    }
+
+    #[test]
+    fn test_load_usage_missing_or_corrupt_file_is_empty() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let corrupt = dir.path().join("corrupt.yaml");
+        std::fs::write(&corrupt, "deploy: [not a count").unwrap();
+
+        // Act
+        let missing = load_usage(&dir.path().join(USAGE_FILE));
+        let corrupt = load_usage(&corrupt);
+
+        // Assert
+        assert!(missing.is_empty());
+        assert!(corrupt.is_empty());
+    }
+}
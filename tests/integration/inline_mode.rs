@@ -1,7 +1,7 @@
 use pane::{
     config::Config,
     skills::{
-        manifest::{ContextConfig, SkillManifest, UiConfig, UiMode},
+        manifest::{ContextConfig, OutputFormat, SkillManifest, UiConfig, UiMode},
         Skill, SkillSource,
     },
     state::AppState,
@@ -23,11 +23,26 @@ fn create_test_skill(id: &str, name: &str, ui_mode: UiMode) -> Skill {
             ui: UiConfig {
                 mode: ui_mode,
                 fullscreen: true,
+                capture: false,
             },
             context: ContextConfig::default(),
+            category: None,
+            hidden: false,
+            output_format: OutputFormat::Text,
+            long_description: None,
+            schema_version: 1,
+            exec_windows: None,
+            exec_macos: None,
+            exec_linux: None,
+            post_run: None,
+            accent: None,
+            retries: 0,
+            pty: false,
+            hint: None,
         },
         source: SkillSource::Project,
         manifest_path: PathBuf::from(format!("/test/{}.yaml", id)),
+        estimated_duration: None,
     }
 }
 
@@ -98,6 +113,8 @@ fn test_output_panel_visibility_toggle() {
         exit_code: Some(0),
         truncated: false,
         execution_time: std::time::Duration::from_millis(100),
+        format: OutputFormat::Text,
+        attempts: 1,
     };
 
     state.show_output_panel(output);
@@ -183,6 +200,8 @@ fn test_context_aware_esc_handling_with_output_panel() {
         exit_code: Some(0),
         truncated: false,
         execution_time: std::time::Duration::from_millis(50),
+        format: OutputFormat::Text,
+        attempts: 1,
     };
     state.show_output_panel(output);
 
@@ -234,6 +253,8 @@ fn test_scroll_output_panel_content() {
         exit_code: Some(0),
         truncated: false,
         execution_time: std::time::Duration::from_millis(100),
+        format: OutputFormat::Text,
+        attempts: 1,
     };
     state.show_output_panel(output);
 
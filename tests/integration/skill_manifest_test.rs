@@ -57,23 +57,23 @@ fn test_claude_tips_manifest_valid() {
     assert_eq!(manifest.ui.mode, UiMode::Tui, "UI mode should be TUI");
 
     // Assert ui.fullscreen is true
-    assert_eq!(manifest.ui.fullscreen, true, "UI fullscreen should be true");
+    assert!(manifest.ui.fullscreen, "UI fullscreen should be true");
 
     // Assert context configuration is set to false (self-contained skill)
-    assert_eq!(
-        manifest.context.pass_cwd, false,
+    assert!(
+        !manifest.context.pass_cwd,
         "pass_cwd should be false for self-contained skill"
     );
-    assert_eq!(
-        manifest.context.pass_git_root, false,
+    assert!(
+        !manifest.context.pass_git_root,
         "pass_git_root should be false for self-contained skill"
     );
-    assert_eq!(
-        manifest.context.pass_project_name, false,
+    assert!(
+        !manifest.context.pass_project_name,
         "pass_project_name should be false for self-contained skill"
     );
-    assert_eq!(
-        manifest.context.pass_stdin_json, false,
+    assert!(
+        !manifest.context.pass_stdin_json,
         "pass_stdin_json should be false for self-contained skill"
     );
 }